@@ -4397,11 +4397,12 @@ pub trait Aax : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
                     panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
                 } else {
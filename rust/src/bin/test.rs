@@ -1,4 +1,4 @@
-use ccxt::exchange::{Exchange, Value, normalize, ValueTrait};
+use ccxt::exchange::{Value, normalize};
 use ccxt::binance::{Binance, BinanceImpl};
 
 use serde_json::json;
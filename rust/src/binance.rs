@@ -11,10 +11,12 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
+use crate::retry::idempotency_context;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -122,6 +124,13 @@ pub trait Binance : Exchange {
             "urls": {
                 "logo": "https://user-images.githubusercontent.com/1294454/29604020-d5483cdc-87ee-11e7-94c7-d1a8d9169293.jpg",
                 "api": {
+                    "ws": {
+                        "spot": "wss://stream.binance.com:9443/stream",
+                        "margin": "wss://stream.binance.com:9443/stream",
+                        "future": "wss://fstream.binance.com/stream",
+                        "delivery": "wss://dstream.binance.com/stream",
+                        "api": "wss://ws-api.binance.com:443/ws-api/v3"
+                    },
                     "wapi": "https://api.binance.com/wapi/v3",
                     "sapi": "https://api.binance.com/sapi/v1",
                     "sapiV3": "https://api.binance.com/sapi/v3",
@@ -145,6 +154,13 @@ pub trait Binance : Exchange {
                 ],
                 "fees": "https://www.binance.com/en/fee/schedule",
                 "test": {
+                    "ws": {
+                        "spot": "wss://testnet.binance.vision/stream",
+                        "margin": "wss://testnet.binance.vision/stream",
+                        "future": "wss://stream.binancefuture.com/stream",
+                        "delivery": "wss://dstream.binancefuture.com/stream",
+                        "api": "wss://testnet.binance.vision/ws-api/v3"
+                    },
                     "dapiPublic": "https://testnet.binancefuture.com/dapi/v1",
                     "dapiPrivate": "https://testnet.binancefuture.com/dapi/v1",
                     "vapiPublic": "https://testnet.binanceops.com/vapi/v1",
@@ -1207,7 +1223,9 @@ pub trait Binance : Exchange {
                 "status": "ok"
             },
             "exceptions": {
-                "exact": {},
+                "exact": {
+                    "-1021": "InvalidNonce"
+                },
                 "broad": {}
             },
             "httpExceptions": {},
@@ -1434,7 +1452,8 @@ pub trait Binance : Exchange {
     }
 
     fn nonce(&self) -> Value {
-        return self.milliseconds() - self.get("options".into()).get(Value::from("timeDifference"));
+        // milliseconds() already applies options.timeDifference
+        return self.milliseconds();
     }
 
     /// Returns the current integer timestamp in milliseconds from the exchange server
@@ -1631,7 +1650,7 @@ pub trait Binance : Exchange {
         let mut future: Value = (r#type.clone() == Value::from("future")).into();
         let mut delivery: Value = (r#type.clone() == Value::from("delivery")).into();
         if !spot.is_truthy() && !margin.is_truthy() && !future.is_truthy() && !delivery.is_truthy() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not support '") + r#type.clone() + Value::from("' type, set exchange.options['defaultType'] to 'spot', 'margin', 'delivery' or 'future'"))"###);
+            panic!("ExchangeError::new({} does not support '{}' type, set exchange.options['defaultType'] to 'spot', 'margin', 'delivery' or 'future')", self.get("id".into()).unwrap_str(), js_string(&r#type));
         };
         // eslint-disable-line quotes
         let mut method: Value = Value::from("publicGetExchangeInfo");
@@ -1900,36 +1919,36 @@ pub trait Binance : Exchange {
             if filters_by_type.contains_key(Value::from("PRICE_FILTER")) {
                 let mut filter: Value = self.safe_value(filters_by_type.clone(), Value::from("PRICE_FILTER"), Value::new_object());
                 let mut tick_size: Value = self.safe_string(filter.clone(), Value::from("tickSize"), Value::Undefined);
-                entry.get(Value::from("precision")).set("price".into(), self.precision_from_string(tick_size.clone()));
+                entry.set_path(&[Value::from("precision"), "price".into()], self.precision_from_string(tick_size.clone()));
                 // PRICE_FILTER reports zero values for maxPrice
                 // since they updated filter types in November 2018
                 // https://github.com/ccxt/ccxt/issues/4286
                 // therefore limits['price']['max'] doesn't have any meaningful value except undefined
-                entry.get(Value::from("limits")).set("price".into(), Value::Json(normalize(&Value::Json(json!({
+                entry.set_path(&[Value::from("limits"), "price".into()], Value::Json(normalize(&Value::Json(json!({
                     "min": Binance::safe_number(self, filter.clone(), Value::from("minPrice"), Value::Undefined),
                     "max": Binance::safe_number(self, filter.clone(), Value::from("maxPrice"), Value::Undefined)
                 }))).unwrap()));
-                entry.get(Value::from("precision")).set("price".into(), self.precision_from_string(filter.get(Value::from("tickSize"))));
+                entry.set_path(&[Value::from("precision"), "price".into()], self.precision_from_string(filter.get(Value::from("tickSize"))));
             };
             if filters_by_type.contains_key(Value::from("LOT_SIZE")) {
                 let mut filter: Value = self.safe_value(filters_by_type.clone(), Value::from("LOT_SIZE"), Value::new_object());
                 let mut step_size: Value = self.safe_string(filter.clone(), Value::from("stepSize"), Value::Undefined);
-                entry.get(Value::from("precision")).set("amount".into(), self.precision_from_string(step_size.clone()));
-                entry.get(Value::from("limits")).set("amount".into(), Value::Json(normalize(&Value::Json(json!({
+                entry.set_path(&[Value::from("precision"), "amount".into()], self.precision_from_string(step_size.clone()));
+                entry.set_path(&[Value::from("limits"), "amount".into()], Value::Json(normalize(&Value::Json(json!({
                     "min": Binance::safe_number(self, filter.clone(), Value::from("minQty"), Value::Undefined),
                     "max": Binance::safe_number(self, filter.clone(), Value::from("maxQty"), Value::Undefined)
                 }))).unwrap()));
             };
             if filters_by_type.contains_key(Value::from("MARKET_LOT_SIZE")) {
                 let mut filter: Value = self.safe_value(filters_by_type.clone(), Value::from("MARKET_LOT_SIZE"), Value::new_object());
-                entry.get(Value::from("limits")).set("market".into(), Value::Json(normalize(&Value::Json(json!({
+                entry.set_path(&[Value::from("limits"), "market".into()], Value::Json(normalize(&Value::Json(json!({
                     "min": Binance::safe_number(self, filter.clone(), Value::from("minQty"), Value::Undefined),
                     "max": Binance::safe_number(self, filter.clone(), Value::from("maxQty"), Value::Undefined)
                 }))).unwrap()));
             };
             if filters_by_type.contains_key(Value::from("MIN_NOTIONAL")) {
                 let mut filter: Value = self.safe_value(filters_by_type.clone(), Value::from("MIN_NOTIONAL"), Value::new_object());
-                entry.get(Value::from("limits")).get(Value::from("cost")).set("min".into(), Binance::safe_number_2(self, filter.clone(), Value::from("minNotional"), Value::from("notional"), Value::Undefined));
+                entry.set_path(&[Value::from("limits"), Value::from("cost"), "min".into()], Binance::safe_number_2(self, filter.clone(), Value::from("minNotional"), Value::from("notional"), Value::Undefined));
             };
             result.push(entry.clone());
             i += 1;
@@ -1971,7 +1990,7 @@ pub trait Binance : Exchange {
             while i < assets.len() {
                 let mut asset: Value = assets.get(i.into());
                 let mut market_id: Value = self.safe_value(asset.clone(), Value::from("symbol"), Value::Undefined);
-                let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), Value::Undefined, Value::Undefined);
+                let mut symbol: Value = self.safe_symbol(market_id.clone(), Value::Undefined, Value::Undefined);
                 let mut base: Value = self.safe_value(asset.clone(), Value::from("baseAsset"), Value::new_object());
                 let mut quote: Value = self.safe_value(asset.clone(), Value::from("quoteAsset"), Value::new_object());
                 let mut base_code: Value = Binance::safe_currency_code(self, self.safe_string(base.clone(), Value::from("asset"), Value::Undefined), Value::Undefined);
@@ -2329,7 +2348,7 @@ pub trait Binance : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -2417,7 +2436,7 @@ pub trait Binance : Exchange {
         //
         let mut timestamp: Value = self.safe_integer(ticker.clone(), Value::from("closeTime"), Value::Undefined);
         let mut market_id: Value = self.safe_string(ticker.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut last: Value = self.safe_string(ticker.clone(), Value::from("lastPrice"), Value::Undefined);
         let mut is_coinm: Value = ticker.contains_key(Value::from("baseVolume")).into();
         let mut base_volume: Value = Value::Undefined;
@@ -2493,7 +2512,7 @@ pub trait Binance : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -2619,7 +2638,7 @@ pub trait Binance : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         // binance docs say that the default limit 500, max 1500 for futures, max 1000 for spot markets
         // the reality is that the time range wider than 500 candles won't work right
         let mut default_limit: Value = Value::from(500);
@@ -2789,7 +2808,7 @@ pub trait Binance : Exchange {
         let mut cost: Value = self.safe_string_2(trade.clone(), Value::from("quoteQty"), Value::from("baseQty"), Value::Undefined);
         // inverse futures
         let mut market_id: Value = self.safe_string(trade.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut id: Value = self.safe_string_2(trade.clone(), Value::from("t"), Value::from("a"), Value::Undefined);
         id = self.safe_string_2(trade.clone(), Value::from("id"), Value::from("tradeId"), id.clone());
         let mut side: Value = Value::Undefined;
@@ -2851,7 +2870,7 @@ pub trait Binance : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -3057,7 +3076,7 @@ pub trait Binance : Exchange {
         //
         let mut status: Value = Binance::parse_order_status(self, self.safe_string(order.clone(), Value::from("status"), Value::Undefined));
         let mut market_id: Value = self.safe_string(order.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut filled: Value = self.safe_string(order.clone(), Value::from("executedQty"), Value::from("0"));
         let mut timestamp: Value = Value::Undefined;
         let mut last_trade_timestamp: Value = Value::Undefined;
@@ -3140,7 +3159,16 @@ pub trait Binance : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
+        let (mut method, mut request) = shift_2(Binance::create_order_request(self, symbol.clone(), r#type.clone(), side.clone(), amount.clone(), price.clone(), params.clone()));
+        let mut client_order_id: Value = self.safe_string_2(params.clone(), Value::from("newClientOrderId"), Value::from("clientOrderId"), Value::Undefined);
+        let mut response: Value = Binance::dispatch(self, method, request, idempotency_context(&client_order_id)).await;
+        return Binance::parse_order(self, response.clone(), market.clone());
+    }
+
+    /// Returns the method and the parameters of the request of `create_order`, `create_order_ws` sends them over the websocket api
+    fn create_order_request(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
+        let mut market: Value = self.market(symbol.clone());
         let mut default_type: Value = self.safe_string_2(self.get("options".into()), Value::from("createOrder"), Value::from("defaultType"), Value::from("spot"));
         let mut market_type: Value = self.safe_string(params.clone(), Value::from("type"), default_type.clone());
         let mut client_order_id: Value = self.safe_string_2(params.clone(), Value::from("newClientOrderId"), Value::from("clientOrderId"), Value::Undefined);
@@ -3149,7 +3177,7 @@ pub trait Binance : Exchange {
         let (mut margin_mode, mut query) = shift_2(Binance::handle_margin_mode_and_params(self, Value::from("createOrder"), params.clone()));
         if reduce_only.clone().is_nonnullish() {
             if market_type.clone() != Value::from("future") && market_type.clone() != Value::from("delivery") {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() does not support reduceOnly for ") + market_type.clone() + Value::from(" orders, reduceOnly orders are supported for future and delivery markets only"))"###);
+                panic!("InvalidOrder::new({} createOrder() does not support reduceOnly for {} orders, reduceOnly orders are supported for future and delivery markets only)", self.get("id".into()).unwrap_str(), js_string(&market_type));
             };
         };
         let mut method: Value = Value::from("privatePostOrder");
@@ -3184,9 +3212,9 @@ pub trait Binance : Exchange {
         let mut valid_order_types: Value = self.safe_value(market.get(Value::from("info")), Value::from("orderTypes"), Value::Undefined);
         if !self.in_array(uppercase_type.clone(), valid_order_types.clone()).is_truthy() {
             if initial_uppercase_type.clone() != uppercase_type.clone() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" stopPrice parameter is not allowed for ") + symbol.clone() + Value::from(" ") + r#type.clone() + Value::from(" orders"))"###);
+                panic!("InvalidOrder::new({} stopPrice parameter is not allowed for {} {} orders)", self.get("id".into()).unwrap_str(), js_string(&symbol), js_string(&r#type));
             } else {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" ") + r#type.clone() + Value::from(" is not a valid order type for the ") + symbol.clone() + Value::from(" market"))"###);
+                panic!("InvalidOrder::new({} {} is not a valid order type for the {} market)", self.get("id".into()).unwrap_str(), js_string(&r#type), js_string(&symbol));
             };
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -3292,7 +3320,7 @@ pub trait Binance : Exchange {
             quantity_is_required = true.into();
             let mut callback_rate: Value = Binance::safe_number(self, query.clone(), Value::from("callbackRate"), Value::Undefined);
             if callback_rate.clone().is_nullish() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() requires a callbackRate extra param for a ") + r#type.clone() + Value::from(" order"))"###);
+                panic!("InvalidOrder::new({} createOrder() requires a callbackRate extra param for a {} order)", self.get("id".into()).unwrap_str(), js_string(&r#type));
             };
         };
         if quantity_is_required.is_truthy() {
@@ -3300,7 +3328,7 @@ pub trait Binance : Exchange {
         };
         if price_is_required.is_truthy() {
             if price.clone().is_nullish() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() requires a price argument for a ") + r#type.clone() + Value::from(" order"))"###);
+                panic!("InvalidOrder::new({} createOrder() requires a price argument for a {} order)", self.get("id".into()).unwrap_str(), js_string(&r#type));
             };
             request.set("price".into(), Binance::price_to_precision(self, symbol.clone(), price.clone()));
         };
@@ -3310,14 +3338,13 @@ pub trait Binance : Exchange {
         // 'GTC' = Good To Cancel (default), 'IOC' = Immediate Or Cancel
         if stop_price_is_required.is_truthy() {
             if stop_price.clone().is_nullish() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() requires a stopPrice extra param for a ") + r#type.clone() + Value::from(" order"))"###);
+                panic!("InvalidOrder::new({} createOrder() requires a stopPrice extra param for a {} order)", self.get("id".into()).unwrap_str(), js_string(&r#type));
             } else {
                 request.set("stopPrice".into(), Binance::price_to_precision(self, symbol.clone(), stop_price.clone()));
             };
         };
         let mut request_params: Value = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("quoteOrderQty").into(), Value::from("cost").into(), Value::from("stopPrice").into(), Value::from("test").into(), Value::from("type").into(), Value::from("newClientOrderId").into(), Value::from("clientOrderId").into(), Value::from("postOnly").into()])));
-        let mut response: Value = Binance::dispatch(self, method, extend_2(request.clone(), request_params.clone()), Value::Undefined).await;
-        return Binance::parse_order(self, response.clone(), market.clone());
+        return Value::Json(serde_json::Value::Array(vec![method.into(), extend_2(request.clone(), request_params.clone()).into()]));
     }
 
     /// Returns an [order structure](https://docs.ccxt.com/en/latest/manual.html#order-structure)
@@ -3332,10 +3359,10 @@ pub trait Binance : Exchange {
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut default_type: Value = self.safe_string_2(self.get("options".into()), Value::from("fetchOrder"), Value::from("defaultType"), Value::from("spot"));
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), default_type.clone());
         let (mut margin_mode, mut query) = shift_2(Binance::handle_margin_mode_and_params(self, Value::from("fetchOrder"), params.clone()));
@@ -3378,10 +3405,10 @@ pub trait Binance : Exchange {
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut default_type: Value = self.safe_string_2(self.get("options".into()), Value::from("fetchOrders"), Value::from("defaultType"), Value::from("spot"));
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), default_type.clone());
         let (mut margin_mode, mut query) = shift_2(Binance::handle_margin_mode_and_params(self, Value::from("fetchOrders"), params.clone()));
@@ -3473,7 +3500,7 @@ pub trait Binance : Exchange {
         let mut request: Value = Value::new_object();
         let (mut margin_mode, mut query) = shift_2(Binance::handle_margin_mode_and_params(self, Value::from("fetchOpenOrders"), params.clone()));
         if symbol.clone().is_nonnullish() {
-            market = Binance::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
             let mut default_type: Value = self.safe_string_2(self.get("options".into()), Value::from("fetchOpenOrders"), Value::from("defaultType"), Value::from("spot"));
             let mut market_type: Value = if market.contains_key(Value::from("type")) { market.get(Value::from("type")) } else { default_type.clone() };
@@ -3482,7 +3509,7 @@ pub trait Binance : Exchange {
             let mut symbols: Value = self.get("symbols".into());
             let mut num_symbols: Value = symbols.len().into();
             let mut fetch_open_orders_rate_limit: Value = parse_int(num_symbols.clone() / Value::from(2));
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchOpenOrders() WARNING: fetching open orders without specifying a symbol is rate-limited to one call per ") + fetch_open_orders_rate_limit.to_string() + Value::from(" seconds. Do not call this method frequently to avoid ban. Set ") + self.get("id".into()) + Value::from(r#".options["warnOnFetchOpenOrdersWithoutSymbol"] = false to suppress this warning message."#))"###);
+            panic!("ExchangeError::new({} fetchOpenOrders() WARNING: fetching open orders without specifying a symbol is rate-limited to one call per {} seconds. Do not call this method frequently to avoid ban. Set {}.options[\"warnOnFetchOpenOrdersWithoutSymbol\"] = false to suppress this warning message.)", self.get("id".into()).unwrap_str(), js_string(&fetch_open_orders_rate_limit), self.get("id".into()).unwrap_str());
         } else {
             let mut default_type: Value = self.safe_string_2(self.get("options".into()), Value::from("fetchOpenOrders"), Value::from("defaultType"), Value::from("spot"));
             r#type = self.safe_string(query.clone(), Value::from("type"), default_type.clone());
@@ -3498,7 +3525,7 @@ pub trait Binance : Exchange {
             if margin_mode.clone() == Value::from("isolated") {
                 request.set("isIsolated".into(), true.into());
                 if symbol.clone().is_nullish() {
-                    panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOpenOrders() requires a symbol argument for isolated markets"))"###);
+                    panic!("ArgumentsRequired::new({} fetchOpenOrders() requires a symbol argument for isolated markets)", self.get("id".into()).unwrap_str());
                 };
             };
         };
@@ -3534,10 +3561,18 @@ pub trait Binance : Exchange {
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
+        let (mut method, mut request) = shift_2(Binance::cancel_order_request(self, id.clone(), symbol.clone(), params.clone()));
+        let mut response: Value = Binance::dispatch(self, method, request, Value::Undefined).await;
+        return Binance::parse_order(self, response.clone(), market.clone());
+    }
+
+    /// Returns the method and the parameters of the request of `cancel_order`, `cancel_order_ws` sends them over the websocket api
+    fn cancel_order_request(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
+        let mut market: Value = self.market(symbol.clone());
         let mut default_type: Value = self.safe_string_2(self.get("options".into()), Value::from("cancelOrder"), Value::from("defaultType"), Value::from("spot"));
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), default_type.clone());
         // https://github.com/ccxt/ccxt/issues/6507
@@ -3563,13 +3598,12 @@ pub trait Binance : Exchange {
             if margin_mode.clone() == Value::from("isolated") {
                 request.set("isIsolated".into(), true.into());
                 if symbol.clone().is_nullish() {
-                    panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument for isolated markets"))"###);
+                    panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument for isolated markets)", self.get("id".into()).unwrap_str());
                 };
             };
         };
         let mut request_params: Value = self.omit(query.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("type").into(), Value::from("origClientOrderId").into(), Value::from("clientOrderId").into()])));
-        let mut response: Value = Binance::dispatch(self, method, extend_2(request.clone(), request_params.clone()), Value::Undefined).await;
-        return Binance::parse_order(self, response.clone(), market.clone());
+        return Value::Json(serde_json::Value::Array(vec![method.into(), extend_2(request.clone(), request_params.clone()).into()]));
     }
 
     /// Returns a list of [order structures](https://docs.ccxt.com/en/latest/manual.html#order-structure)
@@ -3584,10 +3618,10 @@ pub trait Binance : Exchange {
     async fn cancel_all_orders(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelAllOrders () requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelAllOrders () requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -3629,14 +3663,14 @@ pub trait Binance : Exchange {
     async fn fetch_order_trades(&mut self, mut id: Value, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrderTrades() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrderTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), market.get(Value::from("type")));
         params = self.omit(params.clone(), Value::from("type"));
         if r#type.clone() != Value::from("spot") {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrderTrades() supports spot markets only"))"###);
+            panic!("NotSupported::new({} fetchOrderTrades() supports spot markets only)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "orderId": id
@@ -3657,10 +3691,10 @@ pub trait Binance : Exchange {
     async fn fetch_my_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchMyTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), market.get(Value::from("type")));
         params = self.omit(params.clone(), Value::from("type"));
         let mut method: Value = Value::Undefined;
@@ -3813,7 +3847,7 @@ pub trait Binance : Exchange {
             let mut logs: Value = self.safe_value(results.get(i.into()), Value::from("userAssetDribbletDetails"), Value::new_array());
             let mut j: usize = 0;
             while j < logs.len() {
-                logs.get(j.into()).set("isDustTrade".into(), true.into());
+                logs.set_path(&[j.into(), "isDustTrade".into()], true.into());
                 data.push(logs.get(j.into()));
                 j += 1;
             };
@@ -4352,7 +4386,7 @@ pub trait Binance : Exchange {
         //     }
         //
         let mut market_id: Value = self.safe_string(income.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut amount: Value = Binance::safe_number(self, income.clone(), Value::from("income"), Value::Undefined);
         let mut currency_id: Value = self.safe_string(income.clone(), Value::from("asset"), Value::Undefined);
         let mut code: Value = Binance::safe_currency_code(self, currency_id.clone(), Value::Undefined);
@@ -4452,11 +4486,11 @@ pub trait Binance : Exchange {
         if r#type.clone().is_nullish() {
             if from_id.clone().is_nullish() {
                 let mut keys: Value = Object::keys(accounts_by_type.clone());
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fromAccount parameter must be one of ") + keys.join(Value::from(", ")))"###);
+                panic!("ExchangeError::new({} fromAccount parameter must be one of {})", self.get("id".into()).unwrap_str(), js_string(&keys.join(Value::from(", "))));
             };
             if to_id.clone().is_nullish() {
                 let mut keys: Value = Object::keys(accounts_by_type.clone());
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" toAccount parameter must be one of ") + keys.join(Value::from(", ")))"###);
+                panic!("ExchangeError::new({} toAccount parameter must be one of {})", self.get("id".into()).unwrap_str(), js_string(&keys.join(Value::from(", "))));
             };
             r#type = from_id.clone() + Value::from("_") + to_id.clone();
         };
@@ -4678,7 +4712,7 @@ pub trait Binance : Exchange {
                 let mut network_id: Value = self.safe_string(network_entry.clone(), Value::from("network"), Value::Undefined);
                 let mut network_code: Value = Binance::safe_currency_code(self, network_id.clone(), Value::Undefined);
                 let mut fee: Value = Binance::safe_number(self, network_entry.clone(), Value::from("withdrawFee"), Value::Undefined);
-                withdraw_fees.get(code.clone()).set(network_code.clone(), fee.clone());
+                withdraw_fees.set_path(&[code.clone(), network_code.clone()], fee.clone());
                 j += 1;
             };
             i += 1;
@@ -4741,7 +4775,7 @@ pub trait Binance : Exchange {
         //     }
         //
         let mut market_id: Value = self.safe_string(fee.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), Value::Undefined, Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), Value::Undefined, Value::Undefined);
         return Value::Json(normalize(&Value::Json(json!({
             "info": fee,
             "symbol": symbol,
@@ -4761,7 +4795,7 @@ pub trait Binance : Exchange {
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -4963,7 +4997,7 @@ pub trait Binance : Exchange {
     async fn futures_transfer(&mut self, mut code: Value, mut amount: Value, mut r#type: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if r#type.clone() < Value::from(1) || r#type.clone() > Value::from(4) {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" type must be between 1 and 4"))"###);
+            panic!("ArgumentsRequired::new({} type must be between 1 and 4)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut currency: Value = Binance::currency(self, code.clone());
@@ -4992,7 +5026,7 @@ pub trait Binance : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -5002,7 +5036,7 @@ pub trait Binance : Exchange {
         } else if market.get(Value::from("inverse")).is_truthy() {
             method = Value::from("dapiPublicGetPremiumIndex");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} fetchFundingRate() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Binance::dispatch(self, method, extend_2(request.clone(), params.clone()), Value::Undefined).await;
         if market.get(Value::from("inverse")).is_truthy() {
@@ -5048,7 +5082,7 @@ pub trait Binance : Exchange {
             method = Value::from("dapiPublicGetFundingRate");
         };
         if symbol.clone().is_nonnullish() {
-            let mut market: Value = Binance::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
             request.set("symbol".into(), market.get(Value::from("id")));
             if market.get(Value::from("linear")).is_truthy() {
@@ -5058,7 +5092,7 @@ pub trait Binance : Exchange {
             };
         };
         if method.clone().is_nullish() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() is not supported for ") + r#type.clone() + Value::from(" markets"))"###);
+            panic!("NotSupported::new({} fetchFundingRateHistory() is not supported for {} markets)", self.get("id".into()).unwrap_str(), js_string(&r#type));
         };
         if since.clone().is_nonnullish() {
             request.set("startTime".into(), since.clone());
@@ -5089,7 +5123,7 @@ pub trait Binance : Exchange {
             let mut timestamp: Value = self.safe_integer(entry.clone(), Value::from("fundingTime"), Value::Undefined);
             rates.push(Value::Json(normalize(&Value::Json(json!({
                 "info": entry,
-                "symbol": Binance::safe_symbol(self, self.safe_string(entry.clone(), Value::from("symbol"), Value::Undefined), Value::Undefined, Value::Undefined),
+                "symbol": self.safe_symbol(self.safe_string(entry.clone(), Value::from("symbol"), Value::Undefined), Value::Undefined, Value::Undefined),
                 "fundingRate": Binance::safe_number(self, entry.clone(), Value::from("fundingRate"), Value::Undefined),
                 "timestamp": timestamp,
                 "datetime": self.iso8601(timestamp.clone())
@@ -5121,7 +5155,7 @@ pub trait Binance : Exchange {
         } else if r#type.clone() == Value::from("delivery") {
             method = Value::from("dapiPublicGetPremiumIndex");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingRates() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} fetchFundingRates() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Binance::dispatch(self, method, query.clone(), Value::Undefined).await;
         let mut result: Value = Value::new_array();
@@ -5151,7 +5185,7 @@ pub trait Binance : Exchange {
         //
         let mut timestamp: Value = self.safe_integer(contract.clone(), Value::from("time"), Value::Undefined);
         let mut market_id: Value = self.safe_string(contract.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut mark_price: Value = Binance::safe_number(self, contract.clone(), Value::from("markPrice"), Value::Undefined);
         let mut index_price: Value = Binance::safe_number(self, contract.clone(), Value::from("indexPrice"), Value::Undefined);
         let mut interest_rate: Value = Binance::safe_number(self, contract.clone(), Value::from("interestRate"), Value::Undefined);
@@ -5601,15 +5635,15 @@ pub trait Binance : Exchange {
             } else if r#type.clone() == Value::from("delivery") {
                 method = Value::from("dapiPrivateV2GetLeverageBracket");
             } else {
-                panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" loadLeverageBrackets() supports linear and inverse contracts only"))"###);
+                panic!("NotSupported::new({} loadLeverageBrackets() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
             };
             let mut response: Value = Binance::dispatch(self, method, query.clone(), Value::Undefined).await;
-            self.get("options".into()).set("leverageBrackets".into(), Value::new_object());
+            self.set_path(&["options".into(), "leverageBrackets".into()], Value::new_object());
             let mut i: usize = 0;
             while i < response.len() {
                 let mut entry: Value = response.get(i.into());
                 let mut market_id: Value = self.safe_string(entry.clone(), Value::from("symbol"), Value::Undefined);
-                let mut symbol: Value = Binance::safe_symbol(self, market_id.clone(), Value::Undefined, Value::Undefined);
+                let mut symbol: Value = self.safe_symbol(market_id.clone(), Value::Undefined, Value::Undefined);
                 let mut brackets: Value = self.safe_value(entry.clone(), Value::from("brackets"), Value::new_array());
                 let mut result: Value = Value::new_array();
                 let mut j: usize = 0;
@@ -5620,7 +5654,7 @@ pub trait Binance : Exchange {
                     result.push(Value::Json(serde_json::Value::Array(vec![floor_value.clone().into(), maintenance_margin_percentage.clone().into()])));
                     j += 1;
                 };
-                self.get("options".into()).get(Value::from("leverageBrackets")).set(symbol.clone(), result.clone());
+                self.set_path(&["options".into(), Value::from("leverageBrackets"), symbol.clone()], result.clone());
                 i += 1;
             };
         };
@@ -5645,7 +5679,7 @@ pub trait Binance : Exchange {
         } else if r#type.clone() == Value::from("delivery") {
             method = Value::from("dapiPrivateV2GetLeverageBracket");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchLeverageTiers() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} fetchLeverageTiers() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Binance::dispatch(self, method, query.clone(), Value::Undefined).await;
         //
@@ -5749,7 +5783,7 @@ pub trait Binance : Exchange {
         } else if default_method.clone() == Value::from("account") {
             return Binance::fetch_account_positions(self, symbols.clone(), params.clone()).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#".options["fetchPositions"] = ""#) + default_method.clone() + Value::from(r#"" is invalid, please choose between "account" and "positionRisk""#))"###);
+            panic!("NotSupported::new({}.options[\"fetchPositions\"] = \"{}\" is invalid, please choose between \"account\" and \"positionRisk\")", self.get("id".into()).unwrap_str(), js_string(&default_method));
         };
         Value::Undefined
     }
@@ -5766,7 +5800,7 @@ pub trait Binance : Exchange {
         params = params.or_default(Value::new_object());
         if symbols.clone().is_nonnullish() {
             if !Array::is_array(symbols.clone()).is_truthy() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchPositions() requires an array argument for symbols"))"###);
+                panic!("ArgumentsRequired::new({} fetchPositions() requires an array argument for symbols)", self.get("id".into()).unwrap_str());
             };
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
@@ -5780,7 +5814,7 @@ pub trait Binance : Exchange {
         } else if r#type.clone() == Value::from("delivery") {
             method = Value::from("dapiPrivateGetAccount");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPositions() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} fetchPositions() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut account: Value = Binance::dispatch(self, method, query.clone(), Value::Undefined).await;
         let mut result: Value = Binance::parse_account_positions(self, account.clone());
@@ -5800,7 +5834,7 @@ pub trait Binance : Exchange {
         params = params.or_default(Value::new_object());
         if symbols.clone().is_nonnullish() {
             if !Array::is_array(symbols.clone()).is_truthy() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchPositionsRisk() requires an array argument for symbols"))"###);
+                panic!("ArgumentsRequired::new({} fetchPositionsRisk() requires an array argument for symbols)", self.get("id".into()).unwrap_str());
             };
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
@@ -5870,7 +5904,7 @@ pub trait Binance : Exchange {
             //     ]
             method = Value::from("dapiPrivateGetPositionRisk");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPositionsRisk() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} fetchPositionsRisk() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Binance::dispatch(self, method, extend_2(request.clone(), params.clone()), Value::Undefined).await;
         let mut result: Value = Value::new_array();
@@ -5905,14 +5939,14 @@ pub trait Binance : Exchange {
         }))).unwrap());
         // "TRANSFER"，"WELCOME_BONUS", "REALIZED_PNL"，"FUNDING_FEE", "COMMISSION" and "INSURANCE_CLEAR"
         if symbol.clone().is_nonnullish() {
-            market = Binance::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
             if market.get(Value::from("linear")).is_truthy() {
                 default_type = Value::from("future");
             } else if market.get(Value::from("inverse")).is_truthy() {
                 default_type = Value::from("delivery");
             } else {
-                panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingHistory() supports linear and inverse contracts only"))"###);
+                panic!("NotSupported::new({} fetchFundingHistory() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
             };
         };
         if since.clone().is_nonnullish() {
@@ -5929,7 +5963,7 @@ pub trait Binance : Exchange {
         } else if r#type.clone() == Value::from("delivery") || r#type.clone() == Value::from("inverse") {
             method = Value::from("dapiPrivateGetIncome");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingHistory() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} fetchFundingHistory() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Binance::dispatch(self, method, extend_2(request.clone(), params.clone()), Value::Undefined).await;
         return Binance::parse_incomes(self, response.clone(), market.clone(), since.clone(), limit.clone());
@@ -5947,22 +5981,22 @@ pub trait Binance : Exchange {
     async fn set_leverage(&mut self, mut leverage: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         // WARNING: THIS WILL INCREASE LIQUIDATION PRICE FOR OPEN ISOLATED LONG POSITIONS
         // AND DECREASE LIQUIDATION PRICE FOR OPEN ISOLATED SHORT POSITIONS
         if leverage.clone() < Value::from(1) || leverage.clone() > Value::from(125) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" leverage should be between 1 and 125"))"###);
+            panic!("BadRequest::new({} leverage should be between 1 and 125)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = Value::Undefined;
         if market.get(Value::from("linear")).is_truthy() {
             method = Value::from("fapiPrivatePostLeverage");
         } else if market.get(Value::from("inverse")).is_truthy() {
             method = Value::from("dapiPrivatePostLeverage");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" setLeverage() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} setLeverage() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
@@ -5983,7 +6017,7 @@ pub trait Binance : Exchange {
     async fn set_margin_mode(&mut self, mut margin_mode: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         //
         // { "code": -4048 , "msg": "Margin type cannot be changed if there exists position." }
@@ -5997,17 +6031,17 @@ pub trait Binance : Exchange {
             margin_mode = Value::from("CROSSED");
         };
         if margin_mode.clone() != Value::from("ISOLATED") && margin_mode.clone() != Value::from("CROSSED") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" marginMode must be either isolated or cross"))"###);
+            panic!("BadRequest::new({} marginMode must be either isolated or cross)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = Value::Undefined;
         if market.get(Value::from("linear")).is_truthy() {
             method = Value::from("fapiPrivatePostMarginType");
         } else if market.get(Value::from("inverse")).is_truthy() {
             method = Value::from("dapiPrivatePostMarginType");
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" setMarginMode() supports linear and inverse contracts only"))"###);
+            panic!("NotSupported::new({} setMarginMode() supports linear and inverse contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
@@ -6069,7 +6103,7 @@ pub trait Binance : Exchange {
         method = method.or_default(Value::from("GET"));
         params = params.or_default(Value::new_object());
         if !self.get("urls".into()).get(Value::from("api")).contains_key(api.clone()) {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" does not have a testnet/sandbox URL for ") + api.clone() + Value::from(" endpoints"))"###);
+            panic!("NotSupported::new({} does not have a testnet/sandbox URL for {} endpoints)", self.get("id".into()).unwrap_str(), js_string(&api));
         };
         let mut url: Value = self.get("urls".into()).get(Value::from("api")).get(api.clone());
         url = url +  Value::from("/") + path.clone();
@@ -6082,7 +6116,7 @@ pub trait Binance : Exchange {
                     "X-MBX-APIKEY": self.get("apiKey".into())
                 }))).unwrap());
             } else {
-                panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(" historicalTrades endpoint requires `apiKey` credential"))"###);
+                panic!("AuthenticationError::new({} historicalTrades endpoint requires `apiKey` credential)", self.get("id".into()).unwrap_str());
             };
         };
         let mut user_data_stream: Value = (path.clone() == Value::from("userDataStream") || path.clone() == Value::from("listenKey")).into();
@@ -6097,7 +6131,7 @@ pub trait Binance : Exchange {
                     body = self.urlencode(params.clone());
                 };
             } else {
-                panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(" userDataStream endpoint requires `apiKey` credential"))"###);
+                panic!("AuthenticationError::new({} userDataStream endpoint requires `apiKey` credential)", self.get("id".into()).unwrap_str());
             };
         } else if api.clone() == Value::from("private") || api.clone() == Value::from("sapi") && path.clone() != Value::from("system/status") || api.clone() == Value::from("sapiV3") || api.clone() == Value::from("wapi") && path.clone() != Value::from("systemStatus") || api.clone() == Value::from("dapiPrivate") || api.clone() == Value::from("dapiPrivateV2") || api.clone() == Value::from("fapiPrivate") || api.clone() == Value::from("fapiPrivateV2") {
             Binance::check_required_credentials(self, Value::Undefined);
@@ -6146,20 +6180,20 @@ pub trait Binance : Exchange {
 
     fn handle_errors(&mut self, mut code: Value, mut reason: Value, mut url: Value, mut method: Value, mut headers: Value, mut body: Value, mut response: Value, mut request_headers: Value, mut request_body: Value) -> Value {
         if code.clone() == Value::from(418) || code.clone() == Value::from(429) {
-            panic!(r###"DDoSProtection::new(self.get("id".into()) + Value::from(" ") + code.to_string() + Value::from(" ") + reason.clone() + Value::from(" ") + body.clone())"###);
+            panic!("DDoSProtection::new({} {} {} {})", self.get("id".into()).unwrap_str(), js_string(&code), js_string(&reason), js_string(&body));
         };
         // error response in a form: { "code": -1013, "msg": "Invalid quantity." }
         // following block cointains legacy checks against message patterns in "msg" property
         // will switch "code" checks eventually, when we know all of them
         if code.clone() >= Value::from(400) {
             if body.index_of(Value::from("Price * QTY is zero or less")) >= Value::from(0) {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" order cost = amount * price is zero or less ") + body.clone())"###);
+                panic!("InvalidOrder::new({} order cost = amount * price is zero or less {})", self.get("id".into()).unwrap_str(), js_string(&body));
             };
             if body.index_of(Value::from("LOT_SIZE")) >= Value::from(0) {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" order amount should be evenly divisible by lot size ") + body.clone())"###);
+                panic!("InvalidOrder::new({} order amount should be evenly divisible by lot size {})", self.get("id".into()).unwrap_str(), js_string(&body));
             };
             if body.index_of(Value::from("PRICE_FILTER")) >= Value::from(0) {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" order price is invalid, i.e. exceeds allowed price precision, exceeds min price or max price limits or is invalid value in general, use this.priceToPrecision (symbol, amount) ") + body.clone())"###);
+                panic!("InvalidOrder::new({} order price is invalid, i.e. exceeds allowed price precision, exceeds min price or max price limits or is invalid value in general, use this.priceToPrecision (symbol, amount) {})", self.get("id".into()).unwrap_str(), js_string(&body));
             };
         };
         if response.clone().is_nullish() {
@@ -6199,7 +6233,7 @@ pub trait Binance : Exchange {
             // despite that their message is very confusing, it is raised by Binance
             // on a temporary ban, the API key is valid, but disabled for a while
             if error.clone() == Value::from("-2015") && self.get("options".into()).get(Value::from("hasAlreadyAuthenticatedSuccessfully")).is_truthy() {
-                panic!(r###"DDoSProtection::new(self.get("id".into()) + Value::from(" ") + body.clone())"###);
+                panic!("DDoSProtection::new({} {})", self.get("id".into()).unwrap_str(), js_string(&body));
             };
             let mut feedback: Value = self.get("id".into()) + Value::from(" ") + body.clone();
             if message.clone() == Value::from("No need to change margin type.") {
@@ -6208,13 +6242,13 @@ pub trait Binance : Exchange {
                 // https://github.com/ccxt/ccxt/pull/11624
                 // POST https://fapi.binance.com/fapi/v1/marginType 400 Bad Request
                 // binanceusdm {"code":-4046,"msg":"No need to change margin type."}
-                panic!(r###"MarginModeAlreadySet::new(feedback)"###);
+                panic!("MarginModeAlreadySet::new({})", js_string(&feedback));
             };
             Binance::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), error.clone(), feedback.clone());
-            panic!(r###"ExchangeError::new(feedback)"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         if !success.is_truthy() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" ") + body.clone())"###);
+            panic!("ExchangeError::new({} {})", self.get("id".into()).unwrap_str(), js_string(&body));
         };
         Value::Undefined
     }
@@ -6252,7 +6286,7 @@ pub trait Binance : Exchange {
         let mut response: Value = Binance::fetch2(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone(), config.clone(), context.clone()).await;
         // a workaround for {"code":-2015,"msg":"Invalid API-key, IP, or permissions for action."}
         if api.clone() == Value::from("private") || api.clone() == Value::from("wapi") {
-            self.get("options".into()).set("hasAlreadyAuthenticatedSuccessfully".into(), true.into());
+            self.set_path(&["options".into(), "hasAlreadyAuthenticatedSuccessfully".into()], true.into());
         };
         return response.clone();
     }
@@ -6266,10 +6300,10 @@ pub trait Binance : Exchange {
         };
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), default_type.clone());
         if r#type.clone() == Value::from("margin") || r#type.clone() == Value::from("spot") {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" add / reduce margin only supported with type future or delivery"))"###);
+            panic!("NotSupported::new({} add / reduce margin only supported with type future or delivery)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         amount = Binance::amount_to_precision(self, symbol.clone(), amount.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "type": add_or_reduce,
@@ -6391,7 +6425,7 @@ pub trait Binance : Exchange {
             limit = Value::from(93);
         } else if limit.clone() > Value::from(93) {
             // Binance API says the limit is 100, but "Illegal characters found in a parameter." is returned when limit is > 93
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchBorrowRateHistory() limit parameter cannot exceed 92"))"###);
+            panic!("BadRequest::new({} fetchBorrowRateHistory() limit parameter cannot exceed 92)", self.get("id".into()).unwrap_str());
         };
         let mut currency: Value = Binance::currency(self, code.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -6574,7 +6608,7 @@ pub trait Binance : Exchange {
         };
         if symbol.clone().is_nonnullish() {
             // Isolated
-            market = Binance::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("isolatedSymbol".into(), market.get(Value::from("id")));
         };
         let mut response: Value = Binance::dispatch(self, "sapiGetMarginInterestHistory".into(), extend_2(request.clone(), params.clone()), Value::Undefined).await;
@@ -6633,7 +6667,7 @@ pub trait Binance : Exchange {
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Binance::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
         };
         let mut currency: Value = Binance::currency(self, code.clone());
@@ -6646,7 +6680,7 @@ pub trait Binance : Exchange {
         // cross or isolated
         if margin_mode.clone() == Value::from("isolated") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" repayMargin() requires a symbol argument for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} repayMargin() requires a symbol argument for isolated margin)", self.get("id".into()).unwrap_str());
             };
             request.set("isIsolated".into(), Value::from("TRUE"));
             request.set("symbol".into(), market.get(Value::from("id")));
@@ -6682,7 +6716,7 @@ pub trait Binance : Exchange {
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Binance::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
         };
         let mut currency: Value = Binance::currency(self, code.clone());
@@ -6695,7 +6729,7 @@ pub trait Binance : Exchange {
         // cross or isolated
         if margin_mode.clone() == Value::from("isolated") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" borrowMargin() requires a symbol argument for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} borrowMargin() requires a symbol argument for isolated margin)", self.get("id".into()).unwrap_str());
             };
             request.set("isIsolated".into(), Value::from("TRUE"));
             request.set("symbol".into(), market.get(Value::from("id")));
@@ -6749,10 +6783,10 @@ pub trait Binance : Exchange {
         timeframe = timeframe.or_default(Value::from("5m"));
         params = params.or_default(Value::new_object());
         if timeframe.clone() == Value::from("1m") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from("fetchOpenInterestHistory cannot use the 1m timeframe"))"###);
+            panic!("BadRequest::new({}fetchOpenInterestHistory cannot use the 1m timeframe)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "period": self.get("timeframes".into()).get(timeframe.clone())
         }))).unwrap());
@@ -6806,7 +6840,7 @@ pub trait Binance : Exchange {
         let mut id: Value = self.safe_string(interest.clone(), Value::from("symbol"), Value::Undefined);
         market = Binance::safe_market(self, id.clone(), market.clone(), Value::Undefined);
         return Value::Json(normalize(&Value::Json(json!({
-            "symbol": Binance::safe_symbol(self, id.clone(), Value::Undefined, Value::Undefined),
+            "symbol": self.safe_symbol(id.clone(), Value::Undefined, Value::Undefined),
             "baseVolume": Binance::safe_number(self, interest.clone(), Value::from("sumOpenInterest"), Value::Undefined),
             "quoteVolume": Binance::safe_number(self, interest.clone(), Value::from("sumOpenInterestValue"), Value::Undefined),
             "timestamp": timestamp,
@@ -6968,12 +7002,12 @@ pub trait Binance : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -7079,9 +7113,9 @@ pub trait Binance : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -7320,9 +7354,9 @@ pub trait Binance : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Binance::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -7417,14 +7451,14 @@ pub trait Binance : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -7646,7 +7680,7 @@ pub trait Binance : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -7714,10 +7748,10 @@ pub trait Binance : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -7827,7 +7861,7 @@ pub trait Binance : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -7835,7 +7869,7 @@ pub trait Binance : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -7861,19 +7895,8 @@ pub trait Binance : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Binance::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Binance::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Binance::load_time_difference(exchange, params), calculate_rate_limiter_cost: Binance::calculate_rate_limiter_cost, sign: Binance::sign, handle_errors: Binance::handle_errors }).await
     }
 
     async fn load_accounts(&mut self, mut reload: Value, mut params: Value) -> Value {
@@ -7896,7 +7919,7 @@ pub trait Binance : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Binance::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -7932,7 +7955,7 @@ pub trait Binance : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -8032,13 +8055,14 @@ pub trait Binance : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -8052,7 +8076,7 @@ pub trait Binance : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -8082,7 +8106,7 @@ pub trait Binance : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Binance::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -8091,7 +8115,7 @@ pub trait Binance : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Binance::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -8099,7 +8123,7 @@ pub trait Binance : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Binance::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
@@ -8109,7 +8133,7 @@ pub trait Binance : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -8162,14 +8186,14 @@ pub trait Binance : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Binance::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -8205,7 +8229,7 @@ pub trait Binance : Exchange {
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -8226,7 +8250,7 @@ pub trait Binance : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -8235,25 +8259,7 @@ pub trait Binance : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -8302,17 +8308,17 @@ pub trait Binance : Exchange {
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Binance::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -8336,9 +8342,7 @@ pub trait Binance : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = Binance::fetch_time(self, params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -8350,14 +8354,14 @@ pub trait Binance : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Binance::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = Binance::fetch_leverage_tiers(self, Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -8365,7 +8369,7 @@ pub trait Binance : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -8376,7 +8380,7 @@ pub trait Binance : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -8387,10 +8391,10 @@ pub trait Binance : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -8401,7 +8405,7 @@ pub trait Binance : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -8412,7 +8416,7 @@ pub trait Binance : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -8522,11 +8526,6 @@ pub trait Binance : Exchange {
         return Binance::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Binance::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -8558,9 +8557,9 @@ pub trait Binance : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -8604,7 +8603,7 @@ pub trait Binance : Exchange {
             }))).unwrap());
             return Binance::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -8629,7 +8628,7 @@ pub trait Binance : Exchange {
             }))).unwrap());
             return Binance::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -8654,7 +8653,7 @@ pub trait Binance : Exchange {
             }))).unwrap());
             return Binance::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -8670,7 +8669,7 @@ pub trait Binance : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -8691,7 +8690,7 @@ pub trait Binance : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Binance::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -8739,16 +8738,16 @@ pub trait Binance : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Binance::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Binance::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -9196,7 +9195,7 @@ pub trait Binance : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct BinanceImpl(Value);
+pub struct BinanceImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for BinanceImpl {}
 impl Binance for BinanceImpl {}
 impl ValueTrait for BinanceImpl {
@@ -9218,8 +9217,8 @@ impl ValueTrait for BinanceImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -9238,7 +9237,8 @@ impl BinanceImpl {
         let mut rv = BinanceImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Binance::describe(&rv);
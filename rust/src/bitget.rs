@@ -11,10 +11,12 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
+use crate::retry::idempotency_context;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -445,14 +447,10 @@ pub trait Bitget : Exchange {
             let mut price_step: Value = self.safe_string(market.clone(), Value::from("priceEndStep"), Value::Undefined);
             let mut amount_step: Value = self.safe_string(market.clone(), Value::from("minTradeNum"), Value::Undefined);
             let mut precise_price: Value = Precise::new(price_step);
-            precise_price.set("decimals".into(), self.sum(precise_price.get(decimals.clone()), price_decimals.clone()));
-            precise_price.reduce();
-            let mut price_string: Value = precise_price.to_string();
+            let mut price_string: Value = precise_price.unwrap_precise().shift_decimals(if price_decimals.is_number() { price_decimals.unwrap_json().as_i64().unwrap() as i32 } else { 0 }).to_string();
             price_precision = self.parse_number(price_string.clone(), Value::Undefined);
             let mut precise_amount: Value = Precise::new(amount_step);
-            precise_amount.set("decimals".into(), self.sum(precise_amount.get(decimals.clone()), amount_decimals.clone()));
-            precise_amount.reduce();
-            let mut amount_string: Value = precise_amount.to_string();
+            let mut amount_string: Value = precise_amount.unwrap_precise().shift_decimals(if amount_decimals.is_number() { amount_decimals.unwrap_json().as_i64().unwrap() as i32 } else { 0 }).to_string();
             amount_precision = self.parse_number(amount_string.clone(), Value::Undefined);
         };
         let mut status: Value = self.safe_string(market.clone(), Value::from("status"), Value::Undefined);
@@ -686,7 +684,7 @@ pub trait Bitget : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("fetchOrderBook"), market.clone(), params.clone()));
         let mut method: Value = Bitget::get_supported_mapping(self, market_type.clone(), Value::Json(normalize(&Value::Json(json!({
             "spot": "publicSpotGetMarketDepth",
@@ -753,7 +751,7 @@ pub trait Bitget : Exchange {
         if !self.get("markets_by_id".into()).contains_key(market_id.clone()) {
             market_id = market_id +  Value::from("_SPBL");
         };
-        let mut symbol: Value = Bitget::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut high: Value = self.safe_string(ticker.clone(), Value::from("high24h"), Value::Undefined);
         let mut low: Value = self.safe_string(ticker.clone(), Value::from("low24h"), Value::Undefined);
         let mut close: Value = self.safe_string_2(ticker.clone(), Value::from("close"), Value::from("last"), Value::Undefined);
@@ -799,7 +797,7 @@ pub trait Bitget : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -959,7 +957,7 @@ pub trait Bitget : Exchange {
         //     }
         //
         let mut market_id: Value = self.safe_string(trade.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Bitget::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut id: Value = self.safe_string_2(trade.clone(), Value::from("tradeId"), Value::from("fillId"), Value::Undefined);
         let mut order: Value = self.safe_string(trade.clone(), Value::from("orderId"), Value::Undefined);
         let mut side: Value = self.safe_string(trade.clone(), Value::from("side"), Value::Undefined);
@@ -1005,10 +1003,10 @@ pub trait Bitget : Exchange {
     /// * `since` {int|undefined} - timestamp in ms of the earliest trade to fetch
     /// * `limit` {int|undefined} - the maximum amount of trades to fetch
     /// * `params` {object} - extra parameters specific to the bitget api endpoint
-    async fn fetch_trades(&mut self, mut symbol: Value, mut limit: Value, mut since: Value, mut params: Value) -> Value {
+    async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1053,7 +1051,7 @@ pub trait Bitget : Exchange {
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1133,7 +1131,7 @@ pub trait Bitget : Exchange {
         let mut market_id: Value = self.safe_string(data.clone(), Value::from("symbol"), Value::Undefined);
         return Value::Json(normalize(&Value::Json(json!({
             "info": data,
-            "symbol": Bitget::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined),
+            "symbol": self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined),
             "maker": Bitget::safe_number(self, data.clone(), Value::from("makerFeeRate"), Value::Undefined),
             "taker": Bitget::safe_number(self, data.clone(), Value::from("takerFeeRate"), Value::Undefined)
         }))).unwrap());
@@ -1186,7 +1184,7 @@ pub trait Bitget : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1445,7 +1443,7 @@ pub trait Bitget : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("createOrder"), market.clone(), params.clone()));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
@@ -1459,13 +1457,14 @@ pub trait Bitget : Exchange {
         let mut take_profit_price: Value = self.safe_value(params.clone(), Value::from("takeProfitPrice"), Value::Undefined);
         let mut is_take_profit_order: Value = (take_profit_price.clone().is_nonnullish()).into();
         let mut is_stop_order: Value = (is_stop_loss_order.is_truthy() || is_take_profit_order.is_truthy()).into();
-        if self.sum(is_trigger_order.clone(), is_stop_loss_order.clone(), is_take_profit_order.clone()) > Value::from(1) {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" createOrder() params can only contain one of triggerPrice, stopLossPrice, takeProfitPrice"))"###);
+        if Value::from(is_trigger_order.is_truthy() as i64 + is_stop_loss_order.is_truthy() as i64 + is_take_profit_order.is_truthy() as i64) > Value::from(1) {
+            panic!("ExchangeError::new({} createOrder() params can only contain one of triggerPrice, stopLossPrice, takeProfitPrice)", self.get("id".into()).unwrap_str());
         };
         if r#type.clone() == Value::from("limit") && trigger_price.clone().is_nullish() {
             request.set("price".into(), Bitget::price_to_precision(self, symbol.clone(), price.clone()));
         };
         let mut client_order_id: Value = self.safe_string_2(params.clone(), Value::from("client_oid"), Value::from("clientOrderId"), Value::Undefined);
+        let mut context: Value = idempotency_context(&client_order_id);
         if client_order_id.clone().is_nullish() {
             let mut broker: Value = self.safe_value(self.get("options".into()), Value::from("broker"), Value::Undefined);
             if broker.clone().is_nonnullish() {
@@ -1507,7 +1506,7 @@ pub trait Bitget : Exchange {
             };
             if is_stop_order.is_truthy() {
                 if !is_market_order.is_truthy() {
-                    panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" createOrder() bitget stopLoss or takeProfit orders must be market orders"))"###);
+                    panic!("ExchangeError::new({} createOrder() bitget stopLoss or takeProfit orders must be market orders)", self.get("id".into()).unwrap_str());
                 };
                 if is_stop_loss_order.is_truthy() {
                     request.set("triggerPrice".into(), Bitget::price_to_precision(self, symbol.clone(), stop_loss_price.clone()));
@@ -1528,7 +1527,7 @@ pub trait Bitget : Exchange {
             request.set("marginCoin".into(), market.get(Value::from("settleId")));
         };
         let mut omitted: Value = self.omit(query.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("stopPrice").into(), Value::from("triggerType").into(), Value::from("stopLossPrice").into(), Value::from("takeProfitPrice").into(), Value::from("postOnly").into()])));
-        let mut response: Value = Bitget::dispatch(self, method, extend_2(request.clone(), omitted.clone()), Value::Undefined).await;
+        let mut response: Value = Bitget::dispatch(self, method, extend_2(request.clone(), omitted.clone()), context).await;
         //
         //     {
         //         "code": "00000",
@@ -1556,10 +1555,10 @@ pub trait Bitget : Exchange {
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument for spot orders"))"###);
+            panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument for spot orders)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("cancelOrder"), market.clone(), params.clone()));
         let mut method: Value = Bitget::get_supported_mapping(self, market_type.clone(), Value::Json(normalize(&Value::Json(json!({
             "spot": "privateSpotPostTradeCancelOrder",
@@ -1573,7 +1572,7 @@ pub trait Bitget : Exchange {
         if stop.is_truthy() {
             let mut plan_type: Value = self.safe_string(params.clone(), Value::from("planType"), Value::Undefined);
             if plan_type.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a planType parameter for stop orders, either normal_plan, profit_plan or loss_plan"))"###);
+                panic!("ArgumentsRequired::new({} cancelOrder() requires a planType parameter for stop orders, either normal_plan, profit_plan or loss_plan)", self.get("id".into()).unwrap_str());
             };
             request.set("planType".into(), plan_type.clone());
             method = Value::from("privateMixPostPlanCancelPlan");
@@ -1598,13 +1597,13 @@ pub trait Bitget : Exchange {
     async fn cancel_orders(&mut self, mut ids: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut r#type: Value = self.safe_string(params.clone(), Value::from("type"), market.get(Value::from("type")));
         if r#type.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrders() requires a type parameter (one of 'spot', 'swap')."))"###);
+            panic!("ArgumentsRequired::new({} cancelOrders() requires a type parameter (one of 'spot', 'swap').)", self.get("id".into()).unwrap_str());
         };
         params = self.omit(params.clone(), Value::from("type"));
         let mut request: Value = Value::new_object();
@@ -1672,10 +1671,10 @@ pub trait Bitget : Exchange {
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("fetchOrder"), market.clone(), params.clone()));
         let mut method: Value = Bitget::get_supported_mapping(self, market_type.clone(), Value::Json(normalize(&Value::Json(json!({
             "spot": "privateSpotPostTradeOrderInfo",
@@ -1755,10 +1754,10 @@ pub trait Bitget : Exchange {
     async fn fetch_open_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOpenOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOpenOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("fetchOpenOrders"), market.clone(), params.clone()));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -1869,10 +1868,10 @@ pub trait Bitget : Exchange {
     async fn fetch_closed_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchClosedOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchClosedOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("fetchClosedOrders"), market.clone(), params.clone()));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2060,12 +2059,12 @@ pub trait Bitget : Exchange {
     async fn fetch_my_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchMyTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMyTrades() only supports spot markets"))"###);
+            panic!("BadSymbol::new({} fetchMyTrades() only supports spot markets)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2115,10 +2114,10 @@ pub trait Bitget : Exchange {
     async fn fetch_order_trades(&mut self, mut id: Value, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrderTrades() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrderTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Bitget::handle_market_type_and_params(self, Value::from("fetchOrderTrades"), market.clone(), params.clone()));
         let mut method: Value = Bitget::get_supported_mapping(self, market_type.clone(), Value::Json(normalize(&Value::Json(json!({
             "spot": "privateSpotPostTradeFills",
@@ -2153,7 +2152,7 @@ pub trait Bitget : Exchange {
         //     }
         //
         let mut data: Value = self.safe_value(response.clone(), Value::from("data"), Value::Undefined);
-        return Bitget::parse_trades(self, data.clone(), market.clone(), since.clone(), limit.clone(), Value::Undefined).await;
+        return Bitget::parse_trades(self, data.clone(), market.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
     /// Returns a [position structure](https://docs.ccxt.com/en/latest/manual.html#position-structure)
@@ -2167,7 +2166,7 @@ pub trait Bitget : Exchange {
     async fn fetch_position(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
             "marginCoin": market.get(Value::from("settleId"))
@@ -2345,10 +2344,10 @@ pub trait Bitget : Exchange {
     async fn fetch_funding_rate_history(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingRateHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -2379,7 +2378,7 @@ pub trait Bitget : Exchange {
         while i < data.len() {
             let mut entry: Value = data.get(i.into());
             let mut market_id: Value = self.safe_string(entry.clone(), Value::from("symbol"), Value::Undefined);
-            let mut symbol: Value = Bitget::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+            let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
             let mut timestamp: Value = self.safe_integer(entry.clone(), Value::from("settleTime"), Value::Undefined);
             rates.push(Value::Json(normalize(&Value::Json(json!({
                 "info": entry,
@@ -2405,9 +2404,9 @@ pub trait Bitget : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if !market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} fetchFundingRate() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2436,7 +2435,7 @@ pub trait Bitget : Exchange {
         //     }
         //
         let mut market_id: Value = self.safe_string(contract.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Bitget::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         return Value::Json(normalize(&Value::Json(json!({
             "info": contract,
             "symbol": symbol,
@@ -2462,7 +2461,7 @@ pub trait Bitget : Exchange {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut hold_side: Value = self.safe_string(params.clone(), Value::from("holdSide"), Value::Undefined);
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut margin_coin: Value = if market.get(Value::from("linear")).is_truthy() { market.get(Value::from("quote")) } else { market.get(Value::from("base")) };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
@@ -2516,11 +2515,11 @@ pub trait Bitget : Exchange {
     async fn reduce_margin(&mut self, mut symbol: Value, mut amount: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if amount.clone() > Value::from(0) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" reduceMargin() amount parameter must be a negative value"))"###);
+            panic!("BadRequest::new({} reduceMargin() amount parameter must be a negative value)", self.get("id".into()).unwrap_str());
         };
         let mut hold_side: Value = self.safe_string(params.clone(), Value::from("holdSide"), Value::Undefined);
         if hold_side.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" reduceMargin() requires a holdSide parameter, either long or short"))"###);
+            panic!("ArgumentsRequired::new({} reduceMargin() requires a holdSide parameter, either long or short)", self.get("id".into()).unwrap_str());
         };
         return Bitget::modify_margin_helper(self, symbol.clone(), amount.clone(), Value::from("reduce"), params.clone()).await;
     }
@@ -2538,7 +2537,7 @@ pub trait Bitget : Exchange {
         params = params.or_default(Value::new_object());
         let mut hold_side: Value = self.safe_string(params.clone(), Value::from("holdSide"), Value::Undefined);
         if hold_side.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" addMargin() requires a holdSide parameter, either long or short"))"###);
+            panic!("ArgumentsRequired::new({} addMargin() requires a holdSide parameter, either long or short)", self.get("id".into()).unwrap_str());
         };
         return Bitget::modify_margin_helper(self, symbol.clone(), amount.clone(), Value::from("add"), params.clone()).await;
     }
@@ -2605,7 +2604,7 @@ pub trait Bitget : Exchange {
     async fn fetch_leverage(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -2637,14 +2636,14 @@ pub trait Bitget : Exchange {
     async fn set_leverage(&mut self, mut leverage: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         let mut hold_side: Value = self.safe_string(params.clone(), Value::from("holdSide"), Value::Undefined);
         if hold_side.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a holdSide param"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a holdSide param)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
             "marginCoin": market.get(Value::from("settleId")),
@@ -2666,14 +2665,14 @@ pub trait Bitget : Exchange {
     async fn set_margin_mode(&mut self, mut margin_mode: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         margin_mode = margin_mode.to_lower_case();
         if margin_mode.clone() != Value::from("fixed") && margin_mode.clone() != Value::from("crossed") {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(r#" setMarginMode() marginMode must be "fixed" or "crossed""#))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() marginMode must be \"fixed\" or \"crossed\")", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
             "marginCoin": market.get(Value::from("settleId")),
@@ -2724,7 +2723,7 @@ pub trait Bitget : Exchange {
             Bitget::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), error_code.clone(), feedback.clone());
         };
         if non_zero_error_code.is_truthy() || non_empty_message.is_truthy() {
-            panic!(r###"ExchangeError::new(feedback)"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         Value::Undefined
     }
@@ -2882,12 +2881,12 @@ pub trait Bitget : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -2993,9 +2992,9 @@ pub trait Bitget : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -3234,9 +3233,9 @@ pub trait Bitget : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Bitget::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -3331,14 +3330,14 @@ pub trait Bitget : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -3560,7 +3559,7 @@ pub trait Bitget : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -3628,10 +3627,10 @@ pub trait Bitget : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -3745,7 +3744,7 @@ pub trait Bitget : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -3753,7 +3752,7 @@ pub trait Bitget : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -3779,19 +3778,8 @@ pub trait Bitget : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Bitget::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Bitget::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Bitget::load_time_difference(exchange, params), calculate_rate_limiter_cost: Bitget::calculate_rate_limiter_cost, sign: Bitget::sign, handle_errors: Bitget::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -3823,7 +3811,7 @@ pub trait Bitget : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Bitget::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -3859,13 +3847,13 @@ pub trait Bitget : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3965,13 +3953,14 @@ pub trait Bitget : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -3985,7 +3974,7 @@ pub trait Bitget : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4026,7 +4015,7 @@ pub trait Bitget : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Bitget::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -4035,7 +4024,7 @@ pub trait Bitget : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Bitget::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -4043,14 +4032,14 @@ pub trait Bitget : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Bitget::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4059,7 +4048,7 @@ pub trait Bitget : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -4068,12 +4057,12 @@ pub trait Bitget : Exchange {
         params = params.or_default(Value::new_object());
         Bitget::load_markets(self, Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = self.fetch_borrow_rates(params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -4126,14 +4115,14 @@ pub trait Bitget : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Bitget::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -4175,25 +4164,25 @@ pub trait Bitget : Exchange {
 
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_deposits(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchDeposits() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchDeposits() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_withdrawals(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchWithdrawals() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchWithdrawals() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4203,12 +4192,12 @@ pub trait Bitget : Exchange {
             let mut deposit_addresses: Value = self.fetch_deposit_addresses(Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
             let mut deposit_address: Value = self.safe_value(deposit_addresses.clone(), code.clone(), Value::Undefined);
             if deposit_address.clone().is_nullish() {
-                panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() could not find a deposit address for ") + code.clone() + Value::from(", make sure you have created a corresponding deposit address in your wallet on the exchange website"))"###);
+                panic!("InvalidAddress::new({} fetchDepositAddress() could not find a deposit address for {}, make sure you have created a corresponding deposit address in your wallet on the exchange website)", self.get("id".into()).unwrap_str(), js_string(&code));
             } else {
                 return deposit_address.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchDepositAddress() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchDepositAddress() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4230,7 +4219,7 @@ pub trait Bitget : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -4239,25 +4228,7 @@ pub trait Bitget : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -4306,22 +4277,22 @@ pub trait Bitget : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Bitget::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -4361,9 +4332,7 @@ pub trait Bitget : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = Bitget::fetch_time(self, params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -4375,14 +4344,14 @@ pub trait Bitget : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Bitget::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = self.fetch_leverage_tiers(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4390,7 +4359,7 @@ pub trait Bitget : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -4401,7 +4370,7 @@ pub trait Bitget : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -4412,10 +4381,10 @@ pub trait Bitget : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -4426,7 +4395,7 @@ pub trait Bitget : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -4437,7 +4406,7 @@ pub trait Bitget : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -4547,11 +4516,6 @@ pub trait Bitget : Exchange {
         return Bitget::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Bitget::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -4583,9 +4547,9 @@ pub trait Bitget : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -4596,7 +4560,7 @@ pub trait Bitget : Exchange {
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4634,7 +4598,7 @@ pub trait Bitget : Exchange {
             }))).unwrap());
             return Bitget::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4659,7 +4623,7 @@ pub trait Bitget : Exchange {
             }))).unwrap());
             return Bitget::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4684,7 +4648,7 @@ pub trait Bitget : Exchange {
             }))).unwrap());
             return Bitget::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4700,7 +4664,7 @@ pub trait Bitget : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -4721,7 +4685,7 @@ pub trait Bitget : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Bitget::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -4769,16 +4733,16 @@ pub trait Bitget : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Bitget::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Bitget::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -4786,74 +4750,74 @@ pub trait Bitget : Exchange {
         match method {
             Value::Json(serde_json::Value::String(ref m)) => {
                 match m.as_ref() {
-                    "publicSpotGetPublicTime" => Bitget::request(self, "public/time".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetPublicCurrencies" => Bitget::request(self, "public/currencies".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetPublicProducts" => Bitget::request(self, "public/products".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetPublicProduct" => Bitget::request(self, "public/product".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetMarketTicker" => Bitget::request(self, "market/ticker".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetMarketTickers" => Bitget::request(self, "market/tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetMarketFills" => Bitget::request(self, "market/fills".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetMarketCandles" => Bitget::request(self, "market/candles".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetMarketDepth" => Bitget::request(self, "market/depth".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketContracts" => Bitget::request(self, "market/contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketDepth" => Bitget::request(self, "market/depth".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketTicker" => Bitget::request(self, "market/ticker".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketTickers" => Bitget::request(self, "market/tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketFills" => Bitget::request(self, "market/fills".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketCandles" => Bitget::request(self, "market/candles".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketIndex" => Bitget::request(self, "market/index".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketFundingTime" => Bitget::request(self, "market/funding-time".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketHistoryFundRate" => Bitget::request(self, "market/history-fundRate".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketCurrentFundRate" => Bitget::request(self, "market/current-fundRate".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketOpenInterest" => Bitget::request(self, "market/open-interest".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketMarkPrice" => Bitget::request(self, "market/mark-price".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMixGetMarketSymbolLeverage" => Bitget::request(self, "market/symbol-leverage".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetAccountAssets" => Bitget::request(self, "account/assets".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetAccountTransferRecords" => Bitget::request(self, "account/transferRecords".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostAccountBills" => Bitget::request(self, "account/bills".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeOrders" => Bitget::request(self, "trade/orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeBatchOrders" => Bitget::request(self, "trade/batch-orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeCancelOrder" => Bitget::request(self, "trade/cancel-order".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeCancelBatchOrders" => Bitget::request(self, "trade/cancel-batch-orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeOrderInfo" => Bitget::request(self, "trade/orderInfo".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeOpenOrders" => Bitget::request(self, "trade/open-orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeHistory" => Bitget::request(self, "trade/history".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostTradeFills" => Bitget::request(self, "trade/fills".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetAccountAccount" => Bitget::request(self, "account/account".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetAccountAccounts" => Bitget::request(self, "account/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetAccountOpenCount" => Bitget::request(self, "account/open-count".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetOrderCurrent" => Bitget::request(self, "order/current".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetOrderHistory" => Bitget::request(self, "order/history".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetOrderDetail" => Bitget::request(self, "order/detail".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetOrderFills" => Bitget::request(self, "order/fills".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetPlanCurrentPlan" => Bitget::request(self, "plan/currentPlan".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetPlanHistoryPlan" => Bitget::request(self, "plan/historyPlan".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetPositionSinglePosition" => Bitget::request(self, "position/singlePosition".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetPositionAllPosition" => Bitget::request(self, "position/allPosition".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceCurrentTrack" => Bitget::request(self, "trace/currentTrack".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceFollowerOrder" => Bitget::request(self, "trace/followerOrder".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceHistoryTrack" => Bitget::request(self, "trace/historyTrack".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceSummary" => Bitget::request(self, "trace/summary".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceProfitSettleTokenIdGroup" => Bitget::request(self, "trace/profitSettleTokenIdGroup".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceProfitDateGroupList" => Bitget::request(self, "trace/profitDateGroupList".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTradeProfitDateList" => Bitget::request(self, "trade/profitDateList".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceWaitProfitDateList" => Bitget::request(self, "trace/waitProfitDateList".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixGetTraceTraderSymbols" => Bitget::request(self, "trace/traderSymbols".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostAccountSetLeverage" => Bitget::request(self, "account/setLeverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostAccountSetMargin" => Bitget::request(self, "account/setMargin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostAccountSetMarginMode" => Bitget::request(self, "account/setMarginMode".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostOrderPlaceOrder" => Bitget::request(self, "order/placeOrder".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostOrderBatchOrders" => Bitget::request(self, "order/batch-orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostOrderCancelOrder" => Bitget::request(self, "order/cancel-order".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostOrderCancelBatchOrders" => Bitget::request(self, "order/cancel-batch-orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostPlanPlacePlan" => Bitget::request(self, "plan/placePlan".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostPlanModifyPlan" => Bitget::request(self, "plan/modifyPlan".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostPlanModifyPlanPreset" => Bitget::request(self, "plan/modifyPlanPreset".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostPlanPlaceTpsl" => Bitget::request(self, "plan/placeTPSL".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostPlanModifyTpslPlan" => Bitget::request(self, "plan/modifyTPSLPlan".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostPlanCancelPlan" => Bitget::request(self, "plan/cancelPlan".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostTraceCloseTrackOrder" => Bitget::request(self, "trace/closeTrackOrder".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMixPostTraceSetUpCopySymbols" => Bitget::request(self, "trace/setUpCopySymbols".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetPublicTime" => Bitget::request(self, "public/time".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetPublicCurrencies" => Bitget::request(self, "public/currencies".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetPublicProducts" => Bitget::request(self, "public/products".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetPublicProduct" => Bitget::request(self, "public/product".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetMarketTicker" => Bitget::request(self, "market/ticker".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetMarketTickers" => Bitget::request(self, "market/tickers".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetMarketFills" => Bitget::request(self, "market/fills".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetMarketCandles" => Bitget::request(self, "market/candles".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetMarketDepth" => Bitget::request(self, "market/depth".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketContracts" => Bitget::request(self, "market/contracts".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketDepth" => Bitget::request(self, "market/depth".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketTicker" => Bitget::request(self, "market/ticker".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketTickers" => Bitget::request(self, "market/tickers".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketFills" => Bitget::request(self, "market/fills".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketCandles" => Bitget::request(self, "market/candles".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketIndex" => Bitget::request(self, "market/index".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketFundingTime" => Bitget::request(self, "market/funding-time".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketHistoryFundRate" => Bitget::request(self, "market/history-fundRate".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketCurrentFundRate" => Bitget::request(self, "market/current-fundRate".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketOpenInterest" => Bitget::request(self, "market/open-interest".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketMarkPrice" => Bitget::request(self, "market/mark-price".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMixGetMarketSymbolLeverage" => Bitget::request(self, "market/symbol-leverage".into(), Value::Json(json!(["public", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetAccountAssets" => Bitget::request(self, "account/assets".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetAccountTransferRecords" => Bitget::request(self, "account/transferRecords".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostAccountBills" => Bitget::request(self, "account/bills".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeOrders" => Bitget::request(self, "trade/orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeBatchOrders" => Bitget::request(self, "trade/batch-orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeCancelOrder" => Bitget::request(self, "trade/cancel-order".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeCancelBatchOrders" => Bitget::request(self, "trade/cancel-batch-orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeOrderInfo" => Bitget::request(self, "trade/orderInfo".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeOpenOrders" => Bitget::request(self, "trade/open-orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeHistory" => Bitget::request(self, "trade/history".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostTradeFills" => Bitget::request(self, "trade/fills".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetAccountAccount" => Bitget::request(self, "account/account".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetAccountAccounts" => Bitget::request(self, "account/accounts".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetAccountOpenCount" => Bitget::request(self, "account/open-count".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetOrderCurrent" => Bitget::request(self, "order/current".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetOrderHistory" => Bitget::request(self, "order/history".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetOrderDetail" => Bitget::request(self, "order/detail".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetOrderFills" => Bitget::request(self, "order/fills".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetPlanCurrentPlan" => Bitget::request(self, "plan/currentPlan".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetPlanHistoryPlan" => Bitget::request(self, "plan/historyPlan".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetPositionSinglePosition" => Bitget::request(self, "position/singlePosition".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetPositionAllPosition" => Bitget::request(self, "position/allPosition".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceCurrentTrack" => Bitget::request(self, "trace/currentTrack".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceFollowerOrder" => Bitget::request(self, "trace/followerOrder".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceHistoryTrack" => Bitget::request(self, "trace/historyTrack".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceSummary" => Bitget::request(self, "trace/summary".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceProfitSettleTokenIdGroup" => Bitget::request(self, "trace/profitSettleTokenIdGroup".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceProfitDateGroupList" => Bitget::request(self, "trace/profitDateGroupList".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTradeProfitDateList" => Bitget::request(self, "trade/profitDateList".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceWaitProfitDateList" => Bitget::request(self, "trace/waitProfitDateList".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixGetTraceTraderSymbols" => Bitget::request(self, "trace/traderSymbols".into(), Value::Json(json!(["private", "mix"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostAccountSetLeverage" => Bitget::request(self, "account/setLeverage".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostAccountSetMargin" => Bitget::request(self, "account/setMargin".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostAccountSetMarginMode" => Bitget::request(self, "account/setMarginMode".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostOrderPlaceOrder" => Bitget::request(self, "order/placeOrder".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostOrderBatchOrders" => Bitget::request(self, "order/batch-orders".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostOrderCancelOrder" => Bitget::request(self, "order/cancel-order".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostOrderCancelBatchOrders" => Bitget::request(self, "order/cancel-batch-orders".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostPlanPlacePlan" => Bitget::request(self, "plan/placePlan".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostPlanModifyPlan" => Bitget::request(self, "plan/modifyPlan".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostPlanModifyPlanPreset" => Bitget::request(self, "plan/modifyPlanPreset".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostPlanPlaceTpsl" => Bitget::request(self, "plan/placeTPSL".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostPlanModifyTpslPlan" => Bitget::request(self, "plan/modifyTPSLPlan".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostPlanCancelPlan" => Bitget::request(self, "plan/cancelPlan".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostTraceCloseTrackOrder" => Bitget::request(self, "trace/closeTrackOrder".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMixPostTraceSetUpCopySymbols" => Bitget::request(self, "trace/setUpCopySymbols".into(), Value::Json(json!(["private", "mix"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
                     _ => unimplemented!(),
                 }
             },
@@ -4863,7 +4827,7 @@ pub trait Bitget : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct BitgetImpl(Value);
+pub struct BitgetImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for BitgetImpl {}
 impl Bitget for BitgetImpl {}
 impl ValueTrait for BitgetImpl {
@@ -4885,8 +4849,8 @@ impl ValueTrait for BitgetImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -4905,7 +4869,8 @@ impl BitgetImpl {
         let mut rv = BitgetImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Bitget::describe(&rv);
@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use crate::exchange::{Value, ValueTrait};
+
+/// How an exchange provides a unified method, as declared by the values of `describe()["has"]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Support {
+    Native,
+    Emulated,
+    Unsupported,
+}
+
+impl Support {
+    /// `true` is native, `"emulated"` is emulated, anything else (`false`, `null` or a missing key) is unsupported
+    pub fn from_value(value: &Value) -> Support {
+        match value {
+            Value::Json(serde_json::Value::Bool(true)) => Support::Native,
+            Value::Json(serde_json::Value::String(s)) if s == "emulated" => Support::Emulated,
+            _ => Support::Unsupported,
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        *self != Support::Unsupported
+    }
+}
+
+macro_rules! capabilities {
+    ($($field:ident: $key:literal = $default:ident,)*) => {
+        /// The typed form of `describe()["has"]`, one field per capability of the base exchange with the
+        /// exchange's own entries layered over the base defaults, like the deep extend done by the base class
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct Capabilities {
+            $(pub $field: Support,)*
+            /// entries that only some exchanges declare, keyed by their camelCase name
+            pub other: BTreeMap<String, Support>,
+        }
+
+        impl Default for Capabilities {
+            fn default() -> Self {
+                Capabilities {
+                    $($field: Support::$default,)*
+                    other: BTreeMap::new(),
+                }
+            }
+        }
+
+        impl Capabilities {
+            pub fn from_has(has: &Value) -> Capabilities {
+                let mut rv = Capabilities::default();
+                if has.is_object() {
+                    for key in has.keys() {
+                        let support = Support::from_value(&has.get(key.clone()));
+                        match key.unwrap_str() {
+                            $($key => rv.$field = support,)*
+                            other => { rv.other.insert(other.to_owned(), support); }
+                        }
+                    }
+                }
+                rv
+            }
+
+            /// Looks a capability up by its camelCase name, e.g. `"fetchOrderBook"`
+            pub fn get(&self, key: &str) -> Support {
+                match key {
+                    $($key => self.$field,)*
+                    other => self.other.get(other).copied().unwrap_or(Support::Unsupported),
+                }
+            }
+
+            pub fn supports(&self, key: &str) -> bool {
+                self.get(key).is_supported()
+            }
+
+            /// Every capability by camelCase name
+            pub fn iter(&self) -> impl Iterator<Item = (&str, Support)> + '_ {
+                vec![$(($key, self.$field),)*].into_iter()
+                    .chain(self.other.iter().map(|(k, v)| (k.as_str(), *v)))
+            }
+        }
+    };
+}
+
+capabilities! {
+    public_api: "publicAPI" = Native,
+    private_api: "privateAPI" = Native,
+    cors: "CORS" = Unsupported,
+    spot: "spot" = Unsupported,
+    margin: "margin" = Unsupported,
+    swap: "swap" = Unsupported,
+    future: "future" = Unsupported,
+    option: "option" = Unsupported,
+    add_margin: "addMargin" = Unsupported,
+    cancel_all_orders: "cancelAllOrders" = Unsupported,
+    cancel_order: "cancelOrder" = Native,
+    cancel_orders: "cancelOrders" = Unsupported,
+    create_deposit_address: "createDepositAddress" = Unsupported,
+    create_limit_order: "createLimitOrder" = Native,
+    create_market_order: "createMarketOrder" = Native,
+    create_order: "createOrder" = Native,
+    create_post_only_order: "createPostOnlyOrder" = Unsupported,
+    create_reduce_only_order: "createReduceOnlyOrder" = Unsupported,
+    create_stop_order: "createStopOrder" = Unsupported,
+    create_stop_limit_order: "createStopLimitOrder" = Unsupported,
+    create_stop_market_order: "createStopMarketOrder" = Unsupported,
+    edit_order: "editOrder" = Emulated,
+    fetch_accounts: "fetchAccounts" = Unsupported,
+    fetch_balance: "fetchBalance" = Native,
+    fetch_bids_asks: "fetchBidsAsks" = Unsupported,
+    fetch_borrow_interest: "fetchBorrowInterest" = Unsupported,
+    fetch_borrow_rate: "fetchBorrowRate" = Unsupported,
+    fetch_borrow_rate_history: "fetchBorrowRateHistory" = Unsupported,
+    fetch_borrow_rates_per_symbol: "fetchBorrowRatesPerSymbol" = Unsupported,
+    fetch_borrow_rates: "fetchBorrowRates" = Unsupported,
+    fetch_canceled_orders: "fetchCanceledOrders" = Unsupported,
+    fetch_closed_order: "fetchClosedOrder" = Unsupported,
+    fetch_closed_orders: "fetchClosedOrders" = Unsupported,
+    fetch_currencies: "fetchCurrencies" = Emulated,
+    fetch_deposit: "fetchDeposit" = Unsupported,
+    fetch_deposit_address: "fetchDepositAddress" = Unsupported,
+    fetch_deposit_addresses: "fetchDepositAddresses" = Unsupported,
+    fetch_deposit_addresses_by_network: "fetchDepositAddressesByNetwork" = Unsupported,
+    fetch_deposits: "fetchDeposits" = Unsupported,
+    fetch_transaction_fee: "fetchTransactionFee" = Unsupported,
+    fetch_transaction_fees: "fetchTransactionFees" = Unsupported,
+    fetch_funding_history: "fetchFundingHistory" = Unsupported,
+    fetch_funding_rate: "fetchFundingRate" = Unsupported,
+    fetch_funding_rate_history: "fetchFundingRateHistory" = Unsupported,
+    fetch_funding_rates: "fetchFundingRates" = Unsupported,
+    fetch_index_ohlcv: "fetchIndexOHLCV" = Unsupported,
+    fetch_l2_order_book: "fetchL2OrderBook" = Native,
+    fetch_ledger: "fetchLedger" = Unsupported,
+    fetch_ledger_entry: "fetchLedgerEntry" = Unsupported,
+    fetch_leverage_tiers: "fetchLeverageTiers" = Unsupported,
+    fetch_market_leverage_tiers: "fetchMarketLeverageTiers" = Unsupported,
+    fetch_markets: "fetchMarkets" = Native,
+    fetch_mark_ohlcv: "fetchMarkOHLCV" = Unsupported,
+    fetch_my_trades: "fetchMyTrades" = Unsupported,
+    fetch_ohlcv: "fetchOHLCV" = Emulated,
+    fetch_open_order: "fetchOpenOrder" = Unsupported,
+    fetch_open_orders: "fetchOpenOrders" = Unsupported,
+    fetch_order: "fetchOrder" = Unsupported,
+    fetch_order_book: "fetchOrderBook" = Native,
+    fetch_order_books: "fetchOrderBooks" = Unsupported,
+    fetch_orders: "fetchOrders" = Unsupported,
+    fetch_order_trades: "fetchOrderTrades" = Unsupported,
+    fetch_permissions: "fetchPermissions" = Unsupported,
+    fetch_position: "fetchPosition" = Unsupported,
+    fetch_positions: "fetchPositions" = Unsupported,
+    fetch_positions_risk: "fetchPositionsRisk" = Unsupported,
+    fetch_premium_index_ohlcv: "fetchPremiumIndexOHLCV" = Unsupported,
+    fetch_status: "fetchStatus" = Emulated,
+    fetch_ticker: "fetchTicker" = Native,
+    fetch_tickers: "fetchTickers" = Unsupported,
+    fetch_time: "fetchTime" = Unsupported,
+    fetch_trades: "fetchTrades" = Native,
+    fetch_trading_fee: "fetchTradingFee" = Unsupported,
+    fetch_trading_fees: "fetchTradingFees" = Unsupported,
+    fetch_trading_limits: "fetchTradingLimits" = Unsupported,
+    fetch_transactions: "fetchTransactions" = Unsupported,
+    fetch_transfers: "fetchTransfers" = Unsupported,
+    fetch_withdrawal: "fetchWithdrawal" = Unsupported,
+    fetch_withdrawals: "fetchWithdrawals" = Unsupported,
+    reduce_margin: "reduceMargin" = Unsupported,
+    set_leverage: "setLeverage" = Unsupported,
+    set_margin: "setMargin" = Unsupported,
+    set_margin_mode: "setMarginMode" = Unsupported,
+    set_position_mode: "setPositionMode" = Unsupported,
+    sign_in: "signIn" = Unsupported,
+    transfer: "transfer" = Unsupported,
+    withdraw: "withdraw" = Unsupported,
+}
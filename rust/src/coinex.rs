@@ -11,10 +11,11 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, parse_float, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -423,7 +424,7 @@ pub trait Coinex : Exchange {
                 "precision": self.parse_number(Coinex::parse_precision(self, self.safe_string(currency.clone(), Value::from("withdrawal_precision"), Value::Undefined)), Value::Undefined)
             }))).unwrap());
             networks.set(network_id.clone(), network.clone());
-            result.get(code.clone()).set("networks".into(), networks.clone());
+            result.set_path(&[code.clone(), "networks".into()], networks.clone());
             i += 1;
         };
         return result.clone();
@@ -445,7 +446,7 @@ pub trait Coinex : Exchange {
         } else if r#type.clone() == Value::from("swap") {
             result = Coinex::fetch_contract_markets(self, query.clone()).await;
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not support the '") + r#type.clone() + Value::from("' market type, set exchange.options['defaultType'] to 'spot', 'margin' or 'swap'"))"###);
+            panic!("ExchangeError::new({} does not support the '{}' market type, set exchange.options['defaultType'] to 'spot', 'margin' or 'swap')", self.get("id".into()).unwrap_str(), js_string(&r#type));
         };
         return result.clone();
     }
@@ -617,7 +618,7 @@ pub trait Coinex : Exchange {
                 "limits": Value::Json(normalize(&Value::Json(json!({
                     "leverage": Value::Json(normalize(&Value::Json(json!({
                         "min": self.safe_string(leverages.clone(), Value::from(0), Value::Undefined),
-                        "max": self.safe_string(leverages.clone(), leverages.len().into() - Value::from(1), Value::Undefined)
+                        "max": self.safe_string(leverages.clone(), Value::from(leverages.len()) - Value::from(1), Value::Undefined)
                     }))).unwrap()),
                     "amount": Value::Json(normalize(&Value::Json(json!({
                         "min": self.safe_string(entry.clone(), Value::from("amount_min"), Value::Undefined),
@@ -681,7 +682,7 @@ pub trait Coinex : Exchange {
         //     }
         //
         let mut timestamp: Value = self.safe_integer(ticker.clone(), Value::from("date"), Value::Undefined);
-        let mut symbol: Value = Coinex::safe_symbol(self, Value::Undefined, market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(Value::Undefined, market.clone(), Value::Undefined);
         ticker = self.safe_value(ticker.clone(), Value::from("ticker"), Value::new_object());
         let mut last: Value = self.safe_string(ticker.clone(), Value::from("last"), Value::Undefined);
         return Coinex::safe_ticker(self, Value::Json(normalize(&Value::Json(json!({
@@ -719,7 +720,7 @@ pub trait Coinex : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id"))
         }))).unwrap());
@@ -909,10 +910,10 @@ pub trait Coinex : Exchange {
         limit = limit.or_default(Value::from(20));
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrderBook() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrderBook() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if limit.clone().is_nullish() {
             limit = Value::from(20);
         };
@@ -1047,7 +1048,7 @@ pub trait Coinex : Exchange {
         let mut amount_string: Value = self.safe_string(trade.clone(), Value::from("amount"), Value::Undefined);
         let mut market_id: Value = self.safe_string(trade.clone(), Value::from("market"), Value::Undefined);
         market = Coinex::safe_market(self, market_id.clone(), market.clone(), Value::Undefined);
-        let mut symbol: Value = Coinex::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut cost_string: Value = self.safe_string(trade.clone(), Value::from("deal_money"), Value::Undefined);
         let mut fee: Value = Value::Undefined;
         let mut fee_cost_string: Value = self.safe_string_2(trade.clone(), Value::from("fee"), Value::from("deal_fee"), Value::Undefined);
@@ -1109,7 +1110,7 @@ pub trait Coinex : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id"))
         }))).unwrap());
@@ -1151,7 +1152,7 @@ pub trait Coinex : Exchange {
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id"))
         }))).unwrap());
@@ -1210,7 +1211,7 @@ pub trait Coinex : Exchange {
         let mut i: usize = 0;
         while i < self.get("symbols".into()).len() {
             let mut symbol: Value = self.get("symbols".into()).get(i.into());
-            let mut market: Value = Coinex::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             let mut fee: Value = self.safe_value(data.clone(), market.get(Value::from("id")), Value::new_object());
             result.set(symbol.clone(), Coinex::parse_trading_fee(self, fee.clone(), market.clone()));
             i += 1;
@@ -1220,7 +1221,7 @@ pub trait Coinex : Exchange {
 
     fn parse_trading_fee(&self, mut fee: Value, mut market: Value) -> Value {
         let mut market_id: Value = self.safe_value(fee.clone(), Value::from("name"), Value::Undefined);
-        let mut symbol: Value = Coinex::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         return Value::Json(normalize(&Value::Json(json!({
             "info": fee,
             "symbol": symbol,
@@ -1262,7 +1263,7 @@ pub trait Coinex : Exchange {
         timeframe = timeframe.or_default(Value::from("5m"));
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
             "type": self.get("timeframes".into()).get(timeframe.clone())
@@ -1308,10 +1309,10 @@ pub trait Coinex : Exchange {
         let mut market_id: Value = self.safe_string(params.clone(), Value::from("market"), Value::Undefined);
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Coinex::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             market_id = market.get(Value::from("id"));
         } else if market_id.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMarginBalance() fetching a margin account requires a market parameter or a symbol parameter"))"###);
+            panic!("ArgumentsRequired::new({} fetchMarginBalance() fetching a margin account requires a market parameter or a symbol parameter)", self.get("id".into()).unwrap_str());
         };
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("symbol").into(), Value::from("market").into()])));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -1797,7 +1798,7 @@ pub trait Coinex : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut swap: Value = market.get(Value::from("swap"));
         let mut stop_price: Value = self.safe_value_2(params.clone(), Value::from("stopPrice"), Value::from("triggerPrice"), Value::Undefined);
         let mut stop_loss_price: Value = self.safe_value(params.clone(), Value::from("stopLossPrice"), Value::Undefined);
@@ -1812,7 +1813,7 @@ pub trait Coinex : Exchange {
         let mut reduce_only: Value = self.safe_value(params.clone(), Value::from("reduceOnly"), Value::Undefined);
         if reduce_only.clone().is_nonnullish() {
             if market.get(Value::from("type")) != Value::from("swap") {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() does not support reduceOnly for ") + market.get(Value::from("type")) + Value::from(" orders, reduceOnly orders are supported for swap markets only"))"###);
+                panic!("InvalidOrder::new({} createOrder() does not support reduceOnly for {} orders, reduceOnly orders are supported for swap markets only)", self.get("id".into()).unwrap_str(), js_string(&market.get(Value::from("type"))));
             };
         };
         let mut method: Value = Value::Undefined;
@@ -1824,7 +1825,7 @@ pub trait Coinex : Exchange {
                 request.set("stop_type".into(), self.safe_integer(params.clone(), Value::from("stop_type"), Value::from(1)));
                 // 1: triggered by the latest transaction, 2: mark price, 3: index price
                 if position_id.clone().is_nullish() {
-                    panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" createOrder() requires a position_id parameter for stop loss and take profit orders"))"###);
+                    panic!("ArgumentsRequired::new({} createOrder() requires a position_id parameter for stop loss and take profit orders)", self.get("id".into()).unwrap_str());
                 };
                 request.set("position_id".into(), position_id.clone());
                 if stop_loss_price.is_truthy() {
@@ -1891,7 +1892,7 @@ pub trait Coinex : Exchange {
             if r#type.clone() == Value::from("market") && side.clone() == Value::from("buy") {
                 if self.get("options".into()).get(Value::from("createMarketBuyOrderRequiresPrice")).is_truthy() {
                     if price.clone().is_nullish() {
-                        panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() requires the price argument with market buy orders to calculate total order cost (amount to spend), where cost = amount * price. Supply a price argument to createOrder() call if you want the cost to be calculated for you from price and amount, or, alternatively, add .options['createMarketBuyOrderRequiresPrice'] = false to supply the cost in the amount argument (the exchange-specific behaviour)"))"###);
+                        panic!("InvalidOrder::new({} createOrder() requires the price argument with market buy orders to calculate total order cost (amount to spend), where cost = amount * price. Supply a price argument to createOrder() call if you want the cost to be calculated for you from price and amount, or, alternatively, add .options['createMarketBuyOrderRequiresPrice'] = false to supply the cost in the amount argument (the exchange-specific behaviour))", self.get("id".into()).unwrap_str());
                     } else {
                         let mut amount_string: Value = Coinex::amount_to_precision(self, symbol.clone(), amount.clone());
                         let mut price_string: Value = Coinex::price_to_precision(self, symbol.clone(), price.clone());
@@ -1920,7 +1921,7 @@ pub trait Coinex : Exchange {
                 // following options cannot be applied to vanilla market orders (but can be applied to stop-market orders)
                 if time_in_force.clone().is_nonnullish() || post_only.is_truthy() {
                     if post_only.is_truthy() || time_in_force.clone() != Value::from("IOC") && r#type.clone() == Value::from("limit") && stop_price.clone().is_nonnullish() {
-                        panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() only supports the IOC option for stop-limit orders"))"###);
+                        panic!("InvalidOrder::new({} createOrder() only supports the IOC option for stop-limit orders)", self.get("id".into()).unwrap_str());
                     };
                     if post_only.is_truthy() {
                         request.set("option".into(), Value::from("MAKER_ONLY"));
@@ -1937,7 +1938,7 @@ pub trait Coinex : Exchange {
         let mut default_type: Value = self.safe_string(self.get("options".into()), Value::from("defaultType"), Value::Undefined);
         if default_type.clone() == Value::from("margin") {
             if account_id.clone().is_nullish() {
-                panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" createOrder() requires an account_id parameter for margin orders"))"###);
+                panic!("BadRequest::new({} createOrder() requires an account_id parameter for margin orders)", self.get("id".into()).unwrap_str());
             };
             request.set("account_id".into(), account_id.clone());
         };
@@ -2036,7 +2037,7 @@ pub trait Coinex : Exchange {
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut stop: Value = self.safe_value(params.clone(), Value::from("stop"), Value::Undefined);
         let mut swap: Value = market.get(Value::from("swap"));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -2056,7 +2057,7 @@ pub trait Coinex : Exchange {
         let mut default_type: Value = self.safe_string(self.get("options".into()), Value::from("defaultType"), Value::Undefined);
         if default_type.clone() == Value::from("margin") {
             if account_id.clone().is_nullish() {
-                panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" cancelOrder() requires an account_id parameter for margin orders"))"###);
+                panic!("BadRequest::new({} cancelOrder() requires an account_id parameter for margin orders)", self.get("id".into()).unwrap_str());
             };
             request.set("account_id".into(), account_id.clone());
         };
@@ -2183,10 +2184,10 @@ pub trait Coinex : Exchange {
     async fn cancel_all_orders(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancellAllOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancellAllOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut market_id: Value = market.get(Value::from("id"));
         let mut account_id: Value = self.safe_integer(params.clone(), Value::from("account_id"), Value::from(0));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -2234,10 +2235,10 @@ pub trait Coinex : Exchange {
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut swap: Value = market.get(Value::from("swap"));
         let mut stop: Value = self.safe_value(params.clone(), Value::from("stop"), Value::Undefined);
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -2372,14 +2373,14 @@ pub trait Coinex : Exchange {
         params = self.omit(params.clone(), Value::from("stop"));
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Coinex::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("market".into(), market.get(Value::from("id")));
         };
         let (mut market_type, mut query) = shift_2(Coinex::handle_market_type_and_params(self, Value::from("fetchOrdersByStatus"), market.clone(), params.clone()));
         let mut method: Value = Value::Undefined;
         if market_type.clone() == Value::from("swap") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrdersByStatus() requires a symbol argument for swap markets"))"###);
+                panic!("ArgumentsRequired::new({} fetchOrdersByStatus() requires a symbol argument for swap markets)", self.get("id".into()).unwrap_str());
             };
             method = Value::from("perpetualPrivateGetOrder") + self.capitalize(status.clone());
             if stop.is_truthy() {
@@ -2402,7 +2403,7 @@ pub trait Coinex : Exchange {
         let mut default_type: Value = self.safe_string(self.get("options".into()), Value::from("defaultType"), Value::Undefined);
         if default_type.clone() == Value::from("margin") {
             if account_id.clone().is_nullish() {
-                panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchOpenOrders() and fetchClosedOrders() require an account_id parameter for margin orders"))"###);
+                panic!("BadRequest::new({} fetchOpenOrders() and fetchClosedOrders() require an account_id parameter for margin orders)", self.get("id".into()).unwrap_str());
             };
             request.set("account_id".into(), account_id.clone());
         };
@@ -2650,10 +2651,10 @@ pub trait Coinex : Exchange {
         let mut num_of_networks: Value = networks_keys.len().into();
         if networks.clone().is_nonnullish() && num_of_networks.clone() > Value::from(1) {
             if network.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchDepositAddress() ") + code.clone() + Value::from(" requires a network parameter"))"###);
+                panic!("ArgumentsRequired::new({} fetchDepositAddress() {} requires a network parameter)", self.get("id".into()).unwrap_str(), js_string(&code));
             };
             if !networks.contains_key(network.clone()) {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchDepositAddress() ") + network.clone() + Value::from(" network not supported for ") + code.clone())"###);
+                panic!("ExchangeError::new({} fetchDepositAddress() {} network not supported for {})", self.get("id".into()).unwrap_str(), js_string(&network), js_string(&code));
             };
         };
         if network.clone().is_nonnullish() {
@@ -2742,13 +2743,13 @@ pub trait Coinex : Exchange {
         // 'start_time': since, // SWAP
         // 'end_time': 1524228297, // SWAP
         if symbol.clone().is_nonnullish() {
-            market = Coinex::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("market".into(), market.get(Value::from("id")));
         };
         let mut r#type: Value = Value::Undefined;
         (r#type, params) = shift_2(Coinex::handle_market_type_and_params(self, Value::from("fetchMyTrades"), market.clone(), params.clone()));
         if r#type.clone() != Value::from("spot") && symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires a symbol argument for non-spot markets"))"###);
+            panic!("ArgumentsRequired::new({} fetchMyTrades() requires a symbol argument for non-spot markets)", self.get("id".into()).unwrap_str());
         };
         let mut swap: Value = (r#type.clone() == Value::from("swap")).into();
         let mut method: Value = Value::Undefined;
@@ -2756,7 +2757,7 @@ pub trait Coinex : Exchange {
             method = Value::from("perpetualPublicGetMarketUserDeals");
             let mut side: Value = self.safe_integer(params.clone(), Value::from("side"), Value::Undefined);
             if side.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires a side parameter for swap markets"))"###);
+                panic!("ArgumentsRequired::new({} fetchMyTrades() requires a side parameter for swap markets)", self.get("id".into()).unwrap_str());
             };
             if since.clone().is_nonnullish() {
                 request.set("start_time".into(), since.clone());
@@ -2771,7 +2772,7 @@ pub trait Coinex : Exchange {
         let mut default_type: Value = self.safe_string(self.get("options".into()), Value::from("defaultType"), Value::Undefined);
         if default_type.clone() == Value::from("margin") {
             if account_id.clone().is_nullish() {
-                panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires an account_id parameter for margin trades"))"###);
+                panic!("BadRequest::new({} fetchMyTrades() requires an account_id parameter for margin trades)", self.get("id".into()).unwrap_str());
             };
             request.set("account_id".into(), account_id.clone());
             params = self.omit(params.clone(), Value::from("account_id"));
@@ -2874,13 +2875,13 @@ pub trait Coinex : Exchange {
             if Array::is_array(symbols.clone()).is_truthy() {
                 let mut symbols_length: Value = symbols.len().into();
                 if symbols_length.clone() > Value::from(1) {
-                    panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchPositions() symbols argument cannot contain more than 1 symbol"))"###);
+                    panic!("BadRequest::new({} fetchPositions() symbols argument cannot contain more than 1 symbol)", self.get("id".into()).unwrap_str());
                 };
                 symbol = symbols.get(Value::from(0));
             } else {
                 symbol = symbols.clone();
             };
-            market = Coinex::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("market".into(), market.get(Value::from("id")));
         };
         let mut response: Value = Coinex::dispatch(self, "perpetualPrivateGetPositionPending".into(), extend_2(request.clone(), params.clone()), Value::Undefined).await;
@@ -2964,7 +2965,7 @@ pub trait Coinex : Exchange {
     async fn fetch_position(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id"))
         }))).unwrap());
@@ -3142,16 +3143,16 @@ pub trait Coinex : Exchange {
     async fn set_margin_mode(&mut self, mut margin_mode: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         margin_mode = margin_mode.to_lower_case();
         if margin_mode.clone() != Value::from("isolated") && margin_mode.clone() != Value::from("cross") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setMarginMode() marginMode argument should be isolated or cross"))"###);
+            panic!("BadRequest::new({} setMarginMode() marginMode argument should be isolated or cross)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.get(Value::from("type")) != Value::from("swap") {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" setMarginMode() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} setMarginMode() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut default_margin_mode: Value = self.safe_string_2(self.get("options".into()), Value::from("defaultMarginMode"), margin_mode.clone(), Value::Undefined);
         let mut default_position_type: Value = Value::Undefined;
@@ -3164,13 +3165,13 @@ pub trait Coinex : Exchange {
         let mut max_leverage: Value = self.safe_integer(market.get(Value::from("limits")).get(Value::from("leverage")), Value::from("max"), Value::from(100));
         let mut position_type: Value = self.safe_integer(params.clone(), Value::from("position_type"), default_position_type.clone());
         if leverage.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a leverage parameter"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a leverage parameter)", self.get("id".into()).unwrap_str());
         };
         if position_type.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a position_type parameter that will transfer margin to the specified trading pair"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a position_type parameter that will transfer margin to the specified trading pair)", self.get("id".into()).unwrap_str());
         };
         if leverage.clone() < Value::from(3) || leverage.clone() > max_leverage.clone() {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setMarginMode() leverage should be between 3 and ") + max_leverage.to_string() + Value::from(" for ") + symbol.clone())"###);
+            panic!("BadRequest::new({} setMarginMode() leverage should be between 3 and {} for {})", self.get("id".into()).unwrap_str(), js_string(&max_leverage), js_string(&symbol));
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
@@ -3193,7 +3194,7 @@ pub trait Coinex : Exchange {
     async fn set_leverage(&mut self, mut leverage: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut default_margin_mode: Value = self.safe_string_2(self.get("options".into()), Value::from("defaultMarginMode"), Value::from("marginMode"), Value::Undefined);
@@ -3205,15 +3206,15 @@ pub trait Coinex : Exchange {
         };
         let mut position_type: Value = self.safe_integer(params.clone(), Value::from("position_type"), default_position_type.clone());
         if position_type.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a position_type parameter that will transfer margin to the specified trading pair"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a position_type parameter that will transfer margin to the specified trading pair)", self.get("id".into()).unwrap_str());
         };
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut max_leverage: Value = self.safe_integer(market.get(Value::from("limits")).get(Value::from("leverage")), Value::from("max"), Value::from(100));
         if market.get(Value::from("type")) != Value::from("swap") {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" setLeverage() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} setLeverage() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         if leverage.clone() < Value::from(3) || leverage.clone() > max_leverage.clone() {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setLeverage() leverage should be between 3 and ") + max_leverage.to_string() + Value::from(" for ") + symbol.clone())"###);
+            panic!("BadRequest::new({} setLeverage() leverage should be between 3 and {} for {})", self.get("id".into()).unwrap_str(), js_string(&max_leverage), js_string(&symbol));
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
@@ -3315,7 +3316,7 @@ pub trait Coinex : Exchange {
     async fn modify_margin_helper(&mut self, mut symbol: Value, mut amount: Value, mut add_or_reduce: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
             "amount": Coinex::amount_to_precision(self, symbol.clone(), amount.clone()),
@@ -3394,7 +3395,7 @@ pub trait Coinex : Exchange {
             "type": Value::Undefined,
             "amount": Value::Undefined,
             "code": market.get(Value::from("quote")),
-            "symbol": Coinex::safe_symbol(self, Value::Undefined, market.clone(), Value::Undefined),
+            "symbol": self.safe_symbol(Value::Undefined, market.clone(), Value::Undefined),
             "status": Value::Undefined
         }))).unwrap());
     }
@@ -3440,11 +3441,11 @@ pub trait Coinex : Exchange {
     async fn fetch_funding_history(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         limit = if limit.clone().is_nullish() { Value::from(100) } else { limit.clone() };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
             "limit": limit
@@ -3517,9 +3518,9 @@ pub trait Coinex : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if !market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} fetchFundingRate() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id"))
@@ -3591,7 +3592,7 @@ pub trait Coinex : Exchange {
         //
         return Value::Json(normalize(&Value::Json(json!({
             "info": contract,
-            "symbol": Coinex::safe_symbol(self, Value::Undefined, market.clone(), Value::Undefined),
+            "symbol": self.safe_symbol(Value::Undefined, market.clone(), Value::Undefined),
             "markPrice": self.safe_string(contract.clone(), Value::from("sign_price"), Value::Undefined),
             "indexPrice": self.safe_string(contract.clone(), Value::from("index_price"), Value::Undefined),
             "interestRate": Value::Undefined,
@@ -3690,10 +3691,10 @@ pub trait Coinex : Exchange {
         limit = limit.or_default(Value::from(100));
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingRateHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
             "limit": limit,
@@ -3730,7 +3731,7 @@ pub trait Coinex : Exchange {
         while i < result.len() {
             let mut entry: Value = result.get(i.into());
             let mut market_id: Value = self.safe_string(entry.clone(), Value::from("market"), Value::Undefined);
-            let mut symbol: Value = Coinex::safe_symbol(self, market_id.clone(), Value::Undefined, Value::Undefined);
+            let mut symbol: Value = self.safe_symbol(market_id.clone(), Value::Undefined, Value::Undefined);
             let mut timestamp: Value = self.safe_timestamp(entry.clone(), Value::from("time"), Value::Undefined);
             rates.push(Value::Json(normalize(&Value::Json(json!({
                 "info": entry,
@@ -4053,7 +4054,7 @@ pub trait Coinex : Exchange {
     async fn fetch_withdrawals(&mut self, mut code: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if code.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchWithdrawals() requires a currency code argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchWithdrawals() requires a currency code argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut currency: Value = Coinex::currency(self, code.clone());
@@ -4122,7 +4123,7 @@ pub trait Coinex : Exchange {
     async fn fetch_deposits(&mut self, mut code: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if code.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchDeposits() requires a currency code argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchDeposits() requires a currency code argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut currency: Value = Coinex::currency(self, code.clone());
@@ -4209,10 +4210,10 @@ pub trait Coinex : Exchange {
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if self.get("markets".into()).contains_key(code.clone()) {
-            market = Coinex::market(self, code.clone());
+            market = self.market(code.clone());
         } else {
             let mut default_settle: Value = self.safe_string(self.get("options".into()), Value::from("defaultSettle"), Value::from("USDT"));
-            market = Coinex::market(self, code.clone() + Value::from("/") + default_settle.clone());
+            market = self.market(code.clone() + Value::from("/") + default_settle.clone());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id"))
@@ -4303,7 +4304,7 @@ pub trait Coinex : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Coinex::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("market".into(), market.get(Value::from("id")));
         };
         if limit.clone().is_nonnullish() {
@@ -4401,10 +4402,10 @@ pub trait Coinex : Exchange {
     async fn borrow_margin(&mut self, mut code: Value, mut amount: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" borrowMargin() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} borrowMargin() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut currency: Value = Coinex::currency(self, code.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
@@ -4444,10 +4445,10 @@ pub trait Coinex : Exchange {
     async fn repay_margin(&mut self, mut code: Value, mut amount: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" repayMargin() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} repayMargin() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut currency: Value = Coinex::currency(self, code.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "market": market.get(Value::from("id")),
@@ -4501,7 +4502,7 @@ pub trait Coinex : Exchange {
     }
 
     fn nonce(&self) -> Value {
-        return self.milliseconds();
+        return self.monotonic_nonce(self.milliseconds());
     }
 
     fn sign(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value) -> Value {
@@ -4571,18 +4572,18 @@ pub trait Coinex : Exchange {
         let mut message: Value = self.safe_string(response.clone(), Value::from("message"), Value::Undefined);
         if code.clone() != Value::from("0") || message.clone() != Value::from("Success") && message.clone() != Value::from("Succeeded") && message.clone() != Value::from("Ok") && !data.is_truthy() {
             let mut response_codes: Value = Value::Json(normalize(&Value::Json(json!({
-                "23": PermissionDenied,
-                "24": AuthenticationError,
-                "25": AuthenticationError,
-                "34": AuthenticationError,
-                "35": ExchangeNotAvailable,
-                "36": RequestTimeout,
-                "213": RateLimitExceeded,
-                "107": InsufficientFunds,
-                "600": OrderNotFound,
-                "601": InvalidOrder,
-                "602": InvalidOrder,
-                "606": InvalidOrder
+                "23": "PermissionDenied",
+                "24": "AuthenticationError",
+                "25": "AuthenticationError",
+                "34": "AuthenticationError",
+                "35": "ExchangeNotAvailable",
+                "36": "RequestTimeout",
+                "213": "RateLimitExceeded",
+                "107": "InsufficientFunds",
+                "600": "OrderNotFound",
+                "601": "InvalidOrder",
+                "602": "InvalidOrder",
+                "606": "InvalidOrder"
             }))).unwrap());
             // https://github.com/coinexcom/coinex_exchange_api/wiki/013error_code
             // IP Prohibited
@@ -4590,8 +4591,8 @@ pub trait Coinex : Exchange {
             // Service unavailable
             // Service timeout
             // Too many requests
-            let mut ErrorClass: Value = self.safe_value(response_codes.clone(), code.clone(), ExchangeError.clone());
-            panic!(r###"ErrorClass::new(response.get(Value::from("message")))"###);
+            let mut error_class: Value = self.safe_value(response_codes.clone(), code.clone(), Value::from("ExchangeError"));
+            panic!("{}::new({})", error_class.unwrap_str(), message.or_default(body.clone()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4749,12 +4750,12 @@ pub trait Coinex : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -4860,9 +4861,9 @@ pub trait Coinex : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -5101,9 +5102,9 @@ pub trait Coinex : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Coinex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -5198,14 +5199,14 @@ pub trait Coinex : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -5427,7 +5428,7 @@ pub trait Coinex : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -5476,10 +5477,10 @@ pub trait Coinex : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -5589,7 +5590,7 @@ pub trait Coinex : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -5597,7 +5598,7 @@ pub trait Coinex : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -5623,19 +5624,8 @@ pub trait Coinex : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Coinex::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Coinex::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Coinex::load_time_difference(exchange, params), calculate_rate_limiter_cost: Coinex::calculate_rate_limiter_cost, sign: Coinex::sign, handle_errors: Coinex::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -5667,7 +5657,7 @@ pub trait Coinex : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Coinex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Coinex::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -5703,13 +5693,13 @@ pub trait Coinex : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5809,13 +5799,14 @@ pub trait Coinex : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -5829,7 +5820,7 @@ pub trait Coinex : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5870,7 +5861,7 @@ pub trait Coinex : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Coinex::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -5879,7 +5870,7 @@ pub trait Coinex : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Coinex::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -5887,14 +5878,14 @@ pub trait Coinex : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Coinex::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5903,7 +5894,7 @@ pub trait Coinex : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -5956,14 +5947,14 @@ pub trait Coinex : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Coinex::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -6005,13 +5996,13 @@ pub trait Coinex : Exchange {
 
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6032,7 +6023,7 @@ pub trait Coinex : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -6041,25 +6032,7 @@ pub trait Coinex : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -6108,22 +6081,22 @@ pub trait Coinex : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Coinex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -6163,9 +6136,7 @@ pub trait Coinex : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = Coinex::fetch_time(self, params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -6177,14 +6148,14 @@ pub trait Coinex : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Coinex::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = Coinex::fetch_leverage_tiers(self, Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6192,7 +6163,7 @@ pub trait Coinex : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -6203,7 +6174,7 @@ pub trait Coinex : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -6214,10 +6185,10 @@ pub trait Coinex : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -6228,7 +6199,7 @@ pub trait Coinex : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -6239,7 +6210,7 @@ pub trait Coinex : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -6349,11 +6320,6 @@ pub trait Coinex : Exchange {
         return Coinex::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Coinex::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -6385,9 +6351,9 @@ pub trait Coinex : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -6398,7 +6364,7 @@ pub trait Coinex : Exchange {
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6436,7 +6402,7 @@ pub trait Coinex : Exchange {
             }))).unwrap());
             return Coinex::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6461,7 +6427,7 @@ pub trait Coinex : Exchange {
             }))).unwrap());
             return Coinex::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6486,7 +6452,7 @@ pub trait Coinex : Exchange {
             }))).unwrap());
             return Coinex::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6502,7 +6468,7 @@ pub trait Coinex : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -6523,7 +6489,7 @@ pub trait Coinex : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Coinex::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -6571,16 +6537,16 @@ pub trait Coinex : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Coinex::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Coinex::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -6706,7 +6672,7 @@ pub trait Coinex : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct CoinexImpl(Value);
+pub struct CoinexImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for CoinexImpl {}
 impl Coinex for CoinexImpl {}
 impl ValueTrait for CoinexImpl {
@@ -6728,8 +6694,8 @@ impl ValueTrait for CoinexImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -6748,7 +6714,8 @@ impl CoinexImpl {
         let mut rv = CoinexImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Coinex::describe(&rv);
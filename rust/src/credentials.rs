@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+use serde::de::{self, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use zeroize::Zeroizing;
+use crate::errors::{Error, Result};
+use crate::exchange::Value;
+
+/// The credential fields of `ExchangeImpl::init`'s `required_credentials`
+pub const CREDENTIAL_FIELDS: &[&str] = &["apiKey", "secret", "uid", "login", "password", "twofa", "privateKey", "walletAddress", "token"];
+
+/// A credential, wiped from memory when its last copy is dropped and printed as `***`
+#[derive(Clone)]
+pub struct Secret(Arc<Zeroizing<String>>);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Secret(Arc::new(Zeroizing::new(value.into())))
+    }
+
+    pub fn expose(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl PartialEq for Secret {
+    fn eq(&self, other: &Self) -> bool {
+        self.expose() == other.expose()
+    }
+}
+
+impl Eq for Secret {}
+
+/// The credentials of an exchange, kept apart from its JSON state so that neither `Debug` nor `normalize` ever
+/// print them, the generated code still reads them with `self.get("secret".into())`, which hands it a plain copy
+/// that is not wiped, see `get_value`
+///
+/// ```no_run
+/// use ccxt::credentials::Credentials;
+///
+/// // CCXT_BINANCE_APIKEY, CCXT_BINANCE_SECRET, ... over the binance entry of keys.local.json or keys.json
+/// let credentials = Credentials::load("binance").unwrap();
+/// println!("{:?}", credentials); // {"apiKey": ***, "secret": ***}
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Credentials(BTreeMap<&'static str, Secret>);
+
+impl Credentials {
+    pub fn new() -> Self {
+        Credentials::default()
+    }
+
+    /// Sets a credential field, e.g. `Credentials::new().with("apiKey", key).with("secret", secret)`
+    pub fn with(mut self, field: &str, value: impl Into<String>) -> Self {
+        self.set(field, value);
+        self
+    }
+
+    pub fn set(&mut self, field: &str, value: impl Into<String>) {
+        let field = credential_field(field).unwrap_or_else(|| panic!("{} is not a credential field", field));
+        self.0.insert(field, Secret::new(value));
+    }
+
+    pub fn get(&self, field: &str) -> Option<&Secret> {
+        self.0.get(field)
+    }
+
+    pub fn remove(&mut self, field: &str) -> Option<Secret> {
+        self.0.remove(field)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Overrides these credentials with the fields set in `other`
+    pub fn extend(&mut self, other: Credentials) {
+        self.0.extend(other.0);
+    }
+
+    /// Reads `CCXT_<EXCHANGE>_<FIELD>`, e.g. `CCXT_BINANCE_APIKEY`, `CCXT_BINANCE_SECRET` or `CCXT_OKX_PASSWORD`
+    pub fn from_env(exchange_id: &str) -> Self {
+        let mut credentials = Credentials::new();
+        for field in CREDENTIAL_FIELDS {
+            let name = format!("CCXT_{}_{}", exchange_id.to_uppercase(), field.to_uppercase());
+            if let Ok(value) = std::env::var(&name) {
+                credentials.set(field, value);
+            }
+        }
+        credentials
+    }
+
+    /// Reads the credential fields of the `exchange_id` entry of a file in the keys.json format, the other
+    /// settings of the entry, e.g. `skip`, are ignored
+    pub fn from_keys_file(path: impl AsRef<Path>, exchange_id: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = Zeroizing::new(std::fs::read_to_string(path)
+            .map_err(|e| Error::new("AuthenticationError", format!("cannot read {}: {}", path.display(), e)))?);
+        let mut keys: BTreeMap<String, BTreeMap<String, KeysFileSetting>> = serde_json::from_str(&contents)
+            .map_err(|e| Error::new("AuthenticationError", format!("cannot parse {}: {}", path.display(), e)))?;
+        let mut credentials = Credentials::new();
+        for (field, setting) in keys.remove(exchange_id).unwrap_or_default() {
+            if let (Some(field), KeysFileSetting(Some(secret))) = (credential_field(&field), setting) {
+                credentials.0.insert(field, secret);
+            }
+        }
+        Ok(credentials)
+    }
+
+    /// The credentials of `exchange_id` from keys.local.json, or else keys.json, in the working directory,
+    /// overridden by the environment variables of `from_env`
+    pub fn load(exchange_id: &str) -> Result<Self> {
+        let mut credentials = match ["keys.local.json", "keys.json"].into_iter().find(|path| Path::new(path).exists()) {
+            Some(path) => Credentials::from_keys_file(path, exchange_id)?,
+            None => Credentials::new(),
+        };
+        credentials.extend(Credentials::from_env(exchange_id));
+        Ok(credentials)
+    }
+
+    /// Moves the credentials passed in the exchange config out of its JSON state
+    pub fn take_from(config: &mut Value) -> Self {
+        let mut credentials = Credentials::new();
+        if let Value::Json(serde_json::Value::Object(config)) = config {
+            for field in CREDENTIAL_FIELDS {
+                if let Some(serde_json::Value::String(value)) = config.remove(*field) {
+                    credentials.set(field, Zeroizing::new(value).as_str());
+                }
+            }
+        }
+        credentials
+    }
+
+    /// The value `get` returns for `key`, `None` when `key` is not a credential field
+    ///
+    /// The generated signing code works on `Value`s, so the secret is copied into a plain string here and that
+    /// copy, with whatever the signing code derives from it, is not wiped when it is dropped, only the
+    /// `Secret`s held by `Credentials` are
+    pub fn get_value(&self, key: &Value) -> Option<Value> {
+        let field = credential_field(key_str(key)?)?;
+        Some(self.get(field).map_or(Value::Undefined, |secret| secret.expose().into()))
+    }
+
+    /// Stores `value` if `key` is a credential field, strings are kept and anything else unsets the field
+    pub fn set_value(&mut self, key: &Value, value: &Value) -> bool {
+        match key_str(key).and_then(credential_field) {
+            Some(field) => {
+                match value {
+                    Value::Json(serde_json::Value::String(value)) => self.set(field, value.as_str()),
+                    _ => {
+                        self.remove(field);
+                    }
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A setting of a keys file entry, strings are read straight into a `Secret` so that the file is never held in a
+/// plain JSON value, other settings such as `"skip": true` are skipped
+struct KeysFileSetting(Option<Secret>);
+
+impl<'de> Deserialize<'de> for KeysFileSetting {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct SettingVisitor;
+
+        impl<'de> Visitor<'de> for SettingVisitor {
+            type Value = KeysFileSetting;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a keys file setting")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(Some(Secret::new(v))))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(Some(Secret::new(v))))
+            }
+
+            fn visit_bool<E: de::Error>(self, _: bool) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(None))
+            }
+
+            fn visit_i64<E: de::Error>(self, _: i64) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(None))
+            }
+
+            fn visit_u64<E: de::Error>(self, _: u64) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(None))
+            }
+
+            fn visit_f64<E: de::Error>(self, _: f64) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(None))
+            }
+
+            fn visit_unit<E: de::Error>(self) -> std::result::Result<KeysFileSetting, E> {
+                Ok(KeysFileSetting(None))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<KeysFileSetting, A::Error> {
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                Ok(KeysFileSetting(None))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<KeysFileSetting, A::Error> {
+                while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                Ok(KeysFileSetting(None))
+            }
+        }
+
+        deserializer.deserialize_any(SettingVisitor)
+    }
+}
+
+fn credential_field(field: &str) -> Option<&'static str> {
+    CREDENTIAL_FIELDS.iter().copied().find(|f| *f == field)
+}
+
+fn key_str(key: &Value) -> Option<&str> {
+    match key {
+        Value::Json(serde_json::Value::String(key)) => Some(key),
+        _ => None,
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.iter()).finish()
+    }
+}
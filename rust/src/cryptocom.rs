@@ -11,10 +11,11 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, parse_float, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -615,13 +616,13 @@ pub trait Cryptocom : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instrument_name": market.get(Value::from("id"))
         }))).unwrap());
         let (mut market_type, mut query) = shift_2(Cryptocom::handle_market_type_and_params(self, Value::from("fetchTicker"), market.clone(), params.clone()));
         if market_type.clone() != Value::from("spot") {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTicker() only supports spot markets"))"###);
+            panic!("NotSupported::new({} fetchTicker() only supports spot markets)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Cryptocom::dispatch(self, "spotPublicGetPublicGetTicker".into(), extend_2(request.clone(), query.clone()), Value::Undefined).await;
         // {
@@ -649,10 +650,10 @@ pub trait Cryptocom : Exchange {
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instrument_name": market.get(Value::from("id"))
         }))).unwrap());
@@ -758,7 +759,7 @@ pub trait Cryptocom : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instrument_name": market.get(Value::from("id"))
         }))).unwrap());
@@ -814,7 +815,7 @@ pub trait Cryptocom : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instrument_name": market.get(Value::from("id")),
             "timeframe": self.get("timeframes".into()).get(timeframe.clone())
@@ -857,7 +858,7 @@ pub trait Cryptocom : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instrument_name": market.get(Value::from("id"))
         }))).unwrap());
@@ -1039,7 +1040,11 @@ pub trait Cryptocom : Exchange {
             "future": "parseSwapBalance",
             "swap": "parseSwapBalance"
         }))).unwrap()));
-        return self.parser(response.clone());
+        return match parser.unwrap_str() {
+            "parseSpotBalance" => Cryptocom::parse_spot_balance(self, response.clone()),
+            "parseSwapBalance" => Cryptocom::parse_swap_balance(self, response.clone()),
+            _ => panic!("NotSupported::new({} fetchBalance() does not support {} markets)", self.get("id".into()).unwrap_str(), js_string(&market_type)),
+        };
     }
 
     /// Returns an [order structure](https://docs.ccxt.com/en/latest/manual.html#order-structure)
@@ -1055,7 +1060,7 @@ pub trait Cryptocom : Exchange {
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Cryptocom::market(self, symbol.clone());
+            market = self.market(symbol.clone());
         };
         let mut request: Value = Value::new_object();
         let (mut market_type, mut query) = shift_2(Cryptocom::handle_market_type_and_params(self, Value::from("fetchOrder"), market.clone(), params.clone()));
@@ -1127,7 +1132,7 @@ pub trait Cryptocom : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut uppercase_type: Value = r#type.to_upper_case();
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instrument_name": market.get(Value::from("id")),
@@ -1176,13 +1181,13 @@ pub trait Cryptocom : Exchange {
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Cryptocom::market(self, symbol.clone());
+            market = self.market(symbol.clone());
         };
         let mut request: Value = Value::new_object();
         let (mut market_type, mut query) = shift_2(Cryptocom::handle_market_type_and_params(self, Value::from("cancelAllOrders"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") || market_type.clone() == Value::from("margin") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelAllOrders() requires a symbol argument for ") + market_type.clone() + Value::from(" orders"))"###);
+                panic!("ArgumentsRequired::new({} cancelAllOrders() requires a symbol argument for {} orders)", self.get("id".into()).unwrap_str(), js_string(&market_type));
             };
             request.set("instrument_name".into(), market.get(Value::from("id")));
         };
@@ -1209,13 +1214,13 @@ pub trait Cryptocom : Exchange {
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Cryptocom::market(self, symbol.clone());
+            market = self.market(symbol.clone());
         };
         let mut request: Value = Value::new_object();
         let (mut market_type, mut query) = shift_2(Cryptocom::handle_market_type_and_params(self, Value::from("cancelOrder"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") || market_type.clone() == Value::from("margin") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument for ") + market_type.clone() + Value::from(" orders"))"###);
+                panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument for {} orders)", self.get("id".into()).unwrap_str(), js_string(&market_type));
             };
             request.set("instrument_name".into(), market.get(Value::from("id")));
             request.set("order_id".into(), id.to_string());
@@ -1249,7 +1254,7 @@ pub trait Cryptocom : Exchange {
         let mut market: Value = Value::Undefined;
         let mut request: Value = Value::new_object();
         if symbol.clone().is_nonnullish() {
-            market = Cryptocom::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instrument_name".into(), market.get(Value::from("id")));
         };
         if limit.clone().is_nonnullish() {
@@ -1328,7 +1333,7 @@ pub trait Cryptocom : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Cryptocom::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instrument_name".into(), market.get(Value::from("id")));
         };
         if since.clone().is_nonnullish() {
@@ -1476,7 +1481,7 @@ pub trait Cryptocom : Exchange {
         let mut data: Value = self.safe_value(response.clone(), Value::from("result"), Value::new_object());
         let mut addresses: Value = self.safe_value(data.clone(), Value::from("deposit_address_list"), Value::new_array());
         if addresses.len() == 0 {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchDepositAddressesByNetwork() generating address..."))"###);
+            panic!("ExchangeError::new({} fetchDepositAddressesByNetwork() generating address...)", self.get("id".into()).unwrap_str());
         };
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -1696,7 +1701,7 @@ pub trait Cryptocom : Exchange {
     async fn fetch_transfers(&mut self, mut code: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !params.contains_key(Value::from("direction")) {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(r#" fetchTransfers() requires a direction param to be either "IN" or "OUT""#))"###);
+            panic!("ArgumentsRequired::new({} fetchTransfers() requires a direction param to be either \"IN\" or \"OUT\")", self.get("id".into()).unwrap_str());
         };
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut currency: Value = Value::Undefined;
@@ -2023,7 +2028,7 @@ pub trait Cryptocom : Exchange {
         let mut created: Value = self.safe_integer(order.clone(), Value::from("create_time"), Value::Undefined);
         let mut updated: Value = self.safe_integer(order.clone(), Value::from("update_time"), Value::Undefined);
         let mut market_id: Value = self.safe_string(order.clone(), Value::from("instrument_name"), Value::Undefined);
-        let mut symbol: Value = Cryptocom::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut amount: Value = self.safe_string(order.clone(), Value::from("quantity"), Value::Undefined);
         let mut filled: Value = self.safe_string(order.clone(), Value::from("cumulative_quantity"), Value::Undefined);
         let mut status: Value = Cryptocom::parse_order_status(self, self.safe_string(order.clone(), Value::from("status"), Value::Undefined));
@@ -2295,7 +2300,7 @@ pub trait Cryptocom : Exchange {
         let mut market: Value = Value::Undefined;
         let mut currency: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Cryptocom::market(self, symbol.clone());
+            market = self.market(symbol.clone());
         };
         if code.clone().is_nonnullish() {
             currency = Cryptocom::currency(self, code.clone());
@@ -2430,7 +2435,7 @@ pub trait Cryptocom : Exchange {
     }
 
     fn nonce(&self) -> Value {
-        return self.milliseconds();
+        return self.monotonic_nonce(self.milliseconds());
     }
 
     fn sign(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value) -> Value {
@@ -2494,7 +2499,7 @@ pub trait Cryptocom : Exchange {
         if error_code.clone() != Value::from("0") {
             let mut feedback: Value = self.get("id".into()) + Value::from(" ") + body.clone();
             Cryptocom::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), error_code.clone(), feedback.clone());
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" ") + body.clone())"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         Value::Undefined
     }
@@ -2652,12 +2657,12 @@ pub trait Cryptocom : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -2763,9 +2768,9 @@ pub trait Cryptocom : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -3004,9 +3009,9 @@ pub trait Cryptocom : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Cryptocom::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -3101,14 +3106,14 @@ pub trait Cryptocom : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -3330,7 +3335,7 @@ pub trait Cryptocom : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -3398,10 +3403,10 @@ pub trait Cryptocom : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -3511,7 +3516,7 @@ pub trait Cryptocom : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -3519,7 +3524,7 @@ pub trait Cryptocom : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -3545,19 +3550,8 @@ pub trait Cryptocom : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Cryptocom::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Cryptocom::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Cryptocom::load_time_difference(exchange, params), calculate_rate_limiter_cost: Cryptocom::calculate_rate_limiter_cost, sign: Cryptocom::sign, handle_errors: Cryptocom::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -3589,7 +3583,7 @@ pub trait Cryptocom : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Cryptocom::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -3625,13 +3619,13 @@ pub trait Cryptocom : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3731,13 +3725,14 @@ pub trait Cryptocom : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -3751,7 +3746,7 @@ pub trait Cryptocom : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3792,7 +3787,7 @@ pub trait Cryptocom : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Cryptocom::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -3801,7 +3796,7 @@ pub trait Cryptocom : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Cryptocom::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -3809,14 +3804,14 @@ pub trait Cryptocom : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Cryptocom::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3825,7 +3820,7 @@ pub trait Cryptocom : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -3834,12 +3829,12 @@ pub trait Cryptocom : Exchange {
         params = params.or_default(Value::new_object());
         Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = Cryptocom::fetch_borrow_rates(self, params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -3892,14 +3887,14 @@ pub trait Cryptocom : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Cryptocom::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -3941,13 +3936,13 @@ pub trait Cryptocom : Exchange {
 
     async fn fetch_closed_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchClosedOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchClosedOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3968,7 +3963,7 @@ pub trait Cryptocom : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -3977,25 +3972,7 @@ pub trait Cryptocom : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -4044,22 +4021,22 @@ pub trait Cryptocom : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Cryptocom::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -4099,9 +4076,7 @@ pub trait Cryptocom : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = self.fetch_time(params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -4113,14 +4088,14 @@ pub trait Cryptocom : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Cryptocom::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = self.fetch_leverage_tiers(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4128,7 +4103,7 @@ pub trait Cryptocom : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -4139,7 +4114,7 @@ pub trait Cryptocom : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -4150,10 +4125,10 @@ pub trait Cryptocom : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -4164,7 +4139,7 @@ pub trait Cryptocom : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -4175,7 +4150,7 @@ pub trait Cryptocom : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -4285,13 +4260,8 @@ pub trait Cryptocom : Exchange {
         return Cryptocom::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Cryptocom::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rate(&self, mut contract: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseFundingRate() is not supported yet"))"###);
+        panic!("NotSupported::new({} parseFundingRate() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4326,9 +4296,9 @@ pub trait Cryptocom : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -4340,20 +4310,20 @@ pub trait Cryptocom : Exchange {
 
     async fn fetch_trading_fees(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTradingFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTradingFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTradingFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Cryptocom::fetch_trading_fees(self, params.clone()).await;
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4375,19 +4345,19 @@ pub trait Cryptocom : Exchange {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchFundingRates")).is_truthy() {
             Cryptocom::load_markets(self, Value::Undefined, Value::Undefined).await;
-            let mut market: Value = Cryptocom::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchFundingRate() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut rates: Value = self.fetch_funding_rates(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), params.clone()).await;
             let mut rate: Value = self.safe_value(rates.clone(), symbol.clone(), Value::Undefined);
             if rate.clone().is_nullish() {
-                panic!(r###"NullResponse::new(self.get("id".into()) + Value::from(" fetchFundingRate () returned no data for ") + symbol.clone())"###);
+                panic!("NullResponse::new({} fetchFundingRate () returned no data for {})", self.get("id".into()).unwrap_str(), js_string(&symbol));
             } else {
                 return rate.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingRate () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchFundingRate () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4412,7 +4382,7 @@ pub trait Cryptocom : Exchange {
             }))).unwrap());
             return Cryptocom::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4437,7 +4407,7 @@ pub trait Cryptocom : Exchange {
             }))).unwrap());
             return Cryptocom::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4462,7 +4432,7 @@ pub trait Cryptocom : Exchange {
             }))).unwrap());
             return Cryptocom::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4478,7 +4448,7 @@ pub trait Cryptocom : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -4499,7 +4469,7 @@ pub trait Cryptocom : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Cryptocom::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -4547,16 +4517,16 @@ pub trait Cryptocom : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Cryptocom::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Cryptocom::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -4564,84 +4534,84 @@ pub trait Cryptocom : Exchange {
         match method {
             Value::Json(serde_json::Value::String(ref m)) => {
                 match m.as_ref() {
-                    "spotPublicGetPublicAuth" => Cryptocom::request(self, "public/auth".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicGetInstruments" => Cryptocom::request(self, "public/get-instruments".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicGetBook" => Cryptocom::request(self, "public/get-book".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicGetCandlestick" => Cryptocom::request(self, "public/get-candlestick".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicGetTicker" => Cryptocom::request(self, "public/get-ticker".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicGetTrades" => Cryptocom::request(self, "public/get-trades".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicMarginGetTransferCurrencies" => Cryptocom::request(self, "public/margin/get-transfer-currencies".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicMarginGetLoadCurrenices" => Cryptocom::request(self, "public/margin/get-load-currenices".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetPublicRespondHeartbeat" => Cryptocom::request(self, "public/respond-heartbeat".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateSetCancelOnDisconnect" => Cryptocom::request(self, "private/set-cancel-on-disconnect".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetCancelOnDisconnect" => Cryptocom::request(self, "private/get-cancel-on-disconnect".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateCreateWithdrawal" => Cryptocom::request(self, "private/create-withdrawal".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetWithdrawalHistory" => Cryptocom::request(self, "private/get-withdrawal-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetCurrencyNetworks" => Cryptocom::request(self, "private/get-currency-networks".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetDepositHistory" => Cryptocom::request(self, "private/get-deposit-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetDepositAddress" => Cryptocom::request(self, "private/get-deposit-address".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetAccountSummary" => Cryptocom::request(self, "private/get-account-summary".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateCreateOrder" => Cryptocom::request(self, "private/create-order".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateCancelOrder" => Cryptocom::request(self, "private/cancel-order".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateCancelAllOrders" => Cryptocom::request(self, "private/cancel-all-orders".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetOrderHistory" => Cryptocom::request(self, "private/get-order-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetOpenOrders" => Cryptocom::request(self, "private/get-open-orders".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetOrderDetail" => Cryptocom::request(self, "private/get-order-detail".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateGetTrades" => Cryptocom::request(self, "private/get-trades".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetUserConfig" => Cryptocom::request(self, "private/margin/get-user-config".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetAccountSummary" => Cryptocom::request(self, "private/margin/get-account-summary".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginTransfer" => Cryptocom::request(self, "private/margin/transfer".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginBorrow" => Cryptocom::request(self, "private/margin/borrow".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginRepay" => Cryptocom::request(self, "private/margin/repay".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetTransferHistory" => Cryptocom::request(self, "private/margin/get-transfer-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetBorrowHistory" => Cryptocom::request(self, "private/margin/get-borrow-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetInterestHistory" => Cryptocom::request(self, "private/margin/get-interest-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetRepayHistory" => Cryptocom::request(self, "private/margin/get-repay-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetLiquidationHistory" => Cryptocom::request(self, "private/margin/get-liquidation-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetLiquidationOrders" => Cryptocom::request(self, "private/margin/get-liquidation-orders".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginCreateOrder" => Cryptocom::request(self, "private/margin/create-order".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginCancelOrder" => Cryptocom::request(self, "private/margin/cancel-order".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginCancelAllOrders" => Cryptocom::request(self, "private/margin/cancel-all-orders".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetOrderHistory" => Cryptocom::request(self, "private/margin/get-order-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetOpenOrders" => Cryptocom::request(self, "private/margin/get-open-orders".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetOrderDetail" => Cryptocom::request(self, "private/margin/get-order-detail".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateMarginGetTrades" => Cryptocom::request(self, "private/margin/get-trades".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateDerivTransfer" => Cryptocom::request(self, "private/deriv/transfer".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateDerivGetTransferHistory" => Cryptocom::request(self, "private/deriv/get-transfer-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateSubaccountGetSubAccounts" => Cryptocom::request(self, "private/subaccount/get-sub-accounts".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateSubaccountGetTransferHistory" => Cryptocom::request(self, "private/subaccount/get-transfer-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateSubaccountTransfer" => Cryptocom::request(self, "private/subaccount/transfer".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateOtcGetOtcUser" => Cryptocom::request(self, "private/otc/get-otc-user".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateOtcGetInstruments" => Cryptocom::request(self, "private/otc/get-instruments".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateOtcRequestQuote" => Cryptocom::request(self, "private/otc/request-quote".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateOtcAcceptQuote" => Cryptocom::request(self, "private/otc/accept-quote".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateOtcGetQuoteHistory" => Cryptocom::request(self, "private/otc/get-quote-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostPrivateOtcGetTradeHistory" => Cryptocom::request(self, "private/otc/get-trade-history".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicAuth" => Cryptocom::request(self, "public/auth".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetInstruments" => Cryptocom::request(self, "public/get-instruments".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetBook" => Cryptocom::request(self, "public/get-book".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetCandlestick" => Cryptocom::request(self, "public/get-candlestick".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetTrades" => Cryptocom::request(self, "public/get-trades".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetTickers" => Cryptocom::request(self, "public/get-tickers".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetValuations" => Cryptocom::request(self, "public/get-valuations".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetExpiredSettlementPrice" => Cryptocom::request(self, "public/get-expired-settlement-price".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPublicGetPublicGetInsurance" => Cryptocom::request(self, "public/get-insurance".into(), "derivatives".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateSetCancelOnDisconnect" => Cryptocom::request(self, "private/set-cancel-on-disconnect".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetCancelOnDisconnect" => Cryptocom::request(self, "private/get-cancel-on-disconnect".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateUserBalance" => Cryptocom::request(self, "private/user-balance".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateUserBalanceHistory" => Cryptocom::request(self, "private/user-balance-history".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetPositions" => Cryptocom::request(self, "private/get-positions".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateCreateOrder" => Cryptocom::request(self, "private/create-order".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateCancelOrder" => Cryptocom::request(self, "private/cancel-order".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateCancelAllOrders" => Cryptocom::request(self, "private/cancel-all-orders".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateClosePosition" => Cryptocom::request(self, "private/close-position".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateConvertCollateral" => Cryptocom::request(self, "private/convert-collateral".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetOrderHistory" => Cryptocom::request(self, "private/get-order-history".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetOpenOrders" => Cryptocom::request(self, "private/get-open-orders".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetOrderDetail" => Cryptocom::request(self, "private/get-order-detail".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetTrades" => Cryptocom::request(self, "private/get-trades".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateChangeAccountLeverage" => Cryptocom::request(self, "private/change-account-leverage".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "derivativesPrivatePostPrivateGetTransactions" => Cryptocom::request(self, "private/get-transactions".into(), "derivatives".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicAuth" => Cryptocom::request(self, "public/auth".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicGetInstruments" => Cryptocom::request(self, "public/get-instruments".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicGetBook" => Cryptocom::request(self, "public/get-book".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicGetCandlestick" => Cryptocom::request(self, "public/get-candlestick".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicGetTicker" => Cryptocom::request(self, "public/get-ticker".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicGetTrades" => Cryptocom::request(self, "public/get-trades".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicMarginGetTransferCurrencies" => Cryptocom::request(self, "public/margin/get-transfer-currencies".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicMarginGetLoadCurrenices" => Cryptocom::request(self, "public/margin/get-load-currenices".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPublicRespondHeartbeat" => Cryptocom::request(self, "public/respond-heartbeat".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateSetCancelOnDisconnect" => Cryptocom::request(self, "private/set-cancel-on-disconnect".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetCancelOnDisconnect" => Cryptocom::request(self, "private/get-cancel-on-disconnect".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateCreateWithdrawal" => Cryptocom::request(self, "private/create-withdrawal".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetWithdrawalHistory" => Cryptocom::request(self, "private/get-withdrawal-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetCurrencyNetworks" => Cryptocom::request(self, "private/get-currency-networks".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetDepositHistory" => Cryptocom::request(self, "private/get-deposit-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetDepositAddress" => Cryptocom::request(self, "private/get-deposit-address".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetAccountSummary" => Cryptocom::request(self, "private/get-account-summary".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateCreateOrder" => Cryptocom::request(self, "private/create-order".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateCancelOrder" => Cryptocom::request(self, "private/cancel-order".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateCancelAllOrders" => Cryptocom::request(self, "private/cancel-all-orders".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetOrderHistory" => Cryptocom::request(self, "private/get-order-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetOpenOrders" => Cryptocom::request(self, "private/get-open-orders".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetOrderDetail" => Cryptocom::request(self, "private/get-order-detail".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateGetTrades" => Cryptocom::request(self, "private/get-trades".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetUserConfig" => Cryptocom::request(self, "private/margin/get-user-config".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetAccountSummary" => Cryptocom::request(self, "private/margin/get-account-summary".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginTransfer" => Cryptocom::request(self, "private/margin/transfer".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginBorrow" => Cryptocom::request(self, "private/margin/borrow".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginRepay" => Cryptocom::request(self, "private/margin/repay".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetTransferHistory" => Cryptocom::request(self, "private/margin/get-transfer-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetBorrowHistory" => Cryptocom::request(self, "private/margin/get-borrow-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetInterestHistory" => Cryptocom::request(self, "private/margin/get-interest-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetRepayHistory" => Cryptocom::request(self, "private/margin/get-repay-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetLiquidationHistory" => Cryptocom::request(self, "private/margin/get-liquidation-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetLiquidationOrders" => Cryptocom::request(self, "private/margin/get-liquidation-orders".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginCreateOrder" => Cryptocom::request(self, "private/margin/create-order".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginCancelOrder" => Cryptocom::request(self, "private/margin/cancel-order".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginCancelAllOrders" => Cryptocom::request(self, "private/margin/cancel-all-orders".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetOrderHistory" => Cryptocom::request(self, "private/margin/get-order-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetOpenOrders" => Cryptocom::request(self, "private/margin/get-open-orders".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetOrderDetail" => Cryptocom::request(self, "private/margin/get-order-detail".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateMarginGetTrades" => Cryptocom::request(self, "private/margin/get-trades".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateDerivTransfer" => Cryptocom::request(self, "private/deriv/transfer".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateDerivGetTransferHistory" => Cryptocom::request(self, "private/deriv/get-transfer-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateSubaccountGetSubAccounts" => Cryptocom::request(self, "private/subaccount/get-sub-accounts".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateSubaccountGetTransferHistory" => Cryptocom::request(self, "private/subaccount/get-transfer-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateSubaccountTransfer" => Cryptocom::request(self, "private/subaccount/transfer".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateOtcGetOtcUser" => Cryptocom::request(self, "private/otc/get-otc-user".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateOtcGetInstruments" => Cryptocom::request(self, "private/otc/get-instruments".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateOtcRequestQuote" => Cryptocom::request(self, "private/otc/request-quote".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateOtcAcceptQuote" => Cryptocom::request(self, "private/otc/accept-quote".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateOtcGetQuoteHistory" => Cryptocom::request(self, "private/otc/get-quote-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostPrivateOtcGetTradeHistory" => Cryptocom::request(self, "private/otc/get-trade-history".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicAuth" => Cryptocom::request(self, "public/auth".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetInstruments" => Cryptocom::request(self, "public/get-instruments".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetBook" => Cryptocom::request(self, "public/get-book".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetCandlestick" => Cryptocom::request(self, "public/get-candlestick".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetTrades" => Cryptocom::request(self, "public/get-trades".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetTickers" => Cryptocom::request(self, "public/get-tickers".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetValuations" => Cryptocom::request(self, "public/get-valuations".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetExpiredSettlementPrice" => Cryptocom::request(self, "public/get-expired-settlement-price".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPublicGetPublicGetInsurance" => Cryptocom::request(self, "public/get-insurance".into(), Value::Json(json!(["derivatives", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateSetCancelOnDisconnect" => Cryptocom::request(self, "private/set-cancel-on-disconnect".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetCancelOnDisconnect" => Cryptocom::request(self, "private/get-cancel-on-disconnect".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateUserBalance" => Cryptocom::request(self, "private/user-balance".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateUserBalanceHistory" => Cryptocom::request(self, "private/user-balance-history".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetPositions" => Cryptocom::request(self, "private/get-positions".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateCreateOrder" => Cryptocom::request(self, "private/create-order".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateCancelOrder" => Cryptocom::request(self, "private/cancel-order".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateCancelAllOrders" => Cryptocom::request(self, "private/cancel-all-orders".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateClosePosition" => Cryptocom::request(self, "private/close-position".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateConvertCollateral" => Cryptocom::request(self, "private/convert-collateral".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetOrderHistory" => Cryptocom::request(self, "private/get-order-history".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetOpenOrders" => Cryptocom::request(self, "private/get-open-orders".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetOrderDetail" => Cryptocom::request(self, "private/get-order-detail".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetTrades" => Cryptocom::request(self, "private/get-trades".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateChangeAccountLeverage" => Cryptocom::request(self, "private/change-account-leverage".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "derivativesPrivatePostPrivateGetTransactions" => Cryptocom::request(self, "private/get-transactions".into(), Value::Json(json!(["derivatives", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
                     _ => unimplemented!(),
                 }
             },
@@ -4651,7 +4621,7 @@ pub trait Cryptocom : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct CryptocomImpl(Value);
+pub struct CryptocomImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for CryptocomImpl {}
 impl Cryptocom for CryptocomImpl {}
 impl ValueTrait for CryptocomImpl {
@@ -4673,8 +4643,8 @@ impl ValueTrait for CryptocomImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -4693,7 +4663,8 @@ impl CryptocomImpl {
         let mut rv = CryptocomImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Cryptocom::describe(&rv);
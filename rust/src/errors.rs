@@ -0,0 +1,104 @@
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use futures::FutureExt;
+
+// child => parent, from js/base/errorHierarchy.js
+const HIERARCHY: &[(&str, &str)] = &[
+    ("ExchangeError", "BaseError"),
+    ("AuthenticationError", "ExchangeError"),
+    ("PermissionDenied", "AuthenticationError"),
+    ("AccountNotEnabled", "PermissionDenied"),
+    ("AccountSuspended", "AuthenticationError"),
+    ("ArgumentsRequired", "ExchangeError"),
+    ("BadRequest", "ExchangeError"),
+    ("BadSymbol", "BadRequest"),
+    ("MarginModeAlreadySet", "BadRequest"),
+    ("BadResponse", "ExchangeError"),
+    ("NullResponse", "BadResponse"),
+    ("InsufficientFunds", "ExchangeError"),
+    ("InvalidAddress", "ExchangeError"),
+    ("AddressPending", "InvalidAddress"),
+    ("InvalidOrder", "ExchangeError"),
+    ("OrderNotFound", "InvalidOrder"),
+    ("OrderNotCached", "InvalidOrder"),
+    ("CancelPending", "InvalidOrder"),
+    ("OrderImmediatelyFillable", "InvalidOrder"),
+    ("OrderNotFillable", "InvalidOrder"),
+    ("DuplicateOrderId", "InvalidOrder"),
+    ("NotSupported", "ExchangeError"),
+    ("ExchangeNotFound", "ExchangeError"),
+    ("NetworkError", "BaseError"),
+    ("DDoSProtection", "NetworkError"),
+    ("RateLimitExceeded", "DDoSProtection"),
+    ("ExchangeNotAvailable", "NetworkError"),
+    ("OnMaintenance", "ExchangeNotAvailable"),
+    ("InvalidNonce", "NetworkError"),
+    ("ChecksumError", "NetworkError"),
+    ("RequestTimeout", "NetworkError"),
+];
+
+/// A unified error, `kind` is the name of the CCXT error class, e.g. `BadSymbol` or `RateLimitExceeded`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub kind: String,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(kind: &str, message: impl Into<String>) -> Self {
+        Error { kind: kind.to_owned(), message: message.into() }
+    }
+
+    /// Whether the error is `kind` or one of its subclasses, `BadSymbol` is also a `BadRequest` and an `ExchangeError`
+    pub fn is(&self, kind: &str) -> bool {
+        let mut current = self.kind.as_str();
+        loop {
+            if current == kind {
+                return true;
+            }
+            match HIERARCHY.iter().find(|(child, _)| *child == current) {
+                Some((_, parent)) => current = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Recovers the error from a panic raised by the generated code as `Kind::new(message)`
+    pub fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        Error::from_panic_ref(&*payload)
+    }
+
+    /// Like `from_panic`, keeping the payload so that the panic can be resumed
+    pub fn from_panic_ref(payload: &(dyn Any + Send)) -> Self {
+        let text = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            String::new()
+        };
+        match text.split_once("::new(") {
+            Some((kind, rest)) if rest.ends_with(')') && kind.chars().all(char::is_alphanumeric) => {
+                Error::new(kind, &rest[..rest.len() - 1])
+            }
+            _ => Error::new("BaseError", text),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Runs a unified call, turning the errors it raises into an `Err`
+pub async fn catch<F: Future>(future: F) -> Result<F::Output> {
+    AssertUnwindSafe(future).catch_unwind().await.map_err(Error::from_panic)
+}
@@ -9,7 +9,7 @@
 use async_trait::async_trait;
 use rand::RngCore;
 use std::cmp::{max, Ordering};
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeZone, Utc};
 use regex::Regex;
 use serde::{Serialize, Deserialize};
 use std::ops::{Add, Div, Mul, Not, Rem, Sub};
@@ -20,8 +20,18 @@ use sha2::{Digest};
 use num_bigint::BigInt;
 use num_traits::{ToPrimitive, Zero};
 use num_traits::sign::Signed;
-use num_integer::Integer;
 use serde_json::json;
+use crate::markets::{MarketsCache, MarketsLoader, MARKETS_KEYS};
+use crate::credentials::CREDENTIAL_FIELDS;
+use crate::logging::{redact_body, redact_headers, redact_url, verbose_event};
+use std::time::Instant;
+use tracing::Instrument;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use crate::errors::Error;
+use crate::retry::{is_idempotent, RetryPolicy};
+use crate::symbol::Symbol;
+use crate::types::Decimal;
 
 pub const PRECISE_BASE: usize = 10;
 
@@ -47,6 +57,25 @@ pub enum Value {
     Undefined,
 }
 
+// a value as it is logged, strings without quotes
+fn log_text(x: &Value) -> String {
+    match x {
+        Value::Json(serde_json::Value::String(s)) => s.clone(),
+        Value::Json(v) => v.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// A value as JS string concatenation prints it, the messages of the errors the exchanges raise are built with it
+pub fn js_string(x: &Value) -> String {
+    match x {
+        Value::Json(serde_json::Value::String(s)) => s.clone(),
+        Value::Json(v) => v.to_string(),
+        Value::Precise(p) => ToString::to_string(p),
+        Value::Undefined => "undefined".to_owned(),
+    }
+}
+
 pub fn normalize(x: &Value) -> Option<serde_json::Value> {
     match x {
         Value::Json(v) => {
@@ -76,7 +105,7 @@ pub fn normalize(x: &Value) -> Option<serde_json::Value> {
                 }
             }
         },
-        Value::Precise(v) => unimplemented!(),
+        Value::Precise(v) => Some(serde_json::Value::String(v.to_string())),
         Value::Undefined => None,
     }
 }
@@ -115,6 +144,83 @@ pub trait ValueTrait {
     fn slice(&self, start: Value) -> Value;
 }
 
+/// Walks `path` from `node`, unwrapping nested `{"Json": ..}` like `ValueTrait::get` does
+pub(crate) fn json_get_path(mut node: &serde_json::Value, path: &[Value]) -> Value {
+    for key in path {
+        let next = match node {
+            serde_json::Value::Object(o) => o.get(&key.property_key()),
+            serde_json::Value::Array(a) => key.array_index().and_then(|i| a.get(i)),
+            _ => None
+        };
+        node = match next {
+            Some(serde_json::Value::Object(o)) if o.contains_key("Json") => o.get("Json").unwrap(),
+            Some(v) => v,
+            None => return Value::Undefined
+        };
+    }
+    Value::Json(node.clone())
+}
+
+/// Walks `path` from `node`, creating empty objects for missing or null steps
+pub(crate) fn json_path_mut<'a>(mut node: &'a mut serde_json::Value, path: &[Value]) -> &'a mut serde_json::Value {
+    for key in path {
+        if node.is_null() {
+            *node = serde_json::Value::Object(serde_json::Map::new());
+        }
+        node = match node {
+            serde_json::Value::Object(o) => o.entry(key.property_key()).or_insert(serde_json::Value::Null),
+            serde_json::Value::Array(a) => match key.array_index() {
+                Some(i) if i < a.len() => &mut a[i],
+                _ => panic!("array index {:?} out of range", key)
+            },
+            _ => panic!("cannot set key {:?} on a non-object value", key)
+        };
+    }
+    node
+}
+
+/// Deletes `key` like javascript's `delete`, the keys after it keep their order so that the query strings built from
+/// the object do too
+pub(crate) fn json_remove(object: &mut serde_json::Map<String, serde_json::Value>, key: &str) {
+    object.retain(|k, _| k != key);
+}
+
+pub(crate) fn json_set_path(root: &mut serde_json::Value, path: &[Value], value: Value) {
+    let (key, parents) = path.split_last().expect("empty path");
+    let parent = json_path_mut(root, parents);
+    if parent.is_null() {
+        *parent = serde_json::Value::Object(serde_json::Map::new());
+    }
+    match (parent, normalize(&value)) {
+        (serde_json::Value::Object(o), Some(v)) => { o.insert(key.property_key(), v); }
+        (serde_json::Value::Object(o), None) => json_remove(o, &key.property_key()),
+        (serde_json::Value::Array(a), v) => match key.array_index() {
+            Some(i) if i < a.len() => a[i] = v.unwrap_or(serde_json::Value::Null),
+            Some(i) if i == a.len() => a.push(v.unwrap_or(serde_json::Value::Null)),
+            _ => panic!("array index {:?} out of range", key)
+        },
+        _ => panic!("cannot set key {:?} on a non-object value", key)
+    }
+}
+
+// a finite number written in decimal or exponent notation, which Precise can parse
+fn is_decimal(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')) && s.parse::<f64>().is_ok()
+}
+
+// `length` random hex digits, `default_length` when `length` is not a number
+fn random_hex(length: &Value, default_length: usize) -> Value {
+    let length = match length {
+        Value::Json(serde_json::Value::Number(n)) => n.as_u64().unwrap_or(default_length as u64) as usize,
+        _ => default_length
+    };
+    let mut bytes = vec![0u8; length.div_ceil(2)];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let mut rv = hex::encode(bytes);
+    rv.truncate(length);
+    rv.into()
+}
+
 pub fn shift_2(x: Value) -> (Value, Value) {
     match x.unwrap_json() {
         serde_json::Value::Array(x) => {
@@ -140,6 +246,53 @@ impl Value {
     pub fn new_object() -> Self {
         Value::Json(serde_json::Value::Object(serde_json::Map::new()))
     }
+
+    /// Lowercases a string, other values are returned unchanged
+    pub fn to_lower_case(&self) -> Value {
+        match self {
+            Value::Json(serde_json::Value::String(s)) => Value::Json(serde_json::Value::String(s.to_lowercase())),
+            other => other.clone()
+        }
+    }
+
+    /// The key as a javascript property name, so numbers can be used to index objects
+    pub fn property_key(&self) -> String {
+        match self {
+            Value::Json(serde_json::Value::String(s)) => s.clone(),
+            Value::Json(serde_json::Value::Number(n)) => n.to_string(),
+            _ => panic!("unexpected key {:?}", self)
+        }
+    }
+
+    /// The key as an array index, numeric strings are accepted like in javascript
+    pub fn array_index(&self) -> Option<usize> {
+        match self {
+            Value::Json(serde_json::Value::Number(n)) => n.as_u64().map(|n| n as usize),
+            Value::Json(serde_json::Value::String(s)) => s.parse::<usize>().ok(),
+            _ => None
+        }
+    }
+
+    /// Reads a nested key, e.g. `balance.get_path(&[code, "free".into()])`
+    pub fn get_path(&self, path: &[Value]) -> Value {
+        match self {
+            Value::Json(v) => json_get_path(v, path),
+            _ => Value::Undefined
+        }
+    }
+
+    /// Writes a nested key in place, creating intermediate objects as needed.
+    /// Unlike `self.get(a).set(b, v)`, which updates a temporary copy, the write persists
+    pub fn set_path(&mut self, path: &[Value], value: Value) {
+        json_set_path(self.unwrap_json_mut(), path, value)
+    }
+
+    pub fn replace(&self, pattern: Value, replacement: Value) -> Value {
+        match self {
+            Value::Json(serde_json::Value::String(s)) => Value::Json(serde_json::Value::String(s.replacen(pattern.unwrap_str(), replacement.unwrap_str(), 1))),
+            _ => panic!("unexpected value")
+        }
+    }
 }
 
 impl ValueTrait for Value {
@@ -297,8 +450,8 @@ impl ValueTrait for Value {
     fn get(&self, key: Value) -> Value {
         match self {
             Value::Json(v) => {
-                if key.is_string() {
-                    match v.get(key.unwrap_str()) {
+                if key.is_string() && !v.is_array() || key.is_number() && v.is_object() {
+                    match v.get(key.property_key()) {
                         Some(v) => {
                             if v.is_object() && v.as_object().unwrap().contains_key("Json") {
                                 Value::Json(v.as_object().unwrap().get("Json").unwrap().clone())
@@ -308,8 +461,8 @@ impl ValueTrait for Value {
                         },
                         None => Value::Undefined
                     }
-                } else if key.is_number() {
-                    match v.get(key.unwrap_usize()) {
+                } else if key.is_number() || key.is_string() {
+                    match key.array_index().and_then(|i| v.get(i)) {
                         Some(v) => {
                             if v.is_object() && v.as_object().unwrap().contains_key("Json") {
                                 Value::Json(v.as_object().unwrap().get("Json").unwrap().clone())
@@ -333,12 +486,12 @@ impl ValueTrait for Value {
                 match value {
                     Value::Json(v1) => {
                         if v.is_object() {
-                            v.as_object_mut().unwrap().insert(key.unwrap_str().to_string(), v1.clone());
+                            v.as_object_mut().unwrap().insert(key.property_key(), v1.clone());
                         }
                     }
                     Value::Undefined => {
                         if v.is_object() {
-                            v.as_object_mut().unwrap().remove(key.unwrap_str());
+                            json_remove(v.as_object_mut().unwrap(), &key.property_key());
                         }
                     }
                     _ => panic!("unexpected value")
@@ -364,7 +517,7 @@ impl ValueTrait for Value {
 
     fn contains_key(&self, key: Value) -> bool {
         match self {
-            Value::Json(v) => if v.is_object() { v.as_object().unwrap().contains_key(key.unwrap_str()) } else { false },
+            Value::Json(v) => if v.is_object() { v.as_object().unwrap().contains_key(&key.property_key()) } else { false },
             _ => panic!("unexpected value")
         }
     }
@@ -420,7 +573,9 @@ impl ValueTrait for Value {
 
     fn to_string(&self) -> Value {
         match self {
+            Value::Json(serde_json::Value::String(v)) => Value::Json(serde_json::Value::String(v.clone())),
             Value::Json(v) => Value::Json(serde_json::Value::String(v.to_string())),
+            Value::Precise(v) => Value::Json(serde_json::Value::String(ToString::to_string(v))),
             _ => panic!("unexpected value")
         }
     }
@@ -447,22 +602,250 @@ impl ValueTrait for Value {
 
 pub fn parse_int(x: Value) -> Value {
     match x {
-        Value::Json(v) if v.is_number() => {
-            let w: u64 = if v.is_i64() {
-                v.as_i64().unwrap().try_into().unwrap()
-            } else if v.is_f64() {
-                v.as_f64().unwrap().round() as u64
-            } else if v.is_u64() {
-                v.as_u64().unwrap()
+        Value::Json(serde_json::Value::Number(v)) => {
+            if let Some(v) = v.as_i64() {
+                v.into()
+            } else if let Some(v) = v.as_u64() {
+                v.into()
             } else {
-                panic!("unexpected value")
-            };
-            w.into()
+                (v.as_f64().unwrap().trunc() as i64).into()
+            }
         }
+        Value::Json(serde_json::Value::String(v)) => {
+            // like javascript, parse the leading integer and ignore whatever follows it
+            let v = v.trim_start();
+            let end = v.char_indices()
+                .find(|(i, c)| !(c.is_ascii_digit() || (*i == 0 && (*c == '-' || *c == '+'))))
+                .map(|(i, _)| i)
+                .unwrap_or(v.len());
+            match v[..end].parse::<i64>() {
+                Ok(v) => v.into(),
+                Err(_) => Value::Undefined
+            }
+        }
+        _ => Value::Undefined
+    }
+}
+
+pub fn parse_float(x: Value) -> Value {
+    match x {
+        Value::Json(serde_json::Value::Number(v)) => v.as_f64().unwrap().into(),
+        Value::Json(serde_json::Value::String(v)) => match v.trim().parse::<f64>() {
+            Ok(v) => v.into(),
+            Err(_) => Value::Undefined
+        },
+        Value::Precise(v) => parse_float(ToString::to_string(&v).into()),
+        _ => Value::Undefined
+    }
+}
+
+// a millisecond timestamp as a date, fractions are floored and numeric strings parsed like javascript's parseInt
+fn utc_datetime(timestamp: &Value) -> Option<DateTime<Utc>> {
+    let ms = match timestamp {
+        Value::Json(serde_json::Value::Number(n)) => match n.as_i64() {
+            Some(ms) => ms,
+            None => n.as_f64().filter(|x| x.is_finite())?.floor() as i64,
+        },
+        Value::Json(serde_json::Value::String(_)) => parse_int(timestamp.clone()).unwrap_json().as_i64()?,
+        _ => return None
+    };
+    Utc.timestamp_millis_opt(ms).single()
+}
+
+/// A millisecond timestamp like javascript's `Date.prototype.toISOString`, always with milliseconds, undefined
+/// before the epoch
+pub fn iso8601(timestamp: &Value) -> Value {
+    match utc_datetime(timestamp) {
+        Some(t) if t.timestamp_millis() >= 0 => t.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string().into(),
         _ => Value::Undefined
     }
 }
 
+/// The milliseconds timestamp of an ISO 8601 date like javascript's `Date.parse`, dates without an offset are in UTC,
+/// the date and the time can be separated by a space and fractions of a millisecond are truncated
+pub fn parse_iso8601(s: &str) -> Option<i64> {
+    // a number in a string is not a date, and neither is anything without both a dash and a colon
+    let s = s.trim();
+    if s.is_empty() || s.chars().all(|c| c.is_ascii_digit()) || !s.contains('-') || !s.contains(':') {
+        return None;
+    }
+    let mut s = match s.get(10..11) {
+        Some(" ") => format!("{}T{}", &s[..10], &s[11..]),
+        _ => s.to_owned()
+    };
+    if s.ends_with('Z') || s.ends_with('z') {
+        s.replace_range(s.len() - 1.., "+00:00");
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%dT%H:%M%z"].iter()
+        .find_map(|format| DateTime::parse_from_str(&s, format).ok())
+        .map(|t| t.timestamp_millis())
+        .or_else(|| ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"].iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(&s, format).ok())
+            .map(|t| t.timestamp_millis()))
+}
+
+// seconds to milliseconds in decimal, so that "1600000000.123" is not off by one after a float multiplication
+fn seconds_to_milliseconds(seconds: &Value) -> Option<i64> {
+    let text = match seconds {
+        Value::Json(serde_json::Value::Number(n)) => n.to_string(),
+        Value::Json(serde_json::Value::String(s)) => s.trim().to_owned(),
+        Value::Precise(p) => ToString::to_string(p),
+        _ => return None
+    };
+    match Decimal::from_str(&text).or_else(|_| Decimal::from_scientific(&text)) {
+        Ok(d) => d.checked_mul(Decimal::from(1000)).and_then(|ms| ms.trunc().to_i64()),
+        Err(_) => match parse_float(seconds.clone()) {
+            Value::Json(serde_json::Value::Number(n)) => Some((n.as_f64()? * 1000.0).trunc() as i64),
+            _ => None
+        }
+    }
+}
+
+// a number as javascript's String () prints it, without the ".0" of whole floats
+fn js_number(n: &serde_json::Number) -> String {
+    match n.as_f64() {
+        Some(f) if n.is_f64() => format!("{}", f),
+        _ => n.to_string()
+    }
+}
+
+// the pairs of qs.stringify, which the javascript urlencode, rawencode and urlencodeWithArrayRepeat are built on:
+// nested objects as a[b]=c, arrays as a[0]=1&a[1]=2 or a=1&a=2 when repeated and null as an empty value, keys and
+// values percent-encoded as in RFC 3986 unless `encode` is off
+fn push_query_pairs(pairs: &mut Vec<String>, key: &str, value: &serde_json::Value, encode: bool, repeat_arrays: bool) {
+    let text = match value {
+        serde_json::Value::Object(o) => {
+            for (k, v) in o.iter() {
+                push_query_pairs(pairs, &format!("{}[{}]", key, k), v, encode, repeat_arrays);
+            }
+            return;
+        }
+        serde_json::Value::Array(a) => {
+            for (i, v) in a.iter().enumerate() {
+                let key = if repeat_arrays { key.to_owned() } else { format!("{}[{}]", key, i) };
+                push_query_pairs(pairs, &key, v, encode, repeat_arrays);
+            }
+            return;
+        }
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(v) => v.clone(),
+        serde_json::Value::Number(n) => js_number(n),
+        serde_json::Value::Bool(b) => b.to_string(),
+    };
+    if encode {
+        pairs.push(format!("{}={}", urlencoding::encode(key), urlencoding::encode(&text)));
+    } else {
+        pairs.push(format!("{}={}", key, text));
+    }
+}
+
+fn stringify_query(object: &Value, encode: bool, repeat_arrays: bool) -> String {
+    let mut pairs = Vec::new();
+    if let Value::Json(serde_json::Value::Object(o)) = object {
+        for (key, value) in o.iter() {
+            push_query_pairs(&mut pairs, key, value, encode, repeat_arrays);
+        }
+    }
+    pairs.join("&")
+}
+
+// a number or a numeric string as a float, for the comparisons javascript coerces
+fn number_of(x: &Value) -> Option<f64> {
+    match parse_float(x.clone()) {
+        Value::Json(serde_json::Value::Number(n)) => n.as_f64(),
+        _ => None
+    }
+}
+
+// javascript's ===, numbers are equal whether or not they are stored as floats
+fn strict_equals(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Json(serde_json::Value::Number(x)), Value::Json(serde_json::Value::Number(y))) => x.as_f64() == y.as_f64(),
+        _ => a == b
+    }
+}
+
+// the keys of safe_value_n and safe_string_n, a single key is accepted too
+fn key_list_of(key_list: Value) -> Vec<Value> {
+    match key_list {
+        Value::Json(serde_json::Value::Array(keys)) => keys.into_iter().map(Value::Json).collect(),
+        Value::Undefined => vec![],
+        key => vec![key]
+    }
+}
+
+/// The seconds in a timeframe like `1m`, `4h` or `1M`, a month counts as 30 days and a year as 365 days
+pub fn timeframe_seconds(timeframe: &str) -> Option<i64> {
+    let unit = timeframe.chars().last()?;
+    let amount: i64 = timeframe[..timeframe.len() - unit.len_utf8()].parse().ok()?;
+    let scale = match unit {
+        'y' => 60 * 60 * 24 * 365,
+        'M' => 60 * 60 * 24 * 30,
+        'w' => 60 * 60 * 24 * 7,
+        'd' => 60 * 60 * 24,
+        'h' => 60 * 60,
+        'm' => 60,
+        's' => 1,
+        _ => return None
+    };
+    Some(amount * scale)
+}
+
+/// `[timestamp, open, high, low, close, volume, count]` candles from trades sorted oldest first, as in ccxt `limit`
+/// caps the trades that are aggregated and the trades before `since` are left out
+pub fn build_ohlcvc(trades: Value, timeframe: Value, since: Value, limit: Value) -> Value {
+    let timeframe = timeframe.or_default("1m".into());
+    let ms = match timeframe_seconds(timeframe.unwrap_str()) {
+        Some(seconds) => seconds as f64 * 1000.0,
+        None => panic!("NotSupported::new(timeframe {} is not supported)", timeframe.unwrap_str())
+    };
+    let since = if since.is_nonnullish() { number_of(&since) } else { None };
+    let trades = match trades {
+        Value::Json(serde_json::Value::Array(trades)) => trades,
+        Value::Json(serde_json::Value::Object(trades)) => trades.into_iter().map(|(_, trade)| trade).collect(),
+        _ => vec![]
+    };
+    let limit = match if limit.is_nonnullish() { number_of(&limit) } else { None } {
+        Some(limit) => (limit.max(0.0) as usize).min(trades.len()),
+        None => trades.len()
+    };
+    let mut ohlcvs: Vec<[f64; 7]> = vec![];
+    for trade in trades.iter().take(limit) {
+        let field = |key: &str| trade.get(key).and_then(|x| number_of(&Value::Json(x.clone())));
+        let (timestamp, price, amount) = match (field("timestamp"), field("price"), field("amount")) {
+            (Some(timestamp), Some(price), Some(amount)) => (timestamp, price, amount),
+            _ => continue
+        };
+        if since.map_or(false, |since| timestamp < since) {
+            continue;
+        }
+        // the edge of the minute, hour or day, months are not aligned to calendar months
+        let opening_time = (timestamp / ms).floor() * ms;
+        match ohlcvs.last_mut() {
+            Some(candle) if opening_time < candle[0] + ms => {
+                candle[2] = candle[2].max(price);
+                candle[3] = candle[3].min(price);
+                candle[4] = price;
+                candle[5] += amount;
+                candle[6] += 1.0;
+            }
+            _ => ohlcvs.push([opening_time, price, price, price, price, amount, 1.0])
+        }
+    }
+    Value::Json(ohlcvs.into_iter()
+        .map(|[t, o, h, l, c, v, n]| json!([t as i64, o, h, l, c, v, n as u64]))
+        .collect())
+}
+
+// the separator of the date formatting methods, javascript's default parameter when undefined
+fn date_infix(infix: Value, default: &str) -> String {
+    match infix {
+        Value::Undefined => default.to_owned(),
+        Value::Json(serde_json::Value::String(s)) => s,
+        _ => String::new()
+    }
+}
+
 pub fn extend_2(x: Value, y: Value) -> Value {
     let mut x1 = x.unwrap_json().clone();
     let mut y1 = y.unwrap_json().clone();
@@ -477,7 +860,7 @@ pub fn extend_2(x: Value, y: Value) -> Value {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Precise {
     value: BigInt,
-    decimals: u32,
+    decimals: i32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -551,20 +934,48 @@ impl Precise {
             modifier = splits.get(1).unwrap().parse::<i32>().unwrap();
         }
         let decimals = match number.find('.') {
-            Some(i) => number.len() - i - 1,
+            Some(i) => (number.len() - i - 1) as i32,
             None => 0,
         };
         let integer_string = number.replace(".", "");
-        Self::new_with_decimals(integer_string.into(), (decimals - modifier as usize).try_into().unwrap())
+        Self::new_with_decimals(integer_string.into(), decimals - modifier)
     }
 
-    pub fn new_with_decimals(val: Value, decimals: u32) -> Value {
+    pub fn new_with_decimals(val: Value, decimals: i32) -> Value {
         Value::Precise(Self {
             value: BigInt::from_str(val.unwrap_str().try_into().unwrap()).unwrap(),
             decimals,
         })
     }
 
+    /// The number of decimal places the integer is scaled by, can be negative after `reduce`
+    pub fn decimals(&self) -> i32 {
+        self.decimals
+    }
+
+    /// Returns a copy with the decimal point moved, `shift_decimals(2)` turns 12345 (decimals 0) into 123.45
+    pub fn shift_decimals(&self, by: i32) -> Value {
+        let mut rv = Self {
+            value: self.value.clone(),
+            decimals: self.decimals + by,
+        };
+        rv.reduce();
+        Value::Precise(rv)
+    }
+
+    /// Truncates towards zero, like `parseInt` does in javascript
+    pub fn to_integer(&self) -> Value {
+        let integer = if self.decimals > 0 {
+            &self.value / BigInt::from(PRECISE_BASE).pow(self.decimals as u32)
+        } else {
+            &self.value * BigInt::from(PRECISE_BASE).pow(self.decimals.unsigned_abs())
+        };
+        match integer.to_i64() {
+            Some(i) => i.into(),
+            None => Value::Json(serde_json::Value::Number(serde_json::Number::from_str(&integer.to_string()).unwrap())),
+        }
+    }
+
     pub fn mul(&self, other: &Value) -> Value {
         Value::Precise(Self {
             value: self.value.checked_mul(&other.unwrap_precise().value).unwrap(),
@@ -572,15 +983,15 @@ impl Precise {
         })
     }
 
-    pub fn div(&self, other: &Value, precision: Option<u32>) -> Value {
+    pub fn div(&self, other: &Value, precision: Option<i32>) -> Value {
         let precision1 = precision.unwrap_or(18);
-        let distance: i32 = (precision1 - self.decimals + other.unwrap_precise().decimals).try_into().unwrap();
+        let distance: i32 = precision1 - self.decimals + other.unwrap_precise().decimals;
         let numerator = if distance == 0 {
             self.value.clone()
         } else if distance < 0 {
-            self.value.checked_div(&BigInt::from(PRECISE_BASE).pow(distance.abs().try_into().unwrap())).unwrap()
+            self.value.checked_div(&BigInt::from(PRECISE_BASE).pow(distance.unsigned_abs())).unwrap()
         } else {
-            self.value.checked_mul(&BigInt::from(PRECISE_BASE).pow(distance.abs().try_into().unwrap())).unwrap()
+            self.value.checked_mul(&BigInt::from(PRECISE_BASE).pow(distance.unsigned_abs())).unwrap()
         };
         Value::Precise(Self {
             value: numerator.div(&other.unwrap_precise().value),
@@ -601,7 +1012,7 @@ impl Precise {
             } else {
                 (self, other)
             };
-            let exponent = bigger.decimals - smaller.decimals;
+            let exponent = (bigger.decimals - smaller.decimals) as u32;
             let normalised = smaller.value.checked_mul(&BigInt::from(PRECISE_BASE).pow(exponent)).unwrap();
             let result = normalised.add(&bigger.value);
             Self {
@@ -613,12 +1024,12 @@ impl Precise {
 
     pub fn r#mod(&self, other: &Value) -> Value {
         let other = other.unwrap_precise();
-        // XXX
-        let rationizer_numerator: u32 = max(-(self.decimals as i32) + other.decimals as i32, 0).try_into().unwrap();
+        let rationizer_numerator = max(-self.decimals + other.decimals, 0) as u32;
         let numerator = self.value.checked_mul(&BigInt::from(PRECISE_BASE).pow(rationizer_numerator)).unwrap();
-        let rationizer_denominator: u32 = max(-(other.decimals as i32) + self.decimals as i32, 0).try_into().unwrap();
-        let denominator = other.value.checked_mul(&BigInt::from(PRECISE_BASE).pow(rationizer_denominator)).unwrap();
-        let result = numerator.mod_floor(&denominator);
+        let rationizer_denominator = max(-other.decimals + self.decimals, 0);
+        let denominator = other.value.checked_mul(&BigInt::from(PRECISE_BASE).pow(rationizer_denominator as u32)).unwrap();
+        // javascript's % keeps the sign of the dividend
+        let result = numerator.rem(&denominator);
         Value::Precise(Self {
             value: result,
             decimals: rationizer_denominator + other.decimals,
@@ -645,12 +1056,11 @@ impl Precise {
     }
 
     pub fn min(&self, other: &Value) -> Value {
-        todo!()
+        if self.lt(other) { Value::Precise(self.clone()) } else { other.clone() }
     }
 
-    pub fn max(&self, other: &Value) -> &Value {
-        todo!()
-        // if self.gt(other) { self } else { other }
+    pub fn max(&self, other: &Value) -> Value {
+        if self.gt(other) { Value::Precise(self.clone()) } else { other.clone() }
     }
 
     pub fn gt(&self, other: &Value) -> bool {
@@ -658,7 +1068,7 @@ impl Precise {
     }
 
     pub fn ge(&self, other: &Value) -> bool {
-        self.gt(other) || self.eq(other.unwrap_precise())
+        !self.sub(other).unwrap_precise().value.is_negative()
     }
 
     pub fn lt(&self, other: &Value) -> bool {
@@ -666,32 +1076,30 @@ impl Precise {
     }
 
     pub fn le(&self, other: &Value) -> bool {
-        self.lt(other) || self.eq(other.unwrap_precise())
+        !self.sub(other).unwrap_precise().value.is_positive()
     }
 
     pub fn reduce(&mut self) {
         let string = self.value.to_string();
         let start = string.len() - 1;
         if start == 0 {
-            if start == 0 {
+            if string == "0" {
                 self.decimals = 0;
             }
             return;
         }
-        let mut i = 0;
-        let chars = string.chars().collect::<Vec<_>>();
-        for i in (0..=start).rev() {
-            if chars[i] != '0' {
-                break;
-            }
+        let chars = string.as_bytes();
+        let mut i = start as i64;
+        while i >= 0 && chars[i as usize] == b'0' {
+            i -= 1;
         }
-        let difference = start - i;
+        let difference = start as i64 - i;
         if difference == 0 {
             return;
         }
 
-        self.decimals -= difference as u32;
-        self.value = BigInt::from_str(&string[0..=i]).unwrap()
+        self.decimals -= difference as i32;
+        self.value = BigInt::from_str(&string[0..=(i as usize)]).unwrap()
     }
 
     pub fn is_zero(&self) -> bool {
@@ -705,110 +1113,149 @@ impl Precise {
         self.value == other.value && self.decimals == other.decimals
     }
 
+    // The string_* helpers mirror Precise.string* in javascript: they take and return strings,
+    // and an undefined operand yields undefined (or false for comparisons)
+
     pub fn string_mul(x: Value, y: Value) -> Value {
-        Precise::new(x).unwrap_precise().mul(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().mul(&Precise::new(y)).unwrap_precise().to_string().into()
     }
 
     pub fn string_div(x: Value, y: Value, precision: Value) -> Value {
-        Precise::new(x).unwrap_precise().div(&Precise::new(y), if precision.is_undefined() {
-            None
-        } else {
-            Some(precision.unwrap_precise().value.clone().try_into().unwrap())
-        })
+        if x.is_nullish() || y.is_nullish() {
+            return Value::Undefined;
+        }
+        let y = Precise::new(y);
+        if y.unwrap_precise().is_zero() {
+            return Value::Undefined;
+        }
+        let precision = match precision {
+            Value::Json(p) if p.is_number() => Some(p.as_i64().unwrap() as i32),
+            Value::Json(p) if p.is_string() => Some(p.as_str().unwrap().parse::<i32>().unwrap()),
+            _ => None,
+        };
+        Precise::new(x).unwrap_precise().div(&y, precision).unwrap_precise().to_string().into()
     }
 
     pub fn string_add(x: Value, y: Value) -> Value {
-        Precise::new(x).unwrap_precise().add(&Precise::new(y))
+        if x.is_nullish() && y.is_nullish() {
+            return Value::Undefined;
+        }
+        if x.is_nullish() {
+            return y;
+        } else if y.is_nullish() {
+            return x;
+        }
+        Precise::new(x).unwrap_precise().add(&Precise::new(y)).unwrap_precise().to_string().into()
     }
 
     pub fn string_sub(x: Value, y: Value) -> Value {
-        Precise::new(x).unwrap_precise().sub(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().sub(&Precise::new(y)).unwrap_precise().to_string().into()
     }
 
     pub fn string_abs(x: Value) -> Value {
-        Precise::new(x).unwrap_precise().abs()
+        if x.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().abs().unwrap_precise().to_string().into()
     }
 
     pub fn string_neg(x: Value) -> Value {
-        Precise::new(x).unwrap_precise().neg()
+        if x.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().neg().unwrap_precise().to_string().into()
     }
 
     pub fn string_mod(x: Value, y: Value) -> Value {
-        Precise::new(x).unwrap_precise().r#mod(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().r#mod(&Precise::new(y)).unwrap_precise().to_string().into()
     }
 
     pub fn string_equals(x: Value, y: Value) -> bool {
-        Precise::new(x).unwrap_precise().eq(&Precise::new(y).unwrap_precise())
+        if x.is_nullish() || y.is_nullish() {
+            return false;
+        }
+        Precise::new(x).unwrap_precise_mut().equals(&mut Precise::new(y))
     }
 
     pub fn string_eq(x: Value, y: Value) -> bool {
-        Precise::new(x).unwrap_precise().eq(&Precise::new(y).unwrap_precise())
+        Self::string_equals(x, y)
     }
 
     pub fn string_min(x: Value, y: Value) -> Value {
-        let x1 = Precise::new(x);
-        let y1 = Precise::new(y);
-        if x1.lt(&y1) { x1 } else { y1 }
+        if x.is_nullish() || y.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().min(&Precise::new(y)).unwrap_precise().to_string().into()
     }
 
     pub fn string_max(x: Value, y: Value) -> Value {
-        let x1 = Precise::new(x);
-        let y1 = Precise::new(y);
-        if x1.gt(&y1) { x1 } else { y1 }
+        if x.is_nullish() || y.is_nullish() {
+            return Value::Undefined;
+        }
+        Precise::new(x).unwrap_precise().max(&Precise::new(y)).unwrap_precise().to_string().into()
     }
 
     pub fn string_gt(x: Value, y: Value) -> bool {
-        Precise::new(x).gt(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return false;
+        }
+        Precise::new(x).unwrap_precise().gt(&Precise::new(y))
     }
 
     pub fn string_ge(x: Value, y: Value) -> bool {
-        Precise::new(x).ge(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return false;
+        }
+        Precise::new(x).unwrap_precise().ge(&Precise::new(y))
     }
 
     pub fn string_lt(x: Value, y: Value) -> bool {
-        Precise::new(x).lt(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return false;
+        }
+        Precise::new(x).unwrap_precise().lt(&Precise::new(y))
     }
 
     pub fn string_le(x: Value, y: Value) -> bool {
-        Precise::new(x).le(&Precise::new(y))
+        if x.is_nullish() || y.is_nullish() {
+            return false;
+        }
+        Precise::new(x).unwrap_precise().le(&Precise::new(y))
     }
 }
 
 impl ToString for Precise {
     fn to_string(&self) -> String {
-        // self.reduce(); // XXX
-        let (sign, abs) = if self.value.is_negative() {
-            ("-", self.value.abs())
+        let mut reduced = self.clone();
+        reduced.reduce();
+        let (sign, abs) = if reduced.value.is_negative() {
+            ("-", reduced.value.abs())
         } else {
-            ("", self.value.clone())
-        };
-        let abs_string = abs.to_string();
-        if abs_string == "0" {
-            return "0".to_string();
-        }
-         if abs_string.len() < self.decimals as usize {
-            format!("0.{}", "0".repeat(self.decimals as usize - abs_string.len())).to_string()
-            // let mut array = vec!["0"; (self.decimals - abs_string.len() as u32) as usize];
-            // todo!()
-            // array.extend(abs_string.chars().collect());
-            // array
+            ("", reduced.value.clone())
+        };
+        let mut abs_string = abs.to_string();
+        if reduced.decimals > 0 && abs_string.len() < reduced.decimals as usize {
+            abs_string = format!("{}{}", "0".repeat(reduced.decimals as usize - abs_string.len()), abs_string);
+        }
+        let index = abs_string.len() as i32 - reduced.decimals;
+        if reduced.decimals < 0 {
+            format!("{}{}{}", sign, abs_string, "0".repeat(reduced.decimals.unsigned_abs() as usize))
+        } else if reduced.decimals == 0 {
+            format!("{}{}", sign, abs_string)
+        } else if index == 0 {
+            format!("{}0.{}", sign, abs_string)
         } else {
-            todo!()
-            // abs_string.chars().collect()
-        }
-        // let index = integer_array.len() as u32 - self.decimals;
-        // let item = if index == 0 {
-        //     "0."
-        // } else if self.decimals < 0 {
-        //     todo!()
-        //     // "0".repeat(self.decimals as usize)
-        // } else if self.decimals == 0 {
-        //     ""
-        // } else {
-        //     "."
-        // };
-        // todo!()
-        // format!("{}{}{}{}", sign, &integer_array[0..index].iter().collect(), item, &integer_array[index..].iter().collect())
+            format!("{}{}.{}", sign, &abs_string[..index as usize], &abs_string[index as usize..])
+        }
     }
 }
 
@@ -981,7 +1428,7 @@ impl Sub for Value {
     fn sub(self, other: Value) -> Self::Output {
         match (self, other) {
             (Value::Json(x), Value::Json(y)) if x.is_number() && y.is_number() => {
-                if x.is_u64() && y.is_u64() {
+                if x.is_u64() && y.is_u64() && x.as_u64() >= y.as_u64() {
                     (x.as_u64().unwrap() - y.as_u64().unwrap()).into()
                 } else if x.is_i64() && y.is_i64() {
                     (x.as_i64().unwrap() - y.as_i64().unwrap()).into()
@@ -1099,6 +1546,25 @@ impl ExchangeImpl {
     }
 }
 
+/// An exchange's `handle_errors` override: status, status text, url, method, response headers,
+/// response body, parsed response, request headers and request body
+pub type HandleErrors<E> = fn(&mut E, Value, Value, Value, Value, Value, Value, Value, Value, Value) -> Value;
+
+/// An exchange's `load_time_difference` override: params
+pub type LoadTimeDifference<E> = for<'a> fn(&'a mut E, Value) -> futures::future::BoxFuture<'a, Value>;
+
+/// An exchange's `fetch_trades` override: symbol, since, limit and params
+pub type FetchTrades<E> = for<'a> fn(&'a mut E, Value, Value, Value, Value) -> futures::future::BoxFuture<'a, Value>;
+
+/// The steps of a REST request an exchange overrides, the generated exchange traits pass their own
+/// to `fetch2_with_retry` as `Exchange` cannot dispatch to those overrides
+pub struct RestHooks<E: ?Sized> {
+    pub load_time_difference: LoadTimeDifference<E>,
+    pub calculate_rate_limiter_cost: fn(&mut E, Value, Value, Value, Value, Value, Value) -> Value,
+    pub sign: fn(&mut E, Value, Value, Value, Value, Value, Value) -> Value,
+    pub handle_errors: HandleErrors<E>,
+}
+
 #[async_trait]
 pub trait Exchange: ValueTrait + Sync + Send {
     fn set_number_mode(&mut self, mode: Value) {
@@ -1109,6 +1575,22 @@ pub trait Exchange: ValueTrait + Sync + Send {
         Value::new_object()
     }
 
+    /// Reads nested exchange state, e.g. `self.get_path(&["options".into(), "limitsLoaded".into()])`
+    fn get_path(&self, path: &[Value]) -> Value {
+        json_get_path(self.unwrap_json(), path)
+    }
+
+    /// Writes nested exchange state so it persists between calls, e.g.
+    /// `self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds())`
+    fn set_path(&mut self, path: &[Value], value: Value) {
+        json_set_path(self.unwrap_json_mut(), path, value)
+    }
+
+    /// Mutable access to nested exchange state, the missing steps are created as empty objects
+    fn get_path_mut(&mut self, path: &[Value]) -> &mut serde_json::Value {
+        json_path_mut(self.unwrap_json_mut(), path)
+    }
+
     fn parse_number(&self, value: Value, default: Value) -> Value {
         if value.is_undefined() {
             return default;
@@ -1231,8 +1713,13 @@ pub trait Exchange: ValueTrait + Sync + Send {
                 match x1 {
                     serde_json::Value::Object(x2) => {
                         let mut result = serde_json::Map::new();
+                        let omitted: Vec<String> = match &keys {
+                            Value::Json(serde_json::Value::Array(keys)) => keys.iter().filter_map(|k| k.as_str().map(|k| k.to_owned())).collect(),
+                            Value::Json(serde_json::Value::String(key)) => vec![key.clone()],
+                            _ => vec![],
+                        };
                         for key in x2.keys() {
-                            if !keys.contains_key(key.into()) {
+                            if !omitted.contains(key) {
                                 result.insert(key.to_owned(), x2.get(key.into()).unwrap().clone());
                             }
                         }
@@ -1275,18 +1762,19 @@ pub trait Exchange: ValueTrait + Sync + Send {
         let rv = match x {
             Value::Json(j) => match j {
                 serde_json::Value::Object(o) => {
-                    match o.get(key.unwrap_str()) {
+                    match o.get(&key.property_key()) {
                         Some(v) if v.is_string() => Value::Json(v.clone()),
+                        Some(serde_json::Value::Null) => Value::Undefined,
                         Some(v) => Value::Json(v.to_string().into()),
                         _ => Value::Undefined
                     }
                 }
                 serde_json::Value::Array(a) => {
-                    let index = key.unwrap_usize();
-                    if index >= a.len() {
-                        Value::Undefined
-                    } else {
-                        Value::Json(a[index].clone())
+                    match key.array_index().and_then(|i| a.get(i)) {
+                        Some(v) if v.is_string() => Value::Json(v.clone()),
+                        Some(serde_json::Value::Null) => Value::Undefined,
+                        Some(v) => Value::Json(v.to_string().into()),
+                        _ => Value::Undefined
                     }
                 }
                 _ => Value::Undefined
@@ -1309,67 +1797,99 @@ pub trait Exchange: ValueTrait + Sync + Send {
         SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_micros().to_u64().unwrap().into()
     }
 
+    // the clock of the exchange, the local one corrected by `options.timeDifference`, msec and usec stay local
     fn seconds(&self) -> Value {
-        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs().to_u64().unwrap().into()
+        self.milliseconds().unwrap_json().as_i64().unwrap().div_euclid(1000).into()
     }
 
     fn milliseconds(&self) -> Value {
-        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis().to_u64().unwrap().into()
+        (SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64 - self.time_difference()).into()
     }
 
     fn microseconds(&self) -> Value {
-        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_micros().to_u64().unwrap().into()
+        (SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_micros() as i64 - self.time_difference() * 1000).into()
     }
 
-    fn safe_string_lower(&self, x: Value, key: Value, default_value: Value) -> Value {
-        let rv = match x {
-            Value::Json(j) => match j {
-                serde_json::Value::Object(o) => {
-                    match o.get(key.unwrap_str()) {
-                        Some(v) if v.is_string() => v.to_string().to_lowercase().into(),
-                        _ => Value::Undefined
-                    }
-                }
-                _ => Value::Undefined
-            },
-            _ => Value::Undefined
-        };
+    /// How many milliseconds the local clock is ahead of the exchange's, `options.timeDifference`
+    fn time_difference(&self) -> i64 {
+        match self.get_path(&["options".into(), "timeDifference".into()]) {
+            Value::Json(serde_json::Value::Number(n)) => n.as_f64().unwrap() as i64,
+            _ => 0
+        }
+    }
 
-        return if rv.is_undefined() {
-            default_value
+    /// Records the difference between the local clock and `server_time`, just fetched, for `milliseconds()` to apply
+    fn set_time_difference(&mut self, server_time: Value) -> Value {
+        let now = self.msec();
+        self.set_path(&["options".into(), "timeDifference".into()], now.clone() - server_time);
+        self.set_path(&["options".into(), "timeDifferenceUpdatedAt".into()], now);
+        self.get_path(&["options".into(), "timeDifference".into()])
+    }
+
+    /// Whether the time difference has to be fetched again, because `adjustForTimeDifference` is on and it is older
+    /// than `timeDifferenceInterval` milliseconds or because `force` is set after a timestamp error, it is then marked
+    /// as updated so that the `fetch_time` request sent to update it does not start another update
+    fn time_difference_due(&mut self, force: bool) -> bool {
+        if !self.get_path(&["options".into(), "adjustForTimeDifference".into()]).is_truthy() || !self.get_path(&["has".into(), "fetchTime".into()]).is_truthy() {
+            return false;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let updated_at = match self.get_path(&["options".into(), "timeDifferenceUpdatedAt".into()]) {
+            Value::Json(serde_json::Value::Number(n)) => n.as_u64(),
+            _ => None
+        };
+        let interval = match self.get_path(&["options".into(), "timeDifferenceInterval".into()]) {
+            Value::Json(serde_json::Value::Number(n)) => n.as_f64().unwrap() as u64,
+            _ => 3600000
+        };
+        if force || updated_at.map_or(true, |updated_at| now.saturating_sub(updated_at) >= interval) {
+            self.set_path(&["options".into(), "timeDifferenceUpdatedAt".into()], now.into());
+            true
         } else {
-            rv
-        };
+            false
+        }
     }
 
-    fn safe_string_upper(&self, x: Value, key: Value, default_value: Value) -> Value {
-        let rv = match x {
-            Value::Json(j) => match j {
-                serde_json::Value::Object(o) => {
-                    match o.get(key.unwrap_str()) {
-                        Some(v) if v.is_string() => v.to_string().to_uppercase().into(),
-                        _ => Value::Undefined
-                    }
-                }
-                _ => Value::Undefined
-            },
-            _ => Value::Undefined
-        };
+    /// Fetches the time difference again through the exchange's `load_time_difference` when `time_difference_due`,
+    /// true when it did
+    async fn refresh_time_difference(&mut self, force: bool, load_time_difference: LoadTimeDifference<Self>) -> bool {
+        if !self.time_difference_due(force) {
+            return false;
+        }
+        load_time_difference(self, Value::Undefined).await;
+        true
+    }
 
-        return if rv.is_undefined() {
-            default_value
-        } else {
-            rv
+    /// A nonce greater than every other one handed out in this process for the same exchange and API key, `nonce`
+    /// when the clock has moved past them, for venues that reject a nonce they have already seen
+    fn monotonic_nonce(&self, nonce: Value) -> Value {
+        let key = match self.get("apiKey".into()) {
+            Value::Json(serde_json::Value::String(api_key)) => format!("{}:{}", self.get("id".into()).property_key(), api_key),
+            _ => self.get("id".into()).property_key()
         };
+        crate::nonce::next(&key, nonce.unwrap_json().as_u64().unwrap()).into()
+    }
+
+    fn safe_string_lower(&self, x: Value, key: Value, default_value: Value) -> Value {
+        match self.safe_string(x, key, default_value) {
+            Value::Json(serde_json::Value::String(v)) => v.to_lowercase().into(),
+            rv => rv
+        }
+    }
+
+    fn safe_string_upper(&self, x: Value, key: Value, default_value: Value) -> Value {
+        match self.safe_string(x, key, default_value) {
+            Value::Json(serde_json::Value::String(v)) => v.to_uppercase().into(),
+            rv => rv
+        }
     }
 
     fn safe_integer(&self, x: Value, key: Value, default_value: Value) -> Value {
-        let rv = match self.safe_value(x, key, Value::Undefined) {
-            Value::Json(j) => match j {
-                serde_json::Value::Number(o) => {
-                    Value::Json(serde_json::Value::from(o.to_string().parse::<i64>().unwrap()))
-                }
-                _ => Value::Undefined
+        // numeric strings count as numbers and fractions are truncated, like parseInt (parseFloat (x))
+        let rv = match parse_float(self.safe_value(x.clone(), key.clone(), Value::Undefined)) {
+            Value::Json(serde_json::Value::Number(o)) => match self.safe_value(x, key, Value::Undefined) {
+                Value::Json(serde_json::Value::Number(n)) if n.is_i64() || n.is_u64() => Value::Json(serde_json::Value::Number(n)),
+                _ => Value::Json(serde_json::Value::from(o.as_f64().unwrap().trunc() as i64))
             },
             _ => Value::Undefined
         };
@@ -1388,13 +1908,13 @@ pub trait Exchange: ValueTrait + Sync + Send {
         let rv = match x {
             Value::Json(j) => match j {
                 serde_json::Value::Object(o) => {
-                    match o.get(key.unwrap_str()) {
+                    match o.get(&key.property_key()) {
                         Some(v) => Value::Json(v.clone()),
                         _ => Value::Undefined
                     }
                 }
                 serde_json::Value::Array(o) => {
-                    match o.get(key.unwrap_usize()) {
+                    match key.array_index().and_then(|i| o.get(i)) {
                         Some(v) => Value::Json(v.clone()),
                         _ => Value::Undefined
                     }
@@ -1419,6 +1939,27 @@ pub trait Exchange: ValueTrait + Sync + Send {
             self.safe_value(x, key2, default_value))
     }
 
+    fn safe_value_n(&self, x: Value, key_list: Value, default_value: Value) -> Value {
+        // the first key with a value that is neither null nor an empty string
+        for key in key_list_of(key_list) {
+            let rv = self.safe_value(x.clone(), key, Value::Undefined);
+            if rv.is_nonnullish() && rv != Value::from("") {
+                return rv;
+            }
+        }
+        default_value
+    }
+
+    fn safe_string_n(&self, dictionary: Value, key_list: Value, default_value: Value) -> Value {
+        for key in key_list_of(key_list) {
+            let rv = self.safe_string(dictionary.clone(), key, Value::Undefined);
+            if rv.is_nonnullish() && rv != Value::from("") {
+                return rv;
+            }
+        }
+        default_value
+    }
+
     fn safe_string_2(&self, x: Value, key1: Value, key2: Value, default_value: Value) -> Value {
         self.safe_string(x.clone(), key1, Value::Undefined).or_default(
             self.safe_string(x, key2, default_value))
@@ -1538,17 +2079,60 @@ pub trait Exchange: ValueTrait + Sync + Send {
         }
     }
 
+    fn capitalize(&self, string: Value) -> Value {
+        let s = string.unwrap_str();
+        let mut chars = s.chars();
+        match chars.next() {
+            Some(c) => format!("{}{}", c.to_uppercase(), chars.as_str()).into(),
+            None => string.clone()
+        }
+    }
+
     fn parse_transaction(&self, mut transaction: Value, mut currency: Value) -> Value { todo!() }
     fn parse_transfer(&self, mut transfer: Value, mut currency: Value) -> Value { todo!() }
     fn parse_market_leverage_tiers(&self, info: Value, market: Value) -> Value { todo!() }
     fn sign(&self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value) -> Value { todo!() }
-    fn yymmdd(&self, timestamp: Value, infix: Value) -> Value { todo!() }
-    fn yyyymmdd(&self, timestamp: Value, infix: Value) -> Value { todo!() }
-    fn ymdhms(&self, timestamp: Value, infix: Value) -> Value { todo!() }
-    fn ymd(&self, timestamp: Value, infix: Value, full_year: Value) -> Value { todo!() }
-    fn mdy(&self, timestamp: Value, infix: Value) -> Value { todo!() }
+
+    fn yymmdd(&self, timestamp: Value, infix: Value) -> Value {
+        self.ymd(timestamp, date_infix(infix, "").into(), false.into())
+    }
+
+    fn yyyymmdd(&self, timestamp: Value, infix: Value) -> Value {
+        self.ymd(timestamp, date_infix(infix, "-").into(), true.into())
+    }
+
+    fn ymdhms(&self, timestamp: Value, infix: Value) -> Value {
+        match utc_datetime(&timestamp) {
+            Some(t) => format!("{}{}{}", t.format("%Y-%m-%d"), date_infix(infix, " "), t.format("%H:%M:%S")).into(),
+            None => Value::Undefined
+        }
+    }
+
+    fn ymd(&self, timestamp: Value, infix: Value, full_year: Value) -> Value {
+        let t = match utc_datetime(&timestamp) {
+            Some(t) => t,
+            None => return Value::Undefined
+        };
+        let year = if full_year.is_undefined() || full_year.is_truthy() { t.year() } else { t.year() - 2000 };
+        let infix = date_infix(infix, "");
+        format!("{}{}{:02}{}{:02}", year, infix, t.month(), infix, t.day()).into()
+    }
+
+    fn mdy(&self, timestamp: Value, infix: Value) -> Value {
+        match utc_datetime(&timestamp) {
+            Some(t) => {
+                let infix = date_infix(infix, "-");
+                format!("{:02}{}{:02}{}{}", t.month(), infix, t.day(), infix, t.year()).into()
+            }
+            None => Value::Undefined
+        }
+    }
+
     async fn fetch_accounts(&self, parmas: Value) -> Value { todo!() }
-    fn is_array(&self, value: Value) -> Value { todo!() }
+
+    fn is_array(&self, value: Value) -> Value {
+        Array::is_array(value)
+    }
 
     fn precision_from_string(&self, string: Value) -> Value {
         let re = Regex::new("0+$").unwrap();
@@ -1557,47 +2141,72 @@ pub trait Exchange: ValueTrait + Sync + Send {
     }
 
     fn uuid22(&self, length: Value) -> Value {
-        let mut arr = Vec::<u8>::new();
-        arr.resize(11, 0);
-        rand::thread_rng().fill_bytes(&mut arr);
-        hex::encode(arr).into()
+        random_hex(&length, 22)
+    }
+
+    fn uuid16(&self, length: Value) -> Value {
+        random_hex(&length, 16)
+    }
+
+    fn filter_by(&self, array: Value, key: Value, value: Value, out: Value) -> Value {
+        let mut out = match out {
+            Value::Json(serde_json::Value::Array(out)) => out,
+            _ => vec![]
+        };
+        if let Value::Json(serde_json::Value::Array(entries)) = self.to_array(array) {
+            for entry in entries {
+                if strict_equals(&self.safe_value(Value::Json(entry.clone()), key.clone(), Value::Undefined), &value) {
+                    out.push(entry);
+                }
+            }
+        }
+        Value::Json(serde_json::Value::Array(out))
+    }
+
+
+    fn parse8601(&self, value: Value) -> Value {
+        match &value {
+            Value::Json(serde_json::Value::String(s)) => parse_iso8601(s).map(Value::from).unwrap_or(Value::Undefined),
+            _ => Value::Undefined
+        }
+    }
+
+
+    fn rawencode(&self, value: Value) -> Value {
+        stringify_query(&value, false, false).into()
+    }
+
+    fn urlencode_with_array_repeat(&self, value: Value) -> Value {
+        stringify_query(&value, true, true).into()
     }
 
-    fn filter_by(&self, array: Value, key: Value, value: Value, out: Value) -> Value { todo!() }
-    fn parse8601(&self, value: Value) -> Value { todo!() }
-    fn rawencode(&self, value: Value) -> Value { todo!() }
-    fn urlencode_with_array_repeat(&self, value: Value) -> Value { todo!() }
 
     fn decimal_to_precision(&self, n: Value, rounding_mode: Value, precision: Value, counting_mode: Value, padding_mode: Value) -> Value {
         // FIXME not fully implemented
         n.to_string()
     }
 
-    fn number_to_string(&self, x: Value) -> Value { todo!() }
-    async fn fetch_trades(&self, symbol: Value, since: Value, limit: Value, params: Value) -> Value { todo!() }
-
-    fn urlencode(&self, object: Value) -> Value {
-        match object {
-            Value::Json(json) if json.is_object() => {
-                let mut rv = String::new();
-                for (key, value) in json.as_object().unwrap().iter() {
-                    rv.push_str(key);
-                    rv.push_str("=");
-                    if value.is_string() {
-                        rv.push_str(&urlencoding::encode(value.as_str().unwrap()));
-                    } else{
-                        rv.push_str(&urlencoding::encode(&value.to_string()));
-                    }
-                    rv.push_str("&");
-                }
-                if rv.len() > 0 {
-                    rv.pop();
+    fn number_to_string(&self, x: Value) -> Value {
+        match x {
+            Value::Json(serde_json::Value::Number(n)) => {
+                if n.is_f64() {
+                    // Display for f64 never uses exponent notation, which is what we want here
+                    format!("{}", n.as_f64().unwrap()).into()
+                } else {
+                    n.to_string().into()
                 }
-                Value::Json(serde_json::Value::String(rv))
             }
-            _ => unimplemented!()
+            Value::Precise(p) => p.to_string().into(),
+            _ => x
         }
     }
+    async fn fetch_trades(&self, symbol: Value, since: Value, limit: Value, params: Value) -> Value {
+        panic!("NotSupported::new({} fetchTrades() is not supported yet)", self.get("id".into()).unwrap_str())
+    }
+
+    fn urlencode(&self, object: Value) -> Value {
+        stringify_query(&object, true, false).into()
+    }
 
     fn json(&self, data: Value, params: Value) -> Value {
         match data {
@@ -1663,10 +2272,30 @@ pub trait Exchange: ValueTrait + Sync + Send {
     fn encode(&self, x: Value) -> Value { x }
 
     fn parse_ticker(&self, ticker: Value, market: Value) -> Value { todo!() }
-    // TODO
     fn filter_by_value_since_limit(&self, array: Value, field: Value, value: Value, since: Value, limit: Value, key: Value, tail: Value) -> Value {
-        // TODO
-        array
+        // a single pass for the value and since filters, then the first `limit` entries or the last ones with `tail`
+        let key = key.or_default("timestamp".into());
+        let since = if since.is_nonnullish() { number_of(&since) } else { None };
+        let mut entries = match self.to_array(array) {
+            Value::Json(serde_json::Value::Array(entries)) => entries,
+            array => return array
+        };
+        if value.is_nonnullish() || since.is_some() {
+            entries.retain(|entry| {
+                let entry = Value::Json(entry.clone());
+                (value.is_nullish() || strict_equals(&self.safe_value(entry.clone(), field.clone(), Value::Undefined), &value))
+                    && since.map_or(true, |since| matches!(number_of(&self.safe_value(entry, key.clone(), Value::Undefined)), Some(t) if t >= since))
+            });
+        }
+        if let Some(limit) = if limit.is_nonnullish() { number_of(&limit) } else { None } {
+            let limit = (limit.max(0.0) as usize).min(entries.len());
+            if tail.is_truthy() {
+                entries.drain(..entries.len() - limit);
+            } else {
+                entries.truncate(limit);
+            }
+        }
+        Value::Json(serde_json::Value::Array(entries))
     }
     fn parse_deposit_address(&self, deposit_address: Value, currency: Value) -> Value { todo!() }
     fn parse_borrow_interest(&self, info: Value, market: Value) -> Value { todo!() }
@@ -1677,36 +2306,64 @@ pub trait Exchange: ValueTrait + Sync + Send {
     fn parse_trade(&self, trade: Value, market: Value) -> Value { todo!() }
     fn parse_ledger_entry(&self, item: Value, currency: Value) -> Value { todo!() }
     fn parse_position(&self, position: Value, market: Value) -> Value { todo!() }
-    // TODO
-    fn implode_params(&self, string: Value, params: Value) -> Value { todo!() }
-    // TODO
-    fn extract_params(&self, params: Value) -> Value { todo!() }
+    fn implode_params(&self, string: Value, params: Value) -> Value {
+        let mut rv = string.unwrap_str().to_owned();
+        if let Value::Json(serde_json::Value::Object(params)) = params {
+            for (key, value) in params.iter() {
+                if value.is_array() {
+                    continue;
+                }
+                let value = match value {
+                    serde_json::Value::String(v) => v.clone(),
+                    serde_json::Value::Number(n) => js_number(n),
+                    v => v.to_string(),
+                };
+                // like javascript's String.prototype.replace, only the first occurrence
+                rv = rv.replacen(&format!("{{{}}}", key), &value, 1);
+            }
+        }
+        rv.into()
+    }
+
+    fn extract_params(&self, string: Value) -> Value {
+        let re = regex::Regex::new(r"\{([\w-]+)\}").unwrap();
+        let params: Vec<serde_json::Value> = re.captures_iter(string.unwrap_str())
+            .map(|c| serde_json::Value::String(c[1].to_owned()))
+            .collect();
+        Value::Json(serde_json::Value::Array(params))
+    }
     async fn fetch_trading_limits_by_id(&self, id: Value, params: Value) -> Value { todo!() }
-    // TODO
-    fn filter_by_since_limit(&self, array: Value, since: Value, limit: Value, key: Value, tail: Value) -> Value { todo!() }
-    // TODO
-    fn aggregate(&self, bidasks: Value) -> Value { todo!() }
+    fn filter_by_since_limit(&self, array: Value, since: Value, limit: Value, key: Value, tail: Value) -> Value {
+        self.filter_by_value_since_limit(array, Value::Undefined, Value::Undefined, since, limit, key, tail)
+    }
+
+    fn aggregate(&self, bidasks: Value) -> Value {
+        // the volumes summed by price, in the order the prices first appear, without the non-positive volumes
+        let mut result: Vec<(f64, f64)> = vec![];
+        if let Value::Json(serde_json::Value::Array(bidasks)) = bidasks {
+            for bidask in bidasks.into_iter().map(Value::Json) {
+                let price = number_of(&self.safe_value(bidask.clone(), 0.into(), Value::Undefined));
+                let volume = number_of(&self.safe_value(bidask, 1.into(), Value::Undefined));
+                if let (Some(price), Some(volume)) = (price, volume) {
+                    if volume > 0.0 {
+                        match result.iter_mut().find(|(p, _)| *p == price) {
+                            Some((_, total)) => *total += volume,
+                            None => result.push((price, volume))
+                        }
+                    }
+                }
+            }
+        }
+        Value::Json(result.into_iter().map(|(price, volume)| json!([price, volume])).collect())
+    }
+
     fn parse_order(&self, order: Value, market: Value) -> Value { todo!() }
 
     async fn fetch_currencies(&mut self, mut params: Value) -> Value { todo!() }
     async fn fetch_markets(&mut self, mut params: Value) -> Value { todo!() }
 
     fn iso8601(&self, timestamp: Value) -> Value {
-        match timestamp {
-            Value::Json(serde_json::Value::Number(x)) => {
-                let x = x.as_f64().unwrap();
-                if x.is_sign_negative() {
-                    return Value::Undefined
-                }
-
-                let nt = NaiveDateTime::from_timestamp(
-                    (x / 1000.0).floor() as i64, ((x * 1e6).floor() as u64 % 1e9 as u64) as u32
-                );
-                let t: DateTime<Utc> = DateTime::from_utc(nt, Utc);
-                t.format("%+").to_string().into()
-            }
-            _ => Value::Undefined
-        }
+        iso8601(&timestamp)
     }
     // fn fetch_borrow_rate(&self, code: Value, params: Value) -> Value { todo!() }
 
@@ -1730,13 +2387,102 @@ pub trait Exchange: ValueTrait + Sync + Send {
     // }
 
     async fn fetch_time(&self, params: Value) -> Value { todo!() }
-    fn safe_string_n(&self, dictionary: Value, key_list: Value, default_value: Value) -> Value { todo!() }
     async fn fetch_funding_rates(&self, symbols: Value, params: Value) -> Value { todo!() }
     async fn fetch_leverage_tiers(&self, symbols: Value, params: Value) -> Value { todo!() }
-    fn build_ohlcvc(&self, trades: Value, timeframe: Value, since: Value, limit: Value) -> Value { todo!() }
-    async fn throttle(&self, cost: Value) -> Value { todo!() }
-    fn safe_timestamp(&self, dictionary: Value, key: Value, default_value: Value) -> Value { todo!() }
-    fn safe_timestamp_2(&self, dictionary: Value, key1: Value, key2: Value, default_value: Value) -> Value { todo!() }
+
+    fn build_ohlcvc(&self, trades: Value, timeframe: Value, since: Value, limit: Value) -> Value {
+        build_ohlcvc(trades, timeframe, since, limit)
+    }
+
+    /// The span `fetch2` runs in, `cost`, `status`, `latency_ms` and `retries` are recorded as the request goes
+    fn request_span(&self, api: &Value, method: &Value, path: &Value) -> tracing::Span {
+        tracing::debug_span!(
+            "request",
+            exchange = %log_text(&self.get("id".into())),
+            api = %log_text(api),
+            method = %log_text(method),
+            path = %log_text(path),
+            cost = tracing::field::Empty,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            retries = 0,
+        )
+    }
+
+    /// The credentials, blanked out wherever they show up in the logged requests and responses
+    fn log_secrets(&self) -> Vec<String> {
+        CREDENTIAL_FIELDS.iter()
+            .filter_map(|field| match self.get((*field).into()) {
+                // short values like a numeric uid would blank out unrelated text
+                Value::Json(serde_json::Value::String(s)) if s.len() >= 4 => Some(s),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Logs a signed request with its credentials, signatures and passphrases redacted, at the info level when
+    /// `verbose` is set and at the debug level otherwise
+    fn trace_request(&self, request: &Value) {
+        let secrets = self.log_secrets();
+        let url = redact_url(&log_text(&request.get("url".into())), &secrets);
+        let headers = redact_headers(&request.get("headers".into()).into(), &secrets);
+        let body = match request.get("body".into()) {
+            Value::Json(serde_json::Value::String(body)) => redact_body(&body, &secrets),
+            _ => String::new(),
+        };
+        let method = log_text(&request.get("method".into()));
+        verbose_event!(self.get("verbose".into()).is_truthy(), %method, %url, %headers, %body, "request");
+    }
+
+    /// Records the status and latency of a response from `fetch_response` on the request span and logs it redacted
+    fn trace_response(&self, response: &Value, started: Instant) {
+        let latency_ms = started.elapsed().as_millis() as u64;
+        let status = match response.get("status".into()) {
+            Value::Json(serde_json::Value::Number(n)) => n.as_u64().unwrap_or(0),
+            _ => 0,
+        };
+        let span = tracing::Span::current();
+        span.record("status", status);
+        span.record("latency_ms", latency_ms);
+        let body = redact_body(&log_text(&response.get("body".into())), &self.log_secrets());
+        verbose_event!(self.get("verbose".into()).is_truthy(), status, latency_ms, %body, "response");
+    }
+
+    async fn throttle(&self, cost: Value) -> Value {
+        // copies of an exchange share the bucket of their id unless they are given a throttleKey of their own
+        let key = self.get("throttleKey".into()).or_default(self.get("id".into()));
+        let rate_limit = match self.get("rateLimit".into()) {
+            Value::Json(serde_json::Value::Number(n)) => n.as_f64().unwrap(),
+            _ => 0.0,
+        };
+        let cost = match cost {
+            Value::Json(serde_json::Value::Number(n)) => n.as_f64().unwrap(),
+            _ => 1.0,
+        };
+        let started = Instant::now();
+        crate::throttle::throttler(&key.property_key(), rate_limit).throttle(cost).await;
+        tracing::Span::current().record("cost", cost);
+        tracing::trace!(cost, waited_ms = started.elapsed().as_millis() as u64, "throttle");
+        Value::Undefined
+    }
+    fn safe_timestamp(&self, dictionary: Value, key: Value, default_value: Value) -> Value {
+        // seconds to milliseconds, like parseInt (parseFloat (x) * 1000)
+        match seconds_to_milliseconds(&self.safe_value(dictionary, key, Value::Undefined)) {
+            Some(ms) => ms.into(),
+            None => match default_value {
+                Value::Json(j) => Value::Json(j),
+                _ => Value::Undefined
+            }
+        }
+    }
+    fn safe_timestamp_2(&self, dictionary: Value, key1: Value, key2: Value, default_value: Value) -> Value {
+        let rv = self.safe_timestamp(dictionary.clone(), key1, Value::Undefined);
+        if rv.is_undefined() {
+            self.safe_timestamp(dictionary, key2, default_value)
+        } else {
+            rv
+        }
+    }
     fn check_address(&self, address: Value) -> Value { todo!() }
     fn safe_integer_2(&self, x: Value, key1: Value, key2: Value, default_value: Value) -> Value {
         let rv = self.safe_integer(x.clone(), key1, Value::Undefined);
@@ -1746,17 +2492,61 @@ pub trait Exchange: ValueTrait + Sync + Send {
             rv
         }
     }
-    fn parse_timeframe(&self, timeframe: Value) -> Value { todo!() }
-    fn sum(&self, a: Value, b: Value) -> Value { todo!() }
+    fn safe_integer_product(&self, x: Value, key: Value, factor: Value, default_value: Value) -> Value {
+        // scaled with Precise so that large Ep/Er/Ev values stay exact
+        let value = self.safe_string(x, key, Value::Undefined);
+        let factor = match &factor {
+            Value::Json(f) if f.is_number() => f.to_string(),
+            _ => return default_value
+        };
+        match value {
+            Value::Json(serde_json::Value::String(v)) if is_decimal(&v) => {
+                Precise::new(v.into()).unwrap_precise().mul(&Precise::new(factor.into())).unwrap_precise().to_integer()
+            }
+            _ => default_value
+        }
+    }
+
+    fn safe_integer_product_2(&self, x: Value, key1: Value, key2: Value, factor: Value, default_value: Value) -> Value {
+        let rv = self.safe_integer_product(x.clone(), key1, factor.clone(), Value::Undefined);
+        if rv.is_undefined() {
+            self.safe_integer_product(x, key2, factor, default_value)
+        } else {
+            rv
+        }
+    }
+
+
+    fn parse_timeframe(&self, timeframe: Value) -> Value {
+        match timeframe_seconds(timeframe.unwrap_str()) {
+            Some(seconds) => seconds.into(),
+            None => panic!("NotSupported::new(timeframe {} is not supported)", timeframe.unwrap_str())
+        }
+    }
+
+    fn sum(&self, a: Value, b: Value) -> Value {
+        match (a.is_number(), b.is_number()) {
+            (true, true) => a + b,
+            (true, false) => a,
+            (false, true) => b,
+            (false, false) => Value::Undefined,
+        }
+    }
 
     async fn fetch_deposit_addresses(&self, codes: Value, params: Value) -> Value { todo!() }
     async fn fetch_borrow_rates(&self, params: Value) -> Value { todo!() }
     async fn fetch_order_book(&self, symbol: Value, limit: Value, params: Value) -> Value { todo!() }
     async fn fetch_trading_limits(&self, symbols: Value, params: Value) -> Value { todo!() }
 
-    async fn fetch(&self, url: Value, method: Value, headers: Value, mut body: Value) -> Value {
+    async fn fetch(&self, url: Value, method: Value, headers: Value, body: Value) -> Value {
+        let response = self.fetch_response(url, method, headers, body).await;
+        Value::Json(serde_json::Value::from_str(response.get("body".into()).unwrap_str()).unwrap())
+    }
+
+    /// Sends the request and returns the raw http response as
+    /// `{ "status", "statusText", "headers", "body" }` without interpreting the body
+    async fn fetch_response(&self, url: Value, method: Value, headers: Value, mut body: Value) -> Value {
         let method = method.or_default("GET".into());
-        let verbose: bool = self.get("verbose".into()).into();
         let client = reqwest::Client::new();
         let mut req = client.request(
             reqwest::Method::from_str(method.unwrap_str()).unwrap(),
@@ -1779,9 +2569,75 @@ pub trait Exchange: ValueTrait + Sync + Send {
             req = req.body(body.unwrap_str().to_owned());
         }
 
-        let response = req.send().await.unwrap();
-        let text = response.text().await.unwrap();
-        Value::Json(serde_json::Value::from_str(text.as_str()).unwrap())
+        let response = match req.send().await {
+            Ok(response) => response,
+            Err(error) if error.is_timeout() => panic!("RequestTimeout::new({} {} {})", method.unwrap_str(), url.unwrap_str(), error),
+            Err(error) => panic!("NetworkError::new({} {} {})", method.unwrap_str(), url.unwrap_str(), error),
+        };
+        let status = response.status();
+        let mut response_headers = serde_json::Map::new();
+        for (k, v) in response.headers().iter() {
+            if let Ok(v) = v.to_str() {
+                response_headers.insert(k.as_str().to_owned(), v.into());
+            }
+        }
+        let text = match response.text().await {
+            Ok(text) => text,
+            Err(error) => panic!("NetworkError::new({} {} {})", method.unwrap_str(), url.unwrap_str(), error),
+        };
+        Value::Json(json!({
+            "status": status.as_u16(),
+            "statusText": status.canonical_reason().unwrap_or(""),
+            "headers": response_headers,
+            "body": text,
+        }))
+    }
+
+    /// Parses a raw response from `fetch_response`, giving `handle_errors` and
+    /// `handle_http_status_code` a chance to throw before the body is returned,
+    /// `handle_errors` is the exchange's own override as `Exchange` cannot dispatch to it
+    fn handle_rest_response(&mut self, mut response: Value, mut url: Value, mut method: Value, mut request_headers: Value, mut request_body: Value, handle_errors: HandleErrors<Self>) -> Value {
+        let mut status: Value = response.get("status".into());
+        let mut status_text: Value = response.get("statusText".into());
+        let mut response_headers: Value = response.get("headers".into());
+        let mut body: Value = response.get("body".into());
+        let mut json: Value = self.parse_json(body.clone());
+        handle_errors(self, status.clone(), status_text.clone(), url.clone(), method.clone(), response_headers, body.clone(), json.clone(), request_headers, request_body);
+        self.handle_http_status_code(status, status_text, url, method, body.clone());
+        if json.is_undefined() { body } else { json }
+    }
+
+    fn parse_json(&self, json_string: Value) -> Value {
+        match json_string {
+            Value::Json(serde_json::Value::String(s)) => match serde_json::Value::from_str(&s) {
+                Ok(v) => Value::Json(v),
+                Err(_) => Value::Undefined
+            },
+            _ => Value::Undefined
+        }
+    }
+
+    fn handle_http_status_code(&mut self, http_code: Value, reason: Value, url: Value, method: Value, body: Value) {
+        let code = match http_code.clone() {
+            Value::Json(serde_json::Value::Number(n)) => n.as_u64().unwrap_or(0),
+            _ => return
+        };
+        let error_class: Value = self.safe_string(self.get("httpExceptions".into()), code.to_string().into(), Value::Undefined);
+        let error_class: &str = match error_class {
+            Value::Json(serde_json::Value::String(ref c)) => c,
+            // the generated descriptions lose the base httpExceptions table, these are its defaults
+            _ => match code {
+                422 => "ExchangeError",
+                418 => "DDoSProtection",
+                429 => "RateLimitExceeded",
+                400 | 403 | 404 | 405 | 409 | 410 | 451 | 500 | 501 | 502 | 503 | 520 | 521 | 522 | 525 | 526 | 530 => "ExchangeNotAvailable",
+                408 | 504 => "RequestTimeout",
+                401 | 511 => "AuthenticationError",
+                _ => return
+            }
+        };
+        let id = self.get("id".into());
+        panic!("{}::new({} {} {} {} {})", error_class, id.unwrap_str(), method.unwrap_str(), url.unwrap_str(), code, reason.or_default("".into()).unwrap_str());
     }
 
     fn set_sandbox_mode(&mut self, enabled: bool) {
@@ -1952,12 +2808,12 @@ pub trait Exchange: ValueTrait + Sync + Send {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -2063,9 +2919,9 @@ pub trait Exchange: ValueTrait + Sync + Send {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), self.safe_number(reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], self.safe_number(reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), self.safe_number(reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], self.safe_number(reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -2304,9 +3160,9 @@ pub trait Exchange: ValueTrait + Sync + Send {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), self.safe_number(reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], self.safe_number(reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), self.safe_number(reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], self.safe_number(reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -2401,14 +3257,14 @@ pub trait Exchange: ValueTrait + Sync + Send {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -2482,22 +3338,26 @@ pub trait Exchange: ValueTrait + Sync + Send {
         }))).unwrap()));
     }
 
-    async fn fetch_ohlcv(&mut self, mut symbol: Value, mut timeframe: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
-        timeframe = timeframe.or_default(Value::from("1m"));
-        params = params.or_default(Value::new_object());
+    /// Exchanges with a candles endpoint override it, this default cannot reach the `fetch_trades` override of the
+    /// others so `fetch_ohlcv` is only emulated from the trades through `UnifiedExchange::fetch_ohlcv`
+    async fn fetch_ohlcv(&mut self, _symbol: Value, _timeframe: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
+    }
+
+    /// Candles built from `fetch_trades`, the exchange's own override which also loads the markets, how
+    /// `UnifiedExchange::fetch_ohlcv` is emulated on exchanges without a candles endpoint
+    async fn fetch_ohlcv_from_trades(&mut self, symbol: Value, timeframe: Value, since: Value, limit: Value, params: Value, fetch_trades: FetchTrades<Self>) -> Value {
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
-        };
-        self.load_markets(Value::Undefined, Value::Undefined).await;
-        let mut trades: Value = self.fetch_trades(symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
-        let mut ohlcvc: Value = self.build_ohlcvc(trades.clone(), timeframe.clone(), since.clone(), limit.clone());
-        let mut result: Value = Value::new_array();
-        let mut i: usize = 0;
-        while i < ohlcvc.len() {
-            result.push(Value::Json(serde_json::Value::Array(vec![self.safe_integer(ohlcvc.get(i.into()), Value::from(0), Value::Undefined).into(), self.safe_number(ohlcvc.get(i.into()), Value::from(1), Value::Undefined).into(), self.safe_number(ohlcvc.get(i.into()), Value::from(2), Value::Undefined).into(), self.safe_number(ohlcvc.get(i.into()), Value::from(3), Value::Undefined).into(), self.safe_number(ohlcvc.get(i.into()), Value::from(4), Value::Undefined).into(), self.safe_number(ohlcvc.get(i.into()), Value::from(5), Value::Undefined).into()])));
-            i += 1;
-        };
-        return result.clone();
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
+        };
+        let trades: Value = fetch_trades(self, symbol, since.clone(), limit.clone(), params.or_default(Value::new_object())).await;
+        match build_ohlcvc(trades, timeframe, since, limit) {
+            // without the trade counts
+            Value::Json(serde_json::Value::Array(candles)) => Value::Json(candles.into_iter()
+                .map(|candle| serde_json::Value::Array(candle.as_array().unwrap()[..6].to_vec()))
+                .collect()),
+            candles => candles
+        }
     }
 
     fn convert_trading_view_to_ohlcv(&self, mut ohlcvs: Value, mut timestamp: Value, mut open: Value, mut high: Value, mut low: Value, mut close: Value, mut volume: Value, mut ms: Value) -> Value {
@@ -2661,7 +3521,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -2729,10 +3589,10 @@ pub trait Exchange: ValueTrait + Sync + Send {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -2880,19 +3740,71 @@ pub trait Exchange: ValueTrait + Sync + Send {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        let hooks = RestHooks {
+            load_time_difference: |exchange, params| Self::load_time_difference(exchange, params),
+            calculate_rate_limiter_cost: Self::calculate_rate_limiter_cost,
+            sign: |exchange, path, api, method, params, headers, body| Self::sign(exchange, path, api, method, params, headers, body),
+            handle_errors: Self::handle_errors,
+        };
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, hooks).await
+    }
+
+    /// Throttles, signs and sends a REST request through the exchange's `hooks`, refreshing a stale
+    /// time difference and retrying transient failures as configured by `options.retry`
+    async fn fetch2_with_retry(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value, hooks: RestHooks<Self>) -> Value {
         api = api.or_default(Value::from("public"));
         method = method.or_default(Value::from("GET"));
         params = params.or_default(Value::new_object());
         config = config.or_default(Value::new_object());
         context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = self.calculate_rate_limiter_cost(api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = self.sign(path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+        let span = self.request_span(&api, &method, &path);
+        async move {
+            let policy = RetryPolicy::from_options(&self.get("options".into()));
+            let idempotent = is_idempotent(&method, &params, &context);
+            let mut adjusted: bool = false;
+            let mut retries: u32 = 0;
+            loop {
+                self.refresh_time_difference(false, hooks.load_time_difference).await;
+                if self.get("enableRateLimit".into()).is_truthy() {
+                    let mut cost: Value = (hooks.calculate_rate_limiter_cost)(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
+                    self.throttle(cost.clone()).await;
+                };
+                self.set("last_rest_request_timestamp".into(), self.milliseconds());
+                let mut request: Value = tracing::debug_span!("sign").in_scope(|| (hooks.sign)(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone()));
+                self.trace_request(&request);
+                let started = Instant::now();
+                let response = futures::FutureExt::catch_unwind(AssertUnwindSafe(self.fetch_response(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))))).instrument(tracing::debug_span!("fetch")).await;
+                // the raw response is kept to tell whether the error is worth retrying, it is undefined when the connection failed
+                let (error, response): (Box<dyn std::any::Any + Send>, Value) = match response {
+                    Ok(response) => {
+                        self.trace_response(&response, started);
+                        match std::panic::catch_unwind(AssertUnwindSafe(|| self.handle_rest_response(response.clone(), request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body")), hooks.handle_errors))) {
+                            Ok(response) => return response,
+                            Err(error) => (error, response),
+                        }
+                    }
+                    Err(error) => (error, Value::Undefined),
+                };
+                let kind = Error::from_panic_ref(&*error);
+                // signed with a stale clock, sent once more after the time difference is refreshed
+                if !adjusted && kind.is("InvalidNonce") && self.refresh_time_difference(true, hooks.load_time_difference).await {
+                    adjusted = true;
+                    retries += 1;
+                    tracing::Span::current().record("retries", retries);
+                    continue;
+                };
+                match policy.delay(retries, idempotent, &kind, &response) {
+                    Some(delay) => {
+                        retries += 1;
+                        tracing::Span::current().record("retries", retries);
+                        tracing::debug!(error = %kind, delay_ms = delay.as_millis() as u64, "retry");
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => std::panic::resume_unwind(error),
+                }
+            }
+        }.instrument(span).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -2924,7 +3836,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         self.load_markets(Value::Undefined, Value::Undefined).await;
         let mut trades: Value = self.fetch_trades(symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -2960,13 +3872,13 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3066,13 +3978,15 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        // the requiredCredentials of describe() override the defaults of init, e.g. okx and bitget require a password
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -3086,14 +4000,14 @@ pub trait Exchange: ValueTrait + Sync + Send {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
 
     async fn fetch_balance(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBalance() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBalance() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3133,7 +4047,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return self.fetch_transaction_fee(code.clone(), params.clone()).await;
     }
@@ -3142,7 +4056,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return self.fetch_transaction_fees(codes.clone(), params.clone()).await;
     }
@@ -3150,14 +4064,14 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return self.fetch_transaction_fees(Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3166,7 +4080,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -3175,12 +4089,12 @@ pub trait Exchange: ValueTrait + Sync + Send {
         params = params.or_default(Value::new_object());
         self.load_markets(Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = self.fetch_borrow_rates(params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -3233,14 +4147,14 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = self.find_broadly_matched_key(broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -3272,25 +4186,25 @@ pub trait Exchange: ValueTrait + Sync + Send {
             let mut tickers: Value = self.fetch_tickers(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), params.clone()).await;
             let mut ticker: Value = self.safe_value(tickers.clone(), symbol.clone(), Value::Undefined);
             if ticker.clone().is_nullish() {
-                panic!(r###"NullResponse::new(self.get("id".into()) + Value::from(" fetchTickers() could not find a ticker for ") + symbol.clone())"###);
+                panic!("NullResponse::new({} fetchTickers() could not find a ticker for {})", self.get("id".into()).unwrap_str(), js_string(&symbol));
             } else {
                 return ticker.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTicker() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTicker() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
 
     async fn fetch_tickers(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTickers() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTickers() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrder() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3307,13 +4221,13 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createOrder() is not supported yet"))"###);
+        panic!("NotSupported::new({} createOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" cancelOrder() is not supported yet"))"###);
+        panic!("NotSupported::new({} cancelOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3324,43 +4238,43 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_open_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOpenOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOpenOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_closed_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchClosedOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchClosedOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_my_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMyTrades() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchMyTrades() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_deposits(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchDeposits() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchDeposits() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_withdrawals(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchWithdrawals() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchWithdrawals() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3370,12 +4284,12 @@ pub trait Exchange: ValueTrait + Sync + Send {
             let mut deposit_addresses: Value = self.fetch_deposit_addresses(Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
             let mut deposit_address: Value = self.safe_value(deposit_addresses.clone(), code.clone(), Value::Undefined);
             if deposit_address.clone().is_nullish() {
-                panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() could not find a deposit address for ") + code.clone() + Value::from(", make sure you have created a corresponding deposit address in your wallet on the exchange website"))"###);
+                panic!("InvalidAddress::new({} fetchDepositAddress() could not find a deposit address for {}, make sure you have created a corresponding deposit address in your wallet on the exchange website)", self.get("id".into()).unwrap_str(), js_string(&code));
             } else {
                 return deposit_address.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchDepositAddress() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchDepositAddress() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3397,7 +4311,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -3406,16 +4320,14 @@ pub trait Exchange: ValueTrait + Sync + Send {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
     fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
+        let id = self.get("id".into());
+        if self.get("markets".into()).is_nullish() || self.get("markets_by_id".into()).is_nullish() {
+            panic!("ExchangeError::new({} markets not loaded)", id.unwrap_str());
         };
         if symbol.typeof_() == Value::from("string") {
             if self.get("markets".into()).contains_key(symbol.clone()) {
@@ -3423,9 +4335,13 @@ pub trait Exchange: ValueTrait + Sync + Send {
             } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
                 return self.get("markets_by_id".into()).get(symbol.clone());
             };
+            // neither a listed symbol nor an id, tell what is wrong with it as a unified symbol
+            if let Err(e) = symbol.unwrap_str().parse::<Symbol>() {
+                panic!("BadSymbol::new({} does not have market symbol {}, {})", id.unwrap_str(), symbol.unwrap_str(), e.message);
+            };
+            panic!("BadSymbol::new({} does not have market symbol {})", id.unwrap_str(), symbol.unwrap_str());
         };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
-        Value::Undefined
+        panic!("BadSymbol::new({} does not have market symbol {})", id.unwrap_str(), Into::<serde_json::Value>::into(symbol));
     }
 
     fn handle_withdraw_tag_and_params(&mut self, mut tag: Value, mut params: Value) -> Value {
@@ -3528,9 +4444,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = self.fetch_time(params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -3544,12 +4458,12 @@ pub trait Exchange: ValueTrait + Sync + Send {
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
             let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = self.fetch_leverage_tiers(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3557,7 +4471,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -3568,7 +4482,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -3579,10 +4493,10 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -3593,7 +4507,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -3604,7 +4518,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -3716,11 +4630,18 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
         market = self.safe_market(market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
+        let symbol: Value = market.get(Value::from("symbol"));
+        // an unknown id split on the delimiter into an empty base or quote, e.g. "BTC_", is kept as it is
+        if let Value::Json(serde_json::Value::String(s)) = &symbol {
+            if s.contains('/') && s.parse::<Symbol>().is_err() {
+                return market_id;
+            };
+        };
+        return symbol;
     }
 
     fn parse_funding_rate(&self, mut contract: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseFundingRate() is not supported yet"))"###);
+        panic!("NotSupported::new({} parseFundingRate() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3755,9 +4676,9 @@ pub trait Exchange: ValueTrait + Sync + Send {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -3769,20 +4690,20 @@ pub trait Exchange: ValueTrait + Sync + Send {
 
     async fn fetch_trading_fees(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTradingFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTradingFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTradingFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return self.fetch_trading_fees(params.clone()).await;
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -3806,17 +4727,17 @@ pub trait Exchange: ValueTrait + Sync + Send {
             self.load_markets(Value::Undefined, Value::Undefined).await;
             let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchFundingRate() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut rates: Value = self.fetch_funding_rates(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), params.clone()).await;
             let mut rate: Value = self.safe_value(rates.clone(), symbol.clone(), Value::Undefined);
             if rate.clone().is_nullish() {
-                panic!(r###"NullResponse::new(self.get("id".into()) + Value::from(" fetchFundingRate () returned no data for ") + symbol.clone())"###);
+                panic!("NullResponse::new({} fetchFundingRate () returned no data for {})", self.get("id".into()).unwrap_str(), js_string(&symbol));
             } else {
                 return rate.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingRate () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchFundingRate () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3841,7 +4762,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
             }))).unwrap());
             return self.fetch_ohlcv(symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3866,7 +4787,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
             }))).unwrap());
             return self.fetch_ohlcv(symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3891,7 +4812,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
             }))).unwrap());
             return self.fetch_ohlcv(symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -3907,7 +4828,7 @@ pub trait Exchange: ValueTrait + Sync + Send {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -3955,6 +4876,80 @@ pub trait Exchange: ValueTrait + Sync + Send {
         return Value::Json(serde_json::Value::Array(vec![margin_mode.clone().into(), params.clone().into()]));
     }
 
+    /// The markets loader shared with the other copies of this exchange, see `crate::markets`
+    fn markets_loader(&self) -> MarketsLoader {
+        match self.get("marketsLoader".into()) {
+            Value::Json(serde_json::Value::String(key)) => crate::markets::loader(Some(&key)),
+            _ => crate::markets::loader(None),
+        }
+    }
+
+    /// Takes the markets another copy loaded in the meantime and tells whether they still have to be fetched, because
+    /// a reload was requested after the last load or because they are older than `marketsReloadInterval` milliseconds
+    fn markets_reload_needed(&mut self, cache: &mut MarketsCache, reload: Value, requested_at: Value) -> Value {
+        let millis = |v: Value| match v {
+            Value::Json(serde_json::Value::Number(n)) => n.as_u64(),
+            _ => None,
+        };
+        let mut loaded_at = millis(self.get("marketsLoadedAt".into()));
+        if cache.loaded_at.is_none() && !reload.is_truthy() && !self.get("markets".into()).is_truthy() {
+            if let Some(path) = self.markets_cache_file() {
+                // a day unless marketsCacheMaxAge says otherwise
+                let max_age = millis(self.get("marketsCacheMaxAge".into())).unwrap_or(86400000);
+                let now = millis(self.milliseconds()).unwrap();
+                if let Some((at, state)) = crate::markets::read_cache_file(&path, max_age, now) {
+                    cache.loaded_at = Some(at);
+                    cache.state = Arc::new(state);
+                }
+            }
+        }
+        if cache.loaded_at > loaded_at {
+            for (key, value) in cache.state.iter() {
+                self.set(key.as_str().into(), Value::Json(value.clone()));
+            }
+            self.set("marketsLoadedAt".into(), cache.loaded_at.unwrap().into());
+            loaded_at = cache.loaded_at;
+        }
+        if reload.is_truthy() {
+            return (loaded_at <= millis(requested_at)).into();
+        }
+        if !self.get("markets".into()).is_truthy() {
+            return true.into();
+        }
+        match (millis(self.get("marketsReloadInterval".into())), loaded_at, millis(self.milliseconds())) {
+            (Some(interval), Some(loaded_at), Some(now)) => (now.saturating_sub(loaded_at) >= interval).into(),
+            _ => false.into(),
+        }
+    }
+
+    /// The file `load_markets` reads the markets from and saves them to when `marketsCacheDir` is set
+    fn markets_cache_file(&self) -> Option<std::path::PathBuf> {
+        match self.get("marketsCacheDir".into()) {
+            Value::Json(serde_json::Value::String(dir)) => {
+                let sandbox = self.get("urls".into()).get("apiBackup".into()).is_truthy();
+                Some(crate::markets::cache_file(&dir, self.get("id".into()).unwrap_str(), sandbox))
+            }
+            _ => None,
+        }
+    }
+
+    /// Records the markets just loaded by `set_markets` for the other copies of this exchange and the next processes
+    fn cache_markets(&mut self, cache: &mut MarketsCache) {
+        let now = self.milliseconds();
+        self.set("marketsLoadedAt".into(), now.clone());
+        cache.loaded_at = now.unwrap_json().as_u64();
+        cache.state = Arc::new(MARKETS_KEYS.iter()
+            .filter_map(|key| match self.get((*key).into()) {
+                Value::Json(value) => Some((key.to_string(), value)),
+                _ => None,
+            })
+            .collect());
+        if let Some(path) = self.markets_cache_file() {
+            // the cache only saves requests, the markets are loaded either way
+            let _ = crate::markets::write_cache_file(&path, cache.loaded_at.unwrap(), &cache.state);
+        }
+    }
+
     async fn load_markets_helper(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
@@ -3976,16 +4971,16 @@ pub trait Exchange: ValueTrait + Sync + Send {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = self.load_markets_helper(reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = self.load_markets_helper(reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
 }
\ No newline at end of file
@@ -11,10 +11,11 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -100,6 +101,18 @@ pub trait Gate : Exchange {
             "urls": {
                 "logo": "https://user-images.githubusercontent.com/1294454/31784029-0313c702-b509-11e7-9ccc-bc0da6a0e435.jpg",
                 "api": {
+                    "ws": {
+                        "spot": "wss://api.gateio.ws/ws/v4/",
+                        "margin": "wss://api.gateio.ws/ws/v4/",
+                        "swap": {
+                            "usdt": "wss://fx-ws.gateio.ws/v4/ws/usdt",
+                            "btc": "wss://fx-ws.gateio.ws/v4/ws/btc"
+                        },
+                        "future": {
+                            "usdt": "wss://fx-ws.gateio.ws/v4/ws/delivery/usdt",
+                            "btc": "wss://fx-ws.gateio.ws/v4/ws/delivery/btc"
+                        }
+                    },
                     "public": {
                         "wallet": "https://api.gateio.ws/api/v4",
                         "futures": "https://api.gateio.ws/api/v4",
@@ -121,6 +134,16 @@ pub trait Gate : Exchange {
                 "www": "https://gate.io/",
                 "doc": "https://www.gate.io/docs/apiv4/en/index.html",
                 "test": {
+                    "ws": {
+                        "swap": {
+                            "usdt": "wss://fx-ws-testnet.gateio.ws/v4/ws/usdt",
+                            "btc": "wss://fx-ws-testnet.gateio.ws/v4/ws/btc"
+                        },
+                        "future": {
+                            "usdt": "wss://fx-ws-testnet.gateio.ws/v4/ws/delivery/usdt",
+                            "btc": "wss://fx-ws-testnet.gateio.ws/v4/ws/delivery/btc"
+                        }
+                    },
                     "public": {
                         "futures": "https://fx-api-testnet.gateio.ws/api/v4",
                         "delivery": "https://fx-api-testnet.gateio.ws/api/v4"
@@ -822,7 +845,7 @@ pub trait Gate : Exchange {
         };
         let mut result_length: Value = result.len().into();
         if result_length.clone() == Value::from(0) {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not support '") + r#type.clone() + Value::from("' type, set exchange.options['defaultType'] to ") + Value::from("'spot', 'margin', 'swap', 'future' or 'option'"))"###);
+            panic!("ExchangeError::new({} does not support '{}' type, set exchange.options['defaultType'] to 'spot', 'margin', 'swap', 'future' or 'option')", self.get("id".into()).unwrap_str(), js_string(&r#type));
         };
         // eslint-disable-line quotes
         return result.clone();
@@ -1344,7 +1367,7 @@ pub trait Gate : Exchange {
         let mut request: Value = Value::new_object();
         if !stop.is_truthy() {
             if market.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" spotOrderPrepareRequest() requires a market argument for non-stop orders"))"###);
+                panic!("ArgumentsRequired::new({} spotOrderPrepareRequest() requires a market argument for non-stop orders)", self.get("id".into()).unwrap_str());
             };
             request.set("account".into(), margin_mode.clone());
             request.set("currency_pair".into(), market.get(Value::from("id")));
@@ -1408,7 +1431,7 @@ pub trait Gate : Exchange {
                 margin_mode = Value::from("normal");
             };
             if margin_mode.clone() == Value::from("cross_margin") {
-                panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" getMarginMode() does not support stop orders for cross margin"))"###);
+                panic!("BadRequest::new({} getMarginMode() does not support stop orders for cross margin)", self.get("id".into()).unwrap_str());
             };
         };
         return Value::Json(serde_json::Value::Array(vec![margin_mode.clone().into(), params.clone().into()]));
@@ -1493,9 +1516,9 @@ pub trait Gate : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if !market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} fetchFundingRate() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let (mut request, mut query) = shift_2(Gate::prepare_request(self, market.clone(), Value::Undefined, params.clone()));
         let mut response: Value = Gate::dispatch(self, "publicFuturesGetSettleContractsContract".into(), extend_2(request.clone(), query.clone()), Value::Undefined).await;
@@ -1652,7 +1675,7 @@ pub trait Gate : Exchange {
         //    }
         //
         let mut market_id: Value = self.safe_string(contract.clone(), Value::from("name"), Value::Undefined);
-        let mut symbol: Value = Gate::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut mark_price: Value = Gate::safe_number(self, contract.clone(), Value::from("mark_price"), Value::Undefined);
         let mut index_price: Value = Gate::safe_number(self, contract.clone(), Value::from("index_price"), Value::Undefined);
         let mut interest_rate: Value = Gate::safe_number(self, contract.clone(), Value::from("interest_rate"), Value::Undefined);
@@ -1788,7 +1811,7 @@ pub trait Gate : Exchange {
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "currency_pair": market.get(Value::from("id"))
         }))).unwrap());
@@ -1843,7 +1866,7 @@ pub trait Gate : Exchange {
         let mut i: usize = 0;
         while i < self.get("symbols".into()).len() {
             let mut symbol: Value = self.get("symbols".into()).get(i.into());
-            let mut market: Value = Gate::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             result.set(symbol.clone(), Gate::parse_trading_fee(self, response.clone(), market.clone()));
             i += 1;
         };
@@ -1921,7 +1944,7 @@ pub trait Gate : Exchange {
             let mut i: usize = 0;
             while i < keys.len() {
                 let mut key: Value = keys.get(i.into());
-                withdraw_fees.get(code.clone()).set(key.clone(), self.parse_number(withdraw_fix.get(key.clone()), Value::Undefined));
+                withdraw_fees.set_path(&[code.clone(), key.clone()], self.parse_number(withdraw_fix.get(key.clone()), Value::Undefined));
                 i += 1;
             };
             i += 1;
@@ -1949,7 +1972,7 @@ pub trait Gate : Exchange {
         // let defaultType = 'future';
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Gate::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
         };
         let (mut r#type, mut query) = shift_2(Gate::handle_market_type_and_params(self, Value::from("fetchFundingHistory"), market.clone(), params.clone()));
@@ -2031,7 +2054,7 @@ pub trait Gate : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         //
         //     const request = {
         //         'currency_pair': market['id'],
@@ -2140,7 +2163,7 @@ pub trait Gate : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut request, mut query) = shift_2(Gate::prepare_request(self, market.clone(), Value::Undefined, params.clone()));
         let mut method: Value = Gate::get_supported_mapping(self, market.get(Value::from("type")), Value::Json(normalize(&Value::Json(json!({
             "spot": "publicSpotGetTickers",
@@ -2191,7 +2214,7 @@ pub trait Gate : Exchange {
         //     }
         //
         let mut market_id: Value = self.safe_string_2(ticker.clone(), Value::from("currency_pair"), Value::from("contract"), Value::Undefined);
-        let mut symbol: Value = Gate::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut last: Value = self.safe_string(ticker.clone(), Value::from("last"), Value::Undefined);
         let mut ask: Value = self.safe_string(ticker.clone(), Value::from("lowest_ask"), Value::Undefined);
         let mut bid: Value = self.safe_string(ticker.clone(), Value::from("highest_bid"), Value::Undefined);
@@ -2278,7 +2301,7 @@ pub trait Gate : Exchange {
         let (mut request, mut request_params) = shift_2(Gate::prepare_request(self, Value::Undefined, r#type.clone(), query.clone()));
         let (mut margin_mode, mut request_query) = shift_2(Gate::get_margin_mode(self, false.into(), request_params.clone()));
         if symbol.clone().is_nonnullish() {
-            let mut market: Value = Gate::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             request.set("currency_pair".into(), market.get(Value::from("id")));
         };
         let mut method: Value = Gate::get_supported_mapping(self, r#type.clone(), Value::Json(normalize(&Value::Json(json!({
@@ -2433,7 +2456,7 @@ pub trait Gate : Exchange {
             let mut entry: Value = data.get(i.into());
             if margin.is_truthy() && !cross_margin.is_truthy() {
                 let mut market_id: Value = self.safe_string(entry.clone(), Value::from("currency_pair"), Value::Undefined);
-                let mut symbol: Value = Gate::safe_symbol(self, market_id.clone(), Value::Undefined, Value::from("_"));
+                let mut symbol: Value = self.safe_symbol(market_id.clone(), Value::Undefined, Value::from("_"));
                 let mut base: Value = self.safe_value(entry.clone(), Value::from("base"), Value::new_object());
                 let mut quote: Value = self.safe_value(entry.clone(), Value::from("quote"), Value::new_object());
                 let mut base_code: Value = Gate::safe_currency_code(self, self.safe_string(base.clone(), Value::from("currency"), Value::Undefined), Value::Undefined);
@@ -2468,7 +2491,7 @@ pub trait Gate : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut price: Value = self.safe_string(params.clone(), Value::from("price"), Value::Undefined);
         let mut request: Value = Value::new_object();
         (request, params) = shift_2(Gate::prepare_request(self, market.clone(), Value::Undefined, params.clone()));
@@ -2530,12 +2553,12 @@ pub trait Gate : Exchange {
     async fn fetch_funding_rate_history(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingRateHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if !market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} fetchFundingRateHistory() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let (mut request, mut query) = shift_2(Gate::prepare_request(self, market.clone(), Value::Undefined, params.clone()));
         if limit.clone().is_nonnullish() {
@@ -2618,7 +2641,7 @@ pub trait Gate : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         //
         // spot
         //
@@ -2701,7 +2724,7 @@ pub trait Gate : Exchange {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrderTrades requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrderTrades requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         //
         //      [
@@ -2753,7 +2776,7 @@ pub trait Gate : Exchange {
         let mut r#type: Value = Value::Undefined;
         let mut margin_mode: Value = Value::Undefined;
         let mut request: Value = Value::new_object();
-        let mut market: Value = if symbol.clone().is_nonnullish() { Gate::market(self, symbol.clone()) } else { Value::Undefined };
+        let mut market: Value = if symbol.clone().is_nonnullish() { self.market(symbol.clone()) } else { Value::Undefined };
         let mut until: Value = self.safe_integer_2(params.clone(), Value::from("until"), Value::from("till"), Value::Undefined);
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("until").into(), Value::from("till").into()])));
         (r#type, params) = shift_2(Gate::handle_market_type_and_params(self, Value::from("fetchMyTrades"), market.clone(), params.clone()));
@@ -3182,7 +3205,78 @@ pub trait Gate : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
+        let (mut method, mut request) = shift_2(Gate::create_order_request(self, symbol.clone(), r#type.clone(), side.clone(), amount.clone(), price.clone(), params.clone()));
+        let mut response: Value = Gate::dispatch(self, method, request, Value::Undefined).await;
+        //
+        // spot
+        //
+        //     {
+        //         "id": "95282841887",
+        //         "text": "apiv4",
+        //         "create_time": "1637383156",
+        //         "update_time": "1637383156",
+        //         "create_time_ms": 1637383156017,
+        //         "update_time_ms": 1637383156017,
+        //         "status": "open",
+        //         "currency_pair": "ETH_USDT",
+        //         "type": "limit",
+        //         "account": "spot",
+        //         "side": "buy",
+        //         "amount": "0.01",
+        //         "price": "3500",
+        //         "time_in_force": "gtc",
+        //         "iceberg": "0",
+        //         "left": "0.01",
+        //         "fill_price": "0",
+        //         "filled_total": "0",
+        //         "fee": "0",
+        //         "fee_currency": "ETH",
+        //         "point_fee": "0",
+        //         "gt_fee": "0",
+        //         "gt_discount": false,
+        //         "rebated_fee": "0",
+        //         "rebated_fee_currency": "USDT"
+        //     }
+        //
+        // spot conditional
+        //
+        //     {"id": 5891843}
+        //
+        // future and perpetual swaps
+        //
+        //     {
+        //         "id": 95938572327,
+        //         "contract": "ETH_USDT",
+        //         "mkfr": "0",
+        //         "tkfr": "0.0005",
+        //         "tif": "gtc",
+        //         "is_reduce_only": false,
+        //         "create_time": 1637384600.08,
+        //         "price": "3000",
+        //         "size": 1,
+        //         "refr": "0",
+        //         "left": 1,
+        //         "text": "api",
+        //         "fill_price": "0",
+        //         "user": 2436035,
+        //         "status": "open",
+        //         "is_liq": false,
+        //         "refu": 0,
+        //         "is_close": false,
+        //         "iceberg": 0
+        //     }
+        //
+        // futures and perpetual swaps conditionals
+        //
+        //     {"id": 7615567}
+        //
+        return Gate::parse_order(self, response.clone(), market.clone());
+    }
+
+    /// Returns the method and the parameters of the request of `create_order`, `create_order_ws` sends them over the websocket api
+    fn create_order_request(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
+        let mut market: Value = self.market(symbol.clone());
         let mut contract: Value = market.get(Value::from("contract"));
         let mut trigger: Value = self.safe_value(params.clone(), Value::from("trigger"), Value::Undefined);
         let mut trigger_price: Value = self.safe_value_2(params.clone(), Value::from("triggerPrice"), Value::from("stopPrice"), Value::Undefined);
@@ -3192,7 +3286,7 @@ pub trait Gate : Exchange {
         let mut is_take_profit_order: Value = (take_profit_price.clone().is_nonnullish()).into();
         let mut is_stop_order: Value = (is_stop_loss_order.is_truthy() || is_take_profit_order.is_truthy()).into();
         if is_stop_loss_order.is_truthy() && is_take_profit_order.is_truthy() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" createOrder() stopLossPrice and takeProfitPrice cannot both be defined"))"###);
+            panic!("ExchangeError::new({} createOrder() stopLossPrice and takeProfitPrice cannot both be defined)", self.get("id".into()).unwrap_str());
         };
         let mut method_tail: Value = Value::from("Orders");
         let mut reduce_only: Value = self.safe_value(params.clone(), Value::from("reduceOnly"), Value::Undefined);
@@ -3208,7 +3302,7 @@ pub trait Gate : Exchange {
         let mut is_limit_order: Value = (r#type.clone() == Value::from("limit")).into();
         let mut is_market_order: Value = (r#type.clone() == Value::from("market")).into();
         if is_limit_order.is_truthy() && price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" createOrder () requires a price argument for ") + r#type.clone() + Value::from(" orders"))"###);
+            panic!("ArgumentsRequired::new({} createOrder () requires a price argument for {} orders)", self.get("id".into()).unwrap_str(), js_string(&r#type));
         };
         if contract.is_truthy() {
             let mut amount_to_precision: Value = Gate::amount_to_precision(self, symbol.clone(), amount.clone());
@@ -3216,14 +3310,14 @@ pub trait Gate : Exchange {
             amount = parse_int(signed_amount.clone());
             if is_market_order.is_truthy() {
                 if time_in_force.clone() == Value::from("poc") || time_in_force.clone() == Value::from("gtc") {
-                    panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" createOrder () timeInForce for market orders must be "IOC""#))"###);
+                    panic!("ExchangeError::new({} createOrder () timeInForce for market orders must be \"IOC\")", self.get("id".into()).unwrap_str());
                 };
                 time_in_force = Value::from("ioc");
                 price = Value::from(0);
             };
         } else if !is_limit_order.is_truthy() {
             // exchange doesn't have market orders for spot
-            panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder () does not support ") + r#type.clone() + Value::from(" orders for ") + market.get(Value::from("type")) + Value::from(" markets"))"###);
+            panic!("InvalidOrder::new({} createOrder () does not support {} orders for {} markets)", self.get("id".into()).unwrap_str(), js_string(&r#type), js_string(&market.get(Value::from("type"))));
         };
         let mut request: Value = Value::Undefined;
         if !is_stop_order.is_truthy() && trigger.clone().is_nullish() {
@@ -3281,7 +3375,7 @@ pub trait Gate : Exchange {
                 //     no longer than 28 bytes without t- prefix
                 //     can only include 0-9, A-Z, a-z, underscores (_), hyphens (-) or dots (.)
                 if client_order_id.len() > 28 {
-                    panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" createOrder () clientOrderId or text param must be up to 28 characters"))"###);
+                    panic!("BadRequest::new({} createOrder () clientOrderId or text param must be up to 28 characters)", self.get("id".into()).unwrap_str());
                 };
                 params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("text").into(), Value::from("clientOrderId").into()])));
                 if client_order_id.get(Value::from(0)) != Value::from("t") {
@@ -3330,10 +3424,10 @@ pub trait Gate : Exchange {
                 // 1 means price_type >= price, 2 means price_type <= price
                 // 'expiration': expiration, how many seconds to wait for the condition to be triggered before cancelling the order
                 if reduce_only.clone().is_nonnullish() {
-                    request.get(Value::from("initial")).set("reduce_only".into(), reduce_only.clone());
+                    request.set_path(&[Value::from("initial"), "reduce_only".into()], reduce_only.clone());
                 };
                 if time_in_force.clone().is_nonnullish() {
-                    request.get(Value::from("initial")).set("tif".into(), time_in_force.clone());
+                    request.set_path(&[Value::from("initial"), "tif".into()], time_in_force.clone());
                 };
             } else {
                 // spot conditional order
@@ -3375,7 +3469,7 @@ pub trait Gate : Exchange {
                 // >= triggered when market price larger than or equal to price field, <= triggered when market price less than or equal to price field
                 // required, how long (in seconds) to wait for the condition to be triggered before cancelling the order
                 if time_in_force.clone().is_nonnullish() {
-                    request.get(Value::from("put")).set("time_in_force".into(), time_in_force.clone());
+                    request.set_path(&[Value::from("put"), "time_in_force".into()], time_in_force.clone());
                 };
             };
             method_tail = Value::from("PriceOrders");
@@ -3386,71 +3480,7 @@ pub trait Gate : Exchange {
             "swap": Value::from("privateFuturesPostSettle") + method_tail.clone(),
             "future": Value::from("privateDeliveryPostSettle") + method_tail.clone()
         }))).unwrap()));
-        let mut response: Value = Gate::dispatch(self, method, self.deep_extend_2(request.clone(), params.clone()), Value::Undefined).await;
-        //
-        // spot
-        //
-        //     {
-        //         "id": "95282841887",
-        //         "text": "apiv4",
-        //         "create_time": "1637383156",
-        //         "update_time": "1637383156",
-        //         "create_time_ms": 1637383156017,
-        //         "update_time_ms": 1637383156017,
-        //         "status": "open",
-        //         "currency_pair": "ETH_USDT",
-        //         "type": "limit",
-        //         "account": "spot",
-        //         "side": "buy",
-        //         "amount": "0.01",
-        //         "price": "3500",
-        //         "time_in_force": "gtc",
-        //         "iceberg": "0",
-        //         "left": "0.01",
-        //         "fill_price": "0",
-        //         "filled_total": "0",
-        //         "fee": "0",
-        //         "fee_currency": "ETH",
-        //         "point_fee": "0",
-        //         "gt_fee": "0",
-        //         "gt_discount": false,
-        //         "rebated_fee": "0",
-        //         "rebated_fee_currency": "USDT"
-        //     }
-        //
-        // spot conditional
-        //
-        //     {"id": 5891843}
-        //
-        // future and perpetual swaps
-        //
-        //     {
-        //         "id": 95938572327,
-        //         "contract": "ETH_USDT",
-        //         "mkfr": "0",
-        //         "tkfr": "0.0005",
-        //         "tif": "gtc",
-        //         "is_reduce_only": false,
-        //         "create_time": 1637384600.08,
-        //         "price": "3000",
-        //         "size": 1,
-        //         "refr": "0",
-        //         "left": 1,
-        //         "text": "api",
-        //         "fill_price": "0",
-        //         "user": 2436035,
-        //         "status": "open",
-        //         "is_liq": false,
-        //         "refu": 0,
-        //         "is_close": false,
-        //         "iceberg": 0
-        //     }
-        //
-        // futures and perpetual swaps conditionals
-        //
-        //     {"id": 7615567}
-        //
-        return Gate::parse_order(self, response.clone(), market.clone());
+        return Value::Json(serde_json::Value::Array(vec![method.into(), self.deep_extend_2(request.clone(), params.clone()).into()]));
     }
 
     fn parse_order_status(&self, mut status: Value) -> Value {
@@ -3682,7 +3712,7 @@ pub trait Gate : Exchange {
             "datetime": self.iso8601(timestamp.clone()),
             "lastTradeTimestamp": last_trade_timestamp,
             "status": status,
-            "symbol": Gate::safe_symbol(self, exchange_symbol.clone(), Value::Undefined, Value::Undefined),
+            "symbol": self.safe_symbol(exchange_symbol.clone(), Value::Undefined, Value::Undefined),
             "type": r#type,
             "timeInForce": time_in_force,
             "postOnly": post_only,
@@ -3729,7 +3759,7 @@ pub trait Gate : Exchange {
             };
             order_id = client_order_id.clone();
         };
-        let mut market: Value = if symbol.clone().is_nullish() { Value::Undefined } else { Gate::market(self, symbol.clone()) };
+        let mut market: Value = if symbol.clone().is_nullish() { Value::Undefined } else { self.market(symbol.clone()) };
         let (mut r#type, mut query) = shift_2(Gate::handle_market_type_and_params(self, Value::from("fetchOrder"), market.clone(), params.clone()));
         let mut contract: Value = (r#type.clone() == Value::from("swap") || r#type.clone() == Value::from("future")).into();
         let (mut request, mut request_params) = shift_2(if contract.is_truthy() { Gate::prepare_request(self, market.clone(), r#type.clone(), query.clone()) } else { Gate::spot_order_prepare_request(self, market.clone(), stop.clone(), query.clone()) });
@@ -3786,7 +3816,7 @@ pub trait Gate : Exchange {
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Gate::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
         };
         let mut stop: Value = self.safe_value(params.clone(), Value::from("stop"), Value::Undefined);
@@ -3963,7 +3993,7 @@ pub trait Gate : Exchange {
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = if symbol.clone().is_nullish() { Value::Undefined } else { Gate::market(self, symbol.clone()) };
+        let mut market: Value = if symbol.clone().is_nullish() { Value::Undefined } else { self.market(symbol.clone()) };
         let mut stop: Value = self.safe_value_2(params.clone(), Value::from("is_stop_order"), Value::from("stop"), false.into());
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("is_stop_order").into(), Value::from("stop").into()])));
         let (mut r#type, mut query) = shift_2(Gate::handle_market_type_and_params(self, Value::from("cancelOrder"), market.clone(), params.clone()));
@@ -4072,7 +4102,7 @@ pub trait Gate : Exchange {
     async fn cancel_all_orders(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = if symbol.clone().is_nullish() { Value::Undefined } else { Gate::market(self, symbol.clone()) };
+        let mut market: Value = if symbol.clone().is_nullish() { Value::Undefined } else { self.market(symbol.clone()) };
         let mut stop: Value = self.safe_value(params.clone(), Value::from("stop"), Value::Undefined);
         params = self.omit(params.clone(), Value::from("stop"));
         let (mut r#type, mut query) = shift_2(Gate::handle_market_type_and_params(self, Value::from("cancelAllOrders"), market.clone(), params.clone()));
@@ -4154,9 +4184,9 @@ pub trait Gate : Exchange {
         if from_id.clone() == Value::from("margin") || to_id.clone() == Value::from("margin") {
             let mut symbol: Value = self.safe_string_2(params.clone(), Value::from("symbol"), Value::from("currency_pair"), Value::Undefined);
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(r#" transfer requires params["symbol"] for isolated margin transfers"#))"###);
+                panic!("ArgumentsRequired::new({} transfer requires params[\"symbol\"] for isolated margin transfers)", self.get("id".into()).unwrap_str());
             };
-            let mut market: Value = Gate::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             request.set("currency_pair".into(), market.get(Value::from("id")));
             params = self.omit(params.clone(), Value::from("symbol"));
         };
@@ -4188,11 +4218,11 @@ pub trait Gate : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.get("markets".into()).contains_key(account.clone()) {
-            let mut market: Value = Gate::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             let mut keys: Value = Object::keys(accounts_by_type.clone());
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" accounts must be one of ") + keys.join(Value::from(", ")) + Value::from(" or an isolated margin symbol"))"###);
+            panic!("ExchangeError::new({} accounts must be one of {} or an isolated margin symbol)", self.get("id".into()).unwrap_str(), js_string(&keys.join(Value::from(", "))));
         };
         Value::Undefined
     }
@@ -4224,15 +4254,15 @@ pub trait Gate : Exchange {
     async fn set_leverage(&mut self, mut leverage: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         // WARNING: THIS WILL INCREASE LIQUIDATION PRICE FOR OPEN ISOLATED LONG POSITIONS
         // AND DECREASE LIQUIDATION PRICE FOR OPEN ISOLATED SHORT POSITIONS
         if leverage.clone() < Value::from(0) || leverage.clone() > Value::from(100) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setLeverage() leverage should be between 1 and 100"))"###);
+            panic!("BadRequest::new({} setLeverage() leverage should be between 1 and 100)", self.get("id".into()).unwrap_str());
         };
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = Gate::get_supported_mapping(self, market.get(Value::from("type")), Value::Json(normalize(&Value::Json(json!({
             "swap": "privateFuturesPostSettlePositionsContractLeverage",
             "future": "privateDeliveryPostSettlePositionsContractLeverage"
@@ -4427,7 +4457,7 @@ pub trait Gate : Exchange {
         let (mut r#type, mut query) = shift_2(Gate::handle_market_type_and_params(self, Value::from("fetchLeverageTiers"), Value::Undefined, params.clone()));
         let (mut request, mut request_params) = shift_2(Gate::prepare_request(self, Value::Undefined, r#type.clone(), query.clone()));
         if r#type.clone() != Value::from("future") && r#type.clone() != Value::from("swap") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchLeverageTiers only supports swap and future"))"###);
+            panic!("BadRequest::new({} fetchLeverageTiers only supports swap and future)", self.get("id".into()).unwrap_str());
         };
         let mut method: Value = Gate::get_supported_mapping(self, r#type.clone(), Value::Json(normalize(&Value::Json(json!({
             "swap": "publicFuturesGetSettleContracts",
@@ -4676,7 +4706,7 @@ pub trait Gate : Exchange {
         let mut currency: Value = Gate::currency(self, code.clone());
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Gate::market(self, symbol.clone());
+            market = self.market(symbol.clone());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "currency": currency.get(Value::from("id")),
@@ -4688,16 +4718,16 @@ pub trait Gate : Exchange {
         let mut method: Value = Value::from("privateMarginPostCrossRepayments");
         if margin_mode.clone() == Value::from("isolated") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" repayMargin() requires a symbol argument for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} repayMargin() requires a symbol argument for isolated margin)", self.get("id".into()).unwrap_str());
             };
             let mut mode: Value = self.safe_string(params.clone(), Value::from("mode"), Value::Undefined);
             // 'all' or 'partial'
             if mode.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" repayMargin() requires a mode parameter for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} repayMargin() requires a mode parameter for isolated margin)", self.get("id".into()).unwrap_str());
             };
             let mut id: Value = self.safe_string_2(params.clone(), Value::from("loan_id"), Value::from("id"), Value::Undefined);
             if id.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" repayMargin() requires an id parameter for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} repayMargin() requires an id parameter for isolated margin)", self.get("id".into()).unwrap_str());
             };
             method = Value::from("privateMarginPostLoansLoanIdRepayment");
             request.set("currency_pair".into(), market.get(Value::from("id")));
@@ -4769,7 +4799,7 @@ pub trait Gate : Exchange {
         let mut currency: Value = Gate::currency(self, code.clone());
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Gate::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -4782,12 +4812,12 @@ pub trait Gate : Exchange {
         let mut method: Value = Value::from("privateMarginPostCrossLoans");
         if margin_mode.clone() == Value::from("isolated") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" borrowMargin() requires a symbol argument for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} borrowMargin() requires a symbol argument for isolated margin)", self.get("id".into()).unwrap_str());
             };
             request.set("currency_pair".into(), market.get(Value::from("id")));
             let mut rate: Value = self.safe_string(params.clone(), Value::from("rate"), Value::Undefined);
             if rate.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" borrowMargin() requires a rate parameter for isolated margin"))"###);
+                panic!("ArgumentsRequired::new({} borrowMargin() requires a rate parameter for isolated margin)", self.get("id".into()).unwrap_str());
             };
             request.set("rate".into(), rate.clone());
             // Only rates '0.0002', '0.002' are supported.
@@ -4883,7 +4913,7 @@ pub trait Gate : Exchange {
             "id": self.safe_integer(info.clone(), Value::from("id"), Value::Undefined),
             "currency": Gate::safe_currency_code(self, currency_id.clone(), currency.clone()),
             "amount": Gate::safe_number(self, info.clone(), Value::from("amount"), Value::Undefined),
-            "symbol": Gate::safe_symbol(self, market_id.clone(), Value::Undefined, Value::Undefined),
+            "symbol": self.safe_symbol(market_id.clone(), Value::Undefined, Value::Undefined),
             "timestamp": timestamp,
             "datetime": self.iso8601(timestamp.clone()),
             "info": info
@@ -4904,7 +4934,7 @@ pub trait Gate : Exchange {
         let mut entire_path: Value = Value::from("/") + r#type.clone() + end_part.clone();
         let mut url: Value = self.get("urls".into()).get(Value::from("api")).get(authentication.clone()).get(r#type.clone());
         if url.clone().is_nullish() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" does not have a testnet for the ") + r#type.clone() + Value::from(" market type."))"###);
+            panic!("NotSupported::new({} does not have a testnet for the {} market type.)", self.get("id".into()).unwrap_str(), js_string(&r#type));
         };
         url = url +  entire_path.clone();
         if authentication.clone() == Value::from("public") {
@@ -4973,7 +5003,7 @@ pub trait Gate : Exchange {
         if label.clone().is_nonnullish() {
             let mut feedback: Value = self.get("id".into()) + Value::from(" ") + body.clone();
             Gate::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), label.clone(), feedback.clone());
-            panic!(r###"ExchangeError::new(feedback)"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         Value::Undefined
     }
@@ -5131,12 +5161,12 @@ pub trait Gate : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -5242,9 +5272,9 @@ pub trait Gate : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -5483,9 +5513,9 @@ pub trait Gate : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Gate::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -5580,14 +5610,14 @@ pub trait Gate : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -5809,7 +5839,7 @@ pub trait Gate : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -5877,10 +5907,10 @@ pub trait Gate : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -5994,7 +6024,7 @@ pub trait Gate : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -6002,7 +6032,7 @@ pub trait Gate : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -6028,19 +6058,8 @@ pub trait Gate : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Gate::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Gate::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Gate::load_time_difference(exchange, params), calculate_rate_limiter_cost: Gate::calculate_rate_limiter_cost, sign: Gate::sign, handle_errors: Gate::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -6072,7 +6091,7 @@ pub trait Gate : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Gate::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -6108,13 +6127,13 @@ pub trait Gate : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6214,13 +6233,14 @@ pub trait Gate : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -6234,7 +6254,7 @@ pub trait Gate : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6275,7 +6295,7 @@ pub trait Gate : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Gate::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -6284,7 +6304,7 @@ pub trait Gate : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Gate::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -6292,7 +6312,7 @@ pub trait Gate : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Gate::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
@@ -6302,7 +6322,7 @@ pub trait Gate : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -6311,12 +6331,12 @@ pub trait Gate : Exchange {
         params = params.or_default(Value::new_object());
         Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = self.fetch_borrow_rates(params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -6369,14 +6389,14 @@ pub trait Gate : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Gate::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -6418,13 +6438,13 @@ pub trait Gate : Exchange {
 
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6445,7 +6465,7 @@ pub trait Gate : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -6454,25 +6474,7 @@ pub trait Gate : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -6521,22 +6523,22 @@ pub trait Gate : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Gate::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -6576,9 +6578,7 @@ pub trait Gate : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = self.fetch_time(params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -6590,14 +6590,14 @@ pub trait Gate : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Gate::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = Gate::fetch_leverage_tiers(self, Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6605,7 +6605,7 @@ pub trait Gate : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -6616,7 +6616,7 @@ pub trait Gate : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -6627,10 +6627,10 @@ pub trait Gate : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -6641,7 +6641,7 @@ pub trait Gate : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -6652,7 +6652,7 @@ pub trait Gate : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -6762,11 +6762,6 @@ pub trait Gate : Exchange {
         return Gate::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Gate::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -6798,9 +6793,9 @@ pub trait Gate : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -6811,7 +6806,7 @@ pub trait Gate : Exchange {
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6849,7 +6844,7 @@ pub trait Gate : Exchange {
             }))).unwrap());
             return Gate::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6874,7 +6869,7 @@ pub trait Gate : Exchange {
             }))).unwrap());
             return Gate::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6899,7 +6894,7 @@ pub trait Gate : Exchange {
             }))).unwrap());
             return Gate::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6915,7 +6910,7 @@ pub trait Gate : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -6962,16 +6957,16 @@ pub trait Gate : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Gate::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Gate::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -6979,162 +6974,162 @@ pub trait Gate : Exchange {
         match method {
             Value::Json(serde_json::Value::String(ref m)) => {
                 match m.as_ref() {
-                    "publicWalletGetWalletCurrencyChains" => Gate::request(self, "wallet/currency_chains".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrencies" => Gate::request(self, "currencies".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrenciesCurrency" => Gate::request(self, "currencies/{currency}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrencyPairs" => Gate::request(self, "currency_pairs".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrencyPairsCurrencyPair" => Gate::request(self, "currency_pairs/{currency_pair}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetTickers" => Gate::request(self, "tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetOrderBook" => Gate::request(self, "order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetTrades" => Gate::request(self, "trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCandlesticks" => Gate::request(self, "candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCurrencyPairs" => Gate::request(self, "currency_pairs".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCurrencyPairsCurrencyPair" => Gate::request(self, "currency_pairs/{currency_pair}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCrossCurrencies" => Gate::request(self, "cross/currencies".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCrossCurrenciesCurrency" => Gate::request(self, "cross/currencies/{currency}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetFundingBook" => Gate::request(self, "funding_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleContracts" => Gate::request(self, "{settle}/contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleContractsContract" => Gate::request(self, "{settle}/contracts/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleOrderBook" => Gate::request(self, "{settle}/order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleTrades" => Gate::request(self, "{settle}/trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleCandlesticks" => Gate::request(self, "{settle}/candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleTickers" => Gate::request(self, "{settle}/tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleFundingRate" => Gate::request(self, "{settle}/funding_rate".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleInsurance" => Gate::request(self, "{settle}/insurance".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleContractStats" => Gate::request(self, "{settle}/contract_stats".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleLiqOrders" => Gate::request(self, "{settle}/liq_orders".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleContracts" => Gate::request(self, "{settle}/contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleContractsContract" => Gate::request(self, "{settle}/contracts/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleOrderBook" => Gate::request(self, "{settle}/order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleTrades" => Gate::request(self, "{settle}/trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleCandlesticks" => Gate::request(self, "{settle}/candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleTickers" => Gate::request(self, "{settle}/tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleInsurance" => Gate::request(self, "{settle}/insurance".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetUnderlyings" => Gate::request(self, "underlyings".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetExpirations" => Gate::request(self, "expirations".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetContracts" => Gate::request(self, "contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetContractsContract" => Gate::request(self, "contracts/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetSettlements" => Gate::request(self, "settlements".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetSettlementsContract" => Gate::request(self, "settlements/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetOrderBook" => Gate::request(self, "order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetTickers" => Gate::request(self, "tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetUnderlyingTickersUnderlying" => Gate::request(self, "underlying/tickers/{underlying}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetCandlesticks" => Gate::request(self, "candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetUnderlyingCandlesticks" => Gate::request(self, "underlying/candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetTrades" => Gate::request(self, "trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWithdrawalsPost" => Gate::request(self, "".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWithdrawalsDeleteWithdrawalId" => Gate::request(self, "{withdrawal_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetDepositAddress" => Gate::request(self, "deposit_address".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetWithdrawals" => Gate::request(self, "withdrawals".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetDeposits" => Gate::request(self, "deposits".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetSubAccountTransfers" => Gate::request(self, "sub_account_transfers".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetWithdrawStatus" => Gate::request(self, "withdraw_status".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetSubAccountBalances" => Gate::request(self, "sub_account_balances".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetFee" => Gate::request(self, "fee".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetTotalBalance" => Gate::request(self, "total_balance".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletPostTransfers" => Gate::request(self, "transfers".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletPostSubAccountTransfers" => Gate::request(self, "sub_account_transfers".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetAccounts" => Gate::request(self, "accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetOpenOrders" => Gate::request(self, "open_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetOrders" => Gate::request(self, "orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetMyTrades" => Gate::request(self, "my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetPriceOrders" => Gate::request(self, "price_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetPriceOrdersOrderId" => Gate::request(self, "price_orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostBatchOrders" => Gate::request(self, "batch_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostOrders" => Gate::request(self, "orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostCancelBatchOrders" => Gate::request(self, "cancel_batch_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostPriceOrders" => Gate::request(self, "price_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeleteOrders" => Gate::request(self, "orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeleteOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeletePriceOrders" => Gate::request(self, "price_orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeletePriceOrdersOrderId" => Gate::request(self, "price_orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetAccounts" => Gate::request(self, "accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetAccountBook" => Gate::request(self, "account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetFundingAccounts" => Gate::request(self, "funding_accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoans" => Gate::request(self, "loans".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoansLoanId" => Gate::request(self, "loans/{loan_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoansLoanIdRepayment" => Gate::request(self, "loans/{loan_id}/repayment".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoanRecords" => Gate::request(self, "loan_records".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoanRecordsLoadRecordId" => Gate::request(self, "loan_records/{load_record_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetAutoRepay" => Gate::request(self, "auto_repay".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetTransferable" => Gate::request(self, "transferable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossAccounts" => Gate::request(self, "cross/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossAccountBook" => Gate::request(self, "cross/account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossLoans" => Gate::request(self, "cross/loans".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossLoansLoanId" => Gate::request(self, "cross/loans/{loan_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossLoansRepayments" => Gate::request(self, "cross/loans/repayments".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossTransferable" => Gate::request(self, "cross/transferable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoanRecordsLoanRecordId" => Gate::request(self, "loan_records/{loan_record_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetBorrowable" => Gate::request(self, "borrowable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossRepayments" => Gate::request(self, "cross/repayments".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossBorrowable" => Gate::request(self, "cross/borrowable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostLoans" => Gate::request(self, "loans".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostMergedLoans" => Gate::request(self, "merged_loans".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostLoansLoanIdRepayment" => Gate::request(self, "loans/{loan_id}/repayment".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostAutoRepay" => Gate::request(self, "auto_repay".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostCrossLoans" => Gate::request(self, "cross/loans".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostCrossLoansRepayments" => Gate::request(self, "cross/loans/repayments".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostCrossRepayments" => Gate::request(self, "cross/repayments".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginDeleteLoansLoanId" => Gate::request(self, "loans/{loan_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleAccounts" => Gate::request(self, "{settle}/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleAccountBook" => Gate::request(self, "{settle}/account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePositions" => Gate::request(self, "{settle}/positions".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePositionsContract" => Gate::request(self, "{settle}/positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleOrders" => Gate::request(self, "{settle}/orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleMyTrades" => Gate::request(self, "{settle}/my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePositionClose" => Gate::request(self, "{settle}/position_close".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleLiquidates" => Gate::request(self, "{settle}/liquidates".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleDualCompPositionsContract" => Gate::request(self, "{settle}/dual_comp/positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePositionsContractMargin" => Gate::request(self, "{settle}/positions/{contract}/margin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePositionsContractLeverage" => Gate::request(self, "{settle}/positions/{contract}/leverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePositionsContractRiskLimit" => Gate::request(self, "{settle}/positions/{contract}/risk_limit".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualMode" => Gate::request(self, "{settle}/dual_mode".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContract" => Gate::request(self, "{settle}/dual_comp/positions/{contract}".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContractMargin" => Gate::request(self, "{settle}/dual_comp/positions/{contract}/margin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContractLeverage" => Gate::request(self, "{settle}/dual_comp/positions/{contract}/leverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContractRiskLimit" => Gate::request(self, "{settle}/dual_comp/positions/{contract}/risk_limit".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleOrders" => Gate::request(self, "{settle}/orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettleOrders" => Gate::request(self, "{settle}/orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleAccounts" => Gate::request(self, "{settle}/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleAccountBook" => Gate::request(self, "{settle}/account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePositions" => Gate::request(self, "{settle}/positions".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePositionsContract" => Gate::request(self, "{settle}/positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleOrders" => Gate::request(self, "{settle}/orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleMyTrades" => Gate::request(self, "{settle}/my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePositionClose" => Gate::request(self, "{settle}/position_close".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleLiquidates" => Gate::request(self, "{settle}/liquidates".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleSettlements" => Gate::request(self, "{settle}/settlements".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePositionsContractMargin" => Gate::request(self, "{settle}/positions/{contract}/margin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePositionsContractLeverage" => Gate::request(self, "{settle}/positions/{contract}/leverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePositionsContractRiskLimit" => Gate::request(self, "{settle}/positions/{contract}/risk_limit".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettleOrders" => Gate::request(self, "{settle}/orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettleOrders" => Gate::request(self, "{settle}/orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetAccounts" => Gate::request(self, "accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetAccountBook" => Gate::request(self, "account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetPositions" => Gate::request(self, "positions".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetPositionsContract" => Gate::request(self, "positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetPositionClose" => Gate::request(self, "position_close".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetOrders" => Gate::request(self, "orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetMyTrades" => Gate::request(self, "my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsPostOrders" => Gate::request(self, "orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsDeleteOrders" => Gate::request(self, "orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsDeleteOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicWalletGetWalletCurrencyChains" => Gate::request(self, "wallet/currency_chains".into(), Value::Json(json!(["public", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrencies" => Gate::request(self, "currencies".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrenciesCurrency" => Gate::request(self, "currencies/{currency}".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrencyPairs" => Gate::request(self, "currency_pairs".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrencyPairsCurrencyPair" => Gate::request(self, "currency_pairs/{currency_pair}".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetTickers" => Gate::request(self, "tickers".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetOrderBook" => Gate::request(self, "order_book".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetTrades" => Gate::request(self, "trades".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCandlesticks" => Gate::request(self, "candlesticks".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCurrencyPairs" => Gate::request(self, "currency_pairs".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCurrencyPairsCurrencyPair" => Gate::request(self, "currency_pairs/{currency_pair}".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCrossCurrencies" => Gate::request(self, "cross/currencies".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCrossCurrenciesCurrency" => Gate::request(self, "cross/currencies/{currency}".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetFundingBook" => Gate::request(self, "funding_book".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleContracts" => Gate::request(self, "{settle}/contracts".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleContractsContract" => Gate::request(self, "{settle}/contracts/{contract}".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleOrderBook" => Gate::request(self, "{settle}/order_book".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleTrades" => Gate::request(self, "{settle}/trades".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleCandlesticks" => Gate::request(self, "{settle}/candlesticks".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleTickers" => Gate::request(self, "{settle}/tickers".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleFundingRate" => Gate::request(self, "{settle}/funding_rate".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleInsurance" => Gate::request(self, "{settle}/insurance".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleContractStats" => Gate::request(self, "{settle}/contract_stats".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleLiqOrders" => Gate::request(self, "{settle}/liq_orders".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleContracts" => Gate::request(self, "{settle}/contracts".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleContractsContract" => Gate::request(self, "{settle}/contracts/{contract}".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleOrderBook" => Gate::request(self, "{settle}/order_book".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleTrades" => Gate::request(self, "{settle}/trades".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleCandlesticks" => Gate::request(self, "{settle}/candlesticks".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleTickers" => Gate::request(self, "{settle}/tickers".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleInsurance" => Gate::request(self, "{settle}/insurance".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetUnderlyings" => Gate::request(self, "underlyings".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetExpirations" => Gate::request(self, "expirations".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetContracts" => Gate::request(self, "contracts".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetContractsContract" => Gate::request(self, "contracts/{contract}".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetSettlements" => Gate::request(self, "settlements".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetSettlementsContract" => Gate::request(self, "settlements/{contract}".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetOrderBook" => Gate::request(self, "order_book".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetTickers" => Gate::request(self, "tickers".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetUnderlyingTickersUnderlying" => Gate::request(self, "underlying/tickers/{underlying}".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetCandlesticks" => Gate::request(self, "candlesticks".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetUnderlyingCandlesticks" => Gate::request(self, "underlying/candlesticks".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetTrades" => Gate::request(self, "trades".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWithdrawalsPost" => Gate::request(self, "".into(), Value::Json(json!(["private", "withdrawals"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWithdrawalsDeleteWithdrawalId" => Gate::request(self, "{withdrawal_id}".into(), Value::Json(json!(["private", "withdrawals"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetDepositAddress" => Gate::request(self, "deposit_address".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetWithdrawals" => Gate::request(self, "withdrawals".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetDeposits" => Gate::request(self, "deposits".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetSubAccountTransfers" => Gate::request(self, "sub_account_transfers".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetWithdrawStatus" => Gate::request(self, "withdraw_status".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetSubAccountBalances" => Gate::request(self, "sub_account_balances".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetFee" => Gate::request(self, "fee".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetTotalBalance" => Gate::request(self, "total_balance".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletPostTransfers" => Gate::request(self, "transfers".into(), Value::Json(json!(["private", "wallet"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletPostSubAccountTransfers" => Gate::request(self, "sub_account_transfers".into(), Value::Json(json!(["private", "wallet"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetAccounts" => Gate::request(self, "accounts".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetOpenOrders" => Gate::request(self, "open_orders".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetOrders" => Gate::request(self, "orders".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetMyTrades" => Gate::request(self, "my_trades".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetPriceOrders" => Gate::request(self, "price_orders".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetPriceOrdersOrderId" => Gate::request(self, "price_orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostBatchOrders" => Gate::request(self, "batch_orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostOrders" => Gate::request(self, "orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostCancelBatchOrders" => Gate::request(self, "cancel_batch_orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostPriceOrders" => Gate::request(self, "price_orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeleteOrders" => Gate::request(self, "orders".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeleteOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeletePriceOrders" => Gate::request(self, "price_orders".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeletePriceOrdersOrderId" => Gate::request(self, "price_orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetAccounts" => Gate::request(self, "accounts".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetAccountBook" => Gate::request(self, "account_book".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetFundingAccounts" => Gate::request(self, "funding_accounts".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoans" => Gate::request(self, "loans".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoansLoanId" => Gate::request(self, "loans/{loan_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoansLoanIdRepayment" => Gate::request(self, "loans/{loan_id}/repayment".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoanRecords" => Gate::request(self, "loan_records".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoanRecordsLoadRecordId" => Gate::request(self, "loan_records/{load_record_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetAutoRepay" => Gate::request(self, "auto_repay".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetTransferable" => Gate::request(self, "transferable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossAccounts" => Gate::request(self, "cross/accounts".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossAccountBook" => Gate::request(self, "cross/account_book".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossLoans" => Gate::request(self, "cross/loans".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossLoansLoanId" => Gate::request(self, "cross/loans/{loan_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossLoansRepayments" => Gate::request(self, "cross/loans/repayments".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossTransferable" => Gate::request(self, "cross/transferable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoanRecordsLoanRecordId" => Gate::request(self, "loan_records/{loan_record_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetBorrowable" => Gate::request(self, "borrowable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossRepayments" => Gate::request(self, "cross/repayments".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossBorrowable" => Gate::request(self, "cross/borrowable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostLoans" => Gate::request(self, "loans".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostMergedLoans" => Gate::request(self, "merged_loans".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostLoansLoanIdRepayment" => Gate::request(self, "loans/{loan_id}/repayment".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostAutoRepay" => Gate::request(self, "auto_repay".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostCrossLoans" => Gate::request(self, "cross/loans".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostCrossLoansRepayments" => Gate::request(self, "cross/loans/repayments".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostCrossRepayments" => Gate::request(self, "cross/repayments".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginDeleteLoansLoanId" => Gate::request(self, "loans/{loan_id}".into(), Value::Json(json!(["private", "margin"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleAccounts" => Gate::request(self, "{settle}/accounts".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleAccountBook" => Gate::request(self, "{settle}/account_book".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePositions" => Gate::request(self, "{settle}/positions".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePositionsContract" => Gate::request(self, "{settle}/positions/{contract}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleOrders" => Gate::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleMyTrades" => Gate::request(self, "{settle}/my_trades".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePositionClose" => Gate::request(self, "{settle}/position_close".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleLiquidates" => Gate::request(self, "{settle}/liquidates".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleDualCompPositionsContract" => Gate::request(self, "{settle}/dual_comp/positions/{contract}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePositionsContractMargin" => Gate::request(self, "{settle}/positions/{contract}/margin".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePositionsContractLeverage" => Gate::request(self, "{settle}/positions/{contract}/leverage".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePositionsContractRiskLimit" => Gate::request(self, "{settle}/positions/{contract}/risk_limit".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualMode" => Gate::request(self, "{settle}/dual_mode".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContract" => Gate::request(self, "{settle}/dual_comp/positions/{contract}".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContractMargin" => Gate::request(self, "{settle}/dual_comp/positions/{contract}/margin".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContractLeverage" => Gate::request(self, "{settle}/dual_comp/positions/{contract}/leverage".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContractRiskLimit" => Gate::request(self, "{settle}/dual_comp/positions/{contract}/risk_limit".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleOrders" => Gate::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettleOrders" => Gate::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleAccounts" => Gate::request(self, "{settle}/accounts".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleAccountBook" => Gate::request(self, "{settle}/account_book".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePositions" => Gate::request(self, "{settle}/positions".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePositionsContract" => Gate::request(self, "{settle}/positions/{contract}".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleOrders" => Gate::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleMyTrades" => Gate::request(self, "{settle}/my_trades".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePositionClose" => Gate::request(self, "{settle}/position_close".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleLiquidates" => Gate::request(self, "{settle}/liquidates".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleSettlements" => Gate::request(self, "{settle}/settlements".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePositionsContractMargin" => Gate::request(self, "{settle}/positions/{contract}/margin".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePositionsContractLeverage" => Gate::request(self, "{settle}/positions/{contract}/leverage".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePositionsContractRiskLimit" => Gate::request(self, "{settle}/positions/{contract}/risk_limit".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettleOrders" => Gate::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettleOrders" => Gate::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettleOrdersOrderId" => Gate::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettlePriceOrders" => Gate::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettlePriceOrdersOrderId" => Gate::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetAccounts" => Gate::request(self, "accounts".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetAccountBook" => Gate::request(self, "account_book".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetPositions" => Gate::request(self, "positions".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetPositionsContract" => Gate::request(self, "positions/{contract}".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetPositionClose" => Gate::request(self, "position_close".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetOrders" => Gate::request(self, "orders".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetMyTrades" => Gate::request(self, "my_trades".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsPostOrders" => Gate::request(self, "orders".into(), Value::Json(json!(["private", "options"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsDeleteOrders" => Gate::request(self, "orders".into(), Value::Json(json!(["private", "options"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsDeleteOrdersOrderId" => Gate::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "options"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
                     _ => unimplemented!(),
                 }
             },
@@ -7144,7 +7139,7 @@ pub trait Gate : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct GateImpl(Value);
+pub struct GateImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for GateImpl {}
 impl Gate for GateImpl {}
 impl ValueTrait for GateImpl {
@@ -7166,8 +7161,8 @@ impl ValueTrait for GateImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -7186,7 +7181,8 @@ impl GateImpl {
         let mut rv = GateImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Gate::describe(&rv);
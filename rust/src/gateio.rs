@@ -11,16 +11,18 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::gate::Gate;
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
 
 #[async_trait]
-pub trait Gateio : gate {
+pub trait Gateio : Gate {
     fn describe(&self) -> Value {
         Value::Json(serde_json::Value::from_str(r###"{
             "id": "gateio",
@@ -100,6 +102,18 @@ pub trait Gateio : gate {
             "urls": {
                 "logo": "https://user-images.githubusercontent.com/1294454/31784029-0313c702-b509-11e7-9ccc-bc0da6a0e435.jpg",
                 "api": {
+                    "ws": {
+                        "spot": "wss://api.gateio.ws/ws/v4/",
+                        "margin": "wss://api.gateio.ws/ws/v4/",
+                        "swap": {
+                            "usdt": "wss://fx-ws.gateio.ws/v4/ws/usdt",
+                            "btc": "wss://fx-ws.gateio.ws/v4/ws/btc"
+                        },
+                        "future": {
+                            "usdt": "wss://fx-ws.gateio.ws/v4/ws/delivery/usdt",
+                            "btc": "wss://fx-ws.gateio.ws/v4/ws/delivery/btc"
+                        }
+                    },
                     "public": {
                         "wallet": "https://api.gateio.ws/api/v4",
                         "futures": "https://api.gateio.ws/api/v4",
@@ -121,6 +135,16 @@ pub trait Gateio : gate {
                 "www": "https://gate.io/",
                 "doc": "https://www.gate.io/docs/apiv4/en/index.html",
                 "test": {
+                    "ws": {
+                        "swap": {
+                            "usdt": "wss://fx-ws-testnet.gateio.ws/v4/ws/usdt",
+                            "btc": "wss://fx-ws-testnet.gateio.ws/v4/ws/btc"
+                        },
+                        "future": {
+                            "usdt": "wss://fx-ws-testnet.gateio.ws/v4/ws/delivery/usdt",
+                            "btc": "wss://fx-ws-testnet.gateio.ws/v4/ws/delivery/btc"
+                        }
+                    },
                     "public": {
                         "futures": "https://fx-api-testnet.gateio.ws/api/v4",
                         "delivery": "https://fx-api-testnet.gateio.ws/api/v4"
@@ -952,12 +976,12 @@ pub trait Gateio : gate {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -1063,9 +1087,9 @@ pub trait Gateio : gate {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -1304,9 +1328,9 @@ pub trait Gateio : gate {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Gateio::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -1401,14 +1425,14 @@ pub trait Gateio : gate {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -1486,7 +1510,7 @@ pub trait Gateio : gate {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Gateio::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = self.fetch_trades(symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -1661,7 +1685,7 @@ pub trait Gateio : gate {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -1713,7 +1737,7 @@ pub trait Gateio : gate {
             let mut symbol: Value = market.get(Value::from("symbol"));
             let mut contract: Value = self.safe_value(market.clone(), Value::from("contract"), false.into());
             if contract.is_truthy() && symbols.clone().is_nullish() || self.in_array(symbol.clone(), symbols.clone()).is_truthy() {
-                tiers.set(symbol.clone(), self.parse_market_leverage_tiers(item.clone(), market.clone()));
+                tiers.set(symbol.clone(), Gate::parse_market_leverage_tiers(self, item.clone(), market.clone()));
             };
             i += 1;
         };
@@ -1729,10 +1753,10 @@ pub trait Gateio : gate {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -1745,7 +1769,7 @@ pub trait Gateio : gate {
         let mut result: Value = Value::new_array();
         let mut i: usize = 0;
         while i < positions.len() {
-            let mut position: Value = extend_2(self.parse_position(positions.get(i.into()), Value::Undefined), params.clone());
+            let mut position: Value = extend_2(Gate::parse_position(self, positions.get(i.into()), Value::Undefined), params.clone());
             result.push(position.clone());
             i += 1;
         };
@@ -1787,7 +1811,7 @@ pub trait Gateio : gate {
         let mut result: Value = Value::new_array();
         let mut i: usize = 0;
         while i < transactions.len() {
-            let mut transaction: Value = extend_2(self.parse_transaction(transactions.get(i.into()), currency.clone()), params.clone());
+            let mut transaction: Value = extend_2(Gate::parse_transaction(self, transactions.get(i.into()), currency.clone()), params.clone());
             result.push(transaction.clone());
             i += 1;
         };
@@ -1803,7 +1827,7 @@ pub trait Gateio : gate {
         let mut result: Value = Value::new_array();
         let mut i: usize = 0;
         while i < transfers.len() {
-            let mut transfer: Value = extend_2(self.parse_transfer(transfers.get(i.into()), currency.clone()), params.clone());
+            let mut transfer: Value = extend_2(Gate::parse_transfer(self, transfers.get(i.into()), currency.clone()), params.clone());
             result.push(transfer.clone());
             i += 1;
         };
@@ -1846,7 +1870,7 @@ pub trait Gateio : gate {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Gateio::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -1854,7 +1878,7 @@ pub trait Gateio : gate {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Gateio::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -1880,19 +1904,8 @@ pub trait Gateio : gate {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Gateio::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = self.sign(path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Gateio::load_time_difference(exchange, params), calculate_rate_limiter_cost: Gateio::calculate_rate_limiter_cost, sign: Gate::sign, handle_errors: Gateio::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -1924,7 +1937,7 @@ pub trait Gateio : gate {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Gateio::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = self.fetch_trades(symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -1960,13 +1973,13 @@ pub trait Gateio : gate {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2066,13 +2079,14 @@ pub trait Gateio : gate {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -2086,14 +2100,14 @@ pub trait Gateio : gate {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
 
     async fn fetch_balance(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBalance() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBalance() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2133,7 +2147,7 @@ pub trait Gateio : gate {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Gateio::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -2142,7 +2156,7 @@ pub trait Gateio : gate {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Gateio::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -2150,14 +2164,14 @@ pub trait Gateio : gate {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Gateio::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2166,7 +2180,7 @@ pub trait Gateio : gate {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -2175,12 +2189,12 @@ pub trait Gateio : gate {
         params = params.or_default(Value::new_object());
         Gateio::load_markets(self, Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = self.fetch_borrow_rates(params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -2233,14 +2247,14 @@ pub trait Gateio : gate {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Gateio::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -2258,7 +2272,9 @@ pub trait Gateio : gate {
         return Value::Undefined;
     }
 
-    fn handle_errors(&mut self, mut status_code: Value, mut status_text: Value, mut url: Value, mut method: Value, mut response_headers: Value, mut response_body: Value, mut response: Value, mut request_headers: Value, mut request_body: Value) -> Value { Value::Undefined }
+    fn handle_errors(&mut self, mut status_code: Value, mut status_text: Value, mut url: Value, mut method: Value, mut response_headers: Value, mut response_body: Value, mut response: Value, mut request_headers: Value, mut request_body: Value) -> Value {
+        Gate::handle_errors(self, status_code, status_text, url, method, response_headers, response_body, response, request_headers, request_body)
+    }
 
     fn calculate_rate_limiter_cost(&mut self, mut api: Value, mut method: Value, mut path: Value, mut params: Value, mut config: Value, mut context: Value) -> Value {
         config = config.or_default(Value::new_object());
@@ -2272,25 +2288,25 @@ pub trait Gateio : gate {
             let mut tickers: Value = Gateio::fetch_tickers(self, Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), params.clone()).await;
             let mut ticker: Value = self.safe_value(tickers.clone(), symbol.clone(), Value::Undefined);
             if ticker.clone().is_nullish() {
-                panic!(r###"NullResponse::new(self.get("id".into()) + Value::from(" fetchTickers() could not find a ticker for ") + symbol.clone())"###);
+                panic!("NullResponse::new({} fetchTickers() could not find a ticker for {})", self.get("id".into()).unwrap_str(), js_string(&symbol));
             } else {
                 return ticker.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTicker() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTicker() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
 
     async fn fetch_tickers(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTickers() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTickers() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrder() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2307,13 +2323,13 @@ pub trait Gateio : gate {
 
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createOrder() is not supported yet"))"###);
+        panic!("NotSupported::new({} createOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" cancelOrder() is not supported yet"))"###);
+        panic!("NotSupported::new({} cancelOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2324,43 +2340,43 @@ pub trait Gateio : gate {
 
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_open_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOpenOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOpenOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_closed_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchClosedOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchClosedOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_my_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMyTrades() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchMyTrades() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_deposits(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchDeposits() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchDeposits() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_withdrawals(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchWithdrawals() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchWithdrawals() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2370,12 +2386,12 @@ pub trait Gateio : gate {
             let mut deposit_addresses: Value = self.fetch_deposit_addresses(Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
             let mut deposit_address: Value = self.safe_value(deposit_addresses.clone(), code.clone(), Value::Undefined);
             if deposit_address.clone().is_nullish() {
-                panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() could not find a deposit address for ") + code.clone() + Value::from(", make sure you have created a corresponding deposit address in your wallet on the exchange website"))"###);
+                panic!("InvalidAddress::new({} fetchDepositAddress() could not find a deposit address for {}, make sure you have created a corresponding deposit address in your wallet on the exchange website)", self.get("id".into()).unwrap_str(), js_string(&code));
             } else {
                 return deposit_address.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchDepositAddress() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchDepositAddress() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -2397,7 +2413,7 @@ pub trait Gateio : gate {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -2406,25 +2422,7 @@ pub trait Gateio : gate {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -2473,22 +2471,22 @@ pub trait Gateio : gate {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Gateio::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Gateio::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Gateio::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Gateio::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -2528,9 +2526,7 @@ pub trait Gateio : gate {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = self.fetch_time(params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -2542,14 +2538,14 @@ pub trait Gateio : gate {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Gateio::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = self.fetch_leverage_tiers(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -2557,7 +2553,7 @@ pub trait Gateio : gate {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -2568,7 +2564,7 @@ pub trait Gateio : gate {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -2579,10 +2575,10 @@ pub trait Gateio : gate {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -2593,7 +2589,7 @@ pub trait Gateio : gate {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -2604,7 +2600,7 @@ pub trait Gateio : gate {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -2654,7 +2650,7 @@ pub trait Gateio : gate {
         if Array::is_array(tickers.clone()).is_truthy() {
             let mut i: usize = 0;
             while i < tickers.len() {
-                let mut ticker: Value = extend_2(self.parse_ticker(tickers.get(i.into()), Value::Undefined), params.clone());
+                let mut ticker: Value = extend_2(Gate::parse_ticker(self, tickers.get(i.into()), Value::Undefined), params.clone());
                 results.push(ticker.clone());
                 i += 1;
             };
@@ -2664,7 +2660,7 @@ pub trait Gateio : gate {
             while i < market_ids.len() {
                 let mut market_id: Value = market_ids.get(i.into());
                 let mut market: Value = Gateio::safe_market(self, market_id.clone(), Value::Undefined, Value::Undefined);
-                let mut ticker: Value = extend_2(self.parse_ticker(tickers.get(market_id.clone()), market.clone()), params.clone());
+                let mut ticker: Value = extend_2(Gate::parse_ticker(self, tickers.get(market_id.clone()), market.clone()), params.clone());
                 results.push(ticker.clone());
                 i += 1;
             };
@@ -2714,13 +2710,8 @@ pub trait Gateio : gate {
         return Gateio::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Gateio::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rate(&self, mut contract: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseFundingRate() is not supported yet"))"###);
+        panic!("NotSupported::new({} parseFundingRate() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2755,9 +2746,9 @@ pub trait Gateio : gate {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -2769,20 +2760,20 @@ pub trait Gateio : gate {
 
     async fn fetch_trading_fees(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTradingFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTradingFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTradingFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Gateio::fetch_trading_fees(self, params.clone()).await;
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -2804,19 +2795,19 @@ pub trait Gateio : gate {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchFundingRates")).is_truthy() {
             Gateio::load_markets(self, Value::Undefined, Value::Undefined).await;
-            let mut market: Value = Gateio::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchFundingRate() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut rates: Value = self.fetch_funding_rates(Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), params.clone()).await;
             let mut rate: Value = self.safe_value(rates.clone(), symbol.clone(), Value::Undefined);
             if rate.clone().is_nullish() {
-                panic!(r###"NullResponse::new(self.get("id".into()) + Value::from(" fetchFundingRate () returned no data for ") + symbol.clone())"###);
+                panic!("NullResponse::new({} fetchFundingRate () returned no data for {})", self.get("id".into()).unwrap_str(), js_string(&symbol));
             } else {
                 return rate.clone();
             };
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchFundingRate () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchFundingRate () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -2841,7 +2832,7 @@ pub trait Gateio : gate {
             }))).unwrap());
             return Gateio::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -2866,7 +2857,7 @@ pub trait Gateio : gate {
             }))).unwrap());
             return Gateio::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -2891,7 +2882,7 @@ pub trait Gateio : gate {
             }))).unwrap());
             return Gateio::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -2907,7 +2898,7 @@ pub trait Gateio : gate {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -2928,7 +2919,7 @@ pub trait Gateio : gate {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Gateio::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -2967,25 +2958,25 @@ pub trait Gateio : gate {
         let mut currencies: Value = Value::Undefined;
         // only call if exchange API provides endpoint (true), thus avoid emulated versions ('emulated')
         if self.get("has".into()).get(Value::from("fetchCurrencies")) == true.into() {
-            currencies = self.fetch_currencies(Value::Undefined).await;
+            currencies = Gate::fetch_currencies(self, Value::Undefined).await;
         };
-        let mut markets: Value = self.fetch_markets(params.clone()).await;
+        let mut markets: Value = Gate::fetch_markets(self, params.clone()).await;
         return Gateio::set_markets(self, markets.clone(), currencies.clone());
     }
 
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Gateio::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Gateio::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -2993,162 +2984,162 @@ pub trait Gateio : gate {
         match method {
             Value::Json(serde_json::Value::String(ref m)) => {
                 match m.as_ref() {
-                    "publicWalletGetWalletCurrencyChains" => Gateio::request(self, "wallet/currency_chains".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrencies" => Gateio::request(self, "currencies".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrenciesCurrency" => Gateio::request(self, "currencies/{currency}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrencyPairs" => Gateio::request(self, "currency_pairs".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCurrencyPairsCurrencyPair" => Gateio::request(self, "currency_pairs/{currency_pair}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetTickers" => Gateio::request(self, "tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetOrderBook" => Gateio::request(self, "order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetTrades" => Gateio::request(self, "trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicSpotGetCandlesticks" => Gateio::request(self, "candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCurrencyPairs" => Gateio::request(self, "currency_pairs".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCurrencyPairsCurrencyPair" => Gateio::request(self, "currency_pairs/{currency_pair}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCrossCurrencies" => Gateio::request(self, "cross/currencies".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetCrossCurrenciesCurrency" => Gateio::request(self, "cross/currencies/{currency}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicMarginGetFundingBook" => Gateio::request(self, "funding_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleContracts" => Gateio::request(self, "{settle}/contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleContractsContract" => Gateio::request(self, "{settle}/contracts/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleOrderBook" => Gateio::request(self, "{settle}/order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleTrades" => Gateio::request(self, "{settle}/trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleCandlesticks" => Gateio::request(self, "{settle}/candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleTickers" => Gateio::request(self, "{settle}/tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleFundingRate" => Gateio::request(self, "{settle}/funding_rate".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleInsurance" => Gateio::request(self, "{settle}/insurance".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleContractStats" => Gateio::request(self, "{settle}/contract_stats".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicFuturesGetSettleLiqOrders" => Gateio::request(self, "{settle}/liq_orders".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleContracts" => Gateio::request(self, "{settle}/contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleContractsContract" => Gateio::request(self, "{settle}/contracts/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleOrderBook" => Gateio::request(self, "{settle}/order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleTrades" => Gateio::request(self, "{settle}/trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleCandlesticks" => Gateio::request(self, "{settle}/candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleTickers" => Gateio::request(self, "{settle}/tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicDeliveryGetSettleInsurance" => Gateio::request(self, "{settle}/insurance".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetUnderlyings" => Gateio::request(self, "underlyings".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetExpirations" => Gateio::request(self, "expirations".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetContracts" => Gateio::request(self, "contracts".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetContractsContract" => Gateio::request(self, "contracts/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetSettlements" => Gateio::request(self, "settlements".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetSettlementsContract" => Gateio::request(self, "settlements/{contract}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetOrderBook" => Gateio::request(self, "order_book".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetTickers" => Gateio::request(self, "tickers".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetUnderlyingTickersUnderlying" => Gateio::request(self, "underlying/tickers/{underlying}".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetCandlesticks" => Gateio::request(self, "candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetUnderlyingCandlesticks" => Gateio::request(self, "underlying/candlesticks".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "publicOptionsGetTrades" => Gateio::request(self, "trades".into(), "public".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWithdrawalsPost" => Gateio::request(self, "".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWithdrawalsDeleteWithdrawalId" => Gateio::request(self, "{withdrawal_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetDepositAddress" => Gateio::request(self, "deposit_address".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetWithdrawals" => Gateio::request(self, "withdrawals".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetDeposits" => Gateio::request(self, "deposits".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetSubAccountTransfers" => Gateio::request(self, "sub_account_transfers".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetWithdrawStatus" => Gateio::request(self, "withdraw_status".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetSubAccountBalances" => Gateio::request(self, "sub_account_balances".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetFee" => Gateio::request(self, "fee".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletGetTotalBalance" => Gateio::request(self, "total_balance".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletPostTransfers" => Gateio::request(self, "transfers".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateWalletPostSubAccountTransfers" => Gateio::request(self, "sub_account_transfers".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetAccounts" => Gateio::request(self, "accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetOpenOrders" => Gateio::request(self, "open_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetOrders" => Gateio::request(self, "orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetMyTrades" => Gateio::request(self, "my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetPriceOrders" => Gateio::request(self, "price_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotGetPriceOrdersOrderId" => Gateio::request(self, "price_orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostBatchOrders" => Gateio::request(self, "batch_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostOrders" => Gateio::request(self, "orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostCancelBatchOrders" => Gateio::request(self, "cancel_batch_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotPostPriceOrders" => Gateio::request(self, "price_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeleteOrders" => Gateio::request(self, "orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeleteOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeletePriceOrders" => Gateio::request(self, "price_orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateSpotDeletePriceOrdersOrderId" => Gateio::request(self, "price_orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetAccounts" => Gateio::request(self, "accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetAccountBook" => Gateio::request(self, "account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetFundingAccounts" => Gateio::request(self, "funding_accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoans" => Gateio::request(self, "loans".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoansLoanId" => Gateio::request(self, "loans/{loan_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoansLoanIdRepayment" => Gateio::request(self, "loans/{loan_id}/repayment".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoanRecords" => Gateio::request(self, "loan_records".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoanRecordsLoadRecordId" => Gateio::request(self, "loan_records/{load_record_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetAutoRepay" => Gateio::request(self, "auto_repay".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetTransferable" => Gateio::request(self, "transferable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossAccounts" => Gateio::request(self, "cross/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossAccountBook" => Gateio::request(self, "cross/account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossLoans" => Gateio::request(self, "cross/loans".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossLoansLoanId" => Gateio::request(self, "cross/loans/{loan_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossLoansRepayments" => Gateio::request(self, "cross/loans/repayments".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossTransferable" => Gateio::request(self, "cross/transferable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetLoanRecordsLoanRecordId" => Gateio::request(self, "loan_records/{loan_record_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetBorrowable" => Gateio::request(self, "borrowable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossRepayments" => Gateio::request(self, "cross/repayments".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginGetCrossBorrowable" => Gateio::request(self, "cross/borrowable".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostLoans" => Gateio::request(self, "loans".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostMergedLoans" => Gateio::request(self, "merged_loans".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostLoansLoanIdRepayment" => Gateio::request(self, "loans/{loan_id}/repayment".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostAutoRepay" => Gateio::request(self, "auto_repay".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostCrossLoans" => Gateio::request(self, "cross/loans".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostCrossLoansRepayments" => Gateio::request(self, "cross/loans/repayments".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginPostCrossRepayments" => Gateio::request(self, "cross/repayments".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateMarginDeleteLoansLoanId" => Gateio::request(self, "loans/{loan_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleAccounts" => Gateio::request(self, "{settle}/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleAccountBook" => Gateio::request(self, "{settle}/account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePositions" => Gateio::request(self, "{settle}/positions".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePositionsContract" => Gateio::request(self, "{settle}/positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleOrders" => Gateio::request(self, "{settle}/orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleMyTrades" => Gateio::request(self, "{settle}/my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePositionClose" => Gateio::request(self, "{settle}/position_close".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleLiquidates" => Gateio::request(self, "{settle}/liquidates".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesGetSettleDualCompPositionsContract" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePositionsContractMargin" => Gateio::request(self, "{settle}/positions/{contract}/margin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePositionsContractLeverage" => Gateio::request(self, "{settle}/positions/{contract}/leverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePositionsContractRiskLimit" => Gateio::request(self, "{settle}/positions/{contract}/risk_limit".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualMode" => Gateio::request(self, "{settle}/dual_mode".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContract" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContractMargin" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}/margin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContractLeverage" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}/leverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleDualCompPositionsContractRiskLimit" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}/risk_limit".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettleOrders" => Gateio::request(self, "{settle}/orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesPostSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettleOrders" => Gateio::request(self, "{settle}/orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateFuturesDeleteSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleAccounts" => Gateio::request(self, "{settle}/accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleAccountBook" => Gateio::request(self, "{settle}/account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePositions" => Gateio::request(self, "{settle}/positions".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePositionsContract" => Gateio::request(self, "{settle}/positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleOrders" => Gateio::request(self, "{settle}/orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleMyTrades" => Gateio::request(self, "{settle}/my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePositionClose" => Gateio::request(self, "{settle}/position_close".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleLiquidates" => Gateio::request(self, "{settle}/liquidates".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryGetSettleSettlements" => Gateio::request(self, "{settle}/settlements".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePositionsContractMargin" => Gateio::request(self, "{settle}/positions/{contract}/margin".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePositionsContractLeverage" => Gateio::request(self, "{settle}/positions/{contract}/leverage".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePositionsContractRiskLimit" => Gateio::request(self, "{settle}/positions/{contract}/risk_limit".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettleOrders" => Gateio::request(self, "{settle}/orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryPostSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettleOrders" => Gateio::request(self, "{settle}/orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateDeliveryDeleteSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetAccounts" => Gateio::request(self, "accounts".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetAccountBook" => Gateio::request(self, "account_book".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetPositions" => Gateio::request(self, "positions".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetPositionsContract" => Gateio::request(self, "positions/{contract}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetPositionClose" => Gateio::request(self, "position_close".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetOrders" => Gateio::request(self, "orders".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsGetMyTrades" => Gateio::request(self, "my_trades".into(), "private".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsPostOrders" => Gateio::request(self, "orders".into(), "private".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsDeleteOrders" => Gateio::request(self, "orders".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "privateOptionsDeleteOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), "private".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicWalletGetWalletCurrencyChains" => Gateio::request(self, "wallet/currency_chains".into(), Value::Json(json!(["public", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrencies" => Gateio::request(self, "currencies".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrenciesCurrency" => Gateio::request(self, "currencies/{currency}".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrencyPairs" => Gateio::request(self, "currency_pairs".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCurrencyPairsCurrencyPair" => Gateio::request(self, "currency_pairs/{currency_pair}".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetTickers" => Gateio::request(self, "tickers".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetOrderBook" => Gateio::request(self, "order_book".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetTrades" => Gateio::request(self, "trades".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicSpotGetCandlesticks" => Gateio::request(self, "candlesticks".into(), Value::Json(json!(["public", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCurrencyPairs" => Gateio::request(self, "currency_pairs".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCurrencyPairsCurrencyPair" => Gateio::request(self, "currency_pairs/{currency_pair}".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCrossCurrencies" => Gateio::request(self, "cross/currencies".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetCrossCurrenciesCurrency" => Gateio::request(self, "cross/currencies/{currency}".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicMarginGetFundingBook" => Gateio::request(self, "funding_book".into(), Value::Json(json!(["public", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleContracts" => Gateio::request(self, "{settle}/contracts".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleContractsContract" => Gateio::request(self, "{settle}/contracts/{contract}".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleOrderBook" => Gateio::request(self, "{settle}/order_book".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleTrades" => Gateio::request(self, "{settle}/trades".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleCandlesticks" => Gateio::request(self, "{settle}/candlesticks".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleTickers" => Gateio::request(self, "{settle}/tickers".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleFundingRate" => Gateio::request(self, "{settle}/funding_rate".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleInsurance" => Gateio::request(self, "{settle}/insurance".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleContractStats" => Gateio::request(self, "{settle}/contract_stats".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicFuturesGetSettleLiqOrders" => Gateio::request(self, "{settle}/liq_orders".into(), Value::Json(json!(["public", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleContracts" => Gateio::request(self, "{settle}/contracts".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleContractsContract" => Gateio::request(self, "{settle}/contracts/{contract}".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleOrderBook" => Gateio::request(self, "{settle}/order_book".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleTrades" => Gateio::request(self, "{settle}/trades".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleCandlesticks" => Gateio::request(self, "{settle}/candlesticks".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleTickers" => Gateio::request(self, "{settle}/tickers".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicDeliveryGetSettleInsurance" => Gateio::request(self, "{settle}/insurance".into(), Value::Json(json!(["public", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetUnderlyings" => Gateio::request(self, "underlyings".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetExpirations" => Gateio::request(self, "expirations".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetContracts" => Gateio::request(self, "contracts".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetContractsContract" => Gateio::request(self, "contracts/{contract}".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetSettlements" => Gateio::request(self, "settlements".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetSettlementsContract" => Gateio::request(self, "settlements/{contract}".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetOrderBook" => Gateio::request(self, "order_book".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetTickers" => Gateio::request(self, "tickers".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetUnderlyingTickersUnderlying" => Gateio::request(self, "underlying/tickers/{underlying}".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetCandlesticks" => Gateio::request(self, "candlesticks".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetUnderlyingCandlesticks" => Gateio::request(self, "underlying/candlesticks".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "publicOptionsGetTrades" => Gateio::request(self, "trades".into(), Value::Json(json!(["public", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWithdrawalsPost" => Gateio::request(self, "".into(), Value::Json(json!(["private", "withdrawals"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWithdrawalsDeleteWithdrawalId" => Gateio::request(self, "{withdrawal_id}".into(), Value::Json(json!(["private", "withdrawals"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetDepositAddress" => Gateio::request(self, "deposit_address".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetWithdrawals" => Gateio::request(self, "withdrawals".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetDeposits" => Gateio::request(self, "deposits".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetSubAccountTransfers" => Gateio::request(self, "sub_account_transfers".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetWithdrawStatus" => Gateio::request(self, "withdraw_status".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetSubAccountBalances" => Gateio::request(self, "sub_account_balances".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetFee" => Gateio::request(self, "fee".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletGetTotalBalance" => Gateio::request(self, "total_balance".into(), Value::Json(json!(["private", "wallet"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletPostTransfers" => Gateio::request(self, "transfers".into(), Value::Json(json!(["private", "wallet"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateWalletPostSubAccountTransfers" => Gateio::request(self, "sub_account_transfers".into(), Value::Json(json!(["private", "wallet"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetAccounts" => Gateio::request(self, "accounts".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetOpenOrders" => Gateio::request(self, "open_orders".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetOrders" => Gateio::request(self, "orders".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetMyTrades" => Gateio::request(self, "my_trades".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetPriceOrders" => Gateio::request(self, "price_orders".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotGetPriceOrdersOrderId" => Gateio::request(self, "price_orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostBatchOrders" => Gateio::request(self, "batch_orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostOrders" => Gateio::request(self, "orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostCancelBatchOrders" => Gateio::request(self, "cancel_batch_orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotPostPriceOrders" => Gateio::request(self, "price_orders".into(), Value::Json(json!(["private", "spot"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeleteOrders" => Gateio::request(self, "orders".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeleteOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeletePriceOrders" => Gateio::request(self, "price_orders".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateSpotDeletePriceOrdersOrderId" => Gateio::request(self, "price_orders/{order_id}".into(), Value::Json(json!(["private", "spot"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetAccounts" => Gateio::request(self, "accounts".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetAccountBook" => Gateio::request(self, "account_book".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetFundingAccounts" => Gateio::request(self, "funding_accounts".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoans" => Gateio::request(self, "loans".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoansLoanId" => Gateio::request(self, "loans/{loan_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoansLoanIdRepayment" => Gateio::request(self, "loans/{loan_id}/repayment".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoanRecords" => Gateio::request(self, "loan_records".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoanRecordsLoadRecordId" => Gateio::request(self, "loan_records/{load_record_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetAutoRepay" => Gateio::request(self, "auto_repay".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetTransferable" => Gateio::request(self, "transferable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossAccounts" => Gateio::request(self, "cross/accounts".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossAccountBook" => Gateio::request(self, "cross/account_book".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossLoans" => Gateio::request(self, "cross/loans".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossLoansLoanId" => Gateio::request(self, "cross/loans/{loan_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossLoansRepayments" => Gateio::request(self, "cross/loans/repayments".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossTransferable" => Gateio::request(self, "cross/transferable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetLoanRecordsLoanRecordId" => Gateio::request(self, "loan_records/{loan_record_id}".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetBorrowable" => Gateio::request(self, "borrowable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossRepayments" => Gateio::request(self, "cross/repayments".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginGetCrossBorrowable" => Gateio::request(self, "cross/borrowable".into(), Value::Json(json!(["private", "margin"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostLoans" => Gateio::request(self, "loans".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostMergedLoans" => Gateio::request(self, "merged_loans".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostLoansLoanIdRepayment" => Gateio::request(self, "loans/{loan_id}/repayment".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostAutoRepay" => Gateio::request(self, "auto_repay".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostCrossLoans" => Gateio::request(self, "cross/loans".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostCrossLoansRepayments" => Gateio::request(self, "cross/loans/repayments".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginPostCrossRepayments" => Gateio::request(self, "cross/repayments".into(), Value::Json(json!(["private", "margin"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateMarginDeleteLoansLoanId" => Gateio::request(self, "loans/{loan_id}".into(), Value::Json(json!(["private", "margin"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleAccounts" => Gateio::request(self, "{settle}/accounts".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleAccountBook" => Gateio::request(self, "{settle}/account_book".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePositions" => Gateio::request(self, "{settle}/positions".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePositionsContract" => Gateio::request(self, "{settle}/positions/{contract}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleOrders" => Gateio::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleMyTrades" => Gateio::request(self, "{settle}/my_trades".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePositionClose" => Gateio::request(self, "{settle}/position_close".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleLiquidates" => Gateio::request(self, "{settle}/liquidates".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesGetSettleDualCompPositionsContract" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}".into(), Value::Json(json!(["private", "futures"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePositionsContractMargin" => Gateio::request(self, "{settle}/positions/{contract}/margin".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePositionsContractLeverage" => Gateio::request(self, "{settle}/positions/{contract}/leverage".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePositionsContractRiskLimit" => Gateio::request(self, "{settle}/positions/{contract}/risk_limit".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualMode" => Gateio::request(self, "{settle}/dual_mode".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContract" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContractMargin" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}/margin".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContractLeverage" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}/leverage".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleDualCompPositionsContractRiskLimit" => Gateio::request(self, "{settle}/dual_comp/positions/{contract}/risk_limit".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettleOrders" => Gateio::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesPostSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "futures"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettleOrders" => Gateio::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateFuturesDeleteSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "futures"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleAccounts" => Gateio::request(self, "{settle}/accounts".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleAccountBook" => Gateio::request(self, "{settle}/account_book".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePositions" => Gateio::request(self, "{settle}/positions".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePositionsContract" => Gateio::request(self, "{settle}/positions/{contract}".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleOrders" => Gateio::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleMyTrades" => Gateio::request(self, "{settle}/my_trades".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePositionClose" => Gateio::request(self, "{settle}/position_close".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleLiquidates" => Gateio::request(self, "{settle}/liquidates".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryGetSettleSettlements" => Gateio::request(self, "{settle}/settlements".into(), Value::Json(json!(["private", "delivery"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePositionsContractMargin" => Gateio::request(self, "{settle}/positions/{contract}/margin".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePositionsContractLeverage" => Gateio::request(self, "{settle}/positions/{contract}/leverage".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePositionsContractRiskLimit" => Gateio::request(self, "{settle}/positions/{contract}/risk_limit".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettleOrders" => Gateio::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryPostSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "delivery"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettleOrders" => Gateio::request(self, "{settle}/orders".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettleOrdersOrderId" => Gateio::request(self, "{settle}/orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettlePriceOrders" => Gateio::request(self, "{settle}/price_orders".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateDeliveryDeleteSettlePriceOrdersOrderId" => Gateio::request(self, "{settle}/price_orders/{order_id}".into(), Value::Json(json!(["private", "delivery"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetAccounts" => Gateio::request(self, "accounts".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetAccountBook" => Gateio::request(self, "account_book".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetPositions" => Gateio::request(self, "positions".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetPositionsContract" => Gateio::request(self, "positions/{contract}".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetPositionClose" => Gateio::request(self, "position_close".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetOrders" => Gateio::request(self, "orders".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsGetMyTrades" => Gateio::request(self, "my_trades".into(), Value::Json(json!(["private", "options"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsPostOrders" => Gateio::request(self, "orders".into(), Value::Json(json!(["private", "options"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsDeleteOrders" => Gateio::request(self, "orders".into(), Value::Json(json!(["private", "options"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "privateOptionsDeleteOrdersOrderId" => Gateio::request(self, "orders/{order_id}".into(), Value::Json(json!(["private", "options"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
                     _ => unimplemented!(),
                 }
             },
@@ -3158,8 +3149,9 @@ pub trait Gateio : gate {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct GateioImpl(Value);
+pub struct GateioImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for GateioImpl {}
+impl Gate for GateioImpl {}
 impl Gateio for GateioImpl {}
 impl ValueTrait for GateioImpl {
     fn is_undefined(&self) -> bool { self.0.is_undefined() }
@@ -3180,8 +3172,8 @@ impl ValueTrait for GateioImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -3200,7 +3192,8 @@ impl GateioImpl {
         let mut rv = GateioImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Gateio::describe(&rv);
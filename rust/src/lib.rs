@@ -1,3 +1,42 @@
 pub mod exchange;
+pub mod capabilities;
+pub mod credentials;
+pub mod errors;
+pub mod logging;
+pub mod markets;
+pub mod nonce;
+pub mod order_request;
+pub mod retry;
+pub mod shared;
+pub mod symbol;
+pub mod throttle;
+pub mod types;
+#[cfg(feature = "gate")]
 pub mod gate;
+#[cfg(feature = "binance")]
 pub mod binance;
+#[cfg(feature = "phemex")]
+pub mod phemex;
+#[cfg(feature = "bitget")]
+pub mod bitget;
+#[cfg(feature = "mexc3")]
+pub mod mexc3;
+#[cfg(feature = "cryptocom")]
+pub mod cryptocom;
+#[cfg(feature = "coinex")]
+pub mod coinex;
+#[cfg(feature = "gateio")]
+pub mod gateio;
+#[cfg(feature = "okx")]
+pub mod okx;
+pub mod unified;
+#[cfg(feature = "pro")]
+pub mod pro;
+
+pub use capabilities::{Capabilities, Support};
+pub use credentials::Credentials;
+pub use errors::{Error, Result};
+pub use order_request::OrderRequest;
+pub use shared::SharedExchange;
+pub use symbol::Symbol;
+pub use unified::{exchanges, exchanges_supporting, new_exchange, UnifiedExchange};
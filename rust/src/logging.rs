@@ -0,0 +1,84 @@
+/// Parts of a key, compared without case, `-` or `_`, that mark a query parameter, header or body field as
+/// sensitive, e.g. `X-MBX-APIKEY`, `OK-ACCESS-SIGN`, `signature` or `passphrase`
+const SENSITIVE: &[&str] = &["key", "secret", "sign", "passphrase", "password", "token", "authorization", "cookie", "twofa"];
+
+pub const REDACTED: &str = "***";
+
+pub fn is_sensitive(key: &str) -> bool {
+    let key: String = key.chars().filter(|c| *c != '-' && *c != '_').collect::<String>().to_lowercase();
+    SENSITIVE.iter().any(|part| key.contains(part))
+}
+
+// credentials can end up in any part of a request, e.g. a secret used as a passphrase header
+fn redact_secrets(text: &str, secrets: &[String]) -> String {
+    secrets.iter()
+        .filter(|secret| !secret.is_empty())
+        .fold(text.to_owned(), |text, secret| text.replace(secret.as_str(), REDACTED))
+}
+
+fn redact_query(query: &str) -> String {
+    query.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_sensitive(&urlencoding::decode(key).map(|k| k.into_owned()).unwrap_or_default()) => {
+                format!("{}={}", key, REDACTED)
+            }
+            _ => pair.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn redact_json(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(o) => serde_json::Value::Object(o.iter()
+            .map(|(k, v)| (k.clone(), if is_sensitive(k) && !v.is_object() && !v.is_array() { REDACTED.into() } else { redact_json(v) }))
+            .collect()),
+        serde_json::Value::Array(a) => serde_json::Value::Array(a.iter().map(redact_json).collect()),
+        _ => value.clone(),
+    }
+}
+
+/// The url with the values of sensitive query parameters and of the credentials replaced by `***`
+pub fn redact_url(url: &str, secrets: &[String]) -> String {
+    let url = match url.split_once('?') {
+        Some((path, query)) => format!("{}?{}", path, redact_query(query)),
+        None => url.to_owned(),
+    };
+    redact_secrets(&url, secrets)
+}
+
+pub fn redact_headers(headers: &serde_json::Value, secrets: &[String]) -> serde_json::Value {
+    match headers {
+        serde_json::Value::Object(o) => serde_json::Value::Object(o.iter()
+            .map(|(k, v)| (k.clone(), match v {
+                _ if is_sensitive(k) => REDACTED.into(),
+                serde_json::Value::String(s) => redact_secrets(s, secrets).into(),
+                _ => v.clone(),
+            }))
+            .collect()),
+        _ => headers.clone(),
+    }
+}
+
+/// Redacts a JSON or form encoded body
+pub fn redact_body(body: &str, secrets: &[String]) -> String {
+    let body = match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(json) if json.is_object() || json.is_array() => redact_json(&json).to_string(),
+        _ if body.contains('=') => redact_query(body),
+        _ => body.to_owned(),
+    };
+    redact_secrets(&body, secrets)
+}
+
+/// An event at the info level when the exchange is `verbose` and at the debug level otherwise
+macro_rules! verbose_event {
+    ($verbose:expr, $($arg:tt)*) => {
+        if $verbose {
+            tracing::info!($($arg)*)
+        } else {
+            tracing::debug!($($arg)*)
+        }
+    };
+}
+
+pub(crate) use verbose_event;
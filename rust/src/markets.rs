@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// The exchange state written by `set_markets`
+pub const MARKETS_KEYS: &[&str] = &[
+    "markets", "markets_by_id", "symbols", "ids", "currencies", "currencies_by_id", "base_currencies", "quote_currencies", "codes",
+];
+
+/// The markets last loaded by one of the copies of an exchange that share a `marketsLoader`, e.g. the snapshots of a
+/// `SharedExchange`, `load_markets` holds the lock while it fetches so that concurrent callers wait and reuse them
+#[derive(Debug, Default, Clone)]
+pub struct MarketsCache {
+    /// milliseconds timestamp of the last load
+    pub loaded_at: Option<u64>,
+    /// the `MARKETS_KEYS`, replaced as a whole on each load so that readers can hold on to them without copying
+    pub state: Arc<serde_json::Map<String, serde_json::Value>>,
+}
+
+pub type MarketsLoader = Arc<tokio::sync::Mutex<MarketsCache>>;
+
+fn loaders() -> &'static Mutex<HashMap<String, MarketsLoader>> {
+    static LOADERS: OnceLock<Mutex<HashMap<String, MarketsLoader>>> = OnceLock::new();
+    LOADERS.get_or_init(Default::default)
+}
+
+/// A new loader and the key the exchange state refers to it by
+pub fn register() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let key = NEXT.fetch_add(1, Ordering::Relaxed).to_string();
+    loaders().lock().unwrap().insert(key.clone(), Default::default());
+    key
+}
+
+pub fn unregister(key: &str) {
+    loaders().lock().unwrap().remove(key);
+}
+
+/// The loader of `key`, or a private one when the exchange is not shared
+pub fn loader(key: Option<&str>) -> MarketsLoader {
+    key.and_then(|key| loaders().lock().unwrap().get(key).cloned()).unwrap_or_default()
+}
+
+/// Bumped whenever the layout of the cache files changes, files written by other versions are ignored
+pub const MARKETS_CACHE_VERSION: u64 = 1;
+
+/// The cache file of an exchange in `dir`, sandbox markets are kept apart from the production ones
+pub fn cache_file(dir: &str, id: &str, sandbox: bool) -> PathBuf {
+    Path::new(dir).join(if sandbox { format!("{}-sandbox.json", id) } else { format!("{}.json", id) })
+}
+
+/// The markets saved in `path` at most `max_age` milliseconds before `now`, with the time they were loaded
+pub fn read_cache_file(path: &Path, max_age: u64, now: u64) -> Option<(u64, serde_json::Map<String, serde_json::Value>)> {
+    let file: serde_json::Value = serde_json::from_slice(&fs::read(path).ok()?).ok()?;
+    if file["version"].as_u64() != Some(MARKETS_CACHE_VERSION) || file["ccxt"].as_str() != Some(env!("CARGO_PKG_VERSION")) {
+        return None;
+    }
+    let loaded_at = file["loadedAt"].as_u64()?;
+    if now.saturating_sub(loaded_at) >= max_age {
+        return None;
+    }
+    match file.get("state") {
+        Some(serde_json::Value::Object(state)) => Some((loaded_at, state.clone())),
+        _ => None,
+    }
+}
+
+/// Saves the markets to `path`, through a temporary file so that concurrent readers never see half of it
+pub fn write_cache_file(path: &Path, loaded_at: u64, state: &serde_json::Map<String, serde_json::Value>) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let file = serde_json::json!({
+        "version": MARKETS_CACHE_VERSION,
+        "ccxt": env!("CARGO_PKG_VERSION"),
+        "loadedAt": loaded_at,
+        "state": state,
+    });
+    let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+    fs::write(&tmp, serde_json::to_vec(&file)?)?;
+    fs::rename(&tmp, path)
+}
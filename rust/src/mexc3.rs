@@ -11,10 +11,11 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, parse_float, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -870,7 +871,7 @@ pub trait Mexc3 : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -936,7 +937,7 @@ pub trait Mexc3 : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1190,7 +1191,7 @@ pub trait Mexc3 : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut options: Value = self.safe_value(self.get("options".into()), Value::from("timeframes"), Value::new_object());
         let mut timeframes: Value = self.safe_value(options.clone(), market.get(Value::from("type")), Value::new_object());
         let mut timeframe_value: Value = self.safe_string(timeframes.clone(), timeframe.clone(), Value::Undefined);
@@ -1275,9 +1276,9 @@ pub trait Mexc3 : Exchange {
         let mut is_singular_market: Value = false.into();
         if symbols.clone().is_nonnullish() {
             let mut length: Value = symbols.len().into();
-            is_singular_market = (Value::from(len()) == Value::from(1)).into();
+            is_singular_market = (length.clone() == Value::from(1)).into();
             let mut first_symbol: Value = self.safe_string(symbols.clone(), Value::from(0), Value::Undefined);
-            market = Mexc3::market(self, first_symbol.clone());
+            market = self.market(first_symbol.clone());
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchTickers"), market.clone(), params.clone()));
         let mut tickers: Value = Value::Undefined;
@@ -1359,7 +1360,7 @@ pub trait Mexc3 : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchTicker"), market.clone(), params.clone()));
         let mut ticker: Value = Value::Undefined;
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -1554,8 +1555,8 @@ pub trait Mexc3 : Exchange {
         let mut is_singular_market: Value = false.into();
         if symbols.clone().is_nonnullish() {
             let mut length: Value = symbols.len().into();
-            is_singular_market = (Value::from(len()) == Value::from(1)).into();
-            market = Mexc3::market(self, symbols.get(Value::from(0)));
+            is_singular_market = (length.clone() == Value::from(1)).into();
+            market = self.market(symbols.get(Value::from(0)));
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchBidsAsks"), market.clone(), params.clone()));
         let mut tickers: Value = Value::Undefined;
@@ -1573,7 +1574,7 @@ pub trait Mexc3 : Exchange {
             //       },
             //     ]
             //
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not available for ") + market_type.clone() + Value::from(" markets"))"###);
+            panic!("NotSupported::new({} fetchBidsAsks() is not available for {} markets)", self.get("id".into()).unwrap_str(), js_string(&market_type));
         };
         // when it's single symbol request, the returned structure is different (singular object) for both spot & swap, thus we need to wrap inside array
         if is_singular_market.is_truthy() {
@@ -1597,7 +1598,7 @@ pub trait Mexc3 : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.get(Value::from("spot")).is_truthy() {
             return Mexc3::create_spot_order(self, market.clone(), r#type.clone(), side.clone(), amount.clone(), price.clone(), params.clone()).await;
         } else if market.get(Value::from("swap")).is_truthy() {
@@ -1621,7 +1622,7 @@ pub trait Mexc3 : Exchange {
                 amount = quote_order_qty.clone();
             } else if self.get("options".into()).get(Value::from("createMarketBuyOrderRequiresPrice")).is_truthy() {
                 if price.clone().is_nullish() {
-                    panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() requires the price argument with market buy orders to calculate total order cost (amount to spend), where cost = amount * price. Supply a price argument to createOrder() call if you want the cost to be calculated for you from price and amount, or, alternatively, add .options['createMarketBuyOrderRequiresPrice'] = false to supply the cost in the amount argument (the exchange-specific behaviour)"))"###);
+                    panic!("InvalidOrder::new({} createOrder() requires the price argument with market buy orders to calculate total order cost (amount to spend), where cost = amount * price. Supply a price argument to createOrder() call if you want the cost to be calculated for you from price and amount, or, alternatively, add .options['createMarketBuyOrderRequiresPrice'] = false to supply the cost in the amount argument (the exchange-specific behaviour))", self.get("id".into()).unwrap_str());
                 } else {
                     amount = amount.clone() * price.clone();
                 };
@@ -1663,7 +1664,7 @@ pub trait Mexc3 : Exchange {
         let mut unavailable_contracts: Value = self.safe_value(self.get("options".into()), Value::from("unavailableContracts"), Value::new_object());
         let mut is_contract_unavaiable: Value = self.safe_value(unavailable_contracts.clone(), symbol.clone(), false.into());
         if is_contract_unavaiable.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createSwapOrder() does not support yet this symbol:") + symbol.clone())"###);
+            panic!("NotSupported::new({} createSwapOrder() does not support yet this symbol:{})", self.get("id".into()).unwrap_str(), js_string(&symbol));
         };
         let mut open_type: Value = Value::Undefined;
         let mut margin_type: Value = self.safe_string_lower(params.clone(), Value::from("margin"), Value::Undefined);
@@ -1673,14 +1674,14 @@ pub trait Mexc3 : Exchange {
             } else if margin_type.clone() == Value::from("isolated") {
                 open_type = Value::from(1);
             } else {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(r#" createSwapOrder() margin parameter should be either "cross" or "isolated""#))"###);
+                panic!("ArgumentsRequired::new({} createSwapOrder() margin parameter should be either \"cross\" or \"isolated\")", self.get("id".into()).unwrap_str());
             };
         } else {
             open_type = self.safe_integer(params.clone(), Value::from("openType"), Value::from(2));
         };
         // defaulting to cross margin
         if r#type.clone() != Value::from("limit") && r#type.clone() != Value::from("market") && r#type.clone() != Value::from(1) && r#type.clone() != Value::from(2) && r#type.clone() != Value::from(3) && r#type.clone() != Value::from(4) && r#type.clone() != Value::from(5) && r#type.clone() != Value::from(6) {
-            panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createSwapOrder() order type must either limit, market, or 1 for limit orders, 2 for post-only orders, 3 for IOC orders, 4 for FOK orders, 5 for market orders or 6 to convert market price to current price"))"###);
+            panic!("InvalidOrder::new({} createSwapOrder() order type must either limit, market, or 1 for limit orders, 2 for post-only orders, 3 for IOC orders, 4 for FOK orders, 5 for market orders or 6 to convert market price to current price)", self.get("id".into()).unwrap_str());
         };
         let mut post_only: Value = self.safe_value(params.clone(), Value::from("postOnly"), false.into());
         if post_only.is_truthy() {
@@ -1692,7 +1693,7 @@ pub trait Mexc3 : Exchange {
         };
         // TODO: side not unified
         if side.clone() != Value::from(1) && side.clone() != Value::from(2) && side.clone() != Value::from(3) && side.clone() != Value::from(4) {
-            panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createSwapOrder() order side must be 1 open long, 2 close short, 3 open short or 4 close long"))"###);
+            panic!("InvalidOrder::new({} createSwapOrder() order side must be 1 open long, 2 close short, 3 open short or 4 close long)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
@@ -1739,7 +1740,7 @@ pub trait Mexc3 : Exchange {
         if open_type.clone() == Value::from(1) {
             let mut leverage: Value = self.safe_integer(params.clone(), Value::from("leverage"), Value::Undefined);
             if leverage.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" createSwapOrder() requires a leverage parameter for isolated margin orders"))"###);
+                panic!("ArgumentsRequired::new({} createSwapOrder() requires a leverage parameter for isolated margin orders)", self.get("id".into()).unwrap_str());
             };
         };
         let mut client_order_id: Value = self.safe_string_2(params.clone(), Value::from("clientOrderId"), Value::from("externalOid"), Value::Undefined);
@@ -1770,10 +1771,10 @@ pub trait Mexc3 : Exchange {
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1865,13 +1866,13 @@ pub trait Mexc3 : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchOrders"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrders() requires a symbol argument for spot market"))"###);
+                panic!("ArgumentsRequired::new({} fetchOrders() requires a symbol argument for spot market)", self.get("id".into()).unwrap_str());
             };
             if since.clone().is_nonnullish() {
                 request.set("startTime".into(), since.clone());
@@ -2000,12 +2001,12 @@ pub trait Mexc3 : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchOrdersByIds"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchOrdersByIds() is not supported for ") + market_type.clone())"###);
+            panic!("BadRequest::new({} fetchOrdersByIds() is not supported for {})", self.get("id".into()).unwrap_str(), js_string(&market_type));
         } else {
             request.set("order_ids".into(), ids.join(Value::from(",")));
             let mut response: Value = Mexc3::dispatch(self, "contractPrivateGetOrderBatchQuery".into(), extend_2(request.clone(), query.clone()), Value::Undefined).await;
@@ -2065,13 +2066,13 @@ pub trait Mexc3 : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchOpenOrders"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOpenOrders() requires a symbol argument for spot market"))"###);
+                panic!("ArgumentsRequired::new({} fetchOpenOrders() requires a symbol argument for spot market)", self.get("id".into()).unwrap_str());
             };
             let mut response: Value = Mexc3::dispatch(self, "spotPrivateGetOpenOrders".into(), extend_2(request.clone(), query.clone()), Value::Undefined).await;
             //
@@ -2144,15 +2145,15 @@ pub trait Mexc3 : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
         };
-        let (mut market_type) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchOrdersByState"), market.clone(), params.clone()));
+        let (mut market_type, _) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchOrdersByState"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchOrdersByState() is not supported for ") + market_type.clone())"###);
+            panic!("BadRequest::new({} fetchOrdersByState() is not supported for {})", self.get("id".into()).unwrap_str(), js_string(&market_type));
         } else {
             params.set("states".into(), state.clone());
-            return Mexc3::fetch_orders(self, symbol.clone(), since.clone(), limit.clone(), params.clone());
+            return Mexc3::fetch_orders(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
         };
         Value::Undefined
     }
@@ -2172,14 +2173,14 @@ pub trait Mexc3 : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("cancelOrder"), market.clone(), params.clone()));
         let mut data: Value = Value::Undefined;
         if market_type.clone() == Value::from("spot") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument"))"###);
+                panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
             };
             let mut request: Value = Value::Json(normalize(&Value::Json(json!({
                 "symbol": market.get(Value::from("id"))
@@ -2226,7 +2227,7 @@ pub trait Mexc3 : Exchange {
             let mut order: Value = self.safe_value(data.clone(), Value::from(0), Value::Undefined);
             let mut error_msg: Value = self.safe_value(order.clone(), Value::from("errorMsg"), Value::from(""));
             if error_msg.clone() != Value::from("success") {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" cancelOrder() the order with id ") + id.clone() + Value::from(" cannot be cancelled: ") + error_msg.clone())"###);
+                panic!("InvalidOrder::new({} cancelOrder() the order with id {} cannot be cancelled: {})", self.get("id".into()).unwrap_str(), js_string(&id), js_string(&error_msg));
             };
         };
         return Mexc3::parse_order(self, data.clone(), market.clone());
@@ -2244,10 +2245,10 @@ pub trait Mexc3 : Exchange {
     async fn cancel_orders(&mut self, mut ids: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = if symbol.clone().is_nonnullish() { Mexc3::market(self, symbol.clone()) } else { Value::Undefined };
-        let (mut market_type) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("cancelOrders"), market.clone(), params.clone()));
+        let mut market: Value = if symbol.clone().is_nonnullish() { self.market(symbol.clone()) } else { Value::Undefined };
+        let (mut market_type, _) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("cancelOrders"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" cancelOrders() is not supported for ") + market_type.clone())"###);
+            panic!("BadRequest::new({} cancelOrders() is not supported for {})", self.get("id".into()).unwrap_str(), js_string(&market_type));
         } else {
             let mut response: Value = Mexc3::dispatch(self, "contractPrivatePostOrderCancel".into(), ids.clone(), Value::Undefined).await;
             // the request cannot be changed or extended. The only way to send.
@@ -2281,12 +2282,12 @@ pub trait Mexc3 : Exchange {
     async fn cancel_all_orders(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = if symbol.clone().is_nonnullish() { Mexc3::market(self, symbol.clone()) } else { Value::Undefined };
+        let mut market: Value = if symbol.clone().is_nonnullish() { self.market(symbol.clone()) } else { Value::Undefined };
         let mut request: Value = Value::new_object();
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("cancelAllOrders"), market.clone(), params.clone()));
         if market_type.clone() == Value::from("spot") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelAllOrders() requires a symbol argument on spot"))"###);
+                panic!("ArgumentsRequired::new({} cancelAllOrders() requires a symbol argument on spot)", self.get("id".into()).unwrap_str());
             };
             request.set("symbol".into(), market.get(Value::from("id")));
             let mut response: Value = Mexc3::dispatch(self, "spotPrivateDeleteOpenOrders".into(), extend_2(request.clone(), query.clone()), Value::Undefined).await;
@@ -2538,7 +2539,7 @@ pub trait Mexc3 : Exchange {
             //         ]
             //     }
             //
-            let mut response: Value = Mexc3::dispatch(self, "contractPrivateGetAccountAssets".into(), params.clone(), Value::Undefined);
+            let mut response: Value = Mexc3::dispatch(self, "contractPrivateGetAccountAssets".into(), params.clone(), Value::Undefined).await;
             //
             //     {
             //         "success":true,
@@ -2695,10 +2696,10 @@ pub trait Mexc3 : Exchange {
     async fn fetch_my_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchMyTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchMyTrades"), market.clone(), params.clone()));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2790,13 +2791,13 @@ pub trait Mexc3 : Exchange {
         let mut request: Value = Value::new_object();
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
         };
         let (mut market_type, mut query) = shift_2(Mexc3::handle_market_type_and_params(self, Value::from("fetchOrderTrades"), market.clone(), params.clone()));
         let mut trades: Value = Value::Undefined;
         if market_type.clone() == Value::from("spot") {
             if symbol.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrderTrades() requires a symbol argument"))"###);
+                panic!("ArgumentsRequired::new({} fetchOrderTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
             };
             request.set("symbol".into(), market.get(Value::from("id")));
             request.set("orderId".into(), id.clone());
@@ -2857,7 +2858,7 @@ pub trait Mexc3 : Exchange {
         params = params.or_default(Value::new_object());
         let mut position_id: Value = self.safe_integer(params.clone(), Value::from("positionId"), Value::Undefined);
         if position_id.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" modifyMarginHelper() requires a positionId parameter"))"###);
+            panic!("ArgumentsRequired::new({} modifyMarginHelper() requires a positionId parameter)", self.get("id".into()).unwrap_str());
         };
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -2923,9 +2924,9 @@ pub trait Mexc3 : Exchange {
             // 1 or 2
             let mut position_type: Value = Mexc3::safe_number(self, params.clone(), Value::from("positionType"), Value::Undefined);
             // 1 or 2
-            let mut market: Value = if symbol.clone().is_nonnullish() { Mexc3::market(self, symbol.clone()) } else { Value::Undefined };
+            let mut market: Value = if symbol.clone().is_nonnullish() { self.market(symbol.clone()) } else { Value::Undefined };
             if open_type.clone().is_nullish() || position_type.clone().is_nullish() || market.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a positionId parameter or a symbol argument with openType and positionType parameters, use openType 1 or 2 for isolated or cross margin respectively, use positionType 1 or 2 for long or short positions"))"###);
+                panic!("ArgumentsRequired::new({} setLeverage() requires a positionId parameter or a symbol argument with openType and positionType parameters, use openType 1 or 2 for isolated or cross margin respectively, use positionType 1 or 2 for long or short positions)", self.get("id".into()).unwrap_str());
             } else {
                 request.set("openType".into(), open_type.clone());
                 request.set("symbol".into(), market.get(Value::from("symbol")));
@@ -2957,7 +2958,7 @@ pub trait Mexc3 : Exchange {
         // 'page_num': 1,
         // 'page_size': limit, // default 20, max 100
         if symbol.clone().is_nonnullish() {
-            market = Mexc3::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("symbol".into(), market.get(Value::from("id")));
         };
         if limit.clone().is_nonnullish() {
@@ -3032,7 +3033,7 @@ pub trait Mexc3 : Exchange {
         let mut next_funding_rate: Value = Mexc3::safe_number(self, contract.clone(), Value::from("fundingRate"), Value::Undefined);
         let mut next_funding_timestamp: Value = self.safe_integer(contract.clone(), Value::from("nextSettleTime"), Value::Undefined);
         let mut market_id: Value = self.safe_string(contract.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Mexc3::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut timestamp: Value = self.safe_integer(contract.clone(), Value::from("timestamp"), Value::Undefined);
         let mut datetime: Value = self.iso8601(timestamp.clone());
         return Value::Json(normalize(&Value::Json(json!({
@@ -3067,7 +3068,7 @@ pub trait Mexc3 : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -3104,10 +3105,10 @@ pub trait Mexc3 : Exchange {
     async fn fetch_funding_rate_history(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingRateHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -3148,7 +3149,7 @@ pub trait Mexc3 : Exchange {
         while i < result.len() {
             let mut entry: Value = result.get(i.into());
             let mut market_id: Value = self.safe_string(entry.clone(), Value::from("symbol"), Value::Undefined);
-            let mut symbol: Value = Mexc3::safe_symbol(self, market_id.clone(), Value::Undefined, Value::Undefined);
+            let mut symbol: Value = self.safe_symbol(market_id.clone(), Value::Undefined, Value::Undefined);
             let mut timestamp: Value = self.safe_integer(entry.clone(), Value::from("settleTime"), Value::Undefined);
             rates.push(Value::Json(normalize(&Value::Json(json!({
                 "info": entry,
@@ -3224,6 +3225,7 @@ pub trait Mexc3 : Exchange {
         return Mexc3::parse_leverage_tiers(self, data.clone(), symbols.clone(), Value::from("symbol"));
     }
 
+    /// ```text
     ///             {
     ///                 "symbol": "BTC_USDT",
     ///                 "displayName": "BTC_USDT永续",
@@ -3260,6 +3262,7 @@ pub trait Mexc3 : Exchange {
     ///                 "isHot": true,
     ///                 "isHidden": false
     ///             }
+    /// ```
     ///
     /// # Arguments
     ///
@@ -3387,7 +3390,7 @@ pub trait Mexc3 : Exchange {
                         let mut values: Value = Object::values(response.clone());
                         result = self.safe_value(values.clone(), Value::from(0), Value::Undefined);
                         if result.clone().is_nullish() {
-                            panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() cannot find deposit address for ") + code.clone())"###);
+                            panic!("InvalidAddress::new({} fetchDepositAddress() cannot find deposit address for {})", self.get("id".into()).unwrap_str(), js_string(&code));
                         };
                     };
                 };
@@ -3397,7 +3400,7 @@ pub trait Mexc3 : Exchange {
         // TODO: add support for all aliases here
         result = self.safe_value(response.clone(), raw_network.clone(), Value::Undefined);
         if result.clone().is_nullish() {
-            panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() cannot find ") + network.clone() + Value::from(" deposit address for ") + code.clone())"###);
+            panic!("InvalidAddress::new({} fetchDepositAddress() cannot find {} deposit address for {})", self.get("id".into()).unwrap_str(), js_string(&network), js_string(&code));
         };
         return result.clone();
     }
@@ -3427,7 +3430,7 @@ pub trait Mexc3 : Exchange {
             let mut raw_network: Value = self.safe_string(params.clone(), Value::from("network"), Value::Undefined);
             params = self.omit(params.clone(), Value::from("network"));
             if raw_network.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchDeposits() requires a network parameter when the currency is specified"))"###);
+                panic!("ArgumentsRequired::new({} fetchDeposits() requires a network parameter when the currency is specified)", self.get("id".into()).unwrap_str());
             };
             // currently mexc does not have network names unified so for certain things we might need TRX or TRC-20
             // due to that I'm applying the network parameter directly so the user can control it on its side
@@ -3639,7 +3642,7 @@ pub trait Mexc3 : Exchange {
     async fn fetch_position(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -3780,7 +3783,7 @@ pub trait Mexc3 : Exchange {
             let mut data: Value = self.safe_value(response.clone(), Value::from("data"), Value::new_object());
             return Mexc3::parse_transfer(self, data.clone(), Value::Undefined);
         } else if market_type.clone() == Value::from("swap") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchTransfer() is not supported for ") + market_type.clone())"###);
+            panic!("BadRequest::new({} fetchTransfer() is not supported for {})", self.get("id".into()).unwrap_str(), js_string(&market_type));
         };
         Value::Undefined
     }
@@ -3812,7 +3815,7 @@ pub trait Mexc3 : Exchange {
             };
             if limit.clone().is_nonnullish() {
                 if limit.clone() > Value::from(50) {
-                    panic!(r###"ExchangeError::new("This exchange supports a maximum limit of 50")"###);
+                    panic!("ExchangeError::new(This exchange supports a maximum limit of 50)");
                 };
                 request.set("page-size".into(), limit.clone());
             };
@@ -3896,11 +3899,11 @@ pub trait Mexc3 : Exchange {
         let mut to_id: Value = self.safe_string(accounts.clone(), to_account.clone(), Value::Undefined);
         if from_id.clone().is_nullish() {
             let mut keys: Value = Object::keys(accounts.clone());
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fromAccount must be one of ") + keys.join(Value::from(", ")))"###);
+            panic!("ExchangeError::new({} fromAccount must be one of {})", self.get("id".into()).unwrap_str(), js_string(&keys.join(Value::from(", "))));
         };
         if to_id.clone().is_nullish() {
             let mut keys: Value = Object::keys(accounts.clone());
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" toAccount must be one of ") + keys.join(Value::from(", ")))"###);
+            panic!("ExchangeError::new({} toAccount must be one of {})", self.get("id".into()).unwrap_str(), js_string(&keys.join(Value::from(", "))));
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "currency": currency.get(Value::from("id")),
@@ -4168,7 +4171,7 @@ pub trait Mexc3 : Exchange {
             let mut feedback: Value = self.get("id".into()) + Value::from(" ") + body.clone();
             Mexc3::throw_broadly_matched_exception(self, self.get("exceptions".into()).get(Value::from("broad")), body.clone(), feedback.clone());
             Mexc3::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), response_code.clone(), feedback.clone());
-            panic!(r###"ExchangeError::new(feedback)"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         Value::Undefined
     }
@@ -4326,12 +4329,12 @@ pub trait Mexc3 : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -4437,9 +4440,9 @@ pub trait Mexc3 : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -4678,9 +4681,9 @@ pub trait Mexc3 : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Mexc3::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -4775,14 +4778,14 @@ pub trait Mexc3 : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -5004,7 +5007,7 @@ pub trait Mexc3 : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -5072,10 +5075,10 @@ pub trait Mexc3 : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -5189,7 +5192,7 @@ pub trait Mexc3 : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -5197,7 +5200,7 @@ pub trait Mexc3 : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -5223,19 +5226,8 @@ pub trait Mexc3 : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Mexc3::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Mexc3::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Mexc3::load_time_difference(exchange, params), calculate_rate_limiter_cost: Mexc3::calculate_rate_limiter_cost, sign: Mexc3::sign, handle_errors: Mexc3::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -5251,12 +5243,14 @@ pub trait Mexc3 : Exchange {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
         if reload.is_truthy() {
-            self.set("accounts".into(), Mexc3::fetch_accounts(self, params.clone()).await);
+            let mut accounts: Value = Mexc3::fetch_accounts(self, params.clone()).await;
+            self.set("accounts".into(), accounts);
         } else {
             if self.get("accounts".into()).is_truthy() {
                 return self.get("accounts".into());
             } else {
-                self.set("accounts".into(), Mexc3::fetch_accounts(self, params.clone()).await);
+                let mut accounts: Value = Mexc3::fetch_accounts(self, params.clone()).await;
+                self.set("accounts".into(), accounts);
             };
         };
         self.set("accounts_by_id".into(), self.index_by(self.get("accounts".into()), Value::from("id"), Value::Undefined));
@@ -5267,7 +5261,7 @@ pub trait Mexc3 : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Mexc3::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -5303,7 +5297,7 @@ pub trait Mexc3 : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5403,13 +5397,14 @@ pub trait Mexc3 : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -5423,7 +5418,7 @@ pub trait Mexc3 : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5453,7 +5448,7 @@ pub trait Mexc3 : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Mexc3::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -5462,7 +5457,7 @@ pub trait Mexc3 : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Mexc3::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -5470,14 +5465,14 @@ pub trait Mexc3 : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Mexc3::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5486,7 +5481,7 @@ pub trait Mexc3 : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -5495,12 +5490,12 @@ pub trait Mexc3 : Exchange {
         params = params.or_default(Value::new_object());
         Mexc3::load_markets(self, Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = self.fetch_borrow_rates(params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -5553,14 +5548,14 @@ pub trait Mexc3 : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Mexc3::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -5602,7 +5597,7 @@ pub trait Mexc3 : Exchange {
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5623,7 +5618,7 @@ pub trait Mexc3 : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -5632,25 +5627,7 @@ pub trait Mexc3 : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -5699,22 +5676,22 @@ pub trait Mexc3 : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Mexc3::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -5754,9 +5731,7 @@ pub trait Mexc3 : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = Mexc3::fetch_time(self, params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -5768,14 +5743,14 @@ pub trait Mexc3 : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Mexc3::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = Mexc3::fetch_leverage_tiers(self, Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5783,7 +5758,7 @@ pub trait Mexc3 : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -5794,7 +5769,7 @@ pub trait Mexc3 : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -5805,10 +5780,10 @@ pub trait Mexc3 : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -5819,7 +5794,7 @@ pub trait Mexc3 : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -5830,7 +5805,7 @@ pub trait Mexc3 : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -5940,11 +5915,6 @@ pub trait Mexc3 : Exchange {
         return Mexc3::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Mexc3::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -5976,9 +5946,9 @@ pub trait Mexc3 : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -5991,13 +5961,13 @@ pub trait Mexc3 : Exchange {
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTradingFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTradingFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Mexc3::fetch_trading_fees(self, params.clone()).await;
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6035,7 +6005,7 @@ pub trait Mexc3 : Exchange {
             }))).unwrap());
             return Mexc3::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6060,7 +6030,7 @@ pub trait Mexc3 : Exchange {
             }))).unwrap());
             return Mexc3::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6085,7 +6055,7 @@ pub trait Mexc3 : Exchange {
             }))).unwrap());
             return Mexc3::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6101,7 +6071,7 @@ pub trait Mexc3 : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -6122,7 +6092,7 @@ pub trait Mexc3 : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Mexc3::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -6170,16 +6140,16 @@ pub trait Mexc3 : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Mexc3::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Mexc3::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -6187,114 +6157,114 @@ pub trait Mexc3 : Exchange {
         match method {
             Value::Json(serde_json::Value::String(ref m)) => {
                 match m.as_ref() {
-                    "spotPublicGetPing" => Mexc3::request(self, "ping".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetTime" => Mexc3::request(self, "time".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetExchangeInfo" => Mexc3::request(self, "exchangeInfo".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetDepth" => Mexc3::request(self, "depth".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetTrades" => Mexc3::request(self, "trades".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetHistoricalTrades" => Mexc3::request(self, "historicalTrades".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetAggTrades" => Mexc3::request(self, "aggTrades".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetKlines" => Mexc3::request(self, "klines".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetAvgPrice" => Mexc3::request(self, "avgPrice".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetTicker24hr" => Mexc3::request(self, "ticker/24hr".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetTickerPrice" => Mexc3::request(self, "ticker/price".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetTickerBookTicker" => Mexc3::request(self, "ticker/bookTicker".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPublicGetEtfInfo" => Mexc3::request(self, "etf/info".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetOrder" => Mexc3::request(self, "order".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetOpenOrders" => Mexc3::request(self, "openOrders".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetAllOrders" => Mexc3::request(self, "allOrders".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetAccount" => Mexc3::request(self, "account".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetMyTrades" => Mexc3::request(self, "myTrades".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetSubAccountList" => Mexc3::request(self, "sub-account/list".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateGetSubAccountApiKey" => Mexc3::request(self, "sub-account/apiKey".into(), "spot".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostOrder" => Mexc3::request(self, "order".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostOrderTest" => Mexc3::request(self, "order/test".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostSubAccountVirtualSubAccount" => Mexc3::request(self, "sub-account/virtualSubAccount".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivatePostSubAccountApiKey" => Mexc3::request(self, "sub-account/apiKey".into(), "spot".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateDeleteOrder" => Mexc3::request(self, "order".into(), "spot".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateDeleteOpenOrders" => Mexc3::request(self, "openOrders".into(), "spot".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spotPrivateDeleteSubAccountApiKey" => Mexc3::request(self, "sub-account/apiKey".into(), "spot".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetPing" => Mexc3::request(self, "ping".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetDetail" => Mexc3::request(self, "detail".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetSupportCurrencies" => Mexc3::request(self, "support_currencies".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetDepthSymbol" => Mexc3::request(self, "depth/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetDepthCommitsSymbolLimit" => Mexc3::request(self, "depth_commits/{symbol}/{limit}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetIndexPriceSymbol" => Mexc3::request(self, "index_price/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetFairPriceSymbol" => Mexc3::request(self, "fair_price/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetFundingRateSymbol" => Mexc3::request(self, "funding_rate/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetKlineSymbol" => Mexc3::request(self, "kline/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetKlineIndexPriceSymbol" => Mexc3::request(self, "kline/index_price/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetKlineFairPriceSymbol" => Mexc3::request(self, "kline/fair_price/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetDealsSymbol" => Mexc3::request(self, "deals/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetTicker" => Mexc3::request(self, "ticker".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetRiskReverse" => Mexc3::request(self, "risk_reverse".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetRiskReverseHistory" => Mexc3::request(self, "risk_reverse/history".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPublicGetFundingRateHistory" => Mexc3::request(self, "funding_rate/history".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetAccountAssets" => Mexc3::request(self, "account/assets".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetAccountAssetCurrency" => Mexc3::request(self, "account/asset/{currency}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetAccountTransferRecord" => Mexc3::request(self, "account/transfer_record".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetPositionListHistoryPositions" => Mexc3::request(self, "position/list/history_positions".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetPositionOpenPositions" => Mexc3::request(self, "position/open_positions".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetPositionFundingRecords" => Mexc3::request(self, "position/funding_records".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetPositionPositionMode" => Mexc3::request(self, "position/position_mode".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderListOpenOrdersSymbol" => Mexc3::request(self, "order/list/open_orders/{symbol}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderListHistoryOrders" => Mexc3::request(self, "order/list/history_orders".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderExternalSymbolExternalOid" => Mexc3::request(self, "order/external/{symbol}/{external_oid}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderGetOrderId" => Mexc3::request(self, "order/get/{order_id}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderBatchQuery" => Mexc3::request(self, "order/batch_query".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderDealDetailsOrderId" => Mexc3::request(self, "order/deal_details/{order_id}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetOrderListOrderDeals" => Mexc3::request(self, "order/list/order_deals".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetPlanorderListOrders" => Mexc3::request(self, "planorder/list/orders".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetStoporderListOrders" => Mexc3::request(self, "stoporder/list/orders".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetStoporderOrderDetailsStopOrderId" => Mexc3::request(self, "stoporder/order_details/{stop_order_id}".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetAccountRiskLimit" => Mexc3::request(self, "account/risk_limit".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivateGetAccountTieredFeeRate" => Mexc3::request(self, "account/tiered_fee_rate".into(), "contract".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostPositionChangeMargin" => Mexc3::request(self, "position/change_margin".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostPositionChangeLeverage" => Mexc3::request(self, "position/change_leverage".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostPositionChangePositionMode" => Mexc3::request(self, "position/change_position_mode".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostOrderSubmit" => Mexc3::request(self, "order/submit".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostOrderSubmitBatch" => Mexc3::request(self, "order/submit_batch".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostOrderCancel" => Mexc3::request(self, "order/cancel".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostOrderCancelWithExternal" => Mexc3::request(self, "order/cancel_with_external".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostOrderCancelAll" => Mexc3::request(self, "order/cancel_all".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostAccountChangeRiskLevel" => Mexc3::request(self, "account/change_risk_level".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostPlanorderPlace" => Mexc3::request(self, "planorder/place".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostPlanorderCancel" => Mexc3::request(self, "planorder/cancel".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostPlanorderCancelAll" => Mexc3::request(self, "planorder/cancel_all".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostStoporderCancel" => Mexc3::request(self, "stoporder/cancel".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostStoporderCancelAll" => Mexc3::request(self, "stoporder/cancel_all".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostStoporderChangePrice" => Mexc3::request(self, "stoporder/change_price".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "contractPrivatePostStoporderChangePlanPrice" => Mexc3::request(self, "stoporder/change_plan_price".into(), "contract".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketSymbols" => Mexc3::request(self, "market/symbols".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketCoinList" => Mexc3::request(self, "market/coin/list".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetCommonTimestamp" => Mexc3::request(self, "common/timestamp".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetCommonPing" => Mexc3::request(self, "common/ping".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketTicker" => Mexc3::request(self, "market/ticker".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketDepth" => Mexc3::request(self, "market/depth".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketDeals" => Mexc3::request(self, "market/deals".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketKline" => Mexc3::request(self, "market/kline".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PublicGetMarketApiDefaultSymbols" => Mexc3::request(self, "market/api_default_symbols".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAccountInfo" => Mexc3::request(self, "account/info".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetOrderOpenOrders" => Mexc3::request(self, "order/open_orders".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetOrderList" => Mexc3::request(self, "order/list".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetOrderQuery" => Mexc3::request(self, "order/query".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetOrderDeals" => Mexc3::request(self, "order/deals".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetOrderDealDetail" => Mexc3::request(self, "order/deal_detail".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAssetDepositAddressList" => Mexc3::request(self, "asset/deposit/address/list".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAssetDepositList" => Mexc3::request(self, "asset/deposit/list".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAssetAddressList" => Mexc3::request(self, "asset/address/list".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAssetWithdrawList" => Mexc3::request(self, "asset/withdraw/list".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAssetInternalTransferRecord" => Mexc3::request(self, "asset/internal/transfer/record".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAccountBalance" => Mexc3::request(self, "account/balance".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetAssetInternalTransferInfo" => Mexc3::request(self, "asset/internal/transfer/info".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateGetMarketApiSymbols" => Mexc3::request(self, "market/api_symbols".into(), "spot2".into(), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivatePostOrderPlace" => Mexc3::request(self, "order/place".into(), "spot2".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivatePostOrderPlaceBatch" => Mexc3::request(self, "order/place_batch".into(), "spot2".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivatePostAssetWithdraw" => Mexc3::request(self, "asset/withdraw".into(), "spot2".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivatePostAssetInternalTransfer" => Mexc3::request(self, "asset/internal/transfer".into(), "spot2".into(), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateDeleteOrderCancel" => Mexc3::request(self, "order/cancel".into(), "spot2".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateDeleteOrderCancelBySymbol" => Mexc3::request(self, "order/cancel_by_symbol".into(), "spot2".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
-                    "spot2PrivateDeleteAssetWithdraw" => Mexc3::request(self, "asset/withdraw".into(), "spot2".into(), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetPing" => Mexc3::request(self, "ping".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetTime" => Mexc3::request(self, "time".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetExchangeInfo" => Mexc3::request(self, "exchangeInfo".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetDepth" => Mexc3::request(self, "depth".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetTrades" => Mexc3::request(self, "trades".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetHistoricalTrades" => Mexc3::request(self, "historicalTrades".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetAggTrades" => Mexc3::request(self, "aggTrades".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetKlines" => Mexc3::request(self, "klines".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetAvgPrice" => Mexc3::request(self, "avgPrice".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetTicker24hr" => Mexc3::request(self, "ticker/24hr".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetTickerPrice" => Mexc3::request(self, "ticker/price".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetTickerBookTicker" => Mexc3::request(self, "ticker/bookTicker".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPublicGetEtfInfo" => Mexc3::request(self, "etf/info".into(), Value::Json(json!(["spot", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetOrder" => Mexc3::request(self, "order".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetOpenOrders" => Mexc3::request(self, "openOrders".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetAllOrders" => Mexc3::request(self, "allOrders".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetAccount" => Mexc3::request(self, "account".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetMyTrades" => Mexc3::request(self, "myTrades".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetSubAccountList" => Mexc3::request(self, "sub-account/list".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateGetSubAccountApiKey" => Mexc3::request(self, "sub-account/apiKey".into(), Value::Json(json!(["spot", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostOrder" => Mexc3::request(self, "order".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostOrderTest" => Mexc3::request(self, "order/test".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostSubAccountVirtualSubAccount" => Mexc3::request(self, "sub-account/virtualSubAccount".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivatePostSubAccountApiKey" => Mexc3::request(self, "sub-account/apiKey".into(), Value::Json(json!(["spot", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateDeleteOrder" => Mexc3::request(self, "order".into(), Value::Json(json!(["spot", "private"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateDeleteOpenOrders" => Mexc3::request(self, "openOrders".into(), Value::Json(json!(["spot", "private"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spotPrivateDeleteSubAccountApiKey" => Mexc3::request(self, "sub-account/apiKey".into(), Value::Json(json!(["spot", "private"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetPing" => Mexc3::request(self, "ping".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetDetail" => Mexc3::request(self, "detail".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetSupportCurrencies" => Mexc3::request(self, "support_currencies".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetDepthSymbol" => Mexc3::request(self, "depth/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetDepthCommitsSymbolLimit" => Mexc3::request(self, "depth_commits/{symbol}/{limit}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetIndexPriceSymbol" => Mexc3::request(self, "index_price/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetFairPriceSymbol" => Mexc3::request(self, "fair_price/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetFundingRateSymbol" => Mexc3::request(self, "funding_rate/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetKlineSymbol" => Mexc3::request(self, "kline/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetKlineIndexPriceSymbol" => Mexc3::request(self, "kline/index_price/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetKlineFairPriceSymbol" => Mexc3::request(self, "kline/fair_price/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetDealsSymbol" => Mexc3::request(self, "deals/{symbol}".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetTicker" => Mexc3::request(self, "ticker".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetRiskReverse" => Mexc3::request(self, "risk_reverse".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetRiskReverseHistory" => Mexc3::request(self, "risk_reverse/history".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPublicGetFundingRateHistory" => Mexc3::request(self, "funding_rate/history".into(), Value::Json(json!(["contract", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetAccountAssets" => Mexc3::request(self, "account/assets".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetAccountAssetCurrency" => Mexc3::request(self, "account/asset/{currency}".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetAccountTransferRecord" => Mexc3::request(self, "account/transfer_record".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetPositionListHistoryPositions" => Mexc3::request(self, "position/list/history_positions".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetPositionOpenPositions" => Mexc3::request(self, "position/open_positions".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetPositionFundingRecords" => Mexc3::request(self, "position/funding_records".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetPositionPositionMode" => Mexc3::request(self, "position/position_mode".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderListOpenOrdersSymbol" => Mexc3::request(self, "order/list/open_orders/{symbol}".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderListHistoryOrders" => Mexc3::request(self, "order/list/history_orders".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderExternalSymbolExternalOid" => Mexc3::request(self, "order/external/{symbol}/{external_oid}".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderGetOrderId" => Mexc3::request(self, "order/get/{order_id}".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderBatchQuery" => Mexc3::request(self, "order/batch_query".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderDealDetailsOrderId" => Mexc3::request(self, "order/deal_details/{order_id}".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetOrderListOrderDeals" => Mexc3::request(self, "order/list/order_deals".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetPlanorderListOrders" => Mexc3::request(self, "planorder/list/orders".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetStoporderListOrders" => Mexc3::request(self, "stoporder/list/orders".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetStoporderOrderDetailsStopOrderId" => Mexc3::request(self, "stoporder/order_details/{stop_order_id}".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetAccountRiskLimit" => Mexc3::request(self, "account/risk_limit".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivateGetAccountTieredFeeRate" => Mexc3::request(self, "account/tiered_fee_rate".into(), Value::Json(json!(["contract", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostPositionChangeMargin" => Mexc3::request(self, "position/change_margin".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostPositionChangeLeverage" => Mexc3::request(self, "position/change_leverage".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostPositionChangePositionMode" => Mexc3::request(self, "position/change_position_mode".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostOrderSubmit" => Mexc3::request(self, "order/submit".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostOrderSubmitBatch" => Mexc3::request(self, "order/submit_batch".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostOrderCancel" => Mexc3::request(self, "order/cancel".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostOrderCancelWithExternal" => Mexc3::request(self, "order/cancel_with_external".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostOrderCancelAll" => Mexc3::request(self, "order/cancel_all".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostAccountChangeRiskLevel" => Mexc3::request(self, "account/change_risk_level".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostPlanorderPlace" => Mexc3::request(self, "planorder/place".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostPlanorderCancel" => Mexc3::request(self, "planorder/cancel".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostPlanorderCancelAll" => Mexc3::request(self, "planorder/cancel_all".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostStoporderCancel" => Mexc3::request(self, "stoporder/cancel".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostStoporderCancelAll" => Mexc3::request(self, "stoporder/cancel_all".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostStoporderChangePrice" => Mexc3::request(self, "stoporder/change_price".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "contractPrivatePostStoporderChangePlanPrice" => Mexc3::request(self, "stoporder/change_plan_price".into(), Value::Json(json!(["contract", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketSymbols" => Mexc3::request(self, "market/symbols".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketCoinList" => Mexc3::request(self, "market/coin/list".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetCommonTimestamp" => Mexc3::request(self, "common/timestamp".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetCommonPing" => Mexc3::request(self, "common/ping".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketTicker" => Mexc3::request(self, "market/ticker".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketDepth" => Mexc3::request(self, "market/depth".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketDeals" => Mexc3::request(self, "market/deals".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketKline" => Mexc3::request(self, "market/kline".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PublicGetMarketApiDefaultSymbols" => Mexc3::request(self, "market/api_default_symbols".into(), Value::Json(json!(["spot2", "public"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAccountInfo" => Mexc3::request(self, "account/info".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetOrderOpenOrders" => Mexc3::request(self, "order/open_orders".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetOrderList" => Mexc3::request(self, "order/list".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetOrderQuery" => Mexc3::request(self, "order/query".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetOrderDeals" => Mexc3::request(self, "order/deals".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetOrderDealDetail" => Mexc3::request(self, "order/deal_detail".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAssetDepositAddressList" => Mexc3::request(self, "asset/deposit/address/list".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAssetDepositList" => Mexc3::request(self, "asset/deposit/list".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAssetAddressList" => Mexc3::request(self, "asset/address/list".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAssetWithdrawList" => Mexc3::request(self, "asset/withdraw/list".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAssetInternalTransferRecord" => Mexc3::request(self, "asset/internal/transfer/record".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAccountBalance" => Mexc3::request(self, "account/balance".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetAssetInternalTransferInfo" => Mexc3::request(self, "asset/internal/transfer/info".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateGetMarketApiSymbols" => Mexc3::request(self, "market/api_symbols".into(), Value::Json(json!(["spot2", "private"])), "GET".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivatePostOrderPlace" => Mexc3::request(self, "order/place".into(), Value::Json(json!(["spot2", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivatePostOrderPlaceBatch" => Mexc3::request(self, "order/place_batch".into(), Value::Json(json!(["spot2", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivatePostAssetWithdraw" => Mexc3::request(self, "asset/withdraw".into(), Value::Json(json!(["spot2", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivatePostAssetInternalTransfer" => Mexc3::request(self, "asset/internal/transfer".into(), Value::Json(json!(["spot2", "private"])), "POST".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateDeleteOrderCancel" => Mexc3::request(self, "order/cancel".into(), Value::Json(json!(["spot2", "private"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateDeleteOrderCancelBySymbol" => Mexc3::request(self, "order/cancel_by_symbol".into(), Value::Json(json!(["spot2", "private"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
+                    "spot2PrivateDeleteAssetWithdraw" => Mexc3::request(self, "asset/withdraw".into(), Value::Json(json!(["spot2", "private"])), "DELETE".into(), params, Value::Undefined, Value::Undefined, Value::Undefined, context).await,
                     _ => unimplemented!(),
                 }
             },
@@ -6304,7 +6274,7 @@ pub trait Mexc3 : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Mexc3Impl(Value);
+pub struct Mexc3Impl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for Mexc3Impl {}
 impl Mexc3 for Mexc3Impl {}
 impl ValueTrait for Mexc3Impl {
@@ -6326,8 +6296,8 @@ impl ValueTrait for Mexc3Impl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -6346,7 +6316,8 @@ impl Mexc3Impl {
         let mut rv = Mexc3Impl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Mexc3::describe(&rv);
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+// the last nonce handed out per exchange and API key, shared by every copy of an exchange like the throttle buckets
+fn last_nonces() -> &'static Mutex<HashMap<String, u64>> {
+    static LAST_NONCES: OnceLock<Mutex<HashMap<String, u64>>> = OnceLock::new();
+    LAST_NONCES.get_or_init(Default::default)
+}
+
+/// `candidate`, usually the clock, or one more than the last nonce of `key` when the clock has not moved past it, so
+/// that concurrent requests signed with the same key never send the same nonce twice
+pub fn next(key: &str, candidate: u64) -> u64 {
+    let mut last_nonces = last_nonces().lock().unwrap();
+    let last = last_nonces.entry(key.to_owned()).or_insert(0);
+    *last = candidate.max(*last + 1);
+    *last
+}
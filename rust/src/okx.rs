@@ -11,10 +11,12 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
+use crate::retry::idempotency_context;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -623,11 +625,10 @@ pub trait Okx : Exchange {
         let mut result: Value = Value::new_array();
         let mut i: usize = 0;
         while i < types.len() {
-            promises.push(Okx::fetch_markets_by_type(self, types.get(i.into()), params.clone()));
+            let mut markets: Value = Okx::fetch_markets_by_type(self, types.get(i.into()), params.clone()).await;
+            promises.push(markets);
             i += 1;
         };
-        // why not both ¯\_(ツ)_/¯
-        promises = Promise::all(promises.clone()).await;
         let mut i: usize = 0;
         while i < promises.len() {
             result = self.array_concat(result.clone(), promises.get(i.into()));
@@ -802,7 +803,7 @@ pub trait Okx : Exchange {
             let mut default_underlying: Value = self.safe_value(self.get("options".into()), Value::from("defaultUnderlying"), Value::from("BTC-USD"));
             let mut currency_id: Value = self.safe_string_2(params.clone(), Value::from("uly"), Value::from("marketId"), default_underlying.clone());
             if currency_id.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMarketsByType() requires an underlying uly or marketId parameter for options markets"))"###);
+                panic!("ArgumentsRequired::new({} fetchMarketsByType() requires an underlying uly or marketId parameter for options markets)", self.get("id".into()).unwrap_str());
             } else {
                 request.set("uly".into(), currency_id.clone());
             };
@@ -1027,7 +1028,7 @@ pub trait Okx : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
         }))).unwrap());
@@ -1131,7 +1132,7 @@ pub trait Okx : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
         }))).unwrap());
@@ -1177,7 +1178,7 @@ pub trait Okx : Exchange {
             let mut default_underlying: Value = self.safe_value(self.get("options".into()), Value::from("defaultUnderlying"), Value::from("BTC-USD"));
             let mut currency_id: Value = self.safe_string_2(params.clone(), Value::from("uly"), Value::from("marketId"), default_underlying.clone());
             if currency_id.clone().is_nullish() {
-                panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchTickersByType() requires an underlying uly or marketId parameter for options markets"))"###);
+                panic!("ArgumentsRequired::new({} fetchTickersByType() requires an underlying uly or marketId parameter for options markets)", self.get("id".into()).unwrap_str());
             } else {
                 request.set("uly".into(), currency_id.clone());
             };
@@ -1316,7 +1317,7 @@ pub trait Okx : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
         }))).unwrap());
@@ -1372,7 +1373,7 @@ pub trait Okx : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut price: Value = self.safe_string(params.clone(), Value::from("price"), Value::Undefined);
         params = self.omit(params.clone(), Value::from("price"));
         if limit.clone().is_nullish() {
@@ -1443,10 +1444,10 @@ pub trait Okx : Exchange {
     async fn fetch_funding_rate_history(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingRateHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingRateHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
         }))).unwrap());
@@ -1486,7 +1487,7 @@ pub trait Okx : Exchange {
             let mut rate: Value = data.get(i.into());
             let mut timestamp: Value = Okx::safe_number(self, rate.clone(), Value::from("fundingTime"), Value::Undefined);
             rates.push(Value::Json(normalize(&Value::Json(json!({
-                "symbol": Okx::safe_symbol(self, self.safe_string(rate.clone(), Value::from("instId"), Value::Undefined), Value::Undefined, Value::Undefined),
+                "symbol": self.safe_symbol(self.safe_string(rate.clone(), Value::from("instId"), Value::Undefined), Value::Undefined, Value::Undefined),
                 "fundingRate": Okx::safe_number(self, rate.clone(), Value::from("realizedRate"), Value::Undefined),
                 "timestamp": timestamp,
                 "datetime": self.iso8601(timestamp.clone())
@@ -1575,7 +1576,7 @@ pub trait Okx : Exchange {
         //
         return Value::Json(normalize(&Value::Json(json!({
             "info": fee,
-            "symbol": Okx::safe_symbol(self, Value::Undefined, market.clone(), Value::Undefined),
+            "symbol": self.safe_symbol(Value::Undefined, market.clone(), Value::Undefined),
             "maker": self.parse_number(Precise::string_neg(self.safe_string_2(fee.clone(), Value::from("maker"), Value::from("makerU"), Value::Undefined)), Value::Undefined),
             "taker": self.parse_number(Precise::string_neg(self.safe_string_2(fee.clone(), Value::from("taker"), Value::from("takerU"), Value::Undefined)), Value::Undefined)
         }))).unwrap());
@@ -1592,7 +1593,7 @@ pub trait Okx : Exchange {
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instType": Okx::convert_to_instrument_type(self, market.get(Value::from("type")))
         }))).unwrap());
@@ -1605,7 +1606,7 @@ pub trait Okx : Exchange {
         } else if market.get(Value::from("swap")).is_truthy() || market.get(Value::from("future")).is_truthy() || market.get(Value::from("option")).is_truthy() {
             request.set("uly".into(), market.get(Value::from("baseId")) + Value::from("-") + market.get(Value::from("quoteId")));
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFee() supports spot, swap, future or option markets only"))"###);
+            panic!("NotSupported::new({} fetchTradingFee() supports spot, swap, future or option markets only)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Okx::dispatch(self, "privateGetAccountTradeFee".into(), extend_2(request.clone(), params.clone()), Value::Undefined).await;
         //
@@ -1773,7 +1774,7 @@ pub trait Okx : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id")),
             "side": side,
@@ -1822,7 +1823,7 @@ pub trait Okx : Exchange {
             margin = self.safe_value(params.clone(), Value::from("margin"), false.into());
         };
         if margin.clone() == true.into() && market.get(Value::from("spot")).is_truthy() && !market.get(Value::from("margin")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" does not support margin trading for ") + symbol.clone() + Value::from(" market"))"###);
+            panic!("NotSupported::new({} does not support margin trading for {} market)", self.get("id".into()).unwrap_str(), js_string(&symbol));
         };
         if spot.is_truthy() {
             if margin.is_truthy() {
@@ -1865,7 +1866,7 @@ pub trait Okx : Exchange {
                                 notional = amount.clone() * price.clone();
                             };
                         } else if notional.clone().is_nullish() {
-                            panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" createOrder() requires the price argument with market buy orders to calculate total order cost (amount to spend), where cost = amount * price. Supply a price argument to createOrder() call if you want the cost to be calculated for you from price and amount, or, alternatively, add .options['createMarketBuyOrderRequiresPrice'] = false and supply the total cost value in the 'amount' argument or in the 'cost' unified extra parameter or in exchange-specific 'sz' extra parameter (the exchange-specific behaviour)"))"###);
+                            panic!("InvalidOrder::new({} createOrder() requires the price argument with market buy orders to calculate total order cost (amount to spend), where cost = amount * price. Supply a price argument to createOrder() call if you want the cost to be calculated for you from price and amount, or, alternatively, add .options['createMarketBuyOrderRequiresPrice'] = false and supply the total cost value in the 'amount' argument or in the 'cost' unified extra parameter or in exchange-specific 'sz' extra parameter (the exchange-specific behaviour))", self.get("id".into()).unwrap_str());
                         };
                     } else {
                         notional = if notional.clone().is_nullish() { amount.clone() } else { notional.clone() };
@@ -1937,9 +1938,9 @@ pub trait Okx : Exchange {
             // because it has a lower ratelimit
             extended_request = Value::Json(serde_json::Value::Array(vec![extend_2(request.clone(), params.clone()).into()]));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" createOrder() this.options["createOrder"] must be either privatePostTradeBatchOrders or privatePostTradeOrder"#))"###);
+            panic!("ExchangeError::new({} createOrder() this.options[\"createOrder\"] must be either privatePostTradeBatchOrders or privatePostTradeOrder)", self.get("id".into()).unwrap_str());
         };
-        let mut response: Value = Okx::dispatch(self, method, extended_request.clone(), Value::Undefined).await;
+        let mut response: Value = Okx::dispatch(self, method, extended_request.clone(), idempotency_context(&client_order_id)).await;
         let mut data: Value = self.safe_value(response.clone(), Value::from("data"), Value::new_array());
         let mut first: Value = self.safe_value(data.clone(), Value::from(0), Value::Undefined);
         let mut order: Value = Okx::parse_order(self, first.clone(), market.clone());
@@ -1966,10 +1967,10 @@ pub trait Okx : Exchange {
             return self.safe_value(order.clone(), Value::from(0), Value::Undefined);
         };
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
         }))).unwrap());
@@ -2018,10 +2019,10 @@ pub trait Okx : Exchange {
         params = params.or_default(Value::new_object());
         // TODO : the original endpoint signature differs, according to that you can skip individual symbol and assign ids in batch. At this moment, `params` is not being used too.
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::new_array();
         let mut options: Value = self.safe_value(self.get("options".into()), Value::from("cancelOrders"), Value::new_object());
         let mut default_method: Value = self.safe_string(options.clone(), Value::from("method"), Value::from("privatePostTradeCancelBatchOrders"));
@@ -2228,7 +2229,7 @@ pub trait Okx : Exchange {
             r#type = Value::from("limit");
         };
         let mut market_id: Value = self.safe_string(order.clone(), Value::from("instId"), Value::Undefined);
-        let mut symbol: Value = Okx::safe_symbol(self, market_id.clone(), market.clone(), Value::from("-"));
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::from("-"));
         let mut filled: Value = self.safe_string(order.clone(), Value::from("accFillSz"), Value::Undefined);
         let mut price: Value = self.safe_string_2(order.clone(), Value::from("px"), Value::from("ordPx"), Value::Undefined);
         let mut average: Value = self.safe_string(order.clone(), Value::from("avgPx"), Value::Undefined);
@@ -2303,10 +2304,10 @@ pub trait Okx : Exchange {
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
         }))).unwrap());
@@ -2473,7 +2474,7 @@ pub trait Okx : Exchange {
         // 'limit': limit, // default 100, max 100
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Okx::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instId".into(), market.get(Value::from("id")));
         };
         if limit.clone().is_nonnullish() {
@@ -2619,7 +2620,7 @@ pub trait Okx : Exchange {
         // 'algoId': "'433845797218942976'", // Algo order
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Okx::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instId".into(), market.get(Value::from("id")));
         };
         let (mut r#type, mut query) = shift_2(Okx::handle_market_type_and_params(self, Value::from("fetchCanceledOrders"), market.clone(), params.clone()));
@@ -2767,7 +2768,7 @@ pub trait Okx : Exchange {
         // 'limit': limit, // default 100, max 100
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Okx::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instId".into(), market.get(Value::from("id")));
         };
         let (mut r#type, mut query) = shift_2(Okx::handle_market_type_and_params(self, Value::from("fetchClosedOrders"), market.clone(), params.clone()));
@@ -2848,7 +2849,7 @@ pub trait Okx : Exchange {
         // 'limit': limit, // default 100, max 100
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Okx::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instId".into(), market.get(Value::from("id")));
         };
         let (mut r#type, mut query) = shift_2(Okx::handle_market_type_and_params(self, Value::from("fetchMyTrades"), market.clone(), params.clone()));
@@ -3327,7 +3328,7 @@ pub trait Okx : Exchange {
                         let mut values: Value = Object::values(response.clone());
                         result = self.safe_value(values.clone(), Value::from(0), Value::Undefined);
                         if result.clone().is_nullish() {
-                            panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() cannot find deposit address for ") + code.clone())"###);
+                            panic!("InvalidAddress::new({} fetchDepositAddress() cannot find deposit address for {})", self.get("id".into()).unwrap_str(), js_string(&code));
                         };
                     };
                 };
@@ -3336,7 +3337,7 @@ pub trait Okx : Exchange {
         };
         result = self.safe_value(response.clone(), network.clone(), Value::Undefined);
         if result.clone().is_nullish() {
-            panic!(r###"InvalidAddress::new(self.get("id".into()) + Value::from(" fetchDepositAddress() cannot find ") + network.clone() + Value::from(" deposit address for ") + code.clone())"###);
+            panic!("InvalidAddress::new({} fetchDepositAddress() cannot find {} deposit address for {})", self.get("id".into()).unwrap_str(), js_string(&network), js_string(&code));
         };
         return result.clone();
     }
@@ -3363,7 +3364,7 @@ pub trait Okx : Exchange {
         };
         let mut fee: Value = self.safe_string(params.clone(), Value::from("fee"), Value::Undefined);
         if fee.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" withdraw() requires a 'fee' string parameter, network transaction fee must be ≥ 0. Withdrawals to OKCoin or OKX are fee-free, please set '0'. Withdrawing to external digital asset address requires network transaction fee."))"###);
+            panic!("ArgumentsRequired::new({} withdraw() requires a 'fee' string parameter, network transaction fee must be ≥ 0. Withdrawals to OKCoin or OKX are fee-free, please set '0'. Withdrawing to external digital asset address requires network transaction fee.)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "ccy": currency.get(Value::from("id")),
@@ -3390,7 +3391,7 @@ pub trait Okx : Exchange {
         };
         let mut query: Value = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("fee").into(), Value::from("password").into(), Value::from("pwd").into()])));
         if !request.contains_key(Value::from("pwd")) {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" withdraw() requires a password parameter or a pwd parameter, it must be the funding password, not the API passphrase"))"###);
+            panic!("ExchangeError::new({} withdraw() requires a password parameter or a pwd parameter, it must be the funding password, not the API passphrase)", self.get("id".into()).unwrap_str());
         };
         let mut response: Value = Okx::dispatch(self, "privatePostAssetWithdrawal".into(), extend_2(request.clone(), query.clone()), Value::Undefined).await;
         //
@@ -3774,9 +3775,9 @@ pub trait Okx : Exchange {
         let mut margin_mode: Value = self.safe_string_lower(params.clone(), Value::from("mgnMode"), Value::Undefined);
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("mgnMode").into()])));
         if margin_mode.clone() != Value::from("cross") && margin_mode.clone() != Value::from("isolated") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchLeverage() requires a mgnMode parameter that must be either cross or isolated"))"###);
+            panic!("BadRequest::new({} fetchLeverage() requires a mgnMode parameter that must be either cross or isolated)", self.get("id".into()).unwrap_str());
         };
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id")),
             "mgnMode": margin_mode
@@ -3810,7 +3811,7 @@ pub trait Okx : Exchange {
     async fn fetch_position(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let (mut r#type, mut query) = shift_2(Okx::handle_market_type_and_params(self, Value::from("fetchPosition"), market.clone(), params.clone()));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
@@ -4295,7 +4296,7 @@ pub trait Okx : Exchange {
         //
         let mut next_funding_rate_timestamp: Value = self.safe_integer(contract.clone(), Value::from("nextFundingTime"), Value::Undefined);
         let mut market_id: Value = self.safe_string(contract.clone(), Value::from("instId"), Value::Undefined);
-        let mut symbol: Value = Okx::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut next_funding_rate: Value = Okx::safe_number(self, contract.clone(), Value::from("nextFundingRate"), Value::Undefined);
         let mut funding_time: Value = self.safe_integer(contract.clone(), Value::from("fundingTime"), Value::Undefined);
         // https://www.okx.com/support/hc/en-us/articles/360053909272-Ⅸ-Introduction-to-perpetual-swap-funding-fee
@@ -4332,9 +4333,9 @@ pub trait Okx : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if !market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchFundingRate() is only valid for swap markets"))"###);
+            panic!("ExchangeError::new({} fetchFundingRate() is only valid for swap markets)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "instId": market.get(Value::from("id"))
@@ -4456,7 +4457,7 @@ pub trait Okx : Exchange {
         // default 100, max 100
         let mut market: Value = Value::Undefined;
         if symbol.clone().is_nonnullish() {
-            market = Okx::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             symbol = market.get(Value::from("symbol"));
             if market.get(Value::from("contract")).is_truthy() {
                 if market.get(Value::from("linear")).is_truthy() {
@@ -4535,19 +4536,19 @@ pub trait Okx : Exchange {
     async fn set_leverage(&mut self, mut leverage: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         // WARNING: THIS WILL INCREASE LIQUIDATION PRICE FOR OPEN ISOLATED LONG POSITIONS
         // AND DECREASE LIQUIDATION PRICE FOR OPEN ISOLATED SHORT POSITIONS
         if leverage.clone() < Value::from(1) || leverage.clone() > Value::from(125) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setLeverage() leverage should be between 1 and 125"))"###);
+            panic!("BadRequest::new({} setLeverage() leverage should be between 1 and 125)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut margin_mode: Value = self.safe_string_lower(params.clone(), Value::from("mgnMode"), Value::Undefined);
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("mgnMode").into()])));
         if margin_mode.clone() != Value::from("cross") && margin_mode.clone() != Value::from("isolated") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(r#" setLeverage() params["mgnMode"] must be either cross or isolated"#))"###);
+            panic!("BadRequest::new({} setLeverage() params[\"mgnMode\"] must be either cross or isolated)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "lever": leverage,
@@ -4619,19 +4620,19 @@ pub trait Okx : Exchange {
     async fn set_margin_mode(&mut self, mut margin_mode: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         // WARNING: THIS WILL INCREASE LIQUIDATION PRICE FOR OPEN ISOLATED LONG POSITIONS
         // AND DECREASE LIQUIDATION PRICE FOR OPEN ISOLATED SHORT POSITIONS
         margin_mode = margin_mode.to_lower_case();
         if margin_mode.clone() != Value::from("cross") && margin_mode.clone() != Value::from("isolated") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setMarginMode() marginMode must be either cross or isolated"))"###);
+            panic!("BadRequest::new({} setMarginMode() marginMode must be either cross or isolated)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut lever: Value = self.safe_integer(params.clone(), Value::from("lever"), Value::Undefined);
         if lever.clone().is_nullish() || lever.clone() < Value::from(1) || lever.clone() > Value::from(125) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(r#" setMarginMode() params["lever"] should be between 1 and 125"#))"###);
+            panic!("BadRequest::new({} setMarginMode() params[\"lever\"] should be between 1 and 125)", self.get("id".into()).unwrap_str());
         };
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("lever").into()])));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -4895,7 +4896,7 @@ pub trait Okx : Exchange {
     async fn modify_margin_helper(&mut self, mut symbol: Value, mut amount: Value, mut r#type: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut pos_side: Value = self.safe_string(params.clone(), Value::from("posSide"), Value::from("net"));
         params = self.omit(params.clone(), Value::Json(serde_json::Value::Array(vec![Value::from("posSide").into()])));
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -4983,12 +4984,12 @@ pub trait Okx : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut r#type: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("MARGIN") } else { Okx::convert_to_instrument_type(self, market.get(Value::from("type"))) };
         let mut uly: Value = self.safe_string(market.get(Value::from("info")), Value::from("uly"), Value::Undefined);
         if !uly.is_truthy() {
             if r#type.clone() != Value::from("MARGIN") {
-                panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() cannot fetch leverage tiers for ") + symbol.clone())"###);
+                panic!("BadRequest::new({} fetchMarketLeverageTiers() cannot fetch leverage tiers for {})", self.get("id".into()).unwrap_str(), js_string(&symbol));
             };
         };
         let mut default_margin_mode: Value = self.safe_string_2(self.get("options".into()), Value::from("defaultMarginMode"), Value::from("marginMode"), Value::from("cross"));
@@ -5101,7 +5102,7 @@ pub trait Okx : Exchange {
             request.set("limit".into(), limit.clone());
         };
         if symbol.clone().is_nonnullish() {
-            market = Okx::market(self, symbol.clone());
+            market = self.market(symbol.clone());
             request.set("instId".into(), market.get(Value::from("id")));
         };
         let mut response: Value = Okx::dispatch(self, "privateGetAccountInterestAccrued".into(), extend_2(request.clone(), params.clone()), Value::Undefined).await;
@@ -5288,7 +5289,7 @@ pub trait Okx : Exchange {
         let mut timeframes: Value = self.safe_value(options.clone(), Value::from("timeframes"), Value::new_object());
         timeframe = self.safe_string(timeframes.clone(), timeframe.clone(), timeframe.clone());
         if timeframe.clone() != Value::from("5m") && timeframe.clone() != Value::from("1H") && timeframe.clone() != Value::from("1D") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchOpenInterestHistory cannot only use the 5m, 1h, and 1d timeframe"))"###);
+            panic!("BadRequest::new({} fetchOpenInterestHistory cannot only use the 5m, 1h, and 1d timeframe)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut currency: Value = Okx::currency(self, symbol.clone());
@@ -5344,9 +5345,11 @@ pub trait Okx : Exchange {
     }
 
     fn set_sandbox_mode(&mut self, mut enable: Value) -> Value {
-        Exchange::set_sandbox_mode(self, enable.clone());
+        Exchange::set_sandbox_mode(self, enable.is_truthy());
         if enable.is_truthy() {
-            self.get("headers".into()).set("x-simulated-trading".into(), Value::from("1"));
+            let mut headers: Value = self.get("headers".into()).or_default(Value::new_object());
+            headers.set("x-simulated-trading".into(), Value::from("1"));
+            self.set("headers".into(), headers);
         } else if self.get("headers".into()).contains_key(Value::from("x-simulated-trading")) {
             self.set("headers".into(), self.omit(self.get("headers".into()), Value::from("x-simulated-trading")));
         };
@@ -5392,7 +5395,7 @@ pub trait Okx : Exchange {
                 i += 1;
             };
             Okx::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), code.clone(), feedback.clone());
-            panic!(r###"ExchangeError::new(feedback)"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         Value::Undefined
     }
@@ -5550,12 +5553,12 @@ pub trait Okx : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -5661,9 +5664,9 @@ pub trait Okx : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -5902,9 +5905,9 @@ pub trait Okx : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Okx::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -5999,14 +6002,14 @@ pub trait Okx : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -6228,7 +6231,7 @@ pub trait Okx : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -6296,10 +6299,10 @@ pub trait Okx : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -6413,7 +6416,7 @@ pub trait Okx : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -6421,7 +6424,7 @@ pub trait Okx : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -6447,19 +6450,8 @@ pub trait Okx : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Okx::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Okx::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Okx::load_time_difference(exchange, params), calculate_rate_limiter_cost: Okx::calculate_rate_limiter_cost, sign: Okx::sign, handle_errors: Okx::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -6475,12 +6467,14 @@ pub trait Okx : Exchange {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
         if reload.is_truthy() {
-            self.set("accounts".into(), Okx::fetch_accounts(self, params.clone()).await);
+            let mut accounts: Value = Okx::fetch_accounts(self, params.clone()).await;
+            self.set("accounts".into(), accounts);
         } else {
             if self.get("accounts".into()).is_truthy() {
                 return self.get("accounts".into());
             } else {
-                self.set("accounts".into(), Okx::fetch_accounts(self, params.clone()).await);
+                let mut accounts: Value = Okx::fetch_accounts(self, params.clone()).await;
+                self.set("accounts".into(), accounts);
             };
         };
         self.set("accounts_by_id".into(), self.index_by(self.get("accounts".into()), Value::from("id"), Value::Undefined));
@@ -6491,7 +6485,7 @@ pub trait Okx : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Okx::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Okx::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -6527,13 +6521,13 @@ pub trait Okx : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6633,13 +6627,14 @@ pub trait Okx : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -6653,7 +6648,7 @@ pub trait Okx : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -6683,7 +6678,7 @@ pub trait Okx : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Okx::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -6692,7 +6687,7 @@ pub trait Okx : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Okx::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -6700,14 +6695,14 @@ pub trait Okx : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Okx::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6716,7 +6711,7 @@ pub trait Okx : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -6751,14 +6746,14 @@ pub trait Okx : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Okx::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -6800,13 +6795,13 @@ pub trait Okx : Exchange {
 
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOrders() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchOrders() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -6827,7 +6822,7 @@ pub trait Okx : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -6836,25 +6831,7 @@ pub trait Okx : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -6903,22 +6880,22 @@ pub trait Okx : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Okx::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -6958,9 +6935,7 @@ pub trait Okx : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = Okx::fetch_time(self, params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -6972,7 +6947,7 @@ pub trait Okx : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -6983,7 +6958,7 @@ pub trait Okx : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -6994,10 +6969,10 @@ pub trait Okx : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -7008,7 +6983,7 @@ pub trait Okx : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -7019,7 +6994,7 @@ pub trait Okx : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -7129,11 +7104,6 @@ pub trait Okx : Exchange {
         return Okx::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Okx::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -7165,9 +7135,9 @@ pub trait Okx : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -7179,7 +7149,7 @@ pub trait Okx : Exchange {
 
     async fn fetch_trading_fees(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTradingFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -7217,7 +7187,7 @@ pub trait Okx : Exchange {
             }))).unwrap());
             return Okx::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -7242,7 +7212,7 @@ pub trait Okx : Exchange {
             }))).unwrap());
             return Okx::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -7267,7 +7237,7 @@ pub trait Okx : Exchange {
             }))).unwrap());
             return Okx::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -7283,7 +7253,7 @@ pub trait Okx : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -7304,7 +7274,7 @@ pub trait Okx : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Okx::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -7352,16 +7322,16 @@ pub trait Okx : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Okx::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Okx::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -7519,7 +7489,7 @@ pub trait Okx : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct OkxImpl(Value);
+pub struct OkxImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for OkxImpl {}
 impl Okx for OkxImpl {}
 impl ValueTrait for OkxImpl {
@@ -7541,8 +7511,8 @@ impl ValueTrait for OkxImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -7561,7 +7531,8 @@ impl OkxImpl {
         let mut rv = OkxImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Okx::describe(&rv);
@@ -0,0 +1,214 @@
+use rust_decimal::prelude::ToPrimitive;
+use serde_json::json;
+use crate::errors::{Error, Result};
+use crate::exchange::Value;
+use crate::types::{Decimal, OrderSide, OrderType, TimeInForce};
+
+/// The arguments of the unified `create_order`, as produced by `OrderRequest::to_unified`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateOrderArgs {
+    pub symbol: Value,
+    pub r#type: Value,
+    pub side: Value,
+    pub amount: Value,
+    pub price: Value,
+    pub params: Value,
+}
+
+/// A typed order for `create_order`, the optional fields compile down to the unified params
+/// (`timeInForce`, `postOnly`, `reduceOnly`, `clientOrderId`, `stopPrice`, ...) that the exchanges read
+///
+/// ```
+/// use ccxt::order_request::OrderRequest;
+/// use ccxt::types::{Decimal, OrderSide, TimeInForce};
+///
+/// let request = OrderRequest::limit("BTC/USDT", OrderSide::Buy, Decimal::new(1, 2), Decimal::new(20000, 0))
+///     .time_in_force(TimeInForce::GoodTillCancel)
+///     .client_order_id("my-order-1");
+/// assert!(request.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRequest {
+    pub symbol: String,
+    pub r#type: OrderType,
+    pub side: OrderSide,
+    pub amount: Decimal,
+    pub price: Option<Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub post_only: bool,
+    pub reduce_only: bool,
+    pub client_order_id: Option<String>,
+    pub stop_price: Option<Decimal>,
+    pub trigger_price: Option<Decimal>,
+    pub take_profit_price: Option<Decimal>,
+    pub stop_loss_price: Option<Decimal>,
+    /// exchange specific params, merged under the typed fields
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+impl OrderRequest {
+    pub fn new(symbol: impl Into<String>, r#type: OrderType, side: OrderSide, amount: Decimal) -> Self {
+        OrderRequest {
+            symbol: symbol.into(),
+            r#type,
+            side,
+            amount,
+            price: None,
+            time_in_force: None,
+            post_only: false,
+            reduce_only: false,
+            client_order_id: None,
+            stop_price: None,
+            trigger_price: None,
+            take_profit_price: None,
+            stop_loss_price: None,
+            params: serde_json::Map::new(),
+        }
+    }
+
+    pub fn market(symbol: impl Into<String>, side: OrderSide, amount: Decimal) -> Self {
+        OrderRequest::new(symbol, OrderType::Market, side, amount)
+    }
+
+    pub fn limit(symbol: impl Into<String>, side: OrderSide, amount: Decimal, price: Decimal) -> Self {
+        OrderRequest::new(symbol, OrderType::Limit, side, amount).price(price)
+    }
+
+    /// The limit price, on market orders `place_order` only sends it to the exchanges that compute the cost from it
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn post_only(mut self) -> Self {
+        self.post_only = true;
+        self
+    }
+
+    pub fn reduce_only(mut self) -> Self {
+        self.reduce_only = true;
+        self
+    }
+
+    pub fn client_order_id(mut self, id: impl Into<String>) -> Self {
+        self.client_order_id = Some(id.into());
+        self
+    }
+
+    pub fn stop_price(mut self, price: Decimal) -> Self {
+        self.stop_price = Some(price);
+        self
+    }
+
+    pub fn trigger_price(mut self, price: Decimal) -> Self {
+        self.trigger_price = Some(price);
+        self
+    }
+
+    pub fn take_profit(mut self, price: Decimal) -> Self {
+        self.take_profit_price = Some(price);
+        self
+    }
+
+    pub fn stop_loss(mut self, price: Decimal) -> Self {
+        self.stop_loss_price = Some(price);
+        self
+    }
+
+    pub fn param(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.params.insert(key.into(), value);
+        self
+    }
+
+    /// Rejects the combinations no exchange accepts, with the errors the exchanges would raise
+    pub fn validate(&self) -> Result<()> {
+        if self.symbol.is_empty() {
+            return Err(Error::new("ArgumentsRequired", "createOrder() requires a symbol"));
+        }
+        for (key, setter) in [("postOnly", "post_only()"), ("timeInForce", "time_in_force()")] {
+            if self.params.contains_key(key) {
+                return Err(Error::new("BadRequest", format!("createOrder() takes {} through {}, not param()", key, setter)));
+            }
+        }
+        if self.amount <= Decimal::ZERO {
+            return Err(Error::new("InvalidOrder", format!("createOrder() amount must be positive, got {}", self.amount)));
+        }
+        for (name, price) in [
+            ("price", self.price),
+            ("stopPrice", self.stop_price),
+            ("triggerPrice", self.trigger_price),
+            ("takeProfitPrice", self.take_profit_price),
+            ("stopLossPrice", self.stop_loss_price),
+        ] {
+            if matches!(price, Some(p) if p <= Decimal::ZERO) {
+                return Err(Error::new("InvalidOrder", format!("createOrder() {} must be positive", name)));
+            }
+        }
+        if self.r#type == OrderType::Limit && self.price.is_none() {
+            return Err(Error::new("ArgumentsRequired", "createOrder() requires a price argument for limit orders"));
+        }
+        let post_only = self.post_only || self.time_in_force == Some(TimeInForce::PostOnly);
+        if post_only && self.r#type == OrderType::Market {
+            return Err(Error::new("InvalidOrder", "market orders cannot be postOnly"));
+        }
+        if post_only && matches!(self.time_in_force, Some(TimeInForce::ImmediateOrCancel) | Some(TimeInForce::FillOrKill)) {
+            return Err(Error::new("InvalidOrder", format!("postOnly orders cannot have timeInForce equal to {}", self.time_in_force.as_ref().unwrap())));
+        }
+        if self.stop_price.is_some() && self.trigger_price.is_some() {
+            return Err(Error::new("InvalidOrder", "createOrder() takes either a stopPrice or a triggerPrice, not both"));
+        }
+        Ok(())
+    }
+
+    /// Validates the request and builds the arguments of the unified `create_order`
+    pub fn to_unified(&self) -> Result<CreateOrderArgs> {
+        self.validate()?;
+        let mut params = self.params.clone();
+        if let Some(time_in_force) = &self.time_in_force {
+            params.insert("timeInForce".to_owned(), json!(time_in_force.as_str()));
+        }
+        if self.post_only {
+            params.insert("postOnly".to_owned(), json!(true));
+        }
+        if self.reduce_only {
+            params.insert("reduceOnly".to_owned(), json!(true));
+        }
+        if let Some(id) = &self.client_order_id {
+            params.insert("clientOrderId".to_owned(), json!(id));
+        }
+        for (key, price) in [
+            ("stopPrice", self.stop_price),
+            ("triggerPrice", self.trigger_price),
+            ("takeProfitPrice", self.take_profit_price),
+            ("stopLossPrice", self.stop_loss_price),
+        ] {
+            if let Some(price) = price {
+                params.insert(key.to_owned(), number(price));
+            }
+        }
+        Ok(CreateOrderArgs {
+            symbol: self.symbol.as_str().into(),
+            r#type: self.r#type.as_str().into(),
+            side: self.side.as_str().into(),
+            amount: Value::Json(number(self.amount)),
+            price: self.price.map(|p| Value::Json(number(p))).unwrap_or(Value::Undefined),
+            params: Value::Json(serde_json::Value::Object(params)),
+        })
+    }
+}
+
+// JSON numbers like hand written arguments, the generated code does arithmetic on them (amount * price for the
+// cost of market orders) and rounds them with the precision of the market before they are sent
+fn number(d: Decimal) -> serde_json::Value {
+    if d.fract().is_zero() {
+        if let Some(i) = d.to_i64() {
+            return json!(i);
+        }
+    }
+    json!(d.to_f64().unwrap())
+}
@@ -11,10 +11,12 @@ use async_trait::async_trait;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use crate::exchange::{Exchange, ExchangeImpl, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize};
+use crate::exchange::{Exchange, ExchangeImpl, RestHooks, Precise, Value, ValueTrait, JSON, Array, Object, Math, parse_int, shift_2, extend_2, normalize, js_string};
 
 use crate::exchange::{PRECISE_BASE, TRUNCATE, ROUND, ROUND_UP, ROUND_DOWN};
 use crate::exchange::{DECIMAL_PLACES, SIGNIFICANT_DIGITS, TICK_SIZE, NO_PADDING, PAD_WITH_ZERO};
+use crate::credentials::Credentials;
+use crate::retry::idempotency_context;
 
 // PLEASE DO NOT EDIT THIS FILE, IT IS GENERATED AND WILL BE OVERWRITTEN:
 // https://github.com/ccxt/ccxt/blob/master/CONTRIBUTING.md#how-to-contribute-code
@@ -749,7 +751,7 @@ pub trait Phemex : Exchange {
         price_key = price_key.or_default(Value::from(0));
         amount_key = amount_key.or_default(Value::from(1));
         if market.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" parseBidAsk() requires a market argument"))"###);
+            panic!("ArgumentsRequired::new({} parseBidAsk() requires a market argument)", self.get("id".into()).unwrap_str());
         };
         let mut amount: Value = self.safe_string(bidask.clone(), amount_key.clone(), Value::Undefined);
         if market.get(Value::from("spot")).is_truthy() {
@@ -800,7 +802,7 @@ pub trait Phemex : Exchange {
     async fn fetch_order_book(&mut self, mut symbol: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -839,54 +841,52 @@ pub trait Phemex : Exchange {
         return orderbook.clone();
     }
 
-    fn to_en(&mut self, mut n: Value, mut scale: Value) -> Value {
-        let mut string_n: Value = n.to_string();
+    fn to_en(&self, mut n: Value, mut scale: Value) -> Value {
+        // scaled integers are computed through Precise so that large Ep/Ev values do not lose digits to f64
+        let mut string_n: Value = self.number_to_string(n.clone());
         let mut precise: Value = Precise::new(string_n);
-        precise.set("decimals".into(), precise.get(decimals.clone()) - scale.clone());
-        precise.reduce();
-        let mut string_value: Value = precise.to_string();
-        return parse_int(parse_float(string_value.clone()));
+        let mut scale: i32 = if scale.is_number() { scale.unwrap_json().as_i64().unwrap() as i32 } else { 0 };
+        return precise.unwrap_precise().shift_decimals(-scale).unwrap_precise().to_integer();
     }
 
-    fn to_ev(&mut self, mut amount: Value, mut market: Value) -> Value {
+    fn to_ev(&self, mut amount: Value, mut market: Value) -> Value {
         if amount.clone().is_nullish() || market.clone().is_nullish() {
             return amount.clone();
         };
         return Phemex::to_en(self, amount.clone(), market.get(Value::from("valueScale")));
     }
 
-    fn to_ep(&mut self, mut price: Value, mut market: Value) -> Value {
+    fn to_ep(&self, mut price: Value, mut market: Value) -> Value {
         if price.clone().is_nullish() || market.clone().is_nullish() {
             return price.clone();
         };
         return Phemex::to_en(self, price.clone(), market.get(Value::from("priceScale")));
     }
 
-    fn from_en(&mut self, mut en: Value, mut scale: Value) -> Value {
+    fn from_en(&self, mut en: Value, mut scale: Value) -> Value {
         if en.clone().is_nullish() {
             return Value::Undefined;
         };
-        let mut precise: Value = Precise::new(en);
-        precise.set("decimals".into(), self.sum(precise.get(decimals.clone()), scale.clone()));
-        precise.reduce();
-        return precise.to_string();
+        let mut precise: Value = Precise::new(self.number_to_string(en.clone()));
+        let mut scale: i32 = if scale.is_number() { scale.unwrap_json().as_i64().unwrap() as i32 } else { 0 };
+        return precise.unwrap_precise().shift_decimals(scale).unwrap_precise().to_string().into();
     }
 
-    fn from_ep(&mut self, mut ep: Value, mut market: Value) -> Value {
+    fn from_ep(&self, mut ep: Value, mut market: Value) -> Value {
         if ep.clone().is_nullish() || market.clone().is_nullish() {
             return ep.clone();
         };
         return Phemex::from_en(self, ep.clone(), self.safe_integer(market.clone(), Value::from("priceScale"), Value::Undefined));
     }
 
-    fn from_ev(&mut self, mut ev: Value, mut market: Value) -> Value {
+    fn from_ev(&self, mut ev: Value, mut market: Value) -> Value {
         if ev.clone().is_nullish() || market.clone().is_nullish() {
             return ev.clone();
         };
         return Phemex::from_en(self, ev.clone(), self.safe_integer(market.clone(), Value::from("valueScale"), Value::Undefined));
     }
 
-    fn from_er(&mut self, mut er: Value, mut market: Value) -> Value {
+    fn from_er(&self, mut er: Value, mut market: Value) -> Value {
         if er.clone().is_nullish() || market.clone().is_nullish() {
             return er.clone();
         };
@@ -954,10 +954,10 @@ pub trait Phemex : Exchange {
             request.set("from".into(), now.clone() - duration.clone() * self.sum(limit.clone(), Value::from(1)));
             request.set("to".into(), now.clone());
         } else {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOHLCV() requires a since argument, or a limit argument, or both"))"###);
+            panic!("ArgumentsRequired::new({} fetchOHLCV() requires a since argument, or a limit argument, or both)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         request.set("symbol".into(), market.get(Value::from("id")));
         let mut response: Value = Phemex::dispatch(self, "publicGetMdKline".into(), extend_2(request.clone(), params.clone()), Value::Undefined).await;
         //
@@ -1062,7 +1062,7 @@ pub trait Phemex : Exchange {
     async fn fetch_ticker(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1130,7 +1130,7 @@ pub trait Phemex : Exchange {
     async fn fetch_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -1471,7 +1471,7 @@ pub trait Phemex : Exchange {
             } else {
                 let mut currency: Value = self.safe_string(params.clone(), Value::from("currency"), Value::Undefined);
                 if currency.clone().is_nullish() {
-                    panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchBalance() requires a code parameter or a currency parameter for ") + r#type.clone() + Value::from(" type"))"###);
+                    panic!("ArgumentsRequired::new({} fetchBalance() requires a code parameter or a currency parameter for {} type)", self.get("id".into()).unwrap_str(), js_string(&r#type));
                 };
             };
             method = Value::from("privateGetAccountsAccountPositions");
@@ -1616,7 +1616,7 @@ pub trait Phemex : Exchange {
         return self.safe_string(time_in_forces.clone(), time_in_force.clone(), time_in_force.clone());
     }
 
-    fn parse_spot_order(&self, mut order: Value, mut market: Value) -> Value {
+    fn parse_spot_order(&mut self, mut order: Value, mut market: Value) -> Value {
         //
         // spot
         //
@@ -1679,7 +1679,7 @@ pub trait Phemex : Exchange {
             client_order_id = Value::Undefined;
         };
         let mut market_id: Value = self.safe_string(order.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Phemex::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut price: Value = Phemex::from_ep(self, self.safe_string(order.clone(), Value::from("priceEp"), Value::Undefined), market.clone());
         let mut amount: Value = Phemex::from_ev(self, self.safe_string(order.clone(), Value::from("baseQtyEv"), Value::Undefined), market.clone());
         let mut remaining: Value = self.omit_zero(Phemex::from_ev(self, self.safe_string(order.clone(), Value::from("leavesBaseQtyEv"), Value::Undefined), market.clone()));
@@ -1726,7 +1726,7 @@ pub trait Phemex : Exchange {
         }))).unwrap()), market.clone());
     }
 
-    fn parse_swap_order(&self, mut order: Value, mut market: Value) -> Value {
+    fn parse_swap_order(&mut self, mut order: Value, mut market: Value) -> Value {
         //
         //     {
         //         "bizError":0,
@@ -1769,7 +1769,7 @@ pub trait Phemex : Exchange {
             client_order_id = Value::Undefined;
         };
         let mut market_id: Value = self.safe_string(order.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Phemex::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut status: Value = Phemex::parse_order_status(self, self.safe_string(order.clone(), Value::from("ordStatus"), Value::Undefined));
         let mut side: Value = self.safe_string_lower(order.clone(), Value::from("side"), Value::Undefined);
         let mut r#type: Value = Phemex::parse_order_type(self, self.safe_string(order.clone(), Value::from("orderType"), Value::Undefined));
@@ -1839,7 +1839,7 @@ pub trait Phemex : Exchange {
     async fn create_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         side = self.capitalize(side.clone());
         r#type = self.capitalize(r#type.clone());
         let mut reduce_only: Value = self.safe_value(params.clone(), Value::from("reduceOnly"), Value::Undefined);
@@ -1902,7 +1902,7 @@ pub trait Phemex : Exchange {
                     if price.clone().is_nonnullish() {
                         cost = amount.clone() * price.clone();
                     } else if cost.clone().is_nullish() {
-                        panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" createOrder() ") + qty_type.clone() + Value::from(" requires a price argument or a cost parameter"))"###);
+                        panic!("ArgumentsRequired::new({} createOrder() {} requires a price argument or a cost parameter)", self.get("id".into()).unwrap_str(), js_string(&qty_type));
                     };
                 };
                 cost = if cost.clone().is_nullish() { amount.clone() } else { cost.clone() };
@@ -1938,7 +1938,7 @@ pub trait Phemex : Exchange {
         };
         let mut method: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("privatePostSpotOrders") } else { Value::from("privatePostOrders") };
         params = self.omit(params.clone(), Value::from("reduceOnly"));
-        let mut response: Value = Phemex::dispatch(self, method, extend_2(request.clone(), params.clone()), Value::Undefined).await;
+        let mut response: Value = Phemex::dispatch(self, method, extend_2(request.clone(), params.clone()), idempotency_context(&client_order_id)).await;
         //
         // spot
         //
@@ -2022,16 +2022,16 @@ pub trait Phemex : Exchange {
     async fn edit_order(&mut self, mut id: Value, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" editOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} editOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         if r#type.clone().is_nonnullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" editOrder() type changing is not implemented. Try to cancel & recreate order for that purpose"))"###);
+            panic!("ArgumentsRequired::new({} editOrder() type changing is not implemented. Try to cancel & recreate order for that purpose)", self.get("id".into()).unwrap_str());
         };
         if side.clone().is_nonnullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" editOrder() side changing is not implemented. Try to cancel & recreate order for that purpose"))"###);
+            panic!("ArgumentsRequired::new({} editOrder() side changing is not implemented. Try to cancel & recreate order for that purpose)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -2076,10 +2076,10 @@ pub trait Phemex : Exchange {
     async fn cancel_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
@@ -2107,14 +2107,14 @@ pub trait Phemex : Exchange {
     async fn cancel_all_orders(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" cancelAllOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} cancelAllOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut request: Value = Value::new_object();
         // 'symbol': market['id'],
         // 'untriggerred': false, // false to cancel non-conditional orders, true to cancel conditional orders
         // 'text': 'up to 40 characters max',
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = Value::from("privateDeleteSpotOrdersAll");
         if market.get(Value::from("swap")).is_truthy() {
             method = Value::from("privateDeleteOrdersAll");
@@ -2134,10 +2134,10 @@ pub trait Phemex : Exchange {
     async fn fetch_order(&mut self, mut id: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrder() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrder() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("privateGetSpotOrdersActive") } else { Value::from("privateGetExchangeOrder") };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2156,9 +2156,9 @@ pub trait Phemex : Exchange {
             let mut num_orders: Value = data.len().into();
             if num_orders.clone() < Value::from(1) {
                 if client_order_id.clone().is_nonnullish() {
-                    panic!(r###"OrderNotFound::new(self.get("id".into()) + Value::from(" fetchOrder() ") + symbol.clone() + Value::from(" order with clientOrderId ") + client_order_id.clone() + Value::from(" not found"))"###);
+                    panic!("OrderNotFound::new({} fetchOrder() {} order with clientOrderId {} not found)", self.get("id".into()).unwrap_str(), js_string(&symbol), js_string(&client_order_id));
                 } else {
-                    panic!(r###"OrderNotFound::new(self.get("id".into()) + Value::from(" fetchOrder() ") + symbol.clone() + Value::from(" order with id ") + id.clone() + Value::from(" not found"))"###);
+                    panic!("OrderNotFound::new({} fetchOrder() {} order with id {} not found)", self.get("id".into()).unwrap_str(), js_string(&symbol), js_string(&id));
                 };
             };
             order = self.safe_value(data.clone(), Value::from(0), Value::new_object());
@@ -2179,10 +2179,10 @@ pub trait Phemex : Exchange {
     async fn fetch_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("privateGetSpotOrders") } else { Value::from("privateGetExchangeOrderList") };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2212,10 +2212,10 @@ pub trait Phemex : Exchange {
     async fn fetch_open_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchOpenOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchOpenOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("privateGetSpotOrders") } else { Value::from("privateGetOrdersActiveList") };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2247,10 +2247,10 @@ pub trait Phemex : Exchange {
     async fn fetch_closed_orders(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchClosedOrders() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchClosedOrders() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("privateGetExchangeSpotOrder") } else { Value::from("privateGetExchangeOrderList") };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2321,10 +2321,10 @@ pub trait Phemex : Exchange {
     async fn fetch_my_trades(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchMyTrades() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchMyTrades() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut method: Value = if market.get(Value::from("spot")).is_truthy() { Value::from("privateGetExchangeSpotOrderTrades") } else { Value::from("privateGetExchangeOrderTrade") };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -2895,16 +2895,16 @@ pub trait Phemex : Exchange {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchFundingHistory() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchFundingHistory() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
         }))).unwrap());
         // 'limit': 20, // Page size default 20, max 200
         // 'offset': 0, // Page start default 0
         if limit.clone() > Value::from(200) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" fetchFundingHistory() limit argument cannot exceed 200"))"###);
+            panic!("BadRequest::new({} fetchFundingHistory() limit argument cannot exceed 200)", self.get("id".into()).unwrap_str());
         };
         if limit.clone().is_nonnullish() {
             request.set("limit".into(), limit.clone());
@@ -2964,9 +2964,9 @@ pub trait Phemex : Exchange {
     async fn fetch_funding_rate(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if !market.get(Value::from("swap")).is_truthy() {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchFundingRate() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} fetchFundingRate() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id"))
@@ -3020,7 +3020,7 @@ pub trait Phemex : Exchange {
         //     }
         //
         let mut market_id: Value = self.safe_string(contract.clone(), Value::from("symbol"), Value::Undefined);
-        let mut symbol: Value = Phemex::safe_symbol(self, market_id.clone(), market.clone(), Value::Undefined);
+        let mut symbol: Value = self.safe_symbol(market_id.clone(), market.clone(), Value::Undefined);
         let mut timestamp: Value = self.safe_integer_product(contract.clone(), Value::from("timestamp"), Value::from(0.000001), Value::Undefined);
         return Value::Json(normalize(&Value::Json(json!({
             "info": contract,
@@ -3056,7 +3056,7 @@ pub trait Phemex : Exchange {
     async fn set_margin(&mut self, mut symbol: Value, mut amount: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
             "posBalanceEv": Phemex::to_ev(self, amount.clone(), market.clone())
@@ -3098,7 +3098,7 @@ pub trait Phemex : Exchange {
             "amount": Value::Undefined,
             "total": Value::Undefined,
             "code": market.get(code_currency.clone()),
-            "symbol": Phemex::safe_symbol(self, Value::Undefined, market.clone(), Value::Undefined),
+            "symbol": self.safe_symbol(Value::Undefined, market.clone(), Value::Undefined),
             "status": Phemex::parse_margin_status(self, self.safe_string(data.clone(), Value::from("code"), Value::Undefined))
         }))).unwrap());
     }
@@ -3115,23 +3115,23 @@ pub trait Phemex : Exchange {
     async fn set_margin_mode(&mut self, mut margin_mode: Value, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         margin_mode = margin_mode.to_lower_case();
         if margin_mode.clone() != Value::from("isolated") && margin_mode.clone() != Value::from("cross") {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setMarginMode() marginMode argument should be isolated or cross"))"###);
+            panic!("BadRequest::new({} setMarginMode() marginMode argument should be isolated or cross)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.get(Value::from("type")) != Value::from("swap") {
-            panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" setMarginMode() supports swap contracts only"))"###);
+            panic!("BadSymbol::new({} setMarginMode() supports swap contracts only)", self.get("id".into()).unwrap_str());
         };
         let mut leverage: Value = self.safe_integer(params.clone(), Value::from("leverage"), Value::Undefined);
         if margin_mode.clone() == Value::from("cross") {
             leverage = Value::from(0);
         };
         if leverage.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setMarginMode() requires a leverage parameter"))"###);
+            panic!("ArgumentsRequired::new({} setMarginMode() requires a leverage parameter)", self.get("id".into()).unwrap_str());
         };
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
@@ -3331,13 +3331,13 @@ pub trait Phemex : Exchange {
         // WARNING: THIS WILL INCREASE LIQUIDATION PRICE FOR OPEN ISOLATED LONG POSITIONS
         // AND DECREASE LIQUIDATION PRICE FOR OPEN ISOLATED SHORT POSITIONS
         if symbol.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" setLeverage() requires a symbol argument"))"###);
+            panic!("ArgumentsRequired::new({} setLeverage() requires a symbol argument)", self.get("id".into()).unwrap_str());
         };
         if leverage.clone() < Value::from(1) || leverage.clone() > Value::from(100) {
-            panic!(r###"BadRequest::new(self.get("id".into()) + Value::from(" setLeverage() leverage should be between 1 and 100"))"###);
+            panic!("BadRequest::new({} setLeverage() leverage should be between 1 and 100)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
             "symbol": market.get(Value::from("id")),
             "leverage": leverage
@@ -3446,7 +3446,7 @@ pub trait Phemex : Exchange {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
         if code.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" fetchTransfers() requires a code argument"))"###);
+            panic!("ArgumentsRequired::new({} fetchTransfers() requires a code argument)", self.get("id".into()).unwrap_str());
         };
         let mut currency: Value = Phemex::currency(self, code.clone());
         let mut request: Value = Value::Json(normalize(&Value::Json(json!({
@@ -3572,7 +3572,7 @@ pub trait Phemex : Exchange {
             let mut feedback: Value = self.get("id".into()) + Value::from(" ") + body.clone();
             Phemex::throw_exactly_matched_exception(self, self.get("exceptions".into()).get(Value::from("exact")), error_code.clone(), feedback.clone());
             Phemex::throw_broadly_matched_exception(self, self.get("exceptions".into()).get(Value::from("broad")), message.clone(), feedback.clone());
-            panic!(r###"ExchangeError::new(feedback)"###);
+            panic!("ExchangeError::new({})", feedback.unwrap_str());
         };
         Value::Undefined
     }
@@ -3730,12 +3730,12 @@ pub trait Phemex : Exchange {
             if used.clone().is_nullish() && total.clone().is_nonnullish() && free.clone().is_nonnullish() {
                 used = Precise::string_sub(total.clone(), free.clone());
             };
-            balance.get(code.clone()).set("free".into(), self.parse_number(free.clone(), Value::Undefined));
-            balance.get(code.clone()).set("used".into(), self.parse_number(used.clone(), Value::Undefined));
-            balance.get(code.clone()).set("total".into(), self.parse_number(total.clone(), Value::Undefined));
-            balance.get(Value::from("free")).set(code.clone(), balance.get(code.clone()).get(Value::from("free")));
-            balance.get(Value::from("used")).set(code.clone(), balance.get(code.clone()).get(Value::from("used")));
-            balance.get(Value::from("total")).set(code.clone(), balance.get(code.clone()).get(Value::from("total")));
+            balance.set_path(&[code.clone(), "free".into()], self.parse_number(free.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "used".into()], self.parse_number(used.clone(), Value::Undefined));
+            balance.set_path(&[code.clone(), "total".into()], self.parse_number(total.clone(), Value::Undefined));
+            balance.set_path(&[Value::from("free"), code.clone()], balance.get(code.clone()).get(Value::from("free")));
+            balance.set_path(&[Value::from("used"), code.clone()], balance.get(code.clone()).get(Value::from("used")));
+            balance.set_path(&[Value::from("total"), code.clone()], balance.get(code.clone()).get(Value::from("total")));
             i += 1;
         };
         return balance.clone();
@@ -3841,9 +3841,9 @@ pub trait Phemex : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -4082,9 +4082,9 @@ pub trait Phemex : Exchange {
             let mut reduced_length: Value = reduced_fees.len().into();
             let mut i: usize = 0;
             while i < reduced_length.clone().into() {
-                reduced_fees.get(i.into()).set("cost".into(), Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
+                reduced_fees.set_path(&[i.into(), "cost".into()], Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("cost"), Value::Undefined));
                 if reduced_fees.get(i.into()).contains_key(Value::from("rate")) {
-                    reduced_fees.get(i.into()).set("rate".into(), Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
+                    reduced_fees.set_path(&[i.into(), "rate".into()], Phemex::safe_number(self, reduced_fees.get(i.into()), Value::from("rate"), Value::Undefined));
                 };
                 i += 1;
             };
@@ -4179,14 +4179,14 @@ pub trait Phemex : Exchange {
                 };
                 let mut rate_key: Value = if rate.clone().is_nullish() { Value::from("") } else { rate.clone() };
                 if reduced.get(fee_currency_code.clone()).contains_key(rate_key.clone()) {
-                    reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("cost".into(), Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "cost".into()], Precise::string_add(reduced.get(fee_currency_code.clone()).get(rate_key.clone()).get(Value::from("cost")), cost.clone()));
                 } else {
-                    reduced.get(fee_currency_code.clone()).set(rate_key.clone(), Value::Json(normalize(&Value::Json(json!({
+                    reduced.set_path(&[fee_currency_code.clone(), rate_key.clone()], Value::Json(normalize(&Value::Json(json!({
                         "currency": fee_currency_code,
                         "cost": cost
                     }))).unwrap()));
                     if rate.clone().is_nonnullish() {
-                        reduced.get(fee_currency_code.clone()).get(rate_key.clone()).set("rate".into(), rate.clone());
+                        reduced.set_path(&[fee_currency_code.clone(), rate_key.clone(), "rate".into()], rate.clone());
                     };
                 };
             };
@@ -4408,7 +4408,7 @@ pub trait Phemex : Exchange {
         } else if aliases.contains_key(network.clone()) {
             return aliases.get(network.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" network ") + network.clone() + Value::from(" is not yet supported"))"###);
+            panic!("NotSupported::new({} network {} is not yet supported)", self.get("id".into()).unwrap_str(), js_string(&network));
         };
         Value::Undefined
     }
@@ -4459,10 +4459,10 @@ pub trait Phemex : Exchange {
                 let mut i: usize = 0;
                 while i < symbols.len() {
                     let mut symbol: Value = symbols.get(i.into());
-                    self.get("markets".into()).set(symbol.clone(), self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
+                    self.set_path(&["markets".into(), symbol.clone()], self.deep_extend_2(self.get("markets".into()).get(symbol.clone()), response.get(symbol.clone())));
                     i += 1;
                 };
-                self.get("options".into()).set("limitsLoaded".into(), self.milliseconds());
+                self.set_path(&["options".into(), "limitsLoaded".into()], self.milliseconds());
             };
         };
         return self.get("markets".into());
@@ -4576,7 +4576,7 @@ pub trait Phemex : Exchange {
     }
 
     fn market_id(&mut self, mut symbol: Value) -> Value {
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         if market.clone().is_nonnullish() {
             return market.get(Value::from("id"));
         };
@@ -4584,7 +4584,7 @@ pub trait Phemex : Exchange {
     }
 
     fn symbol(&self, mut symbol: Value) -> Value {
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.safe_string(market.clone(), Value::from("symbol"), symbol.clone());
     }
 
@@ -4610,19 +4610,8 @@ pub trait Phemex : Exchange {
         return if indexed.is_truthy() { self.index_by(results.clone(), key.clone(), Value::Undefined) } else { results.clone() };
     }
 
-    async fn fetch2(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
-        api = api.or_default(Value::from("public"));
-        method = method.or_default(Value::from("GET"));
-        params = params.or_default(Value::new_object());
-        config = config.or_default(Value::new_object());
-        context = context.or_default(Value::new_object());
-        if self.get("enable_rate_limit".into()).is_truthy() {
-            let mut cost: Value = Phemex::calculate_rate_limiter_cost(self, api.clone(), method.clone(), path.clone(), params.clone(), config.clone(), context.clone());
-            self.throttle(cost.clone()).await;
-        };
-        self.set("last_rest_request_timestamp".into(), self.milliseconds());
-        let mut request: Value = Phemex::sign(self, path.clone(), api.clone(), method.clone(), params.clone(), headers.clone(), body.clone());
-        return self.fetch(request.get(Value::from("url")), request.get(Value::from("method")), request.get(Value::from("headers")), request.get(Value::from("body"))).await;
+    async fn fetch2(&mut self, path: Value, api: Value, method: Value, params: Value, headers: Value, body: Value, config: Value, context: Value) -> Value {
+        self.fetch2_with_retry(path, api, method, params, headers, body, config, context, RestHooks { load_time_difference: |exchange, params| Phemex::load_time_difference(exchange, params), calculate_rate_limiter_cost: Phemex::calculate_rate_limiter_cost, sign: Phemex::sign, handle_errors: Phemex::handle_errors }).await
     }
 
     async fn request(&mut self, mut path: Value, mut api: Value, mut method: Value, mut params: Value, mut headers: Value, mut body: Value, mut config: Value, mut context: Value) -> Value {
@@ -4654,7 +4643,7 @@ pub trait Phemex : Exchange {
         timeframe = timeframe.or_default(Value::from("1m"));
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTrades")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchOHLCV() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchOHLCV() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
         let mut trades: Value = Phemex::fetch_trades(self, symbol.clone(), since.clone(), limit.clone(), params.clone()).await;
@@ -4684,13 +4673,13 @@ pub trait Phemex : Exchange {
 
     async fn fetch_permissions(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPermissions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchPermissions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_bids_asks(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBidsAsks() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchBidsAsks() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4782,13 +4771,14 @@ pub trait Phemex : Exchange {
 
     fn check_required_credentials(&mut self, mut error: Value) -> Value {
         error = error.or_default(true.into());
-        let mut keys: Value = Object::keys(self.get("required_credentials".into()));
+        let mut required_credentials: Value = self.deep_extend_2(self.get("required_credentials".into()), self.get("requiredCredentials".into()));
+        let mut keys: Value = Object::keys(required_credentials.clone());
         let mut i: usize = 0;
         while i < keys.len() {
             let mut key: Value = keys.get(i.into());
-            if self.get("required_credentials".into()).get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
+            if required_credentials.get(key.clone()).is_truthy() && !self.get(key.clone()).is_truthy() {
                 if error.is_truthy() {
-                    panic!(r###"AuthenticationError::new(self.get("id".into()) + Value::from(r#" requires ""#) + key.clone() + Value::from(r#"" credential"#))"###);
+                    panic!("AuthenticationError::new({} requires \"{}\" credential)", self.get("id".into()).unwrap_str(), key.unwrap_str());
                 } else {
                     return error.clone();
                 };
@@ -4802,7 +4792,7 @@ pub trait Phemex : Exchange {
         if self.get("twofa".into()).is_nonnullish() {
             return self.totp(self.get("twofa".into()));
         } else {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" exchange.twofa has not been set for 2FA Two-Factor Authentication"))"###);
+            panic!("ExchangeError::new({} exchange.twofa has not been set for 2FA Two-Factor Authentication)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -4843,7 +4833,7 @@ pub trait Phemex : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fee: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFee"), true.into());
         if warn_on_fetch_funding_fee.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options["warnOnFetchFundingFee"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFee() method is deprecated, it will be removed in July 2022, please, use fetchTransactionFee() or set exchange.options[\"warnOnFetchFundingFee\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Phemex::fetch_transaction_fee(self, code.clone(), params.clone()).await;
     }
@@ -4852,7 +4842,7 @@ pub trait Phemex : Exchange {
         params = params.or_default(Value::new_object());
         let mut warn_on_fetch_funding_fees: Value = self.safe_value(self.get("options".into()), Value::from("warnOnFetchFundingFees"), true.into());
         if warn_on_fetch_funding_fees.is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(r#" fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options["warnOnFetchFundingFees"] = false to suppress this warning"#))"###);
+            panic!("NotSupported::new({} fetchFundingFees() method is deprecated, it will be removed in July 2022. Please, use fetchTransactionFees() or set exchange.options[\"warnOnFetchFundingFees\"] = false to suppress this warning)", self.get("id".into()).unwrap_str());
         };
         return Phemex::fetch_transaction_fees(self, codes.clone(), params.clone()).await;
     }
@@ -4860,14 +4850,14 @@ pub trait Phemex : Exchange {
     async fn fetch_transaction_fee(&mut self, mut code: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTransactionFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTransactionFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Phemex::fetch_transaction_fees(self, Value::Json(serde_json::Value::Array(vec![code.clone().into()])), params.clone()).await;
     }
 
     async fn fetch_transaction_fees(&mut self, mut codes: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactionFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactionFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4876,7 +4866,7 @@ pub trait Phemex : Exchange {
         if mapping.contains_key(key.clone()) {
             return mapping.get(key.clone());
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" ") + key.clone() + Value::from(" does not have a value in mapping"))"###);
+            panic!("NotSupported::new({} {} does not have a value in mapping)", self.get("id".into()).unwrap_str(), js_string(&key));
         };
         Value::Undefined
     }
@@ -4885,12 +4875,12 @@ pub trait Phemex : Exchange {
         params = params.or_default(Value::new_object());
         Phemex::load_markets(self, Value::Undefined, Value::Undefined).await;
         if !self.get("has".into()).get(Value::from("fetchBorrowRates")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchBorrowRate() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchBorrowRate() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut borrow_rates: Value = self.fetch_borrow_rates(params.clone()).await;
         let mut rate: Value = self.safe_value(borrow_rates.clone(), code.clone(), Value::Undefined);
         if rate.clone().is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" fetchBorrowRate() could not find the borrow rate for currency code ") + code.clone())"###);
+            panic!("ExchangeError::new({} fetchBorrowRate() could not find the borrow rate for currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         };
         return rate.clone();
     }
@@ -4943,14 +4933,14 @@ pub trait Phemex : Exchange {
 
     fn throw_exactly_matched_exception(&mut self, mut exact: Value, mut string: Value, mut message: Value) -> () {
         if exact.contains_key(string.clone()) {
-            panic!(r###"exact.get(string.clone())::new(message)"###);
+            panic!("{}::new({})", exact.get(string.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
     fn throw_broadly_matched_exception(&mut self, mut broad: Value, mut string: Value, mut message: Value) -> () {
         let mut broad_key: Value = Phemex::find_broadly_matched_key(self, broad.clone(), string.clone());
         if broad_key.clone().is_nonnullish() {
-            panic!(r###"broad.get(broad_key.clone())::new(message)"###);
+            panic!("{}::new({})", broad.get(broad_key.clone()).unwrap_str(), message.unwrap_str());
         };
     }
 
@@ -4976,7 +4966,7 @@ pub trait Phemex : Exchange {
 
     async fn fetch_tickers(&mut self, mut symbols: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTickers() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTickers() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -4998,7 +4988,7 @@ pub trait Phemex : Exchange {
 
     async fn fetch_transactions(&mut self, mut symbol: Value, mut since: Value, mut limit: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTransactions() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTransactions() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5019,7 +5009,7 @@ pub trait Phemex : Exchange {
 
     fn currency(&self, mut code: Value) -> Value {
         if self.get("currencies".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" currencies not loaded"))"###);
+            panic!("ExchangeError::new({} currencies not loaded)", self.get("id".into()).unwrap_str());
         };
         if code.typeof_() == Value::from("string") {
             if self.get("currencies".into()).contains_key(code.clone()) {
@@ -5028,25 +5018,7 @@ pub trait Phemex : Exchange {
                 return self.get("currencies_by_id".into()).get(code.clone());
             };
         };
-        panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" does not have currency code ") + code.clone())"###);
-        Value::Undefined
-    }
-
-    fn market(&self, mut symbol: Value) -> Value {
-        if self.get("markets".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if self.get("markets_by_id".into()).is_nullish() {
-            panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(" markets not loaded"))"###);
-        };
-        if symbol.typeof_() == Value::from("string") {
-            if self.get("markets".into()).contains_key(symbol.clone()) {
-                return self.get("markets".into()).get(symbol.clone());
-            } else if self.get("markets_by_id".into()).contains_key(symbol.clone()) {
-                return self.get("markets_by_id".into()).get(symbol.clone());
-            };
-        };
-        panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" does not have market symbol ") + symbol.clone())"###);
+        panic!("ExchangeError::new({} does not have currency code {})", self.get("id".into()).unwrap_str(), js_string(&code));
         Value::Undefined
     }
 
@@ -5095,22 +5067,22 @@ pub trait Phemex : Exchange {
     }
 
     fn cost_to_precision(&mut self, mut symbol: Value, mut cost: Value) -> Value {
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(cost.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn price_to_precision(&mut self, mut symbol: Value, mut price: Value) -> Value {
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(price.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn amount_to_precision(&mut self, mut symbol: Value, mut amount: Value) -> Value {
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(amount.clone(), TRUNCATE.into(), market.get(Value::from("precision")).get(Value::from("amount")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
     fn fee_to_precision(&mut self, mut symbol: Value, mut fee: Value) -> Value {
-        let mut market: Value = Phemex::market(self, symbol.clone());
+        let mut market: Value = self.market(symbol.clone());
         return self.decimal_to_precision(fee.clone(), ROUND.into(), market.get(Value::from("precision")).get(Value::from("price")), self.get("precision_mode".into()), self.get("padding_mode".into()));
     }
 
@@ -5150,9 +5122,7 @@ pub trait Phemex : Exchange {
     async fn load_time_difference(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         let mut server_time: Value = self.fetch_time(params.clone()).await;
-        let mut after: Value = self.milliseconds();
-        self.get("options".into()).set("timeDifference".into(), after.clone() - server_time.clone());
-        return self.get("options".into()).get(Value::from("timeDifference"));
+        return self.set_time_difference(server_time);
     }
 
     fn implode_hostname(&mut self, mut url: Value) -> Value {
@@ -5164,14 +5134,14 @@ pub trait Phemex : Exchange {
     async fn fetch_market_leverage_tiers(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if self.get("has".into()).get(Value::from("fetchLeverageTiers")).is_truthy() {
-            let mut market: Value = Phemex::market(self, symbol.clone());
+            let mut market: Value = self.market(symbol.clone());
             if !market.get(Value::from("contract")).is_truthy() {
-                panic!(r###"BadSymbol::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() supports contract markets only"))"###);
+                panic!("BadSymbol::new({} fetchMarketLeverageTiers() supports contract markets only)", self.get("id".into()).unwrap_str());
             };
             let mut tiers: Value = Phemex::fetch_leverage_tiers(self, Value::Json(serde_json::Value::Array(vec![symbol.clone().into()])), Value::Undefined).await;
             return self.safe_value(tiers.clone(), symbol.clone(), Value::Undefined);
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarketLeverageTiers() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarketLeverageTiers() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5179,7 +5149,7 @@ pub trait Phemex : Exchange {
     async fn create_post_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createPostOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createPostOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createPostOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "postOnly": true
@@ -5190,7 +5160,7 @@ pub trait Phemex : Exchange {
     async fn create_reduce_only_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createReduceOnlyOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from("createReduceOnlyOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({}createReduceOnlyOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "reduceOnly": true
@@ -5201,10 +5171,10 @@ pub trait Phemex : Exchange {
     async fn create_stop_order(&mut self, mut symbol: Value, mut r#type: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         if stop_price.clone().is_nullish() {
-            panic!(r###"ArgumentsRequired::new(self.get("id".into()) + Value::from(" create_stop_order() requires a stopPrice argument"))"###);
+            panic!("ArgumentsRequired::new({} create_stop_order() requires a stopPrice argument)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -5215,7 +5185,7 @@ pub trait Phemex : Exchange {
     async fn create_stop_limit_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut price: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopLimitOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopLimitOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopLimitOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -5226,7 +5196,7 @@ pub trait Phemex : Exchange {
     async fn create_stop_market_order(&mut self, mut symbol: Value, mut side: Value, mut amount: Value, mut stop_price: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("createStopMarketOrder")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" createStopMarketOrder() is not supported yet"))"###);
+            panic!("NotSupported::new({} createStopMarketOrder() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         let mut query: Value = extend_2(params.clone(), Value::Json(normalize(&Value::Json(json!({
             "stopPrice": stop_price
@@ -5336,11 +5306,6 @@ pub trait Phemex : Exchange {
         return Phemex::filter_by_symbol_since_limit(self, sorted.clone(), symbol.clone(), since.clone(), limit.clone(), Value::Undefined);
     }
 
-    fn safe_symbol(&self, mut market_id: Value, mut market: Value, mut delimiter: Value) -> Value {
-        market = Phemex::safe_market(self, market_id.clone(), market.clone(), delimiter.clone());
-        return market.get(Value::from("symbol"));
-    }
-
     fn parse_funding_rates(&self, mut response: Value, mut market: Value) -> Value {
         let mut result: Value = Value::new_object();
         let mut i: usize = 0;
@@ -5372,9 +5337,9 @@ pub trait Phemex : Exchange {
         post_only = (post_only.is_truthy() || time_in_force_post_only.is_truthy() || exchange_specific_param.is_truthy()).into();
         if post_only.is_truthy() {
             if ioc.is_truthy() || fok.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" postOnly orders cannot have timeInForce equal to ") + time_in_force.clone())"###);
+                panic!("InvalidOrder::new({} postOnly orders cannot have timeInForce equal to {})", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             } else if is_market_order.is_truthy() {
-                panic!(r###"InvalidOrder::new(self.get("id".into()) + Value::from(" market orders cannot be postOnly"))"###);
+                panic!("InvalidOrder::new({} market orders cannot be postOnly)", self.get("id".into()).unwrap_str());
             } else {
                 return true.into();
             };
@@ -5386,20 +5351,20 @@ pub trait Phemex : Exchange {
 
     async fn fetch_trading_fees(&mut self, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFees() is not supported yet"))"###);
+        panic!("NotSupported::new({} fetchTradingFees() is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
     async fn fetch_trading_fee(&mut self, mut symbol: Value, mut params: Value) -> Value {
         params = params.or_default(Value::new_object());
         if !self.get("has".into()).get(Value::from("fetchTradingFees")).is_truthy() {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchTradingFee() is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchTradingFee() is not supported yet)", self.get("id".into()).unwrap_str());
         };
         return Phemex::fetch_trading_fees(self, params.clone()).await;
     }
 
     fn parse_open_interest(&self, mut interest: Value, mut market: Value) -> Value {
-        panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" parseOpenInterest () is not supported yet"))"###);
+        panic!("NotSupported::new({} parseOpenInterest () is not supported yet)", self.get("id".into()).unwrap_str());
         Value::Undefined
     }
 
@@ -5437,7 +5402,7 @@ pub trait Phemex : Exchange {
             }))).unwrap());
             return Phemex::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchMarkOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchMarkOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5462,7 +5427,7 @@ pub trait Phemex : Exchange {
             }))).unwrap());
             return Phemex::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5487,7 +5452,7 @@ pub trait Phemex : Exchange {
             }))).unwrap());
             return Phemex::fetch_ohlcv(self, symbol.clone(), timeframe.clone(), since.clone(), limit.clone(), extend_2(request.clone(), params.clone())).await;
         } else {
-            panic!(r###"NotSupported::new(self.get("id".into()) + Value::from(" fetchPremiumIndexOHLCV () is not supported yet"))"###);
+            panic!("NotSupported::new({} fetchPremiumIndexOHLCV () is not supported yet)", self.get("id".into()).unwrap_str());
         };
         Value::Undefined
     }
@@ -5503,7 +5468,7 @@ pub trait Phemex : Exchange {
         if time_in_force.clone().is_nonnullish() {
             let mut exchange_value: Value = self.safe_string(self.get("options".into()).get(Value::from("timeInForce")), time_in_force.clone(), Value::Undefined);
             if exchange_value.clone().is_nullish() {
-                panic!(r###"ExchangeError::new(self.get("id".into()) + Value::from(r#" does not support timeInForce ""#) + time_in_force.clone() + Value::from(r#"""#))"###);
+                panic!("ExchangeError::new({} does not support timeInForce \"{}\")", self.get("id".into()).unwrap_str(), js_string(&time_in_force));
             };
             return exchange_value.clone();
         };
@@ -5524,7 +5489,7 @@ pub trait Phemex : Exchange {
         if accounts_by_type.contains_key(account.clone()) {
             return accounts_by_type.get(account.clone());
         } else if self.in_array(account.clone(), symbols.clone()).is_truthy() {
-            let mut market: Value = Phemex::market(self, account.clone());
+            let mut market: Value = self.market(account.clone());
             return market.get(Value::from("id"));
         } else {
             return account.clone();
@@ -5572,16 +5537,16 @@ pub trait Phemex : Exchange {
     async fn load_markets(&mut self, mut reload: Value, mut params: Value) -> Value {
         reload = reload.or_default(false.into());
         params = params.or_default(Value::new_object());
-        // this method is async, it returns a promise
-        if reload.is_truthy() && !self.get("reloading_markets".into()).is_truthy() || !self.get("markets_loading".into()).is_truthy() {
-            self.set("reloading_markets".into(), true.into());
-            // TODO This should use a finally block
-            let mut markets_loading: Value = Phemex::load_markets_helper(self, reload.clone(), params.clone()).await;
-            self.set("markets_loading".into(), markets_loading.clone());
-            self.set("reloading_markets".into(), false.into());
-            return self.get("markets_loading".into());
+        // concurrent callers wait for the load in flight and reuse the markets it loaded
+        let requested_at: Value = self.milliseconds();
+        let loader = self.markets_loader();
+        let mut cache = loader.lock().await;
+        reload = self.markets_reload_needed(&mut cache, reload.clone(), requested_at.clone());
+        let markets: Value = Phemex::load_markets_helper(self, reload.clone(), params.clone()).await;
+        if reload.is_truthy() {
+            self.cache_markets(&mut cache);
         };
-        return self.get("markets_loading".into());
+        return markets;
     }
 
     
@@ -5660,7 +5625,7 @@ pub trait Phemex : Exchange {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct PhemexImpl(Value);
+pub struct PhemexImpl(Value, #[serde(skip)] pub(crate) Credentials);
 impl Exchange for PhemexImpl {}
 impl Phemex for PhemexImpl {}
 impl ValueTrait for PhemexImpl {
@@ -5682,8 +5647,8 @@ impl ValueTrait for PhemexImpl {
     fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
     fn unwrap_precise_mut(&mut self) -> &mut Precise { self.0.unwrap_precise_mut() }
     fn len(&self) -> usize { self.0.len() }
-    fn get(&self, key: Value) -> Value { self.0.get(key) }
-    fn set(&mut self, key: Value, value: Value) { self.0.set(key, value) }
+    fn get(&self, key: Value) -> Value { self.1.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+    fn set(&mut self, key: Value, value: Value) { if !self.1.set_value(&key, &value) { self.0.set(key, value) } }
     fn push(&mut self, value: Value) { self.0.push(value) }
     fn split(&self, separator: Value) -> Value { self.0.split(separator) }
     fn contains_key(&self, key: Value) -> bool { self.0.contains_key(key) }
@@ -5702,7 +5667,8 @@ impl PhemexImpl {
         let mut rv = PhemexImpl(match params {
             Value::Json(_) => params,
             _ => Value::new_object()
-        });
+        }, Credentials::default());
+        rv.1 = Credentials::take_from(&mut rv.0);
         ExchangeImpl::init(&mut rv.0);
 
         let config_entries = Phemex::describe(&rv);
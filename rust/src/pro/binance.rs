@@ -0,0 +1,647 @@
+use std::collections::{BTreeMap, HashMap};
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::Duration;
+use async_trait::async_trait;
+use serde_json::json;
+use futures::stream::{self, BoxStream, StreamExt};
+use tokio::sync::{mpsc, watch as key_watch};
+use crate::binance::{Binance, BinanceImpl};
+use crate::errors::{catch, Error};
+use crate::exchange::{LoadTimeDifference, Value, ValueTrait};
+use crate::pro::client::{self, Client, Event, Incoming, Protocol, Subscription, Topic};
+use crate::pro::order_book::{watch_order_book, Delta, Level, LocalOrderBook};
+use crate::pro::{filter_since_limit, streaming_config, watch, WatchStream};
+
+// the names the 24hr ticker stream gives the fields of the rest ticker
+const TICKER_FIELDS: &[(&str, &str)] = &[
+    ("s", "symbol"), ("p", "priceChange"), ("P", "priceChangePercent"), ("w", "weightedAvgPrice"), ("x", "prevClosePrice"),
+    ("c", "lastPrice"), ("Q", "lastQty"), ("b", "bidPrice"), ("B", "bidQty"), ("a", "askPrice"), ("A", "askQty"),
+    ("o", "openPrice"), ("h", "highPrice"), ("l", "lowPrice"), ("v", "volume"), ("q", "quoteVolume"), ("O", "openTime"),
+    ("C", "closeTime"), ("F", "firstId"), ("L", "lastId"), ("n", "count"),
+];
+
+/// The combined stream endpoints, a topic is a stream name like `btcusdt@trade` and every update carries it, and the
+/// websocket api, whose requests are a method and its params
+pub struct BinanceProtocol;
+
+impl Protocol for BinanceProtocol {
+    fn subscribe(&self, topic: &Topic, id: u64) -> serde_json::Value {
+        json!({"method": "SUBSCRIBE", "params": [topic.request], "id": id})
+    }
+
+    fn unsubscribe(&self, topic: &Topic, id: u64) -> Option<serde_json::Value> {
+        Some(json!({"method": "UNSUBSCRIBE", "params": [topic.request], "id": id}))
+    }
+
+    fn route(&self, mut message: serde_json::Value) -> Incoming {
+        //
+        //     {"stream": "btcusdt@trade", "data": {"e": "trade", "E": 1672515782136, "s": "BTCUSDT", ...}}
+        //     {"result": null, "id": 1}
+        //     {"error": {"code": 2, "msg": "Invalid request: unknown variant"}, "id": 1}
+        //     {"id": 3, "status": 200, "result": {"symbol": "BTCUSDT", "orderId": 12510053279, ...}, "rateLimits": [...]}
+        //
+        if let Some(stream) = message["stream"].as_str().map(str::to_owned) {
+            if let Some(data) = message.get_mut("data") {
+                return Incoming::Updates(vec![(stream, data.take())]);
+            }
+        }
+        if let (Some(id), Some(_)) = (message["id"].as_u64(), message.get("status")) {
+            return Incoming::Response { id, message };
+        }
+        match message["id"].as_u64() {
+            Some(id) => Incoming::Reply {
+                id,
+                error: message.get("error").filter(|error| !error.is_null()).map(|error| {
+                    Error::new("ExchangeError", format!("binance {} {}", error["code"], error["msg"].as_str().unwrap_or_default()))
+                }),
+            },
+            None => Incoming::Ignore,
+        }
+    }
+}
+
+/// The rest ticker shape of a `24hrTicker` update, COIN-M tickers count the volume in contracts and the base
+/// volume in `q`
+fn rest_ticker(ticker: &serde_json::Value, inverse: bool) -> Value {
+    let mut rv = serde_json::Map::new();
+    for (short, long) in TICKER_FIELDS {
+        if let Some(value) = ticker.get(*short) {
+            let long = if inverse && *short == "q" { "baseVolume" } else { *long };
+            rv.insert(long.to_owned(), value.clone());
+        }
+    }
+    Value::Json(serde_json::Value::Object(rv))
+}
+
+/// A listenKey of the user data streams, kept alive by a task that creates a new one when the key expired or could
+/// not be kept alive and closes it once the last stream using it is dropped
+pub struct ListenKey {
+    keys: key_watch::Receiver<String>,
+    // the keys the streams were told expired, the task ends when the sender is dropped
+    expired: mpsc::UnboundedSender<String>,
+}
+
+impl ListenKey {
+    /// The current key, the streams subscribe again when it is replaced
+    pub fn key(&self) -> String {
+        self.keys.borrow().clone()
+    }
+}
+
+fn listen_keys() -> &'static Mutex<HashMap<String, Weak<ListenKey>>> {
+    static LISTEN_KEYS: OnceLock<Mutex<HashMap<String, Weak<ListenKey>>>> = OnceLock::new();
+    LISTEN_KEYS.get_or_init(Default::default)
+}
+
+/// The events of the user data stream of `listen_key` on `client`, subscribed again under the new key whenever the
+/// key is replaced, a `listenKeyExpired` event is reported to the task keeping the key instead of being yielded
+fn user_data_events(client: Arc<Client>, listen_key: Arc<ListenKey>) -> BoxStream<'static, Event> {
+    let mut keys = listen_key.keys.clone();
+    let key = keys.borrow_and_update().clone();
+    let subscription = client.subscribe(Topic { hash: key.clone(), request: key.into() });
+    stream::unfold((client, listen_key, keys, subscription), |(client, listen_key, mut keys, mut subscription)| async move {
+        loop {
+            tokio::select! {
+                changed = keys.changed() => {
+                    changed.ok()?;
+                    let key = keys.borrow_and_update().clone();
+                    subscription = client.subscribe(Topic { hash: key.clone(), request: key.into() });
+                    // the events sent before the new key was subscribed are lost
+                    return Some((Event::Reconnected, (client, listen_key, keys, subscription)));
+                }
+                event = subscription.next() => {
+                    let event = event?;
+                    if let Event::Update(message) = &event {
+                        if message["e"] == "listenKeyExpired" {
+                            let key = message["listenKey"].as_str().map_or_else(|| keys.borrow().clone(), str::to_owned);
+                            tracing::debug!(%key, "listenKey expired");
+                            let _ = listen_key.expired.send(key);
+                            continue;
+                        }
+                    }
+                    return Some((event, (client, listen_key, keys, subscription)));
+                }
+            }
+        }
+    }).boxed()
+}
+
+/// The rest order shape of an `executionReport` or of the order of an `ORDER_TRADE_UPDATE`
+fn rest_order(event: &serde_json::Value) -> Option<serde_json::Value> {
+    //
+    // spot
+    //
+    //     {"e": "executionReport", "E": 1499405658658, "s": "ETHBTC", "c": "mUvoqJxFIILMdfAW5iGSOW", "S": "BUY",
+    //      "o": "LIMIT", "f": "GTC", "q": "1.00000000", "p": "0.10264410", "P": "0.00000000", "F": "0.00000000",
+    //      "g": -1, "C": "", "x": "NEW", "X": "NEW", "r": "NONE", "i": 4293153, "l": "0.00000000",
+    //      "z": "0.00000000", "L": "0.00000000", "n": "0", "N": null, "T": 1499405658657, "t": -1, "I": 8641984,
+    //      "w": true, "m": false, "M": false, "O": 1499405658657, "Z": "0.00000000", "Y": "0.00000000",
+    //      "Q": "0.00000000"}
+    //
+    // futures
+    //
+    //     {"e": "ORDER_TRADE_UPDATE", "E": 1568879465651, "T": 1568879465650, "o": {"s": "BTCUSDT",
+    //      "c": "TEST", "S": "SELL", "o": "TRAILING_STOP_MARKET", "f": "GTC", "q": "0.001", "p": "0",
+    //      "ap": "0", "sp": "7103.04", "x": "NEW", "X": "NEW", "i": 8886774, "l": "0", "z": "0", "L": "0",
+    //      "N": "USDT", "n": "0", "T": 1568879465651, "t": 0, "b": "0", "a": "9.91", "m": false, "R": false,
+    //      "wt": "CONTRACT_PRICE", "ot": "TRAILING_STOP_MARKET", "ps": "LONG", "cp": false, "rp": "0"}}
+    //
+    match event["e"].as_str()? {
+        "executionReport" => {
+            // the id of a canceled order is the one it was placed with
+            let client_order_id = if event["X"] == "CANCELED" { &event["C"] } else { &event["c"] };
+            Some(json!({
+                "symbol": event["s"], "orderId": event["i"], "clientOrderId": client_order_id, "price": event["p"],
+                "origQty": event["q"], "executedQty": event["z"], "cummulativeQuoteQty": event["Z"],
+                "status": event["X"], "timeInForce": event["f"], "type": event["o"], "side": event["S"],
+                "stopPrice": event["P"], "time": event["O"], "updateTime": event["T"], "isWorking": event["w"],
+            }))
+        }
+        "ORDER_TRADE_UPDATE" => {
+            let order = &event["o"];
+            Some(json!({
+                "symbol": order["s"], "orderId": order["i"], "clientOrderId": order["c"], "price": order["p"],
+                "avgPrice": order["ap"], "origQty": order["q"], "executedQty": order["z"], "status": order["X"],
+                "timeInForce": order["f"], "type": order["o"], "side": order["S"], "stopPrice": order["sp"],
+                "updateTime": order["T"], "reduceOnly": order["R"], "positionSide": order["ps"],
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// The rest trade shape of the fill of an `executionReport` or an `ORDER_TRADE_UPDATE`, `None` for other updates
+fn rest_trade(event: &serde_json::Value) -> Option<serde_json::Value> {
+    let (fill, quote_quantity) = match event["e"].as_str()? {
+        "executionReport" => (event, &event["Y"]),
+        "ORDER_TRADE_UPDATE" => (&event["o"], &serde_json::Value::Null),
+        _ => return None,
+    };
+    if fill["x"] != "TRADE" {
+        return None;
+    }
+    Some(json!({
+        "symbol": fill["s"], "id": fill["t"], "orderId": fill["i"], "price": fill["L"], "qty": fill["l"],
+        "quoteQty": quote_quantity, "commission": fill["n"], "commissionAsset": fill["N"], "time": fill["T"],
+        "side": fill["S"], "isMaker": fill["m"],
+    }))
+}
+
+/// Streaming market data over the websocket API, see https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams,
+/// the user data of an account, see https://binance-docs.github.io/apidocs/spot/en/#user-data-streams, and spot trading
+/// over the websocket api, see https://binance-docs.github.io/apidocs/websocket_api/en/
+#[async_trait]
+pub trait BinancePro: Binance + Clone + 'static {
+    /// The stream url of the market, `urls.api.ws.spot`, `future` for USDⓈ-M markets or `delivery` for COIN-M ones
+    fn ws_url(&self, market: &Value) -> String {
+        let r#type = if market.get("linear".into()).is_truthy() {
+            "future"
+        } else if market.get("inverse".into()).is_truthy() {
+            "delivery"
+        } else {
+            "spot"
+        };
+        BinancePro::endpoint(self, r#type)
+    }
+
+    /// The stream url of the `spot`, `margin`, `future` or `delivery` api
+    fn endpoint(&self, r#type: &str) -> String {
+        self.get("urls".into()).get("api".into()).get("ws".into()).get(r#type.into()).unwrap_str().to_owned()
+    }
+
+    /// A stream of the updates of the stream `name` of the market
+    fn subscribe(&self, market: &Value, name: String) -> Subscription {
+        BinancePro::subscribe_url(self, &BinancePro::ws_url(self, market), name)
+    }
+
+    /// A stream of the updates of the stream `name` at `url`
+    fn subscribe_url(&self, url: &str, name: String) -> Subscription {
+        let key = self.get("id".into()).property_key();
+        let client = client::client(&key, url, streaming_config(self), || Box::new(BinanceProtocol));
+        client.subscribe(Topic { hash: name.clone(), request: name.into() })
+    }
+
+    /// The stream name of a market, the lowercase market id followed by `@` and the channel
+    fn stream_name(&self, market: &Value, channel: &str) -> String {
+        format!("{}@{}", market.get("id".into()).unwrap_str().to_lowercase(), channel)
+    }
+
+    /// Ticker updates of `symbol` from its `<symbol>@ticker` stream, once a second
+    async fn watch_ticker(&mut self, symbol: Value, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let name = self.safe_string(params, "name".into(), "ticker".into());
+        let subscription = BinancePro::subscribe(self, &market, BinancePro::stream_name(self, &market, name.unwrap_str()));
+        let inverse = market.get("inverse".into()).is_truthy();
+        watch(subscription, self.clone(), move |exchange, message| {
+            Some(Binance::parse_ticker(exchange, rest_ticker(&message, inverse), market.clone()))
+        })
+    }
+
+    /// The public trades of `symbol` as they happen, each update is a list of the new trades from `since` on, from
+    /// the `<symbol>@trade` stream on spot and `<symbol>@aggTrade` on derivatives, `params.name` picks another one
+    async fn watch_trades(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let default_name = if market.get("spot".into()).is_truthy() { "trade" } else { "aggTrade" };
+        let name = self.safe_string(params, "name".into(), default_name.into());
+        let subscription = BinancePro::subscribe(self, &market, BinancePro::stream_name(self, &market, name.unwrap_str()));
+        watch(subscription, self.clone(), move |exchange, message| {
+            let trade = Binance::parse_trade(exchange, Value::Json(message), market.clone());
+            let trades = filter_since_limit(Value::Json(json!([trade])), &since, &limit, |trade| trade["timestamp"].as_i64());
+            (trades.len() > 0).then_some(trades)
+        })
+    }
+
+    /// The best `limit` levels of the order book of `symbol` kept from the REST snapshot and the `<symbol>@depth@100ms`
+    /// diff stream, the book holds the best `params.depth` levels, 1000 by default, and `nonce` is the last update id
+    async fn watch_order_book(&mut self, symbol: Value, limit: Value, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let symbol = market.get("symbol".into());
+        let speed = self.safe_string(params.clone(), "speed".into(), "100ms".into());
+        let depth = self.safe_integer(params, "depth".into(), 1000.into());
+        let channel = format!("depth@{}", speed.unwrap_str());
+        let subscription = BinancePro::subscribe(self, &market, BinancePro::stream_name(self, &market, &channel));
+        let book = LocalOrderBook::new(symbol.unwrap_str(), depth.unwrap_json().as_u64().map(|depth| depth as usize));
+        let snapshot = move |mut exchange: Self| {
+            let (symbol, depth) = (symbol.clone(), depth.clone());
+            async move { Binance::fetch_order_book(&mut exchange, symbol, depth, Value::Undefined).await }
+        };
+        let retry = streaming_config(self).reconnect;
+        watch_order_book(subscription, self.clone(), book, limit, retry, snapshot, |message| {
+            //
+            // spot
+            //
+            //     {"e": "depthUpdate", "E": 123456789, "s": "BNBBTC", "U": 157, "u": 160,
+            //      "b": [["0.0024", "10"]], "a": [["0.0026", "100"]]}
+            //
+            // futures
+            //
+            //     {"e": "depthUpdate", "E": 1571889248277, "T": 1571889248276, "s": "BTCUSDT", "U": 390497796,
+            //      "u": 390497878, "pu": 390497794, "b": [["7403.89", "0.002"]], "a": [["7405.96", "3.340"]]}
+            //
+            Some(Delta {
+                first: message["U"].as_i64(),
+                last: message["u"].as_i64()?,
+                previous: message["pu"].as_i64(),
+                timestamp: message["T"].as_i64().or(message["E"].as_i64()),
+                bids: Level::parse_all(&message["b"], 0, 1),
+                asks: Level::parse_all(&message["a"], 0, 1),
+            })
+        })
+    }
+
+    /// Candles of `symbol` from its `<symbol>@kline_<interval>` stream, each update is a list holding the current
+    /// candle, sent again as it changes, from `since` on
+    async fn watch_ohlcv(&mut self, symbol: Value, timeframe: Value, since: Value, limit: Value, _params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let timeframe = timeframe.or_default("1m".into());
+        let interval = self.get("timeframes".into()).get(timeframe);
+        let subscription = BinancePro::subscribe(self, &market, BinancePro::stream_name(self, &market, &format!("kline_{}", interval.unwrap_str())));
+        watch(subscription, self.clone(), move |exchange, message| {
+            //
+            //     {"e": "kline", "E": 123456789, "s": "BNBBTC", "k": {"t": 123400000, "T": 123460000, "s": "BNBBTC",
+            //      "i": "1m", "f": 100, "L": 200, "o": "0.0010", "c": "0.0020", "h": "0.0025", "l": "0.0015",
+            //      "v": "1000", "n": 100, "x": false, "q": "1.0000", "V": "500", "Q": "0.500", "B": "123456"}}
+            //
+            let kline = &message["k"];
+            let ohlcv = json!([kline["t"], kline["o"], kline["h"], kline["l"], kline["c"], kline["v"]]);
+            let candles = Value::Json(json!([Binance::parse_ohlcv(exchange, Value::Json(ohlcv), market.clone())]));
+            let candles = filter_since_limit(candles, &since, &limit, |candle| candle[0].as_i64());
+            (candles.len() > 0).then_some(candles)
+        })
+    }
+    /// The account of the user data of `symbol`, or of `params.type` and `options.defaultType` without one, `spot`,
+    /// `margin`, `future` or `delivery`
+    fn user_data_type(&mut self, symbol: &Value, params: &Value) -> String {
+        let default_type = self.safe_string(self.get("options".into()), "defaultType".into(), "spot".into());
+        let r#type = self.safe_string(params.clone(), "type".into(), default_type);
+        let r#type = if symbol.is_nonnullish() {
+            let market = self.market(symbol.clone());
+            if market.get("linear".into()).is_truthy() {
+                "future"
+            } else if market.get("inverse".into()).is_truthy() {
+                "delivery"
+            } else if r#type.unwrap_str() == "margin" {
+                "margin"
+            } else {
+                "spot"
+            }
+        } else {
+            match r#type.unwrap_str() {
+                "future" | "swap" | "linear" => "future",
+                "delivery" | "inverse" => "delivery",
+                "margin" => "margin",
+                _ => "spot",
+            }
+        };
+        r#type.to_owned()
+    }
+
+    /// The listenKey of the user data of the `type` account, shared by every copy of the exchange and kept alive every
+    /// `options.listenKeyRefreshRate` milliseconds, 30 minutes by default, isolated margin accounts take the market id
+    /// in `params.symbol`, a key that expired or failed to be kept alive is replaced after the backoff of
+    /// `options.retry` and the last stream using the key closes it
+    async fn listen_key(&mut self, r#type: &str, params: Value) -> Arc<ListenKey> {
+        Binance::check_required_credentials(self, Value::Undefined);
+        let isolated = if r#type == "margin" { self.safe_string(params, "symbol".into(), Value::Undefined) } else { Value::Undefined };
+        let (create, keep_alive, close) = match (r#type, isolated.is_nonnullish()) {
+            ("margin", true) => ("sapiPostUserDataStreamIsolated", "sapiPutUserDataStreamIsolated", "sapiDeleteUserDataStreamIsolated"),
+            ("margin", false) => ("sapiPostUserDataStream", "sapiPutUserDataStream", "sapiDeleteUserDataStream"),
+            ("future", _) => ("fapiPrivatePostListenKey", "fapiPrivatePutListenKey", "fapiPrivateDeleteListenKey"),
+            ("delivery", _) => ("dapiPrivatePostListenKey", "dapiPrivatePutListenKey", "dapiPrivateDeleteListenKey"),
+            _ => ("publicPostUserDataStream", "publicPutUserDataStream", "publicDeleteUserDataStream"),
+        };
+        let api_key = self.get("apiKey".into());
+        let symbol = if isolated.is_nonnullish() { isolated.unwrap_str() } else { "" };
+        let registry_key = format!("{}|{}|{}|{}", self.get("id".into()).property_key(), api_key.property_key(), r#type, symbol);
+        if let Some(listen_key) = listen_keys().lock().unwrap().get(&registry_key).and_then(Weak::upgrade) {
+            return listen_key;
+        }
+        let request = if isolated.is_nonnullish() { json!({"symbol": isolated}) } else { json!({}) };
+        let key = BinancePro::create_listen_key(self, create, &request).await;
+        let refresh = self.safe_integer(self.get("options".into()), "listenKeyRefreshRate".into(), 1800000.into());
+        let refresh = Duration::from_millis(refresh.unwrap_json().as_u64().unwrap_or(1800000));
+        let retry = streaming_config(self).reconnect;
+        let (keys, receiver) = key_watch::channel(key);
+        let (expired, mut expired_keys) = mpsc::unbounded_channel::<String>();
+        let mut exchange = self.clone();
+        tokio::spawn(async move {
+            let with_key = |key: &str| {
+                let mut request = request.clone();
+                request["listenKey"] = key.into();
+                Value::Json(request)
+            };
+            loop {
+                let key = keys.borrow().clone();
+                let renew = tokio::select! {
+                    _ = tokio::time::sleep(refresh) => {
+                        match catch(Binance::dispatch(&mut exchange, keep_alive.into(), with_key(&key), Value::Undefined)).await {
+                            Ok(_) => false,
+                            Err(error) => {
+                                tracing::debug!(%error, "listenKey keep alive failed");
+                                true
+                            }
+                        }
+                    }
+                    expired = expired_keys.recv() => match expired {
+                        // the other streams of the key report it too, after it was replaced
+                        Some(expired) => expired == key,
+                        None => break,
+                    },
+                };
+                let mut retries = 0;
+                while renew && !keys.is_closed() {
+                    match catch(BinancePro::create_listen_key(&mut exchange, create, &request)).await {
+                        Ok(key) => {
+                            keys.send_replace(key);
+                            break;
+                        }
+                        Err(error) => {
+                            tracing::debug!(%error, "listenKey could not be replaced");
+                            tokio::time::sleep(retry.backoff(retries)).await;
+                            retries += 1;
+                        }
+                    }
+                }
+            }
+            let key = keys.borrow().clone();
+            if let Err(error) = catch(Binance::dispatch(&mut exchange, close.into(), with_key(&key), Value::Undefined)).await {
+                tracing::debug!(%error, "listenKey could not be closed");
+            }
+        });
+        let listen_key = Arc::new(ListenKey { keys: receiver, expired });
+        let mut listen_keys = listen_keys().lock().unwrap();
+        listen_keys.retain(|_, listen_key| listen_key.strong_count() > 0);
+        listen_keys.insert(registry_key, Arc::downgrade(&listen_key));
+        drop(listen_keys);
+        listen_key
+    }
+
+    /// A new listenKey from the api method `create`
+    async fn create_listen_key(&mut self, create: &str, request: &serde_json::Value) -> String {
+        let response = Binance::dispatch(self, create.into(), Value::Json(request.clone()), Value::Undefined).await;
+        //
+        //     {"listenKey": "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}
+        //
+        let key = self.safe_string(response, "listenKey".into(), Value::Undefined);
+        if key.is_nullish() {
+            panic!("ExchangeError::new({} did not return a listenKey)", self.get("id".into()).property_key());
+        }
+        key.unwrap_str().to_owned()
+    }
+
+    /// The events of the user data of the `type` account, subscribed by its listenKey on the stream url of the account
+    async fn subscribe_user_data(&mut self, r#type: &str, params: Value) -> BoxStream<'static, Event> {
+        let listen_key = BinancePro::listen_key(self, r#type, params).await;
+        let key = self.get("id".into()).property_key();
+        let client = client::client(&key, &BinancePro::endpoint(self, r#type), streaming_config(self), || Box::new(BinanceProtocol));
+        user_data_events(client, listen_key)
+    }
+
+    /// The orders of `symbol`, or of every symbol, as they change, each update is a list of the changed order from
+    /// `since` on, from the `executionReport` events of spot and margin accounts and the `ORDER_TRADE_UPDATE` ones
+    /// of futures
+    async fn watch_orders(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let r#type = BinancePro::user_data_type(self, &symbol, &params);
+        let market = if symbol.is_nonnullish() { self.market(symbol) } else { Value::Undefined };
+        let events = BinancePro::subscribe_user_data(self, &r#type, params).await;
+        watch(events, self.clone(), move |exchange, message| {
+            let order = Binance::parse_order(exchange, Value::Json(rest_order(&message)?), market.clone());
+            if market.is_nonnullish() && order.get("symbol".into()) != market.get("symbol".into()) {
+                return None;
+            }
+            let orders = filter_since_limit(Value::Json(json!([order])), &since, &limit, |order| order["timestamp"].as_i64());
+            (orders.len() > 0).then_some(orders)
+        })
+    }
+
+    /// The fills of the orders of `symbol`, or of every symbol, each update is a list of the new trade from `since` on
+    async fn watch_my_trades(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let r#type = BinancePro::user_data_type(self, &symbol, &params);
+        let market = if symbol.is_nonnullish() { self.market(symbol) } else { Value::Undefined };
+        let events = BinancePro::subscribe_user_data(self, &r#type, params).await;
+        watch(events, self.clone(), move |exchange, message| {
+            let trade = Binance::parse_trade(exchange, Value::Json(rest_trade(&message)?), market.clone());
+            if market.is_nonnullish() && trade.get("symbol".into()) != market.get("symbol".into()) {
+                return None;
+            }
+            let trades = filter_since_limit(Value::Json(json!([trade])), &since, &limit, |trade| trade["timestamp"].as_i64());
+            (trades.len() > 0).then_some(trades)
+        })
+    }
+
+    /// The balance of the `params.type` account, `options.defaultType` by default, as it changes, the updates only
+    /// hold the assets that changed since the stream was opened, from `outboundAccountPosition` events on spot and
+    /// margin and the `ACCOUNT_UPDATE` ones of futures
+    async fn watch_balance(&mut self, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let r#type = BinancePro::user_data_type(self, &Value::Undefined, &params);
+        let events = BinancePro::subscribe_user_data(self, &r#type, params).await;
+        // the latest entry of every asset in the rest balance shape
+        let mut assets = BTreeMap::new();
+        watch(events, self.clone(), move |exchange, message| {
+            //
+            // spot
+            //
+            //     {"e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+            //      "B": [{"a": "ETH", "f": "10000.000000", "l": "0.000000"}]}
+            //
+            // futures
+            //
+            //     {"e": "ACCOUNT_UPDATE", "E": 1564745798939, "T": 1564745798938, "a": {"m": "ORDER",
+            //      "B": [{"a": "USDT", "wb": "122624.12345678", "cw": "100.12345678", "bc": "50.12345678"}], "P": []}}
+            //
+            let event = &message;
+            let (timestamp, response) = match event["e"].as_str()? {
+                "outboundAccountPosition" => {
+                    for balance in event["B"].as_array()? {
+                        assets.insert(balance["a"].to_string(), json!({"asset": balance["a"], "free": balance["f"], "locked": balance["l"]}));
+                    }
+                    (&event["E"], json!({"balances": assets.values().collect::<Vec<_>>(), "updateTime": event["E"]}))
+                }
+                "ACCOUNT_UPDATE" => {
+                    for balance in event["a"]["B"].as_array()? {
+                        assets.insert(balance["a"].to_string(), json!({"asset": balance["a"], "balance": balance["wb"], "crossWalletBalance": balance["cw"]}));
+                    }
+                    (&event["E"], json!(assets.values().collect::<Vec<_>>()))
+                }
+                _ => return None,
+            };
+            let account = if r#type == "margin" { "margin" } else if r#type == "spot" { "spot" } else { "future" };
+            let mut balance = Binance::parse_balance(exchange, Value::Json(response), account.into(), Value::Undefined);
+            balance.set("timestamp".into(), Value::Json(timestamp.clone()));
+            balance.set("datetime".into(), exchange.iso8601(Value::Json(timestamp.clone())));
+            Some(balance)
+        })
+    }
+
+    /// The positions of `symbols`, or of every symbol, of the `params.type` futures account as they change, each
+    /// update is a list of the changed positions from `since` on, from the `ACCOUNT_UPDATE` events
+    async fn watch_positions(&mut self, symbols: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let first = match &symbols {
+            Value::Json(serde_json::Value::Array(symbols)) if !symbols.is_empty() => Value::Json(symbols[0].clone()),
+            _ => Value::Undefined,
+        };
+        let r#type = BinancePro::user_data_type(self, &first, &params);
+        if r#type != "future" && r#type != "delivery" {
+            panic!("NotSupported::new({} watch_positions() supports future and delivery accounts only)", self.get("id".into()).property_key());
+        }
+        let events = BinancePro::subscribe_user_data(self, &r#type, params).await;
+        watch(events, self.clone(), move |exchange, message| {
+            //
+            //     {"e": "ACCOUNT_UPDATE", "E": 1564745798939, "T": 1564745798938, "a": {"m": "ORDER", "B": [],
+            //      "P": [{"s": "BTCUSDT", "pa": "0", "ep": "0.00000", "cr": "200", "up": "0", "mt": "isolated",
+            //      "iw": "0.00000000", "ps": "BOTH"}]}}
+            //
+            let event = &message;
+            if event["e"] != "ACCOUNT_UPDATE" {
+                return None;
+            }
+            let mut positions = Vec::new();
+            for position in event["a"]["P"].as_array()? {
+                let position = json!({
+                    "symbol": position["s"], "positionAmt": position["pa"], "entryPrice": position["ep"],
+                    "unRealizedProfit": position["up"], "marginType": position["mt"], "isolatedWallet": position["iw"],
+                    "positionSide": position["ps"], "updateTime": event["T"],
+                });
+                let position = Binance::parse_position_risk(exchange, Value::Json(position), Value::Undefined);
+                let wanted = match &symbols {
+                    Value::Json(serde_json::Value::Array(symbols)) if !symbols.is_empty() => symbols.contains(&position.get("symbol".into()).into()),
+                    _ => true,
+                };
+                if wanted {
+                    positions.push(position);
+                }
+            }
+            let positions = filter_since_limit(Value::Json(json!(positions)), &since, &limit, |position| position["timestamp"].as_i64());
+            (positions.len() > 0).then_some(positions)
+        })
+    }
+    /// The result of the websocket api request `method` with `params` signed with the api key, failures are raised
+    /// like the ones of the rest api
+    async fn request_ws(&mut self, method: &str, params: Value) -> Value {
+        Binance::check_required_credentials(self, Value::Undefined);
+        let load_time_difference: LoadTimeDifference<Self> = |exchange, params| Binance::load_time_difference(exchange, params);
+        self.refresh_time_difference(false, load_time_difference).await;
+        let url = BinancePro::endpoint(self, "api");
+        let client = client::api_client(&self.get("id".into()).property_key(), &url, streaming_config(self), || Box::new(BinanceProtocol));
+        let mut adjusted = false;
+        loop {
+            let mut params = params.clone().or_default(Value::new_object());
+            params.set("apiKey".into(), self.get("apiKey".into()));
+            params.set("timestamp".into(), Binance::nonce(self));
+            let recv_window = self.safe_integer(self.get("options".into()), "recvWindow".into(), Value::Undefined);
+            if recv_window.is_nonnullish() && !params.contains_key("recvWindow".into()) {
+                params.set("recvWindow".into(), recv_window);
+            }
+            // the payload is the params sorted by name
+            let query = self.urlencode(self.keysort(params.clone(), Value::Undefined));
+            let signature = self.hmac(self.encode(query), self.encode(self.get("secret".into())), Value::Undefined, Value::Undefined);
+            params.set("signature".into(), signature);
+            let response = match client.request(json!({"method": method, "params": params.unwrap_json()})).await {
+                Ok(response) => response,
+                Err(error) => panic!("{}::new({})", error.kind, error.message),
+            };
+            //
+            //     {"id": 3, "status": 400, "error": {"code": -2010, "msg": "Account has insufficient balance for requested action."}}
+            //
+            let error = match response.get("error") {
+                Some(error) => error.clone(),
+                None => return Value::Json(response["result"].clone()),
+            };
+            let raised = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                let status = Value::Json(response["status"].clone());
+                let body = Value::from(error.to_string());
+                Binance::handle_errors(self, status, "".into(), url.as_str().into(), method.into(), Value::Undefined, body, Value::Json(error.clone()), Value::Undefined, Value::Undefined);
+                panic!("ExchangeError::new({} {})", self.get("id".into()).property_key(), error);
+            })).unwrap_err();
+            // signed with a stale clock, sent once more after the time difference is refreshed like the rest api does
+            if !adjusted && Error::from_panic_ref(&*raised).is("InvalidNonce") && self.refresh_time_difference(true, load_time_difference).await {
+                adjusted = true;
+                continue;
+            }
+            std::panic::resume_unwind(raised);
+        }
+    }
+
+    /// `create_order` over the websocket api of spot markets, `order.place`, or `order.test` with `params.test`
+    async fn create_order_ws(&mut self, symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Value {
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol.clone());
+        let params = params.or_default(Value::new_object());
+        let request = Binance::create_order_request(self, symbol, r#type, side, amount, price, params);
+        let (method, request) = (request.get(0.into()), request.get(1.into()));
+        let method = match method.unwrap_str() {
+            "privatePostOrder" => "order.place",
+            "privatePostOrderTest" => "order.test",
+            _ => panic!("NotSupported::new({} create_order_ws() supports spot orders only)", self.get("id".into()).property_key()),
+        };
+        let response = BinancePro::request_ws(self, method, request).await;
+        Binance::parse_order(self, response, market)
+    }
+
+    /// `cancel_order` over the websocket api of spot markets, `order.cancel`
+    async fn cancel_order_ws(&mut self, id: Value, symbol: Value, params: Value) -> Value {
+        if symbol.is_nullish() {
+            panic!("ArgumentsRequired::new({} cancel_order_ws() requires a symbol argument)", self.get("id".into()).property_key());
+        }
+        Binance::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol.clone());
+        let params = params.or_default(Value::new_object());
+        let request = Binance::cancel_order_request(self, id, symbol, params);
+        if request.get(0.into()).unwrap_str() != "privateDeleteOrder" {
+            panic!("NotSupported::new({} cancel_order_ws() supports spot orders only)", self.get("id".into()).property_key());
+        }
+        let response = BinancePro::request_ws(self, "order.cancel", request.get(1.into())).await;
+        Binance::parse_order(self, response, market)
+    }
+}
+
+impl BinancePro for BinanceImpl {}
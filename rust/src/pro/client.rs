@@ -0,0 +1,573 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use futures::{SinkExt, Stream, StreamExt};
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::Message;
+use crate::errors::{Error, Result};
+use crate::exchange::{Value, ValueTrait};
+use crate::retry::RetryPolicy;
+
+/// A stream of updates the exchange sends on a connection, `hash` tells its messages apart from the others and
+/// `request` is what the protocol builds the subscribe message from, e.g. a channel and its arguments
+#[derive(Debug, Clone, PartialEq)]
+pub struct Topic {
+    pub hash: String,
+    pub request: serde_json::Value,
+}
+
+/// What the protocol of an exchange makes of a message it received
+#[derive(Debug, Clone, PartialEq)]
+pub enum Incoming {
+    /// updates keyed by the hash of the topic they belong to
+    Updates(Vec<(String, serde_json::Value)>),
+    /// the answer to the subscription or the login sent with `id`, with the error when the exchange rejected it
+    Reply { id: u64, error: Option<Error> },
+    /// the response to the api request sent with `id`, as the exchange sent it
+    Response { id: u64, message: serde_json::Value },
+    /// pongs and anything else nobody is waiting for
+    Ignore,
+}
+
+/// The websocket API of an exchange, how to subscribe, how to keep the connection alive and where messages go
+pub trait Protocol: Send + Sync + 'static {
+    /// The message subscribing to `topic`, built again on every reconnect, `id` comes back in the reply
+    fn subscribe(&self, topic: &Topic, id: u64) -> serde_json::Value;
+
+    /// The message ending the subscription to `topic`, once its last stream is dropped
+    fn unsubscribe(&self, topic: &Topic, id: u64) -> Option<serde_json::Value>;
+
+    /// The message sent every `keepAlive` milliseconds, a websocket ping frame when the exchange has none of its own
+    fn ping(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// The message authenticating the connection, sent first whenever it is opened, api requests wait for its reply
+    fn login(&self, _id: u64) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// The message of the api request `request`, `id` comes back in the response, by default the request with an `id`
+    fn request(&self, request: &serde_json::Value, id: u64) -> serde_json::Value {
+        let mut message = request.clone();
+        message["id"] = id.into();
+        message
+    }
+
+    fn route(&self, message: serde_json::Value) -> Incoming;
+}
+
+/// What the streams of a topic receive
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Update(serde_json::Value),
+    /// the connection dropped and was opened again, the updates sent in between are lost
+    Reconnected,
+    /// the stream was not read fast enough and this many updates that arrived while it was full were dropped
+    Lagged(u64),
+    /// the exchange rejected the subscription, nothing follows
+    Error(Error),
+}
+
+/// How a connection is kept alive, read from the `streaming` config as `{ keepAlive, maxPingPongMisses }` like
+/// ccxt.pro, the connection is reopened when nothing arrives for `keepAlive * maxPingPongMisses` milliseconds after
+/// a jittered backoff with the delays of `options.retry`, api requests wait `requestTimeout` milliseconds for their
+/// response and the connection of `api_client` is let go `apiIdleTimeout` milliseconds after its last request
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamingConfig {
+    pub keep_alive: Duration,
+    pub max_ping_pong_misses: f64,
+    pub reconnect: RetryPolicy,
+    pub request_timeout: Duration,
+    pub api_idle_timeout: Duration,
+}
+
+impl Default for StreamingConfig {
+    fn default() -> Self {
+        StreamingConfig {
+            keep_alive: Duration::from_secs(30),
+            max_ping_pong_misses: 2.0,
+            reconnect: RetryPolicy::default(),
+            request_timeout: Duration::from_secs(10),
+            api_idle_timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl StreamingConfig {
+    pub fn from_config(streaming: &Value, options: &Value) -> Self {
+        let default = StreamingConfig::default();
+        let number = |key: &str| match streaming.is_object().then(|| streaming.get(key.into())) {
+            Some(Value::Json(serde_json::Value::Number(n))) => n.as_f64().filter(|n| *n > 0.0),
+            _ => None,
+        };
+        StreamingConfig {
+            keep_alive: number("keepAlive").map_or(default.keep_alive, |ms| Duration::from_millis(ms as u64)),
+            max_ping_pong_misses: number("maxPingPongMisses").unwrap_or(default.max_ping_pong_misses),
+            reconnect: RetryPolicy::from_options(options),
+            request_timeout: number("requestTimeout").map_or(default.request_timeout, |ms| Duration::from_millis(ms as u64)),
+            api_idle_timeout: number("apiIdleTimeout").map_or(default.api_idle_timeout, |ms| Duration::from_millis(ms as u64)),
+        }
+    }
+}
+
+/// The updates a stream holds until it is read, the ones that arrive once it is full are dropped and reported with a
+/// `Lagged` event, so that a slow reader does not make the connection buffer without bound
+pub const SUBSCRIPTION_BUFFER: usize = 1024;
+
+enum Command {
+    Subscribe { topic: Topic, subscriber: u64, events: mpsc::Sender<Event> },
+    Unsubscribe { hash: String, subscriber: u64 },
+    Resubscribe { hash: String },
+    Request { request: serde_json::Value, response: oneshot::Sender<Result<serde_json::Value>> },
+}
+
+/// One websocket connection multiplexing the topics of its streams, it is opened by the first subscription or api
+/// request and closed once the last stream is dropped
+pub struct Client {
+    pub url: String,
+    commands: mpsc::UnboundedSender<Command>,
+    next_subscriber: AtomicU64,
+    request_timeout: Duration,
+}
+
+impl Client {
+    fn new(url: String, config: StreamingConfig, protocol: Box<dyn Protocol>) -> Self {
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let request_timeout = config.request_timeout;
+        tokio::spawn(run(url.clone(), config, protocol, receiver));
+        Client { url, commands, next_subscriber: AtomicU64::new(0), request_timeout }
+    }
+
+    /// Sends the api request `request` as soon as the connection is open and returns the response the protocol
+    /// routes to its id, `NetworkError` when the connection drops before the response, the request is not sent
+    /// again since it may have been executed, and `RequestTimeout` when the response takes longer than
+    /// `requestTimeout`
+    pub async fn request(&self, request: serde_json::Value) -> Result<serde_json::Value> {
+        let (response, receiver) = oneshot::channel();
+        let _ = self.commands.send(Command::Request { request, response });
+        match tokio::time::timeout(self.request_timeout, receiver).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => Err(Error::new("NetworkError", format!("{} closed", self.url))),
+            Err(_) => Err(Error::new("RequestTimeout", format!("{} did not respond within {} ms", self.url, self.request_timeout.as_millis()))),
+        }
+    }
+
+    /// A stream of the updates of `topic`, streams of the same topic share one subscription on the exchange
+    pub fn subscribe(self: &Arc<Self>, topic: Topic) -> Subscription {
+        let (events, receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+        let subscriber = self.next_subscriber.fetch_add(1, Ordering::Relaxed);
+        let hash = topic.hash.clone();
+        let _ = self.commands.send(Command::Subscribe { topic, subscriber, events });
+        Subscription { client: self.clone(), hash, subscriber, events: receiver }
+    }
+}
+
+/// The events of one topic, dropping the last stream of a topic unsubscribes from it
+pub struct Subscription {
+    client: Arc<Client>,
+    hash: String,
+    subscriber: u64,
+    events: mpsc::Receiver<Event>,
+}
+
+impl Subscription {
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Sends the subscribe message of the topic again, for streams that lost track of its updates
+    pub fn resubscribe(&self) {
+        let _ = self.client.commands.send(Command::Resubscribe { hash: self.hash.clone() });
+    }
+}
+
+impl Stream for Subscription {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let _ = self.client.commands.send(Command::Unsubscribe { hash: self.hash.clone(), subscriber: self.subscriber });
+    }
+}
+
+fn clients() -> &'static Mutex<HashMap<String, Weak<Client>>> {
+    static CLIENTS: OnceLock<Mutex<HashMap<String, Weak<Client>>>> = OnceLock::new();
+    CLIENTS.get_or_init(Default::default)
+}
+
+/// The connection of `key` to `url`, shared by the streams of every copy of an exchange like the throttle buckets,
+/// `protocol` is only called when the connection is not open yet
+pub fn client(key: &str, url: &str, config: StreamingConfig, protocol: impl FnOnce() -> Box<dyn Protocol>) -> Arc<Client> {
+    let mut clients = clients().lock().unwrap();
+    let key = format!("{}|{}", key, url);
+    // the task of a connection ends with the runtime it was opened on
+    if let Some(client) = clients.get(&key).and_then(Weak::upgrade).filter(|client| !client.commands.is_closed()) {
+        return client;
+    }
+    clients.retain(|_, client| client.strong_count() > 0);
+    let client = Arc::new(Client::new(url.to_owned(), config, protocol()));
+    clients.insert(key, Arc::downgrade(&client));
+    client
+}
+
+// the connections of `api_client` with the time of their last request
+type ApiClients = Mutex<HashMap<String, (Arc<Client>, Instant)>>;
+
+fn api_clients() -> &'static ApiClients {
+    static API_CLIENTS: OnceLock<ApiClients> = OnceLock::new();
+    API_CLIENTS.get_or_init(Default::default)
+}
+
+/// The connection of `key` to `url` for api requests, unlike the ones only streams use it stays open between requests
+/// so orders do not wait for a handshake, until none was sent for `apiIdleTimeout`
+pub fn api_client(key: &str, url: &str, config: StreamingConfig, protocol: impl FnOnce() -> Box<dyn Protocol>) -> Arc<Client> {
+    let idle_timeout = config.api_idle_timeout;
+    let client = client(key, url, config, protocol);
+    let key = format!("{}|{}", key, url);
+    let replaced = api_clients().lock().unwrap().insert(key.clone(), (client.clone(), Instant::now()));
+    if !replaced.is_some_and(|(replaced, _)| Arc::ptr_eq(&replaced, &client)) {
+        tokio::spawn(release_api_client(key, client.clone(), idle_timeout));
+    }
+    client
+}
+
+// lets go of the api connection `client` once it was not used for `idle_timeout`, it closes unless streams hold it
+async fn release_api_client(key: String, client: Arc<Client>, idle_timeout: Duration) {
+    let client = Arc::downgrade(&client);
+    loop {
+        let idle = {
+            let mut api_clients = api_clients().lock().unwrap();
+            match api_clients.get(&key) {
+                Some((current, used_at)) if Arc::as_ptr(current) == client.as_ptr() => {
+                    let idle = used_at.elapsed();
+                    if idle >= idle_timeout {
+                        api_clients.remove(&key);
+                        return;
+                    }
+                    idle
+                }
+                // replaced by a new connection, which has its own task
+                _ => return,
+            }
+        };
+        tokio::time::sleep(idle_timeout - idle).await;
+    }
+}
+
+struct TopicState {
+    topic: Topic,
+    subscribers: Vec<Subscriber>,
+}
+
+struct Subscriber {
+    id: u64,
+    events: mpsc::Sender<Event>,
+    // the updates dropped while the stream was full, reported before the next one
+    dropped: u64,
+}
+
+impl Subscriber {
+    // false once the stream is dropped
+    fn send(&mut self, event: Event) -> bool {
+        if self.dropped > 0 {
+            match self.events.try_send(Event::Lagged(self.dropped)) {
+                Ok(()) => self.dropped = 0,
+                Err(TrySendError::Full(_)) => {
+                    self.dropped += 1;
+                    return true;
+                }
+                Err(TrySendError::Closed(_)) => return false,
+            }
+        }
+        match self.events.try_send(event) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                true
+            }
+            Err(TrySendError::Closed(_)) => false,
+        }
+    }
+}
+
+// the topics of a connection and the subscribe requests waiting for their reply, kept across reconnects, the api
+// requests waiting to be sent and for their response, and the state of the login of the connection
+struct Topics {
+    topics: HashMap<String, TopicState>,
+    pending: HashMap<u64, String>,
+    queued: Vec<(serde_json::Value, oneshot::Sender<Result<serde_json::Value>>)>,
+    requests: HashMap<u64, oneshot::Sender<Result<serde_json::Value>>>,
+    next_id: u64,
+    login: Login,
+}
+
+enum Login {
+    /// nothing to wait for, the connection is logged in or has no login
+    Done,
+    /// the login sent with the id waits for its reply
+    Pending(u64),
+    /// the exchange rejected the login, api requests fail with its error until the connection is opened again
+    Rejected(Error),
+}
+
+impl Topics {
+    fn request_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    // the subscribe message when `command` opened a new topic, the unsubscribe one when it closed the last stream
+    fn apply(&mut self, command: Command, protocol: &dyn Protocol) -> Option<serde_json::Value> {
+        match command {
+            Command::Subscribe { topic, subscriber, events } => {
+                let subscriber = Subscriber { id: subscriber, events, dropped: 0 };
+                if let Some(state) = self.topics.get_mut(&topic.hash) {
+                    state.subscribers.push(subscriber);
+                    return None;
+                }
+                let id = self.request_id();
+                let message = protocol.subscribe(&topic, id);
+                self.pending.insert(id, topic.hash.clone());
+                self.topics.insert(topic.hash.clone(), TopicState { topic, subscribers: vec![subscriber] });
+                Some(message)
+            }
+            Command::Unsubscribe { hash, subscriber } => {
+                let state = self.topics.get_mut(&hash)?;
+                state.subscribers.retain(|state| state.id != subscriber);
+                if !state.subscribers.is_empty() {
+                    return None;
+                }
+                let state = self.topics.remove(&hash).unwrap();
+                let id = self.request_id();
+                protocol.unsubscribe(&state.topic, id)
+            }
+            Command::Resubscribe { hash } => {
+                let topic = self.topics.get(&hash)?.topic.clone();
+                let id = self.request_id();
+                self.pending.insert(id, hash);
+                Some(protocol.subscribe(&topic, id))
+            }
+            // sent by `send_requests` once connected
+            Command::Request { request, response } => {
+                self.queued.push((request, response));
+                None
+            }
+        }
+    }
+
+    // the messages of the queued api requests whose caller is still waiting, once the connection is logged in
+    fn send_requests(&mut self, protocol: &dyn Protocol) -> Vec<serde_json::Value> {
+        self.requests.retain(|_, response| !response.is_closed());
+        match &self.login {
+            Login::Done => {}
+            Login::Pending(_) => return Vec::new(),
+            Login::Rejected(error) => {
+                for (_, response) in self.queued.drain(..) {
+                    let _ = response.send(Err(error.clone()));
+                }
+                return Vec::new();
+            }
+        }
+        let queued = std::mem::take(&mut self.queued);
+        queued.into_iter().filter(|(_, response)| !response.is_closed()).map(|(request, response)| {
+            let id = self.request_id();
+            self.requests.insert(id, response);
+            protocol.request(&request, id)
+        }).collect()
+    }
+
+    // fails the api requests sent on a connection that dropped
+    fn fail_requests(&mut self, url: &str) {
+        for (_, response) in self.requests.drain() {
+            let _ = response.send(Err(Error::new("NetworkError", format!("{} connection lost before the response", url))));
+        }
+    }
+
+    fn resubscribe(&mut self, protocol: &dyn Protocol) -> Vec<serde_json::Value> {
+        self.pending.clear();
+        let topics: Vec<Topic> = self.topics.values().map(|state| state.topic.clone()).collect();
+        topics.into_iter().map(|topic| {
+            let id = self.request_id();
+            self.pending.insert(id, topic.hash.clone());
+            protocol.subscribe(&topic, id)
+        }).collect()
+    }
+
+    fn send(&mut self, hash: &str, event: Event) {
+        if let Some(state) = self.topics.get_mut(hash) {
+            state.subscribers.retain_mut(|subscriber| subscriber.send(event.clone()));
+        }
+    }
+
+    fn broadcast(&mut self, event: Event) {
+        for state in self.topics.values_mut() {
+            state.subscribers.retain_mut(|subscriber| subscriber.send(event.clone()));
+        }
+    }
+
+    fn handle(&mut self, incoming: Incoming) {
+        match incoming {
+            Incoming::Updates(updates) => {
+                for (hash, update) in updates {
+                    self.send(&hash, Event::Update(update));
+                }
+            }
+            Incoming::Reply { id, error } if matches!(self.login, Login::Pending(login) if login == id) => {
+                self.login = error.map_or(Login::Done, Login::Rejected);
+            }
+            Incoming::Reply { id, error } => {
+                let hash = self.pending.remove(&id);
+                if let (Some(hash), Some(error)) = (hash, error) {
+                    self.send(&hash, Event::Error(error));
+                    self.topics.remove(&hash);
+                }
+            }
+            Incoming::Response { id, message } => {
+                if let Some(response) = self.requests.remove(&id) {
+                    let _ = response.send(Ok(message));
+                }
+            }
+            Incoming::Ignore => {}
+        }
+    }
+}
+
+// waits `delay` while streams come and go, false once the client is dropped
+async fn wait(delay: Duration, commands: &mut mpsc::UnboundedReceiver<Command>, topics: &mut Topics, protocol: &dyn Protocol) -> bool {
+    let sleep = tokio::time::sleep(delay);
+    tokio::pin!(sleep);
+    loop {
+        tokio::select! {
+            _ = &mut sleep => return true,
+            command = commands.recv() => match command {
+                // subscribed once connected
+                Some(command) => { topics.apply(command, protocol); }
+                None => return false,
+            },
+        }
+    }
+}
+
+// the connection task, it reconnects until the client is dropped and then closes the socket
+async fn run(url: String, config: StreamingConfig, protocol: Box<dyn Protocol>, mut commands: mpsc::UnboundedReceiver<Command>) {
+    let mut topics = Topics { topics: HashMap::new(), pending: HashMap::new(), queued: Vec::new(), requests: HashMap::new(), next_id: 0, login: Login::Done };
+    // reconnects in a row without receiving anything, the backoff grows with them
+    let mut attempts: u32 = 0;
+    let mut connected_before = false;
+    // nothing is opened before the first stream or api request
+    while topics.topics.is_empty() && topics.queued.is_empty() {
+        match commands.recv().await {
+            Some(command) => {
+                topics.apply(command, protocol.as_ref());
+            }
+            None => return,
+        }
+    }
+    loop {
+        if attempts > 0 {
+            let delay = config.reconnect.backoff(attempts - 1);
+            tracing::debug!(%url, delay_ms = delay.as_millis() as u64, "reconnecting");
+            if !wait(delay, &mut commands, &mut topics, protocol.as_ref()).await {
+                return;
+            }
+        }
+        attempts = attempts.saturating_add(1);
+        let mut socket = match tokio_tungstenite::connect_async(url.as_str()).await {
+            Ok((socket, _)) => socket,
+            Err(error) => {
+                tracing::debug!(%url, %error, "connect failed");
+                continue;
+            }
+        };
+        tracing::debug!(%url, "connected");
+        if connected_before {
+            topics.broadcast(Event::Reconnected);
+        }
+        connected_before = true;
+        let mut failed = false;
+        let id = topics.request_id();
+        let login = protocol.login(id);
+        topics.login = if login.is_some() { Login::Pending(id) } else { Login::Done };
+        let mut messages: Vec<serde_json::Value> = login.into_iter().collect();
+        messages.extend(topics.resubscribe(protocol.as_ref()));
+        messages.extend(topics.send_requests(protocol.as_ref()));
+        for message in messages {
+            if socket.send(Message::Text(message.to_string())).await.is_err() {
+                failed = true;
+                break;
+            }
+        }
+        let mut last_received = Instant::now();
+        let timeout = config.keep_alive.mul_f64(config.max_ping_pong_misses);
+        let mut keep_alive = tokio::time::interval_at(tokio::time::Instant::now() + config.keep_alive, config.keep_alive);
+        while !failed {
+            tokio::select! {
+                message = socket.next() => match message {
+                    Some(Ok(Message::Close(_))) | None => {
+                        tracing::debug!(%url, "closed by the exchange");
+                        failed = true;
+                    }
+                    Some(Ok(message)) => {
+                        last_received = Instant::now();
+                        attempts = 0;
+                        if let Message::Text(text) = message {
+                            if let Ok(message) = serde_json::from_str(&text) {
+                                topics.handle(protocol.route(message));
+                                // the requests held until the login reply
+                                for message in topics.send_requests(protocol.as_ref()) {
+                                    if socket.send(Message::Text(message.to_string())).await.is_err() {
+                                        failed = true;
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Some(Err(error)) => {
+                        tracing::debug!(%url, %error, "connection lost");
+                        failed = true;
+                    }
+                },
+                command = commands.recv() => match command {
+                    Some(command) => {
+                        let mut messages: Vec<serde_json::Value> = topics.apply(command, protocol.as_ref()).into_iter().collect();
+                        messages.extend(topics.send_requests(protocol.as_ref()));
+                        for message in messages {
+                            if socket.send(Message::Text(message.to_string())).await.is_err() {
+                                failed = true;
+                                break;
+                            }
+                        }
+                    }
+                    None => {
+                        let _ = socket.close(None).await;
+                        return;
+                    }
+                },
+                _ = keep_alive.tick() => {
+                    if last_received.elapsed() > timeout {
+                        tracing::debug!(%url, "keep alive timed out");
+                        failed = true;
+                    } else {
+                        let ping = match protocol.ping() {
+                            Some(message) => Message::Text(message.to_string()),
+                            None => Message::Ping(Vec::new()),
+                        };
+                        failed = socket.send(ping).await.is_err();
+                    }
+                },
+            }
+        }
+        topics.fail_requests(&url);
+    }
+}
@@ -0,0 +1,530 @@
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use async_trait::async_trait;
+use hmac::Mac;
+use serde_json::json;
+use crate::errors::Error;
+use crate::exchange::{extend_2, shift_2, Value, ValueTrait};
+use crate::gate::{Gate, GateImpl};
+use crate::pro::client::{self, Incoming, Protocol, Subscription, Topic};
+use crate::pro::order_book::{watch_order_book, Delta, Level, LocalOrderBook};
+use crate::pro::{filter_since_limit, streaming_config, watch, WatchStream};
+
+// the fields of an update that name its market, or the interval and market of a candle
+const MARKET_KEYS: &[&str] = &["currency_pair", "contract", "s", "n"];
+
+fn seconds() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
+
+fn sign(secret: &str, payload: &str) -> String {
+    let mut mac = hmac::Hmac::<sha2::Sha512>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// The v4 websocket API, a topic is a channel and its payload, e.g. `spot.trades` of `["BTC_USDT"]`, and its hash
+/// the channel and the market id, `spot.trades:BTC_USDT`, api requests are a channel and its params, e.g.
+/// `spot.order_place` and the body of the rest request
+pub struct GateProtocol {
+    /// `spot` or `futures`, the prefix of the channels of the endpoint
+    pub prefix: String,
+    /// the api key and secret signing the subscriptions of a private connection, whose topics are hashed by channel,
+    /// and logging it in for api requests
+    pub credentials: Option<(String, String)>,
+}
+
+impl GateProtocol {
+    fn request(&self, topic: &Topic, id: u64, event: &str) -> serde_json::Value {
+        let time = seconds();
+        let mut request = json!({
+            "id": id,
+            "time": time,
+            "channel": topic.request["channel"],
+            "event": event,
+            "payload": topic.request["payload"],
+        });
+        if let Some((key, secret)) = &self.credentials {
+            let payload = format!("channel={}&event={}&time={}", topic.request["channel"].as_str().unwrap_or_default(), event, time);
+            request["auth"] = json!({"method": "api_key", "KEY": key, "SIGN": sign(secret, &payload)});
+        }
+        request
+    }
+}
+
+impl Protocol for GateProtocol {
+    fn subscribe(&self, topic: &Topic, id: u64) -> serde_json::Value {
+        self.request(topic, id, "subscribe")
+    }
+
+    fn unsubscribe(&self, topic: &Topic, id: u64) -> Option<serde_json::Value> {
+        Some(self.request(topic, id, "unsubscribe"))
+    }
+
+    fn ping(&self) -> Option<serde_json::Value> {
+        Some(json!({"time": seconds(), "channel": format!("{}.ping", self.prefix)}))
+    }
+
+    fn login(&self, id: u64) -> Option<serde_json::Value> {
+        let (key, secret) = self.credentials.as_ref()?;
+        let time = seconds();
+        let channel = format!("{}.login", self.prefix);
+        let signature = sign(secret, &format!("api\n{}\n\n{}", channel, time));
+        Some(json!({
+            "time": time,
+            "channel": channel,
+            "event": "api",
+            "payload": {"api_key": key, "signature": signature, "timestamp": time.to_string(), "req_id": id.to_string()},
+        }))
+    }
+
+    fn request(&self, request: &serde_json::Value, id: u64) -> serde_json::Value {
+        json!({
+            "time": seconds(),
+            "channel": request["channel"],
+            "event": "api",
+            "payload": {"req_id": id.to_string(), "req_param": request["params"]},
+        })
+    }
+
+    fn route(&self, mut message: serde_json::Value) -> Incoming {
+        //
+        //     {"time": 1606292218, "channel": "spot.trades", "event": "update", "result": {"id": 309143071, ...}}
+        //     {"time": 1606292218, "channel": "futures.trades", "event": "update", "result": [{"id": 27753479, ...}]}
+        //     {"time": 1606292218, "id": 1, "channel": "spot.trades", "event": "subscribe", "error": null, "result": {"status": "success"}}
+        //     {"time": 1606292218, "id": 1, "channel": "spot.trades", "event": "subscribe", "error": {"code": 2, "message": "unknown currency pair"}, "result": null}
+        //     {"request_id": "3", "ack": false, "header": {"status": "200", "channel": "spot.order_place", "event": "api", ...}, "data": {"result": {...}}}
+        //     {"request_id": "1", "header": {"status": "401", "channel": "spot.login", ...}, "data": {"errs": {"label": "INVALID_KEY", "message": "Invalid key provided"}}}
+        //
+        if let Some(id) = message["request_id"].as_str().and_then(|id| id.parse().ok()) {
+            if message["header"]["channel"].as_str().is_some_and(|channel| channel.ends_with(".login")) {
+                let error = message["data"].get("errs").map(|error| {
+                    Error::new("AuthenticationError", format!("gate login {} {}", error["label"].as_str().unwrap_or_default(), error["message"].as_str().unwrap_or_default()))
+                });
+                return Incoming::Reply { id, error };
+            }
+            // orders are acknowledged before their result
+            if message["ack"] == true {
+                return Incoming::Ignore;
+            }
+            return Incoming::Response { id, message };
+        }
+        let channel = message["channel"].as_str().unwrap_or_default().to_owned();
+        match message["event"].as_str() {
+            Some("update") | Some("all") => {
+                let updates = match message["result"].take() {
+                    serde_json::Value::Array(updates) => updates,
+                    update => vec![update],
+                };
+                if self.credentials.is_some() {
+                    return Incoming::Updates(updates.into_iter().map(|update| (channel.clone(), update)).collect());
+                }
+                Incoming::Updates(updates.into_iter().filter_map(|update| {
+                    let key = MARKET_KEYS.iter().find_map(|key| update[key].as_str())?;
+                    Some((format!("{}:{}", channel, key), update))
+                }).collect())
+            }
+            Some("subscribe") | Some("unsubscribe") => match message["id"].as_u64() {
+                Some(id) => Incoming::Reply {
+                    id,
+                    error: message.get("error").filter(|error| !error.is_null()).map(|error| {
+                        Error::new("ExchangeError", format!("gate {} {} {}", channel, error["code"], error["message"].as_str().unwrap_or_default()))
+                    }),
+                },
+                None => Incoming::Ignore,
+            },
+            _ => Incoming::Ignore,
+        }
+    }
+}
+
+/// Streaming market data and the orders, trades, balances and positions of the account over the v4 websocket API,
+/// see https://www.gate.io/docs/developers/apiv4/ws/en/ and https://www.gate.io/docs/developers/futures/ws/en/, and
+/// spot trading over its api channels, see https://www.gate.io/docs/developers/apiv4/ws/en/#websocket-api
+#[async_trait]
+pub trait GatePro: Gate + Clone + 'static {
+    /// The endpoint of the market, `urls.api.ws.spot` or the one of its settle currency in `urls.api.ws.swap`
+    /// and `urls.api.ws.future`
+    fn ws_url(&self, market: &Value) -> String {
+        let urls = self.get("urls".into()).get("api".into()).get("ws".into());
+        if market.get("contract".into()).is_truthy() {
+            let settle = market.get("settleId".into()).unwrap_str().to_lowercase();
+            urls.get(market.get("type".into())).get(settle.into()).unwrap_str().to_owned()
+        } else {
+            urls.get("spot".into()).unwrap_str().to_owned()
+        }
+    }
+
+    /// The prefix of the channels of the market, `spot` or `futures`
+    fn channel_prefix(&self, market: &Value) -> &'static str {
+        if market.get("contract".into()).is_truthy() { "futures" } else { "spot" }
+    }
+
+    /// A stream of the updates of `channel` of the market, `payload` are the arguments of the channel and `key` the
+    /// field of the updates that names their market
+    fn subscribe(&self, market: &Value, channel: &str, payload: serde_json::Value, key: &str) -> Subscription {
+        let url = GatePro::ws_url(self, market);
+        let prefix = GatePro::channel_prefix(self, market);
+        let channel = format!("{}.{}", prefix, channel);
+        let client = client::client(&self.get("id".into()).property_key(), &url, streaming_config(self), || {
+            Box::new(GateProtocol { prefix: prefix.to_owned(), credentials: None })
+        });
+        client.subscribe(Topic {
+            hash: format!("{}:{}", channel, key),
+            request: json!({"channel": channel, "payload": payload}),
+        })
+    }
+
+    /// Ticker updates of `symbol` from the `tickers` channel
+    async fn watch_ticker(&mut self, symbol: Value, _params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let id = market.get("id".into()).unwrap_str().to_owned();
+        let subscription = GatePro::subscribe(self, &market, "tickers", json!([id]), &id);
+        watch(subscription, self.clone(), move |exchange, message| {
+            Some(Gate::parse_ticker(exchange, Value::Json(message), market.clone()))
+        })
+    }
+
+    /// The public trades of `symbol` as they happen from the `trades` channel, each update is a list of the new
+    /// trades from `since` on
+    async fn watch_trades(&mut self, symbol: Value, since: Value, limit: Value, _params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let id = market.get("id".into()).unwrap_str().to_owned();
+        let subscription = GatePro::subscribe(self, &market, "trades", json!([id]), &id);
+        watch(subscription, self.clone(), move |exchange, message| {
+            let trade = Gate::parse_trade(exchange, Value::Json(message), market.clone());
+            let trades = filter_since_limit(Value::Json(json!([trade])), &since, &limit, |trade| trade["timestamp"].as_i64());
+            (trades.len() > 0).then_some(trades)
+        })
+    }
+
+    /// The best `limit` levels of the order book of `symbol` kept from the REST snapshot and the `order_book_update`
+    /// channel, the book holds the best `params.depth` levels, 100 by default, and `nonce` is the last update id
+    async fn watch_order_book(&mut self, symbol: Value, limit: Value, params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let id = market.get("id".into()).unwrap_str().to_owned();
+        let symbol = market.get("symbol".into());
+        let spot = !market.get("contract".into()).is_truthy();
+        let interval = self.safe_string(params.clone(), "interval".into(), "100ms".into());
+        let depth = self.safe_integer(params, "depth".into(), 100.into());
+        let payload = if spot { json!([id, interval.unwrap_str()]) } else { json!([id, interval.unwrap_str(), "100"]) };
+        let subscription = GatePro::subscribe(self, &market, "order_book_update", payload, &id);
+        let book = LocalOrderBook::new(symbol.unwrap_str(), depth.unwrap_json().as_u64().map(|depth| depth as usize));
+        let snapshot = move |mut exchange: Self| {
+            let (symbol, depth) = (symbol.clone(), depth.clone());
+            async move { Gate::fetch_order_book(&mut exchange, symbol, depth, Value::Undefined).await }
+        };
+        let retry = streaming_config(self).reconnect;
+        watch_order_book(subscription, self.clone(), book, limit, retry, snapshot, move |message| {
+            //
+            // spot
+            //
+            //     {"t": 1606294781123, "e": "depthUpdate", "E": 1606294781, "s": "BTC_USDT", "U": 48776301,
+            //      "u": 48776306, "b": [["19137.74", "0.0001"]], "a": [["19137.75", "0.6135"]]}
+            //
+            // futures
+            //
+            //     {"t": 1615366379123, "s": "BTC_USD", "U": 2517661101, "u": 2517661113,
+            //      "b": [{"p": "54672.1", "s": 0}], "a": [{"p": "54743.6", "s": 62}]}
+            //
+            let (bids, asks) = if spot {
+                (Level::parse_all(&message["b"], 0, 1), Level::parse_all(&message["a"], 0, 1))
+            } else {
+                (Level::parse_all(&message["b"], "p", "s"), Level::parse_all(&message["a"], "p", "s"))
+            };
+            Some(Delta {
+                first: message["U"].as_i64(),
+                last: message["u"].as_i64()?,
+                previous: None,
+                timestamp: message["t"].as_i64(),
+                bids,
+                asks,
+            })
+        })
+    }
+
+    /// Candles of `symbol` from the `candlesticks` channel, each update is a list holding the current candle, sent
+    /// again as it changes, from `since` on
+    async fn watch_ohlcv(&mut self, symbol: Value, timeframe: Value, since: Value, limit: Value, _params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        let id = market.get("id".into()).unwrap_str().to_owned();
+        let interval = self.get("timeframes".into()).get(timeframe.or_default("1m".into()));
+        let interval = interval.unwrap_str();
+        let key = format!("{}_{}", interval, id);
+        let subscription = GatePro::subscribe(self, &market, "candlesticks", json!([interval, id]), &key);
+        watch(subscription, self.clone(), move |exchange, message| {
+            //
+            //     {"t": "1606292580", "v": "2362.32035", "c": "19128.1", "h": "19128.1", "l": "19128.1",
+            //      "o": "19128.1", "n": "1m_BTC_USDT", "a": "3.8283"}
+            //
+            let candles = Value::Json(json!([Gate::parse_ohlcv(exchange, Value::Json(message), market.clone())]));
+            let candles = filter_since_limit(candles, &since, &limit, |candle| candle[0].as_i64());
+            (candles.len() > 0).then_some(candles)
+        })
+    }
+    /// The account of `symbol`, or of `params.type` and `options.defaultType` without one, `spot`, `swap` or `future`,
+    /// and the settle currency id of contracts, `params.settle` or `usdt` without a symbol
+    fn private_account(&mut self, symbol: &Value, params: &Value) -> (String, String) {
+        if symbol.is_nonnullish() {
+            let market = self.market(symbol.clone());
+            if market.get("contract".into()).is_truthy() {
+                let settle = market.get("settleId".into()).unwrap_str().to_lowercase();
+                return (market.get("type".into()).unwrap_str().to_owned(), settle);
+            }
+            return ("spot".to_owned(), String::new());
+        }
+        let default_type = self.safe_string(self.get("options".into()), "defaultType".into(), "spot".into());
+        let r#type = self.safe_string(params.clone(), "type".into(), default_type);
+        let settle = self.safe_string_lower(params.clone(), "settle".into(), "usdt".into());
+        match r#type.unwrap_str() {
+            "swap" | "future" => (r#type.unwrap_str().to_owned(), settle.unwrap_str().to_owned()),
+            _ => ("spot".to_owned(), String::new()),
+        }
+    }
+
+    /// The user id the futures channels take, the `uid` credential, `options.userId` or the one of the trading fees
+    async fn user_id(&mut self) -> serde_json::Value {
+        let uid = self.get("uid".into());
+        if uid.is_truthy() {
+            return uid.into();
+        }
+        let options = self.get("options".into());
+        if options.get("userId".into()).is_nullish() {
+            let response = Gate::dispatch(self, "privateWalletGetFee".into(), Value::new_object(), Value::Undefined).await;
+            //
+            //     {"user_id": 1486602, "taker_fee": "0.002", "maker_fee": "0.002", ...}
+            //
+            self.set_path(&["options".into(), "userId".into()], response.get("user_id".into()));
+        }
+        self.get("options".into()).get("userId".into()).into()
+    }
+
+    /// The client key and the protocol of the connections of the api key, shared by private channels and api requests
+    fn private_protocol(&self, prefix: &str) -> (String, GateProtocol) {
+        let (key, secret) = (self.get("apiKey".into()).property_key(), self.get("secret".into()).property_key());
+        let protocol = GateProtocol { prefix: prefix.to_owned(), credentials: Some((key.clone(), secret)) };
+        (format!("{}:{}", self.get("id".into()).property_key(), key), protocol)
+    }
+
+    /// A stream of the updates of the private `channel` of the `type` account signed with the api key, the futures
+    /// channels take the user id before `payload`
+    async fn subscribe_private(&mut self, r#type: &str, settle: &str, channel: &str, payload: serde_json::Value) -> Subscription {
+        Gate::check_required_credentials(self, Value::Undefined);
+        let urls = self.get("urls".into()).get("api".into()).get("ws".into());
+        let (url, prefix, payload) = if r#type == "spot" {
+            (urls.get("spot".into()), "spot", payload)
+        } else {
+            let mut arguments = vec![GatePro::user_id(self).await];
+            arguments.extend(payload.as_array().cloned().unwrap_or_default());
+            (urls.get(r#type.into()).get(settle.into()), "futures", serde_json::Value::Array(arguments))
+        };
+        let url = url.unwrap_str().to_owned();
+        let channel = format!("{}.{}", prefix, channel);
+        let (key, protocol) = GatePro::private_protocol(self, prefix);
+        let client = client::client(&key, &url, streaming_config(self), || Box::new(protocol));
+        client.subscribe(Topic { hash: channel.clone(), request: json!({"channel": channel, "payload": payload}) })
+    }
+
+    /// The orders of `symbol`, or of every symbol, as they change from the `orders` channel, each update is a list of
+    /// the changed order from `since` on
+    async fn watch_orders(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let (r#type, settle) = GatePro::private_account(self, &symbol, &params);
+        let market = if symbol.is_nonnullish() { self.market(symbol) } else { Value::Undefined };
+        let subscription = GatePro::subscribe_private(self, &r#type, &settle, "orders", json!(["!all"])).await;
+        let spot = r#type == "spot";
+        watch(subscription, self.clone(), move |exchange, mut message| {
+            //
+            // spot
+            //
+            //     {"id": "12332324", "text": "t-123456", "create_time": "1622638707", "update_time": "1622638807",
+            //      "currency_pair": "BTC_USDT", "type": "limit", "account": "spot", "side": "sell", "amount": "1",
+            //      "price": "10001", "time_in_force": "gtc", "left": "1", "filled_total": "0", "fee": "0",
+            //      "fee_currency": "USDT", "event": "put", "finish_as": "open", "create_time_ms": "1622638707000",
+            //      "update_time_ms": "1622638807000"}
+            //
+            // futures
+            //
+            //     {"contract": "BTC_USDT", "create_time": 1628736847, "create_time_ms": 1628736847325,
+            //      "fill_price": 40000.4, "finish_as": "filled", "finish_time": 1628736848, "iceberg": 0, "id": 4872460,
+            //      "is_close": false, "is_liq": false, "is_reduce_only": false, "left": 0, "price": 40000.4,
+            //      "size": 1, "status": "finished", "text": "-", "tif": "gtc", "user": "110xxxxx"}
+            //
+            if spot {
+                // the status of the spot channel is the event, the end of an order says how it ended
+                let status = if message["event"] == "finish" { message["finish_as"].clone() } else { json!("open") };
+                message["status"] = status;
+            }
+            let order = Gate::parse_order(exchange, Value::Json(message), Value::Undefined);
+            if market.is_nonnullish() && order.get("symbol".into()) != market.get("symbol".into()) {
+                return None;
+            }
+            let orders = filter_since_limit(Value::Json(json!([order])), &since, &limit, |order| order["timestamp"].as_i64());
+            (orders.len() > 0).then_some(orders)
+        })
+    }
+
+    /// The fills of the orders of `symbol`, or of every symbol, from the `usertrades` channel, each update is a list
+    /// of the new trade from `since` on
+    async fn watch_my_trades(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let (r#type, settle) = GatePro::private_account(self, &symbol, &params);
+        let market = if symbol.is_nonnullish() { self.market(symbol) } else { Value::Undefined };
+        let subscription = GatePro::subscribe_private(self, &r#type, &settle, "usertrades", json!(["!all"])).await;
+        watch(subscription, self.clone(), move |exchange, message| {
+            //
+            //     {"id": 5736713, "user_id": 1000001, "order_id": "30784428", "currency_pair": "BTC_USDT",
+            //      "create_time": 1605176741, "create_time_ms": "1605176741123.456", "side": "sell",
+            //      "amount": "1.00000000", "role": "taker", "price": "10000.00000000", "fee": "0.00200000000000",
+            //      "point_fee": "0", "gt_fee": "0", "text": "apiv4"}
+            //
+            let trade = Gate::parse_trade(exchange, Value::Json(message), Value::Undefined);
+            if market.is_nonnullish() && trade.get("symbol".into()) != market.get("symbol".into()) {
+                return None;
+            }
+            let trades = filter_since_limit(Value::Json(json!([trade])), &since, &limit, |trade| trade["timestamp"].as_i64());
+            (trades.len() > 0).then_some(trades)
+        })
+    }
+
+    /// The balance of the `params.type` account, `options.defaultType` by default, from the `balances` channel, the
+    /// updates only hold the currencies that changed since the stream was opened
+    async fn watch_balance(&mut self, params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let (r#type, settle) = GatePro::private_account(self, &Value::Undefined, &params);
+        let subscription = GatePro::subscribe_private(self, &r#type, &settle, "balances", json!([])).await;
+        // the latest entry of every currency
+        let mut currencies = BTreeMap::new();
+        watch(subscription, self.clone(), move |exchange, message| {
+            //
+            // spot
+            //
+            //     {"timestamp": "1625140519", "timestamp_ms": "1625140519747", "user": "1000001", "currency": "USDT",
+            //      "change": "100", "total": "1032951.325075926", "available": "1022943.325075926", "freeze": "10008"}
+            //
+            // futures
+            //
+            //     {"balance": 9.998739899488, "change": -0.000002074115, "text": "BTC_USD:3914424",
+            //      "time": 1547199246, "time_ms": 1547199246123, "type": "fee", "user": "211xxx"}
+            //
+            let currency = message["currency"].as_str().map_or_else(|| settle.to_uppercase(), str::to_owned);
+            let code = Gate::safe_currency_code(exchange, currency.into(), Value::Undefined);
+            let mut account = Gate::account(exchange);
+            if message.get("available").is_some() {
+                account.set("free".into(), exchange.safe_string(Value::Json(message.clone()), "available".into(), Value::Undefined));
+                account.set("used".into(), exchange.safe_string(Value::Json(message.clone()), "freeze".into(), Value::Undefined));
+                account.set("total".into(), exchange.safe_string(Value::Json(message.clone()), "total".into(), Value::Undefined));
+            } else {
+                account.set("total".into(), exchange.safe_string(Value::Json(message.clone()), "balance".into(), Value::Undefined));
+            }
+            currencies.insert(code.property_key(), account);
+            let timestamp = exchange.safe_integer_2(Value::Json(message.clone()), "timestamp_ms".into(), "time_ms".into(), Value::Undefined);
+            let mut balance = Value::Json(json!({"info": message}));
+            for (code, account) in &currencies {
+                balance.set(code.as_str().into(), account.clone());
+            }
+            balance.set("timestamp".into(), timestamp.clone());
+            balance.set("datetime".into(), exchange.iso8601(timestamp));
+            Some(Gate::safe_balance(exchange, balance))
+        })
+    }
+
+    /// The positions of `symbols`, or of every symbol, of the `params.type` futures account from the `positions`
+    /// channel, each update is a list of the changed position from `since` on
+    async fn watch_positions(&mut self, symbols: Value, since: Value, limit: Value, params: Value) -> WatchStream {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let symbols = match symbols {
+            Value::Json(serde_json::Value::Array(symbols)) => symbols,
+            _ => Vec::new(),
+        };
+        let first = symbols.first().cloned().map_or(Value::Undefined, Value::Json);
+        let (r#type, settle) = GatePro::private_account(self, &first, &params);
+        if r#type == "spot" {
+            panic!("NotSupported::new({} watch_positions() supports swap and future accounts only)", self.get("id".into()).property_key());
+        }
+        let subscription = GatePro::subscribe_private(self, &r#type, &settle, "positions", json!(["!all"])).await;
+        watch(subscription, self.clone(), move |exchange, message| {
+            //
+            //     {"contract": "BTC_USD", "cross_leverage_limit": 0, "entry_price": 40000.36666661111,
+            //      "history_pnl": -0.000108569505, "history_point": 0, "last_close_pnl": -0.000050123368,
+            //      "leverage": 0, "leverage_max": 100, "liq_price": 0.1, "maintenance_rate": 0.005,
+            //      "margin": 49.999890611186, "mode": "single", "realised_pnl": -1.25e-8, "realised_point": 0,
+            //      "risk_limit": 100, "size": 3, "time": 1628736848, "time_ms": 1628736848321, "user": "110xxxxx"}
+            //
+            let timestamp = exchange.safe_integer(Value::Json(message.clone()), "time_ms".into(), Value::Undefined);
+            let mut position = Gate::parse_position(exchange, Value::Json(message), Value::Undefined);
+            if !symbols.is_empty() && !symbols.contains(&position.get("symbol".into()).into()) {
+                return None;
+            }
+            position.set("timestamp".into(), timestamp.clone());
+            position.set("datetime".into(), exchange.iso8601(timestamp));
+            let positions = filter_since_limit(Value::Json(json!([position])), &since, &limit, |position| position["timestamp"].as_i64());
+            (positions.len() > 0).then_some(positions)
+        })
+    }
+    /// The result of the spot api request `channel` with `params` on the logged in connection of the api key,
+    /// failures are raised like the ones of the rest api
+    async fn request_ws(&mut self, channel: &str, params: Value) -> Value {
+        Gate::check_required_credentials(self, Value::Undefined);
+        let url = self.get("urls".into()).get("api".into()).get("ws".into()).get("spot".into()).unwrap_str().to_owned();
+        let (key, protocol) = GatePro::private_protocol(self, "spot");
+        let client = client::api_client(&key, &url, streaming_config(self), || Box::new(protocol));
+        let response = match client.request(json!({"channel": channel, "params": params.unwrap_json()})).await {
+            Ok(response) => response,
+            Err(error) => panic!("{}::new({})", error.kind, error.message),
+        };
+        //
+        //     {"request_id": "3", "ack": false, "header": {"response_time": "1681986204784", "status": "200",
+        //      "channel": "spot.order_place", "event": "api", "client_id": "::1-0x140001623c0-0"},
+        //      "data": {"result": {"id": "12332324", "text": "t-123456", ...}}}
+        //     {"request_id": "3", "ack": false, "header": {"status": "400", ...},
+        //      "data": {"errs": {"label": "INVALID_PARAM_VALUE", "message": "invalid argument: amount"}}}
+        //
+        if let Some(error) = response["data"].get("errs") {
+            let status = Value::Json(response["header"]["status"].clone());
+            let body = Value::from(error.to_string());
+            Gate::handle_errors(self, status, "".into(), url.into(), channel.into(), Value::Undefined, body, Value::Json(error.clone()), Value::Undefined, Value::Undefined);
+            panic!("ExchangeError::new({} {})", self.get("id".into()).property_key(), error);
+        }
+        Value::Json(response["data"]["result"].clone())
+    }
+
+    /// `create_order` over the `spot.order_place` api channel, for spot and margin orders without a trigger
+    async fn create_order_ws(&mut self, symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Value {
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol.clone());
+        let params = params.or_default(Value::new_object());
+        let request = Gate::create_order_request(self, symbol, r#type, side, amount, price, params);
+        if request.get(0.into()).unwrap_str() != "privateSpotPostOrders" {
+            panic!("NotSupported::new({} create_order_ws() supports spot and margin orders without a trigger only)", self.get("id".into()).property_key());
+        }
+        let response = GatePro::request_ws(self, "spot.order_place", request.get(1.into())).await;
+        Gate::parse_order(self, response, market)
+    }
+
+    /// `cancel_order` over the `spot.order_cancel` api channel, for spot and margin orders
+    async fn cancel_order_ws(&mut self, id: Value, symbol: Value, params: Value) -> Value {
+        if symbol.is_nullish() {
+            panic!("ArgumentsRequired::new({} cancel_order_ws() requires a symbol argument)", self.get("id".into()).property_key());
+        }
+        Gate::load_markets(self, Value::Undefined, Value::Undefined).await;
+        let market = self.market(symbol);
+        if market.get("contract".into()).is_truthy() {
+            panic!("NotSupported::new({} cancel_order_ws() supports spot and margin orders only)", self.get("id".into()).property_key());
+        }
+        let params = params.or_default(Value::new_object());
+        let (mut request, params) = shift_2(Gate::spot_order_prepare_request(self, market.clone(), false.into(), params));
+        request.set("order_id".into(), id);
+        let response = GatePro::request_ws(self, "spot.order_cancel", extend_2(request, params)).await;
+        Gate::parse_order(self, response, market)
+    }
+}
+
+impl GatePro for GateImpl {}
+
+#[cfg(feature = "gateio")]
+impl GatePro for crate::gateio::GateioImpl {}
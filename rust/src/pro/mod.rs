@@ -0,0 +1,60 @@
+// websocket streaming like ccxt.pro, every exchange keeps one connection per url for all its copies, topics watched
+// by several streams are subscribed once, and connections ping, reconnect and subscribe again when they drop
+
+pub mod client;
+pub mod order_book;
+#[cfg(feature = "binance")]
+pub mod binance;
+#[cfg(feature = "gate")]
+pub mod gate;
+
+use std::panic::AssertUnwindSafe;
+use futures::future;
+use futures::stream::{BoxStream, Stream, StreamExt};
+use crate::errors::{Error, Result};
+use crate::exchange::{Value, ValueTrait};
+use self::client::{Event, StreamingConfig};
+
+/// The updates of a `watch_*` method, unified like the result of the matching fetch method
+pub type WatchStream = BoxStream<'static, Result<Value>>;
+
+/// The `streaming` config and reconnect delays of an exchange
+pub fn streaming_config<T: ValueTrait>(exchange: &T) -> StreamingConfig {
+    StreamingConfig::from_config(&exchange.get("streaming".into()), &exchange.get("options".into()))
+}
+
+/// Turns the updates of `subscription`, or of another stream of events, into unified structures with `parse`, which
+/// runs on a copy of the exchange owned by the stream and skips an update by returning `None`, errors raised while
+/// parsing are yielded instead
+pub fn watch<S, T, F>(subscription: S, mut exchange: T, mut parse: F) -> WatchStream
+where
+    S: Stream<Item = Event> + Send + 'static,
+    T: Send + 'static,
+    F: FnMut(&mut T, serde_json::Value) -> Option<Value> + Send + 'static,
+{
+    subscription.filter_map(move |event| future::ready(match event {
+        Event::Update(message) => match std::panic::catch_unwind(AssertUnwindSafe(|| parse(&mut exchange, message))) {
+            Ok(update) => update.map(Ok),
+            Err(error) => Some(Err(Error::from_panic(error))),
+        },
+        Event::Reconnected | Event::Lagged(_) => None,
+        Event::Error(error) => Some(Err(error)),
+    })).boxed()
+}
+
+/// The last `limit` entries of `items` with a `timestamp` of at least `since`, either can be undefined
+pub fn filter_since_limit(items: Value, since: &Value, limit: &Value, timestamp: impl Fn(&serde_json::Value) -> Option<i64>) -> Value {
+    let mut items = match items {
+        Value::Json(serde_json::Value::Array(items)) => items,
+        items => return items,
+    };
+    if let Value::Json(serde_json::Value::Number(since)) = since {
+        let since = since.as_i64().unwrap_or(0);
+        items.retain(|item| timestamp(item).is_none_or(|t| t >= since));
+    }
+    if let Value::Json(serde_json::Value::Number(limit)) = limit {
+        let limit = limit.as_u64().unwrap_or(0) as usize;
+        items.drain(..items.len().saturating_sub(limit));
+    }
+    Value::Json(serde_json::Value::Array(items))
+}
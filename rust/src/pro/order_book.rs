@@ -0,0 +1,346 @@
+use std::future::Future;
+use futures::future::BoxFuture;
+use futures::stream::{self, StreamExt};
+use serde_json::json;
+use crate::errors::{catch, Error, Result};
+use crate::exchange::{iso8601, Value};
+use crate::pro::client::{Event, Subscription};
+use crate::pro::WatchStream;
+use crate::retry::RetryPolicy;
+
+/// A price level
+#[derive(Debug, Clone, PartialEq)]
+pub struct Level {
+    pub price: f64,
+    pub amount: f64,
+}
+
+fn number(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        serde_json::Value::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+impl Level {
+    /// A level from a price and an amount sent as strings or numbers, `None` when either is missing
+    pub fn parse(price: &serde_json::Value, amount: &serde_json::Value) -> Option<Level> {
+        Some(Level { price: number(price)?, amount: number(amount)? })
+    }
+
+    /// The levels of a list of `[price, amount]` arrays or `{"p": price, "s": amount}` objects, like `parse_bids_asks`
+    pub fn parse_all<I: serde_json::value::Index + Copy>(levels: &serde_json::Value, price_key: I, amount_key: I) -> Vec<Level> {
+        levels.as_array().map_or_else(Vec::new, |levels| {
+            levels.iter().filter_map(|level| Level::parse(&level[price_key], &level[amount_key])).collect()
+        })
+    }
+}
+
+/// One side of a book, best price first, updates find their place by binary search so the side is never sorted again
+#[derive(Debug, Clone, PartialEq)]
+pub struct Side {
+    levels: Vec<Level>,
+    descending: bool,
+}
+
+impl Side {
+    pub fn bids() -> Self {
+        Side { levels: Vec::new(), descending: true }
+    }
+
+    pub fn asks() -> Self {
+        Side { levels: Vec::new(), descending: false }
+    }
+
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    /// Sets the amount at the price of `level`, a zero amount removes the level
+    pub fn store(&mut self, level: Level) {
+        let descending = self.descending;
+        let position = self.levels.binary_search_by(|other| {
+            let ordering = other.price.total_cmp(&level.price);
+            if descending { ordering.reverse() } else { ordering }
+        });
+        match (position, level.amount == 0.0) {
+            (Ok(i), true) => { self.levels.remove(i); }
+            (Ok(i), false) => self.levels[i] = level,
+            (Err(_), true) => {}
+            (Err(i), false) => self.levels.insert(i, level),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.levels.clear();
+    }
+
+    pub fn truncate(&mut self, depth: usize) {
+        self.levels.truncate(depth);
+    }
+
+    fn to_json(&self, limit: usize) -> serde_json::Value {
+        self.levels.iter().take(limit).map(|level| json!([level.price, level.amount])).collect()
+    }
+}
+
+/// An update of a diff stream, `first` and `last` are the ids of the first and the last change it includes and
+/// `previous` the `last` of the update before it when the exchange sends it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Delta {
+    pub first: Option<i64>,
+    pub last: i64,
+    pub previous: Option<i64>,
+    pub timestamp: Option<i64>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/// An order book kept from a snapshot and the deltas that follow it, bounded to the best `depth` levels a side
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalOrderBook {
+    pub symbol: String,
+    pub bids: Side,
+    pub asks: Side,
+    /// the id of the last change applied, the `nonce` of the snapshot until a delta is applied
+    pub nonce: Option<i64>,
+    pub timestamp: Option<i64>,
+    pub depth: Option<usize>,
+    // whether a delta was applied since the snapshot
+    synced: bool,
+}
+
+impl LocalOrderBook {
+    pub fn new(symbol: &str, depth: Option<usize>) -> Self {
+        LocalOrderBook {
+            symbol: symbol.to_owned(),
+            bids: Side::bids(),
+            asks: Side::asks(),
+            nonce: None,
+            timestamp: None,
+            depth,
+            synced: false,
+        }
+    }
+
+    /// Replaces the book with a unified order book, as returned by `fetch_order_book`
+    pub fn reset(&mut self, snapshot: &Value) {
+        let snapshot = match snapshot {
+            Value::Json(snapshot) => snapshot,
+            _ => &serde_json::Value::Null,
+        };
+        self.bids.clear();
+        self.asks.clear();
+        for level in Level::parse_all(&snapshot["bids"], 0, 1) {
+            self.bids.store(level);
+        }
+        for level in Level::parse_all(&snapshot["asks"], 0, 1) {
+            self.asks.store(level);
+        }
+        self.nonce = snapshot["nonce"].as_i64();
+        self.timestamp = snapshot["timestamp"].as_i64();
+        self.synced = false;
+        self.truncate();
+    }
+
+    /// Applies `delta`, false when the book already includes it and `InvalidNonce` when changes between the book and
+    /// the delta are missing, the book has to be reset from a new snapshot after an error
+    pub fn apply(&mut self, delta: &Delta) -> Result<bool> {
+        if let Some(nonce) = self.nonce {
+            if delta.last <= nonce {
+                return Ok(false);
+            }
+            let in_sequence = match (self.synced, delta.previous) {
+                // the first delta applied to a snapshot has to include the change after it
+                (false, _) => delta.first.is_none_or(|first| first <= nonce + 1),
+                (true, Some(previous)) => previous == nonce,
+                (true, None) => delta.first.is_none_or(|first| first == nonce + 1),
+            };
+            if !in_sequence {
+                return Err(Error::new("InvalidNonce", format!(
+                    "{} order book update {}..{} does not follow {}",
+                    self.symbol, delta.first.or(delta.previous).unwrap_or(delta.last), delta.last, nonce,
+                )));
+            }
+        }
+        for level in &delta.bids {
+            self.bids.store(level.clone());
+        }
+        for level in &delta.asks {
+            self.asks.store(level.clone());
+        }
+        self.nonce = Some(delta.last);
+        self.timestamp = delta.timestamp.or(self.timestamp);
+        self.synced = true;
+        self.truncate();
+        Ok(true)
+    }
+
+    fn truncate(&mut self) {
+        if let Some(depth) = self.depth {
+            self.bids.truncate(depth);
+            self.asks.truncate(depth);
+        }
+    }
+
+    /// The unified order book of the best `limit` levels a side, or all of them when `limit` is undefined
+    pub fn order_book(&self, limit: &Value) -> Value {
+        let limit = match limit {
+            Value::Json(serde_json::Value::Number(limit)) => limit.as_u64().unwrap_or(0) as usize,
+            _ => usize::MAX,
+        };
+        let timestamp = self.timestamp.map_or(Value::Undefined, Value::from);
+        Value::Json(json!({
+            "symbol": self.symbol,
+            "bids": self.bids.to_json(limit),
+            "asks": self.asks.to_json(limit),
+            "timestamp": self.timestamp,
+            "datetime": iso8601(&timestamp),
+            "nonce": self.nonce,
+        }))
+    }
+}
+
+/// The deltas kept while a snapshot is fetched, once more arrive the stream subscribes again and starts over, so
+/// that a snapshot failing for long does not buffer the whole stream
+pub const MAX_BUFFERED_DELTAS: usize = 1000;
+
+struct BookSync<T, S, D> {
+    subscription: Subscription,
+    exchange: T,
+    book: LocalOrderBook,
+    limit: Value,
+    snapshot: S,
+    delta: D,
+    retry: RetryPolicy,
+    loaded: bool,
+    // the deltas received before the snapshot they follow, at most MAX_BUFFERED_DELTAS
+    buffer: Vec<Delta>,
+    // the snapshot being fetched, the deltas are buffered meanwhile and no other one is requested
+    pending: Option<BoxFuture<'static, Result<Value>>>,
+    // snapshots in a row that failed or did not match the deltas, the next one waits the backoff of as many retries
+    failures: u32,
+}
+
+enum Step {
+    Snapshot(Result<Value>),
+    Event(Option<Event>),
+}
+
+impl<T, S, D> BookSync<T, S, D>
+where T: Clone + Send + 'static, S: Fn(T) -> BoxFuture<'static, Value> + Send, D: FnMut(serde_json::Value) -> Option<Delta> + Send {
+    fn fetch_snapshot(&mut self) {
+        let delay = self.failures.checked_sub(1).map(|retries| self.retry.backoff(retries));
+        let snapshot = (self.snapshot)(self.exchange.clone());
+        self.pending = Some(Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+            catch(snapshot).await
+        }));
+    }
+
+    // resets the book from the snapshot and applies the buffered deltas, the ones from the delta that does not
+    // follow on are kept for the next snapshot
+    fn load(&mut self, snapshot: &Value) {
+        self.book.reset(snapshot);
+        self.loaded = true;
+        let mut buffer = std::mem::take(&mut self.buffer).into_iter();
+        while let Some(delta) = buffer.next() {
+            if let Err(error) = self.book.apply(&delta) {
+                tracing::debug!(symbol = %self.book.symbol, %error, "order book snapshot does not match its updates");
+                self.loaded = false;
+                self.buffer = std::iter::once(delta).chain(buffer).collect();
+                break;
+            }
+        }
+    }
+
+    async fn next(&mut self) -> Option<Result<Value>> {
+        loop {
+            if !self.loaded && self.pending.is_none() && !self.buffer.is_empty() {
+                self.fetch_snapshot();
+            }
+            let step = match self.pending.as_mut() {
+                Some(pending) => tokio::select! {
+                    snapshot = pending => Step::Snapshot(snapshot),
+                    event = self.subscription.next() => Step::Event(event),
+                },
+                None => Step::Event(self.subscription.next().await),
+            };
+            let message = match step {
+                Step::Snapshot(snapshot) => {
+                    self.pending = None;
+                    match snapshot {
+                        Ok(snapshot) => self.load(&snapshot),
+                        Err(error) => {
+                            self.failures += 1;
+                            return Some(Err(error));
+                        }
+                    }
+                    if !self.loaded {
+                        self.failures += 1;
+                        continue;
+                    }
+                    self.failures = 0;
+                    return Some(Ok(self.book.order_book(&self.limit)));
+                }
+                Step::Event(event) => match event? {
+                    Event::Update(message) => message,
+                    // the updates missed in between make the book resync like after a reconnect
+                    Event::Reconnected | Event::Lagged(_) => {
+                        self.loaded = false;
+                        self.buffer.clear();
+                        self.pending = None;
+                        continue;
+                    }
+                    Event::Error(error) => return Some(Err(error)),
+                },
+            };
+            let delta = match (self.delta)(message) {
+                Some(delta) => delta,
+                None => continue,
+            };
+            if self.loaded {
+                match self.book.apply(&delta) {
+                    Ok(true) => return Some(Ok(self.book.order_book(&self.limit))),
+                    Ok(false) => continue,
+                    Err(error) => {
+                        tracing::debug!(symbol = %self.book.symbol, %error, "resyncing order book");
+                        self.loaded = false;
+                    }
+                }
+            }
+            if self.buffer.len() == MAX_BUFFERED_DELTAS {
+                tracing::debug!(symbol = %self.book.symbol, "order book updates overflowed while waiting for a snapshot, subscribing again");
+                self.buffer.clear();
+                self.pending = None;
+                self.subscription.resubscribe();
+            }
+            self.buffer.push(delta);
+        }
+    }
+}
+
+/// A stream of the best `limit` levels of `book` kept from the deltas of `subscription` parsed with `delta`, the
+/// book is reset from `snapshot` with the deltas received meanwhile applied to it, again whenever a delta does not
+/// follow on the book and after reconnecting, one snapshot is fetched at a time and the ones that fail, which are
+/// yielded as errors, or do not match the deltas are fetched again after the backoff of `retry`, while the deltas
+/// received meanwhile are buffered up to `MAX_BUFFERED_DELTAS`
+pub fn watch_order_book<T, S, F, D>(subscription: Subscription, exchange: T, book: LocalOrderBook, limit: Value, retry: RetryPolicy, snapshot: S, delta: D) -> WatchStream
+where
+    T: Clone + Send + 'static,
+    S: Fn(T) -> F + Send + 'static,
+    F: Future<Output = Value> + Send + 'static,
+    D: FnMut(serde_json::Value) -> Option<Delta> + Send + 'static,
+{
+    let snapshot = move |exchange: T| -> BoxFuture<'static, Value> { Box::pin(snapshot(exchange)) };
+    let sync = BookSync {
+        subscription, exchange, book, limit, snapshot, delta, retry, loaded: false, buffer: Vec::new(), pending: None, failures: 0,
+    };
+    stream::unfold(sync, |mut sync| async move {
+        let item = sync.next().await?;
+        Some((item, sync))
+    }).boxed()
+}
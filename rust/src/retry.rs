@@ -0,0 +1,122 @@
+use std::time::Duration;
+use chrono::DateTime;
+use rand::Rng;
+use crate::errors::Error;
+use crate::exchange::{Value, ValueTrait};
+
+// the parameters the exchanges take a client order id in, a request carrying one cannot place the same order twice
+const CLIENT_ORDER_ID_KEYS: &[&str] = &[
+    "clientOrderId", "newClientOrderId", "origClientOrderId", "clOrdId", "clOrdID", "clientOid", "client_oid", "client_id",
+];
+
+/// How `fetch2` retries a request that failed with a transient error: a dropped connection, a 502, 503 or 504,
+/// `DDoSProtection` or `RequestTimeout`, read from `options.retry` as `{ maxAttempts, baseDelay, maxDelay }`
+/// with the delays in milliseconds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    // attempts per request including the first one, 1 turns retries off
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+fn millis_of(value: Value) -> Option<u64> {
+    match value {
+        Value::Json(serde_json::Value::Number(n)) => n.as_f64().map(|n| n.max(0.0) as u64),
+        _ => None,
+    }
+}
+
+impl RetryPolicy {
+    pub fn from_options(options: &Value) -> Self {
+        let retry = options.get("retry".into());
+        let default = RetryPolicy::default();
+        if !retry.is_object() {
+            return default;
+        }
+        RetryPolicy {
+            max_attempts: millis_of(retry.get("maxAttempts".into())).map_or(default.max_attempts, |n| n.max(1) as u32),
+            base_delay: millis_of(retry.get("baseDelay".into())).map_or(default.base_delay, Duration::from_millis),
+            max_delay: millis_of(retry.get("maxDelay".into())).map_or(default.max_delay, Duration::from_millis),
+        }
+    }
+
+    /// How long to wait before sending the request again after `retries` retries, when `error` is transient and the
+    /// request is safe to repeat, `response` is the raw response from `fetch_response` or undefined when the
+    /// connection failed, its `Retry-After` header takes precedence over the jittered exponential backoff and one
+    /// longer than `max_delay` raises the error instead, retrying earlier than the server allows gets ips banned
+    pub fn delay(&self, retries: u32, idempotent: bool, error: &Error, response: &Value) -> Option<Duration> {
+        if !idempotent || retries + 1 >= self.max_attempts || !is_transient(error, response) {
+            return None;
+        }
+        if let Some(retry_after) = response.is_object().then(|| retry_after(&response.get("headers".into()))).flatten() {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+        Some(self.backoff(retries))
+    }
+
+    /// The jittered exponential backoff after `retries` retries, between half and all of `base_delay * 2^retries`
+    /// capped at `max_delay`, streams wait as long before reconnecting
+    pub fn backoff(&self, retries: u32) -> Duration {
+        let backoff = self.base_delay.saturating_mul(2u32.saturating_pow(retries)).min(self.max_delay);
+        rand::thread_rng().gen_range(backoff / 2..=backoff)
+    }
+}
+
+fn is_transient(error: &Error, response: &Value) -> bool {
+    let status = match response {
+        Value::Json(serde_json::Value::Object(response)) => response.get("status").and_then(serde_json::Value::as_u64),
+        _ => None,
+    };
+    match status {
+        None => error.is("NetworkError"),
+        Some(502..=504) => true,
+        Some(_) => error.is("DDoSProtection") || error.is("RequestTimeout"),
+    }
+}
+
+/// Whether a request can be sent twice without side effects, reads are and so are writes carrying a client order id,
+/// unless `context.idempotent` says otherwise for a unified method that adds a client order id of its own
+pub fn is_idempotent(method: &Value, params: &Value, context: &Value) -> bool {
+    let method = match method {
+        Value::Json(serde_json::Value::String(m)) => m.to_uppercase(),
+        _ => "GET".to_owned(),
+    };
+    if matches!(method.as_str(), "GET" | "HEAD" | "OPTIONS") {
+        return true;
+    }
+    match context.is_object().then(|| context.get("idempotent".into())) {
+        Some(Value::Json(serde_json::Value::Bool(idempotent))) => idempotent,
+        _ => params.is_object() && CLIENT_ORDER_ID_KEYS.iter().any(|key| params.get((*key).into()).is_nonnullish()),
+    }
+}
+
+/// The `context` of a request a unified method adds a client order id to, it is only safe to repeat when
+/// `client_order_id`, the id taken from the caller's params, is set
+pub fn idempotency_context(client_order_id: &Value) -> Value {
+    Value::Json(serde_json::json!({ "idempotent": client_order_id.is_nonnullish() }))
+}
+
+/// The `Retry-After` header of a response, in seconds or as an http date
+pub fn retry_after(headers: &Value) -> Option<Duration> {
+    let headers = match headers {
+        Value::Json(serde_json::Value::Object(headers)) => headers,
+        _ => return None,
+    };
+    let value = headers.iter().find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))?.1.as_str()?.trim();
+    if let Ok(seconds) = value.parse::<f64>() {
+        return Some(Duration::from_secs_f64(seconds.max(0.0)));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.timestamp_millis() - chrono::Utc::now().timestamp_millis()).try_into().map_or(Duration::ZERO, Duration::from_millis))
+}
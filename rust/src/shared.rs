@@ -0,0 +1,332 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, RwLock};
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::capabilities::Capabilities;
+use crate::errors::Result;
+use crate::exchange::{json_get_path, json_set_path, Value};
+use crate::markets::{MarketsCache, MARKETS_KEYS};
+use crate::order_request::OrderRequest;
+use crate::types::{
+    Balances, Currency, LedgerEntry, Market, Order, OrderBook, Position, Ticker, Trade, TradingFee, Transaction, OHLCV,
+};
+use crate::unified::UnifiedExchange;
+
+type JsonMap = serde_json::Map<String, serde_json::Value>;
+
+// a copy of the exchange between calls, with the state it is known to be at
+type IdleCopy = (Option<Arc<JsonMap>>, Box<dyn UnifiedExchange>);
+
+/// A cloneable handle to one exchange whose unified methods take `&self`, so that many tasks can use the same
+/// account concurrently
+///
+/// Each call runs on its own copy of the exchange and only the state that call changed, e.g. an option, is written
+/// back, the copies share the rate limiter of the exchange id. The copies are kept between calls, so the exchange is
+/// only cloned when more calls run at once than ever before, and a copy is brought up to date by comparing the few
+/// keys the calls change, the describe parts they only read, e.g. `api` or `has`, are left alone. The markets are
+/// kept out of the copies, a call takes them from the shared markets loader when it loads the markets, as every
+/// unified method does first
+#[derive(Clone)]
+pub struct SharedExchange {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    // the exchange the copies are cloned from, without its markets, its keys that calls change are out of date
+    template: RwLock<Arc<dyn UnifiedExchange>>,
+    // the up to date keys that calls change, replaced as a whole when a call changed some of them
+    state: RwLock<Arc<JsonMap>>,
+    // the copies of the calls that ended
+    idle: Mutex<Vec<IdleCopy>>,
+    // the markets last loaded, the same map the loader hands out to the copies
+    markets: RwLock<MarketsCache>,
+    markets_loader: String,
+}
+
+/// The describe keys the calls only read, neither compared nor written back after a call
+const READ_ONLY_KEYS: &[&str] = &[
+    "id", "name", "api", "has", "fees", "exceptions", "httpExceptions", "timeframes", "commonCurrencies", "requiredCredentials",
+    "required_credentials",
+];
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        crate::markets::unregister(&self.markets_loader);
+    }
+}
+
+macro_rules! shared_methods {
+    ($($(#[$attr:meta])* fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)*) => {
+        $($(#[$attr])*
+        pub async fn $name(&self, $($arg: $ty),*) -> $ret {
+            self.with(move |exchange| exchange.$name($($arg),*)).await
+        })*
+    };
+}
+
+impl SharedExchange {
+    pub fn new(mut exchange: Box<dyn UnifiedExchange>) -> Self {
+        // the copies the calls run on load the markets once through the loader of the handle
+        let markets_loader = crate::markets::register();
+        let mut markets = JsonMap::new();
+        let mut loaded_at = None;
+        if let Some(state) = exchange.state_mut().as_object_mut() {
+            state.insert("marketsLoader".to_owned(), markets_loader.clone().into());
+            loaded_at = state.remove("marketsLoadedAt").and_then(|at| at.as_u64());
+            for key in MARKETS_KEYS {
+                if matches!(state.get(*key), Some(value) if !value.is_null()) {
+                    markets.insert(key.to_string(), state.remove(*key).unwrap());
+                }
+            }
+        }
+        let mut markets = MarketsCache { loaded_at: None, state: Arc::new(markets) };
+        if !markets.state.is_empty() {
+            // the markets loaded before the exchange was shared seed the loader
+            markets.loaded_at = loaded_at.or_else(|| Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64));
+            *crate::markets::loader(Some(&markets_loader)).try_lock().unwrap() = markets.clone();
+        }
+        let state = match exchange.state().as_object() {
+            Some(state) => state.iter().filter(|(key, _)| changeable(key)).map(|(k, v)| (k.clone(), v.clone())).collect(),
+            None => JsonMap::new(),
+        };
+        SharedExchange {
+            inner: Arc::new(Inner {
+                template: RwLock::new(Arc::from(exchange)),
+                state: RwLock::new(Arc::new(state)),
+                idle: Mutex::new(Vec::new()),
+                markets: RwLock::new(markets),
+                markets_loader,
+            }),
+        }
+    }
+
+    fn snapshot(&self) -> Arc<dyn UnifiedExchange> {
+        self.inner.template.read().unwrap().clone()
+    }
+
+    pub fn id(&self) -> Value {
+        self.snapshot().id()
+    }
+
+    pub fn name(&self) -> Value {
+        self.snapshot().name()
+    }
+
+    pub fn has(&self) -> Value {
+        self.snapshot().has()
+    }
+
+    pub fn capabilities(&self) -> Capabilities {
+        self.snapshot().capabilities()
+    }
+
+    /// Reads the shared state, e.g. `exchange.get_path(&["options".into(), "defaultType".into()])`
+    pub fn get_path(&self, path: &[Value]) -> Value {
+        let markets = self.inner.markets.read().unwrap().clone();
+        match path.split_first().map(|(key, rest)| (key.property_key(), rest)) {
+            Some((key, [])) if key == "marketsLoadedAt" => markets.loaded_at.map_or(Value::Undefined, Value::from),
+            Some((key, rest)) if markets.state.contains_key(&key) => json_get_path(&markets.state[&key], rest),
+            Some((key, rest)) if changeable(&key) => match self.inner.state.read().unwrap().get(&key) {
+                Some(value) => json_get_path(value, rest),
+                None => Value::Undefined,
+            },
+            _ => json_get_path(self.snapshot().state(), path),
+        }
+    }
+
+    /// Updates the shared state, the calls started afterwards see the new value
+    pub fn set_path(&self, path: &[Value], value: Value) {
+        match path.first().map(Value::property_key) {
+            Some(key) if changeable(&key) => {
+                let mut state = self.inner.state.write().unwrap();
+                let mut updated = serde_json::Value::Object((**state).clone());
+                json_set_path(&mut updated, path, value);
+                if let serde_json::Value::Object(updated) = updated {
+                    *state = Arc::new(updated);
+                }
+            }
+            _ => {
+                // the copies hold the read only keys as they were cloned, they are cloned again
+                let mut template = self.inner.template.write().unwrap();
+                json_set_path(writable(&mut template).state_mut(), path, value);
+                self.inner.idle.lock().unwrap().clear();
+            }
+        }
+    }
+
+    /// Runs `call` on a copy of the exchange and writes the state it changed back into the shared one
+    pub async fn with<T, F>(&self, call: F) -> T
+    where F: for<'a> FnOnce(&'a mut dyn UnifiedExchange) -> Pin<Box<dyn Future<Output = T> + Send + 'a>> {
+        let before = self.inner.state.read().unwrap().clone();
+        let mut exchange = match self.inner.idle.lock().unwrap().pop() {
+            Some((Some(at), exchange)) if Arc::ptr_eq(&at, &before) => exchange,
+            Some((_, mut exchange)) => {
+                update(exchange.as_mut(), &before);
+                exchange
+            }
+            None => {
+                let mut exchange = self.snapshot().clone_box();
+                update(exchange.as_mut(), &before);
+                exchange
+            }
+        };
+        let rv = call(exchange.as_mut()).await;
+        let mut changed = Vec::new();
+        if let Some(after) = exchange.state().as_object() {
+            changes(&before, after, &[], &mut changed);
+        }
+        let at = if changed.is_empty() {
+            Some(before)
+        } else {
+            let mut state = self.inner.state.write().unwrap();
+            // the copy holds the new state unless another call changed it in the meantime
+            let unchanged = Arc::ptr_eq(&state, &before);
+            let mut updated = (**state).clone();
+            for (path, value) in changed {
+                apply(&mut updated, &path, value);
+            }
+            *state = Arc::new(updated);
+            unchanged.then(|| state.clone())
+        };
+        if matches!(exchange.state().get("marketsLoadedAt"), Some(at) if !at.is_null()) {
+            let markets = crate::markets::loader(Some(&self.inner.markets_loader)).lock().await.clone();
+            *self.inner.markets.write().unwrap() = markets;
+        }
+        self.inner.idle.lock().unwrap().push((at, exchange));
+        rv
+    }
+
+    pub async fn place_order(&self, request: OrderRequest) -> Result<Order> {
+        self.with(move |exchange| Box::pin(async move { exchange.place_order(&request).await })).await
+    }
+
+    shared_methods! {
+        fn load_markets(reload: Value, params: Value) -> Value;
+        fn reload_markets(params: Value) -> Value;
+        fn fetch_markets(params: Value) -> Value;
+        fn fetch_currencies(params: Value) -> Value;
+        fn fetch_ticker(symbol: Value, params: Value) -> Value;
+        fn fetch_tickers(symbols: Value, params: Value) -> Value;
+        fn fetch_order_book(symbol: Value, limit: Value, params: Value) -> Value;
+        fn fetch_ohlcv(symbol: Value, timeframe: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_trades(symbol: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_status(params: Value) -> Value;
+        fn fetch_time(params: Value) -> Value;
+        fn fetch_balance(params: Value) -> Value;
+        fn create_order(symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Value;
+        #[allow(clippy::too_many_arguments)]
+        fn edit_order(id: Value, symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Value;
+        fn cancel_order(id: Value, symbol: Value, params: Value) -> Value;
+        fn cancel_all_orders(symbol: Value, params: Value) -> Value;
+        fn fetch_order(id: Value, symbol: Value, params: Value) -> Value;
+        fn fetch_orders(symbol: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_open_orders(symbol: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_closed_orders(symbol: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_my_trades(symbol: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_deposit_address(code: Value, params: Value) -> Value;
+        fn fetch_deposits(code: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_withdrawals(code: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_transactions(code: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn withdraw(code: Value, amount: Value, address: Value, tag: Value, params: Value) -> Value;
+        fn transfer(code: Value, amount: Value, from_account: Value, to_account: Value, params: Value) -> Value;
+        fn fetch_ledger(code: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn fetch_trading_fee(symbol: Value, params: Value) -> Value;
+        fn fetch_trading_fees(params: Value) -> Value;
+        fn fetch_positions(symbols: Value, params: Value) -> Value;
+        fn fetch_funding_rate(symbol: Value, params: Value) -> Value;
+        fn fetch_funding_rates(symbols: Value, params: Value) -> Value;
+        fn fetch_funding_history(symbol: Value, since: Value, limit: Value, params: Value) -> Value;
+        fn set_leverage(leverage: Value, symbol: Value, params: Value) -> Value;
+        fn fetch_leverage_tiers(symbols: Value, params: Value) -> Value;
+        fn load_markets_typed(reload: Value, params: Value) -> Result<BTreeMap<String, Market>>;
+        fn fetch_markets_typed(params: Value) -> Result<Vec<Market>>;
+        fn fetch_currencies_typed(params: Value) -> Result<BTreeMap<String, Currency>>;
+        fn fetch_ticker_typed(symbol: Value, params: Value) -> Result<Ticker>;
+        fn fetch_tickers_typed(symbols: Value, params: Value) -> Result<BTreeMap<String, Ticker>>;
+        fn fetch_order_book_typed(symbol: Value, limit: Value, params: Value) -> Result<OrderBook>;
+        fn fetch_ohlcv_typed(symbol: Value, timeframe: Value, since: Value, limit: Value, params: Value) -> Result<Vec<OHLCV>>;
+        fn fetch_trades_typed(symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Trade>>;
+        fn fetch_balance_typed(params: Value) -> Result<Balances>;
+        fn create_order_typed(symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Result<Order>;
+        #[allow(clippy::too_many_arguments)]
+        fn edit_order_typed(id: Value, symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Result<Order>;
+        fn cancel_order_typed(id: Value, symbol: Value, params: Value) -> Result<Order>;
+        fn fetch_order_typed(id: Value, symbol: Value, params: Value) -> Result<Order>;
+        fn fetch_orders_typed(symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Order>>;
+        fn fetch_open_orders_typed(symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Order>>;
+        fn fetch_closed_orders_typed(symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Order>>;
+        fn fetch_my_trades_typed(symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Trade>>;
+        fn fetch_deposits_typed(code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Transaction>>;
+        fn fetch_withdrawals_typed(code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Transaction>>;
+        fn fetch_transactions_typed(code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Transaction>>;
+        fn withdraw_typed(code: Value, amount: Value, address: Value, tag: Value, params: Value) -> Result<Transaction>;
+        fn fetch_ledger_typed(code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<LedgerEntry>>;
+        fn fetch_trading_fee_typed(symbol: Value, params: Value) -> Result<TradingFee>;
+        fn fetch_trading_fees_typed(params: Value) -> Result<BTreeMap<String, TradingFee>>;
+        fn fetch_positions_typed(symbols: Value, params: Value) -> Result<Vec<Position>>;
+    }
+}
+
+impl From<Box<dyn UnifiedExchange>> for SharedExchange {
+    fn from(exchange: Box<dyn UnifiedExchange>) -> Self {
+        SharedExchange::new(exchange)
+    }
+}
+
+// the snapshot itself when no call holds on to it, a fresh copy otherwise
+fn writable(exchange: &mut Arc<dyn UnifiedExchange>) -> &mut dyn UnifiedExchange {
+    if Arc::get_mut(exchange).is_none() {
+        *exchange = Arc::from(exchange.clone_box());
+    }
+    Arc::get_mut(exchange).unwrap()
+}
+
+// whether calls may change a key of the exchange state, the markets are left out as the loader shares them
+fn changeable(key: &str) -> bool {
+    key != "marketsLoadedAt" && !MARKETS_KEYS.contains(&key) && !READ_ONLY_KEYS.contains(&key)
+}
+
+// brings the keys calls change of a copy to `state`, copying only the values that differ
+fn update(exchange: &mut dyn UnifiedExchange, state: &JsonMap) {
+    if let Some(current) = exchange.state_mut().as_object_mut() {
+        current.retain(|key, _| !changeable(key) || state.contains_key(key));
+        for (key, value) in state {
+            if current.get(key) != Some(value) {
+                current.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+// collects the keys that changed between `before` and `after`, options key by key so that concurrent calls changing
+// different options do not overwrite each other
+fn changes(before: &JsonMap, after: &JsonMap, path: &[String], changed: &mut Vec<(Vec<String>, Option<serde_json::Value>)>) {
+    for key in before.keys().chain(after.keys().filter(|key| !before.contains_key(*key))) {
+        if path.is_empty() && !changeable(key) {
+            continue;
+        }
+        match (before.get(key), after.get(key)) {
+            (old, new) if old == new => {}
+            (Some(serde_json::Value::Object(old)), Some(serde_json::Value::Object(new))) if path.is_empty() && key == "options" => {
+                changes(old, new, std::slice::from_ref(key), changed);
+            }
+            (_, new) => changed.push(([path, std::slice::from_ref(key)].concat(), new.cloned())),
+        }
+    }
+}
+
+fn apply(state: &mut JsonMap, path: &[String], value: Option<serde_json::Value>) {
+    let (key, parents) = path.split_last().unwrap();
+    let mut node = state;
+    for parent in parents {
+        node = match node.entry(parent.clone()).or_insert_with(|| serde_json::json!({})) {
+            serde_json::Value::Object(o) => o,
+            _ => return,
+        };
+    }
+    match value {
+        Some(value) => node.insert(key.clone(), value),
+        None => node.remove(key),
+    };
+}
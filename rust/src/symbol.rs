@@ -0,0 +1,189 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::errors::{Error, Result};
+use crate::types::Decimal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+/// A unified symbol, `BTC/USDT` for spot, `BTC/USDT:USDT` for a swap settled in USDT, `BTC/USD:BTC-231229` for a
+/// future expiring on 2023-12-29 and `BTC/USD:BTC-231229-30000-C` for a call option with a strike of 30000
+///
+/// ```
+/// use ccxt::Symbol;
+///
+/// let symbol: Symbol = "BTC/USD:BTC-231229-30000-C".parse().unwrap();
+/// assert!(symbol.is_option());
+/// assert_eq!(symbol.settle.as_deref(), Some("BTC"));
+/// assert_eq!(symbol.to_string(), "BTC/USD:BTC-231229-30000-C");
+/// assert!("BTC-USDT".parse::<Symbol>().unwrap_err().is("BadSymbol"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    pub base: String,
+    pub quote: String,
+    pub settle: Option<String>,
+    pub expiry: Option<NaiveDate>,
+    pub strike: Option<Decimal>,
+    pub option_type: Option<OptionType>,
+}
+
+impl Symbol {
+    pub fn spot(base: impl Into<String>, quote: impl Into<String>) -> Self {
+        Symbol { base: base.into(), quote: quote.into(), settle: None, expiry: None, strike: None, option_type: None }
+    }
+
+    pub fn swap(base: impl Into<String>, quote: impl Into<String>, settle: impl Into<String>) -> Self {
+        Symbol { settle: Some(settle.into()), ..Symbol::spot(base, quote) }
+    }
+
+    pub fn future(base: impl Into<String>, quote: impl Into<String>, settle: impl Into<String>, expiry: NaiveDate) -> Self {
+        Symbol { expiry: Some(expiry), ..Symbol::swap(base, quote, settle) }
+    }
+
+    pub fn option(
+        base: impl Into<String>,
+        quote: impl Into<String>,
+        settle: impl Into<String>,
+        expiry: NaiveDate,
+        strike: Decimal,
+        option_type: OptionType,
+    ) -> Self {
+        Symbol { strike: Some(strike), option_type: Some(option_type), ..Symbol::future(base, quote, settle, expiry) }
+    }
+
+    pub fn is_spot(&self) -> bool {
+        self.settle.is_none()
+    }
+
+    pub fn is_swap(&self) -> bool {
+        self.settle.is_some() && self.expiry.is_none()
+    }
+
+    pub fn is_future(&self) -> bool {
+        self.expiry.is_some() && self.option_type.is_none()
+    }
+
+    pub fn is_option(&self) -> bool {
+        self.option_type.is_some()
+    }
+
+    /// The unified market type, `spot`, `swap`, `future` or `option`
+    pub fn market_type(&self) -> &'static str {
+        if self.is_option() {
+            "option"
+        } else if self.is_future() {
+            "future"
+        } else if self.is_swap() {
+            "swap"
+        } else {
+            "spot"
+        }
+    }
+
+    /// Rejects the symbols that `parse` would not read back, e.g. an empty base or a strike without an option type
+    pub fn validate(&self) -> Result<()> {
+        self.check().map_err(|reason| bad_symbol(self, reason))
+    }
+
+    fn check(&self) -> std::result::Result<(), String> {
+        for (name, code) in [("base", Some(&self.base)), ("quote", Some(&self.quote)), ("settle", self.settle.as_ref())] {
+            if let Some(code) = code {
+                if code.is_empty() || code.contains(|c: char| c.is_whitespace() || c == '/' || c == ':') {
+                    return Err(format!("invalid {} currency {:?}", name, code));
+                }
+            }
+        }
+        if matches!(&self.settle, Some(settle) if settle.contains('-')) {
+            return Err("the settle currency cannot contain a dash".to_owned());
+        }
+        if self.expiry.is_some() && self.settle.is_none() {
+            return Err("an expiry requires a settle currency".to_owned());
+        }
+        if self.strike.is_some() != self.option_type.is_some() || (self.option_type.is_some() && self.expiry.is_none()) {
+            return Err("an option requires an expiry, a strike and an option type".to_owned());
+        }
+        if matches!(self.strike, Some(strike) if strike <= Decimal::ZERO) {
+            return Err("the strike must be positive".to_owned());
+        }
+        Ok(())
+    }
+}
+
+fn bad_symbol(symbol: impl fmt::Display, reason: impl fmt::Display) -> Error {
+    Error::new("BadSymbol", format!("invalid symbol {}, {}", symbol, reason))
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)?;
+        if let Some(settle) = &self.settle {
+            write!(f, ":{}", settle)?;
+        }
+        if let Some(expiry) = &self.expiry {
+            write!(f, "-{}", expiry.format("%y%m%d"))?;
+        }
+        if let Some(strike) = &self.strike {
+            write!(f, "-{}", strike.normalize())?;
+        }
+        match self.option_type {
+            Some(OptionType::Call) => write!(f, "-C"),
+            Some(OptionType::Put) => write!(f, "-P"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (pair, contract) = match s.split_once(':') {
+            Some((pair, contract)) => (pair, Some(contract)),
+            None => (s, None),
+        };
+        let (base, quote) = pair.split_once('/').ok_or_else(|| bad_symbol(s, "expected BASE/QUOTE"))?;
+        let mut symbol = Symbol::spot(base, quote);
+        if let Some(contract) = contract {
+            let mut parts = contract.split('-');
+            symbol.settle = parts.next().map(str::to_owned);
+            if let Some(expiry) = parts.next() {
+                if expiry.len() != 6 {
+                    return Err(bad_symbol(s, format!("expected the expiry as YYMMDD, got {:?}", expiry)));
+                }
+                symbol.expiry = Some(NaiveDate::parse_from_str(expiry, "%y%m%d")
+                    .map_err(|_| bad_symbol(s, format!("expected the expiry as YYMMDD, got {:?}", expiry)))?);
+            }
+            if let Some(strike) = parts.next() {
+                symbol.strike = Some(Decimal::from_str(strike).map_err(|_| bad_symbol(s, format!("invalid strike {:?}", strike)))?);
+                symbol.option_type = match parts.next() {
+                    Some("C") => Some(OptionType::Call),
+                    Some("P") => Some(OptionType::Put),
+                    other => return Err(bad_symbol(s, format!("expected the option type C or P, got {:?}", other.unwrap_or("")))),
+                };
+            }
+            if parts.next().is_some() {
+                return Err(bad_symbol(s, "unexpected trailing parts"));
+            }
+        }
+        symbol.check().map_err(|reason| bad_symbol(s, reason))?;
+        Ok(symbol)
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
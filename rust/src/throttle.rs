@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// The leaky bucket of js/base/functions/throttle.js, a request of cost `c` waits until `c * rateLimit`
+/// milliseconds have passed since the previous one, the bucket is shared by every copy of an exchange
+#[derive(Debug)]
+pub struct Throttler {
+    capacity: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+    // tokens refilled per millisecond
+    refill_rate: f64,
+}
+
+fn refill_rate(rate_limit: f64) -> f64 {
+    if rate_limit > 0.0 { 1.0 / rate_limit } else { f64::INFINITY }
+}
+
+impl Throttler {
+    pub fn new(rate_limit: f64) -> Self {
+        Throttler {
+            capacity: 1.0,
+            bucket: Mutex::new(Bucket { tokens: 0.0, updated_at: Instant::now(), refill_rate: refill_rate(rate_limit) }),
+        }
+    }
+
+    /// Changes the milliseconds per request, the requests already waiting pick up the new rate
+    pub fn set_rate_limit(&self, rate_limit: f64) {
+        self.bucket.lock().unwrap().refill_rate = refill_rate(rate_limit);
+    }
+
+    pub async fn throttle(&self, cost: f64) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.updated_at).as_secs_f64() * 1000.0;
+                bucket.tokens = (bucket.tokens + elapsed * bucket.refill_rate).min(self.capacity);
+                bucket.updated_at = now;
+                if bucket.tokens >= 0.0 {
+                    bucket.tokens -= cost;
+                    return;
+                }
+                -bucket.tokens / bucket.refill_rate
+            };
+            tokio::time::sleep(Duration::from_secs_f64(wait / 1000.0)).await;
+        }
+    }
+}
+
+/// The throttler of `key` running at `rate_limit` milliseconds per request, a copy that changed its `rateLimit`
+/// changes the rate of the bucket it shares
+pub fn throttler(key: &str, rate_limit: f64) -> Arc<Throttler> {
+    static THROTTLERS: OnceLock<Mutex<HashMap<String, Arc<Throttler>>>> = OnceLock::new();
+    let throttler = THROTTLERS.get_or_init(Default::default).lock().unwrap()
+        .entry(key.to_owned())
+        .or_insert_with(|| Arc::new(Throttler::new(rate_limit)))
+        .clone();
+    throttler.set_rate_limit(rate_limit);
+    throttler
+}
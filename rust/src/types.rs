@@ -0,0 +1,565 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::de::{self, DeserializeOwned, SeqAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::errors::{Error, Result};
+use crate::exchange::{normalize, Value};
+
+pub use rust_decimal::Decimal;
+
+/// Decodes a unified structure returned by the untyped methods, numbers kept as strings by `Precise` are parsed losslessly
+pub fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T> {
+    let json = normalize(value).unwrap_or(serde_json::Value::Null);
+    serde_json::from_value(json).map_err(|e| Error::new("BadResponse", e.to_string()))
+}
+
+// exchanges send ids as strings or numbers
+fn id<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<String, D::Error> {
+    Ok(optional_id(deserializer)?.unwrap_or_default())
+}
+
+fn optional_id<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<String>, D::Error> {
+    Ok(match Option::<serde_json::Value>::deserialize(deserializer)? {
+        Some(serde_json::Value::String(s)) => Some(s),
+        Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+// the generated code leaves lists it has nothing for as null
+fn nullable_vec<'de, D: Deserializer<'de>, T: Deserialize<'de>>(deserializer: D) -> std::result::Result<Vec<T>, D::Error> {
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MinMax {
+    pub min: Option<Decimal>,
+    pub max: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketPrecision {
+    pub amount: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub base: Option<Decimal>,
+    pub quote: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketLimits {
+    pub amount: MinMax,
+    pub price: MinMax,
+    pub cost: MinMax,
+    pub leverage: MinMax,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Market {
+    #[serde(deserialize_with = "id")]
+    pub id: String,
+    pub symbol: String,
+    pub base: String,
+    pub quote: String,
+    pub settle: Option<String>,
+    #[serde(deserialize_with = "optional_id")]
+    pub base_id: Option<String>,
+    #[serde(deserialize_with = "optional_id")]
+    pub quote_id: Option<String>,
+    #[serde(deserialize_with = "optional_id")]
+    pub settle_id: Option<String>,
+    pub r#type: Option<String>,
+    pub spot: bool,
+    pub margin: Option<bool>,
+    pub swap: bool,
+    pub future: bool,
+    pub option: bool,
+    pub contract: bool,
+    pub active: Option<bool>,
+    pub linear: Option<bool>,
+    pub inverse: Option<bool>,
+    pub taker: Option<Decimal>,
+    pub maker: Option<Decimal>,
+    pub contract_size: Option<Decimal>,
+    pub expiry: Option<i64>,
+    pub expiry_datetime: Option<String>,
+    pub strike: Option<Decimal>,
+    pub option_type: Option<String>,
+    pub precision: MarketPrecision,
+    pub limits: MarketLimits,
+    pub info: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrencyLimits {
+    pub amount: MinMax,
+    pub withdraw: MinMax,
+    pub deposit: MinMax,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrencyNetwork {
+    #[serde(deserialize_with = "optional_id")]
+    pub id: Option<String>,
+    pub network: Option<String>,
+    pub name: Option<String>,
+    pub active: Option<bool>,
+    pub deposit: Option<bool>,
+    pub withdraw: Option<bool>,
+    pub fee: Option<Decimal>,
+    pub precision: Option<Decimal>,
+    pub limits: CurrencyLimits,
+    pub info: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Currency {
+    #[serde(deserialize_with = "id")]
+    pub id: String,
+    pub code: String,
+    pub name: Option<String>,
+    pub active: Option<bool>,
+    pub deposit: Option<bool>,
+    pub withdraw: Option<bool>,
+    pub fee: Option<Decimal>,
+    pub precision: Option<Decimal>,
+    pub limits: CurrencyLimits,
+    pub networks: BTreeMap<String, CurrencyNetwork>,
+    pub info: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Ticker {
+    pub symbol: String,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub high: Option<Decimal>,
+    pub low: Option<Decimal>,
+    pub bid: Option<Decimal>,
+    pub bid_volume: Option<Decimal>,
+    pub ask: Option<Decimal>,
+    pub ask_volume: Option<Decimal>,
+    pub vwap: Option<Decimal>,
+    pub open: Option<Decimal>,
+    pub close: Option<Decimal>,
+    pub last: Option<Decimal>,
+    pub previous_close: Option<Decimal>,
+    pub change: Option<Decimal>,
+    pub percentage: Option<Decimal>,
+    pub average: Option<Decimal>,
+    pub base_volume: Option<Decimal>,
+    pub quote_volume: Option<Decimal>,
+    pub info: serde_json::Value,
+}
+
+/// A `[price, amount]` entry of an order book side, extra fields such as order counts are ignored
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PriceLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl Serialize for PriceLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        (self.price, self.amount).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PriceLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct LevelVisitor;
+
+        impl<'de> Visitor<'de> for LevelVisitor {
+            type Value = PriceLevel;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a [price, amount] array")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<PriceLevel, A::Error> {
+                let price = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let amount = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+                Ok(PriceLevel { price, amount })
+            }
+        }
+
+        deserializer.deserialize_seq(LevelVisitor)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrderBook {
+    pub symbol: Option<String>,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub nonce: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Fee {
+    pub currency: Option<String>,
+    pub cost: Option<Decimal>,
+    pub rate: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Trade {
+    #[serde(deserialize_with = "optional_id")]
+    pub id: Option<String>,
+    #[serde(deserialize_with = "optional_id")]
+    pub order: Option<String>,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub symbol: Option<String>,
+    pub r#type: Option<String>,
+    pub side: Option<String>,
+    pub taker_or_maker: Option<String>,
+    pub price: Option<Decimal>,
+    pub amount: Option<Decimal>,
+    pub cost: Option<Decimal>,
+    pub fee: Option<Fee>,
+    #[serde(deserialize_with = "nullable_vec")]
+    pub fees: Vec<Fee>,
+    pub info: serde_json::Value,
+}
+
+/// A `[timestamp, open, high, low, close, volume]` candle
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OHLCV {
+    pub timestamp: i64,
+    pub open: Option<Decimal>,
+    pub high: Option<Decimal>,
+    pub low: Option<Decimal>,
+    pub close: Option<Decimal>,
+    pub volume: Option<Decimal>,
+}
+
+impl Serialize for OHLCV {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        (self.timestamp, self.open, self.high, self.low, self.close, self.volume).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OHLCV {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct CandleVisitor;
+
+        impl<'de> Visitor<'de> for CandleVisitor {
+            type Value = OHLCV;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a [timestamp, open, high, low, close, volume] array")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<OHLCV, A::Error> {
+                let timestamp = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let candle = OHLCV {
+                    timestamp,
+                    open: seq.next_element()?.flatten(),
+                    high: seq.next_element()?.flatten(),
+                    low: seq.next_element()?.flatten(),
+                    close: seq.next_element()?.flatten(),
+                    volume: seq.next_element()?.flatten(),
+                };
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+                Ok(candle)
+            }
+        }
+
+        deserializer.deserialize_seq(CandleVisitor)
+    }
+}
+
+// the unified string values of an enum, matched case-insensitively, anything else is kept in `Other`
+macro_rules! unified_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(s) => s.as_str(),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                $(if s.eq_ignore_ascii_case($value) {
+                    return Ok($name::$variant);
+                })*
+                Ok($name::Other(s.to_owned()))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                Ok(s.parse().unwrap())
+            }
+        }
+    };
+}
+
+unified_enum!(OrderStatus {
+    Open => "open",
+    Closed => "closed",
+    Canceled => "canceled",
+    Expired => "expired",
+    Rejected => "rejected",
+});
+
+unified_enum!(OrderSide {
+    Buy => "buy",
+    Sell => "sell",
+});
+
+unified_enum!(OrderType {
+    Market => "market",
+    Limit => "limit",
+});
+
+unified_enum!(TimeInForce {
+    GoodTillCancel => "GTC",
+    ImmediateOrCancel => "IOC",
+    FillOrKill => "FOK",
+    PostOnly => "PO",
+});
+
+unified_enum!(PositionSide {
+    Long => "long",
+    Short => "short",
+});
+
+unified_enum!(MarginMode {
+    Cross => "cross",
+    Isolated => "isolated",
+});
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Order {
+    #[serde(deserialize_with = "optional_id")]
+    pub id: Option<String>,
+    #[serde(deserialize_with = "optional_id")]
+    pub client_order_id: Option<String>,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub last_trade_timestamp: Option<i64>,
+    pub symbol: Option<String>,
+    pub r#type: Option<OrderType>,
+    pub time_in_force: Option<TimeInForce>,
+    pub post_only: Option<bool>,
+    pub reduce_only: Option<bool>,
+    pub side: Option<OrderSide>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub trigger_price: Option<Decimal>,
+    pub amount: Option<Decimal>,
+    pub cost: Option<Decimal>,
+    pub average: Option<Decimal>,
+    pub filled: Option<Decimal>,
+    pub remaining: Option<Decimal>,
+    pub status: Option<OrderStatus>,
+    pub fee: Option<Fee>,
+    #[serde(deserialize_with = "nullable_vec")]
+    pub fees: Vec<Fee>,
+    #[serde(deserialize_with = "nullable_vec")]
+    pub trades: Vec<Trade>,
+    pub info: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Balance {
+    pub free: Option<Decimal>,
+    pub used: Option<Decimal>,
+    pub total: Option<Decimal>,
+}
+
+/// The balances of an account by currency code, the `free`, `used` and `total` lookups of the untyped
+/// structure are folded into `currencies`, they are serialized back next to them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Balances {
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub currencies: BTreeMap<String, Balance>,
+    pub info: serde_json::Value,
+}
+
+impl Balances {
+    pub fn get(&self, code: &str) -> Option<&Balance> {
+        self.currencies.get(code)
+    }
+}
+
+impl Serialize for Balances {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("info", &self.info)?;
+        map.serialize_entry("timestamp", &self.timestamp)?;
+        map.serialize_entry("datetime", &self.datetime)?;
+        for (code, balance) in &self.currencies {
+            map.serialize_entry(code, balance)?;
+        }
+        let lookup = |field: fn(&Balance) -> Option<Decimal>| {
+            self.currencies.iter().map(|(code, balance)| (code, field(balance))).collect::<BTreeMap<_, _>>()
+        };
+        map.serialize_entry("free", &lookup(|b| b.free))?;
+        map.serialize_entry("used", &lookup(|b| b.used))?;
+        map.serialize_entry("total", &lookup(|b| b.total))?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Balances {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let mut balances = Balances::default();
+        for (key, value) in serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)? {
+            match key.as_str() {
+                "info" => balances.info = value,
+                "timestamp" => balances.timestamp = serde_json::from_value(value).map_err(de::Error::custom)?,
+                "datetime" => balances.datetime = serde_json::from_value(value).map_err(de::Error::custom)?,
+                "free" | "used" | "total" | "debt" => {}
+                _ if value.is_object() => {
+                    balances.currencies.insert(key, serde_json::from_value(value).map_err(de::Error::custom)?);
+                }
+                _ => {}
+            }
+        }
+        Ok(balances)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Position {
+    #[serde(deserialize_with = "optional_id")]
+    pub id: Option<String>,
+    pub symbol: Option<String>,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub side: Option<PositionSide>,
+    pub margin_mode: Option<MarginMode>,
+    pub hedged: Option<bool>,
+    pub contracts: Option<Decimal>,
+    pub contract_size: Option<Decimal>,
+    pub entry_price: Option<Decimal>,
+    pub mark_price: Option<Decimal>,
+    pub notional: Option<Decimal>,
+    pub leverage: Option<Decimal>,
+    pub collateral: Option<Decimal>,
+    pub initial_margin: Option<Decimal>,
+    pub initial_margin_percentage: Option<Decimal>,
+    pub maintenance_margin: Option<Decimal>,
+    pub maintenance_margin_percentage: Option<Decimal>,
+    pub unrealized_pnl: Option<Decimal>,
+    pub liquidation_price: Option<Decimal>,
+    pub margin_ratio: Option<Decimal>,
+    pub percentage: Option<Decimal>,
+    pub info: serde_json::Value,
+}
+
+/// A deposit or a withdrawal, `type` tells which
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Transaction {
+    #[serde(deserialize_with = "optional_id")]
+    pub id: Option<String>,
+    pub txid: Option<String>,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub r#type: Option<String>,
+    pub currency: Option<String>,
+    pub network: Option<String>,
+    pub amount: Option<Decimal>,
+    pub address: Option<String>,
+    pub address_from: Option<String>,
+    pub address_to: Option<String>,
+    pub tag: Option<String>,
+    pub tag_from: Option<String>,
+    pub tag_to: Option<String>,
+    pub status: Option<String>,
+    pub updated: Option<i64>,
+    pub comment: Option<String>,
+    pub fee: Option<Fee>,
+    pub info: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LedgerEntry {
+    #[serde(deserialize_with = "optional_id")]
+    pub id: Option<String>,
+    pub timestamp: Option<i64>,
+    pub datetime: Option<String>,
+    pub direction: Option<String>,
+    pub account: Option<String>,
+    #[serde(deserialize_with = "optional_id")]
+    pub reference_id: Option<String>,
+    pub reference_account: Option<String>,
+    pub r#type: Option<String>,
+    pub currency: Option<String>,
+    pub amount: Option<Decimal>,
+    pub before: Option<Decimal>,
+    pub after: Option<Decimal>,
+    pub status: Option<String>,
+    pub fee: Option<Fee>,
+    pub info: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TradingFee {
+    pub symbol: Option<String>,
+    pub maker: Option<Decimal>,
+    pub taker: Option<Decimal>,
+    pub percentage: Option<bool>,
+    pub tier_based: Option<bool>,
+    pub info: serde_json::Value,
+}
+
+macro_rules! try_from_value {
+    ($($name:ty),*) => {
+        $(impl TryFrom<Value> for $name {
+            type Error = Error;
+
+            fn try_from(value: Value) -> Result<Self> {
+                from_value(&value)
+            }
+        })*
+    };
+}
+
+try_from_value!(Market, Currency, Ticker, OrderBook, Trade, OHLCV, Order, Balances, Position, Transaction, LedgerEntry, TradingFee);
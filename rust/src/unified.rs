@@ -0,0 +1,660 @@
+
+#[doc(hidden)]
+pub use async_trait::async_trait;
+use std::future::Future;
+use std::collections::BTreeMap;
+use serde::de::DeserializeOwned;
+use crate::capabilities::Capabilities;
+use crate::credentials::Credentials;
+use crate::errors::{catch, Error, Result};
+use crate::order_request::OrderRequest;
+use crate::types::{
+    from_value, Balances, Currency, LedgerEntry, Market, Order, OrderBook, OrderSide, OrderType, Position, Ticker, Trade, TradingFee,
+    Transaction, OHLCV,
+};
+use crate::exchange::{Value, ValueTrait};
+use tracing::instrument::{Instrument, Instrumented};
+
+#[doc(hidden)]
+pub fn not_supported(id: Value, method: &str) -> Value {
+    panic!("NotSupported::new({} {}() is not supported yet)", id.unwrap_str(), method)
+}
+
+/// The unified API of an exchange as an object-safe trait, so that venues can be chosen at runtime by id,
+/// methods an exchange does not implement or does not declare in `has` raise `NotSupported` without sending anything
+#[async_trait]
+pub trait UnifiedExchange: Send + Sync {
+    fn id(&self) -> Value;
+    fn name(&self) -> Value;
+    fn has(&self) -> Value;
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::from_has(&self.has())
+    }
+
+    /// The exchange state, config, options, markets and so on, as JSON
+    fn state(&self) -> &serde_json::Value;
+
+    fn state_mut(&mut self) -> &mut serde_json::Value;
+
+    /// An independent copy of the exchange, `SharedExchange` runs each call on one
+    fn clone_box(&self) -> Box<dyn UnifiedExchange>;
+
+    /// The credentials the exchange signs its requests with, they are not part of `state`
+    fn credentials_mut(&mut self) -> &mut Credentials;
+
+    /// Adds `credentials` to the exchange, overriding the fields it already has
+    fn set_credentials(&mut self, credentials: Credentials) {
+        self.credentials_mut().extend(credentials);
+    }
+
+    async fn load_markets(&mut self, _reload: Value, _params: Value) -> Value {
+        not_supported(self.id(), "loadMarkets")
+    }
+
+    /// Fetches the markets again and swaps `markets`, `markets_by_id` and `currencies` once they arrive, so that new
+    /// listings show up, setting `marketsReloadInterval` in milliseconds makes `load_markets` do it on its own
+    async fn reload_markets(&mut self, params: Value) -> Value {
+        self.load_markets(true.into(), params).await
+    }
+
+    async fn fetch_markets(&mut self, _params: Value) -> Value {
+        not_supported(self.id(), "fetchMarkets")
+    }
+
+    async fn fetch_currencies(&mut self, _params: Value) -> Value {
+        not_supported(self.id(), "fetchCurrencies")
+    }
+
+    async fn fetch_ticker(&mut self, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTicker")
+    }
+
+    async fn fetch_tickers(&mut self, _symbols: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTickers")
+    }
+
+    async fn fetch_order_book(&mut self, _symbol: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchOrderBook")
+    }
+
+    async fn fetch_ohlcv(&mut self, _symbol: Value, _timeframe: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchOHLCV")
+    }
+
+    async fn fetch_trades(&mut self, _symbol: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTrades")
+    }
+
+    async fn fetch_status(&mut self, _params: Value) -> Value {
+        not_supported(self.id(), "fetchStatus")
+    }
+
+    async fn fetch_time(&mut self, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTime")
+    }
+
+    async fn fetch_balance(&mut self, _params: Value) -> Value {
+        not_supported(self.id(), "fetchBalance")
+    }
+
+    async fn create_order(&mut self, _symbol: Value, _type: Value, _side: Value, _amount: Value, _price: Value, _params: Value) -> Value {
+        not_supported(self.id(), "createOrder")
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn edit_order(&mut self, _id: Value, _symbol: Value, _type: Value, _side: Value, _amount: Value, _price: Value, _params: Value) -> Value {
+        not_supported(self.id(), "editOrder")
+    }
+
+    async fn cancel_order(&mut self, _id: Value, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "cancelOrder")
+    }
+
+    async fn cancel_all_orders(&mut self, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "cancelAllOrders")
+    }
+
+    async fn fetch_order(&mut self, _id: Value, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchOrder")
+    }
+
+    async fn fetch_orders(&mut self, _symbol: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchOrders")
+    }
+
+    async fn fetch_open_orders(&mut self, _symbol: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchOpenOrders")
+    }
+
+    async fn fetch_closed_orders(&mut self, _symbol: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchClosedOrders")
+    }
+
+    async fn fetch_my_trades(&mut self, _symbol: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchMyTrades")
+    }
+
+    async fn fetch_deposit_address(&mut self, _code: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchDepositAddress")
+    }
+
+    async fn fetch_deposits(&mut self, _code: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchDeposits")
+    }
+
+    async fn fetch_withdrawals(&mut self, _code: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchWithdrawals")
+    }
+
+    async fn fetch_transactions(&mut self, _code: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTransactions")
+    }
+
+    async fn withdraw(&mut self, _code: Value, _amount: Value, _address: Value, _tag: Value, _params: Value) -> Value {
+        not_supported(self.id(), "withdraw")
+    }
+
+    async fn transfer(&mut self, _code: Value, _amount: Value, _from_account: Value, _to_account: Value, _params: Value) -> Value {
+        not_supported(self.id(), "transfer")
+    }
+
+    async fn fetch_ledger(&mut self, _code: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchLedger")
+    }
+
+    async fn fetch_trading_fee(&mut self, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTradingFee")
+    }
+
+    async fn fetch_trading_fees(&mut self, _params: Value) -> Value {
+        not_supported(self.id(), "fetchTradingFees")
+    }
+
+    async fn fetch_positions(&mut self, _symbols: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchPositions")
+    }
+
+    async fn fetch_funding_rate(&mut self, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchFundingRate")
+    }
+
+    async fn fetch_funding_rates(&mut self, _symbols: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchFundingRates")
+    }
+
+    async fn fetch_funding_history(&mut self, _symbol: Value, _since: Value, _limit: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchFundingHistory")
+    }
+
+    async fn set_leverage(&mut self, _leverage: Value, _symbol: Value, _params: Value) -> Value {
+        not_supported(self.id(), "setLeverage")
+    }
+
+    async fn fetch_leverage_tiers(&mut self, _symbols: Value, _params: Value) -> Value {
+        not_supported(self.id(), "fetchLeverageTiers")
+    }
+
+    // typed variants of the market data methods, errors raised by the exchange are returned as `Err`
+
+    async fn load_markets_typed(&mut self, reload: Value, params: Value) -> Result<BTreeMap<String, Market>> {
+        typed(self.load_markets(reload, params)).await
+    }
+
+    async fn fetch_markets_typed(&mut self, params: Value) -> Result<Vec<Market>> {
+        typed(self.fetch_markets(params)).await
+    }
+
+    async fn fetch_currencies_typed(&mut self, params: Value) -> Result<BTreeMap<String, Currency>> {
+        typed(self.fetch_currencies(params)).await
+    }
+
+    async fn fetch_ticker_typed(&mut self, symbol: Value, params: Value) -> Result<Ticker> {
+        typed(self.fetch_ticker(symbol, params)).await
+    }
+
+    async fn fetch_tickers_typed(&mut self, symbols: Value, params: Value) -> Result<BTreeMap<String, Ticker>> {
+        typed(self.fetch_tickers(symbols, params)).await
+    }
+
+    async fn fetch_order_book_typed(&mut self, symbol: Value, limit: Value, params: Value) -> Result<OrderBook> {
+        typed(self.fetch_order_book(symbol, limit, params)).await
+    }
+
+    async fn fetch_ohlcv_typed(&mut self, symbol: Value, timeframe: Value, since: Value, limit: Value, params: Value) -> Result<Vec<OHLCV>> {
+        typed(self.fetch_ohlcv(symbol, timeframe, since, limit, params)).await
+    }
+
+    async fn fetch_trades_typed(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Trade>> {
+        typed(self.fetch_trades(symbol, since, limit, params)).await
+    }
+
+    // typed variants of the trading and account methods
+
+    /// Places a typed order, invalid requests are rejected before anything is sent
+    async fn place_order(&mut self, request: &OrderRequest) -> Result<Order> {
+        let mut args = request.to_unified()?;
+        if request.r#type == OrderType::Market && !market_order_takes_price(&self.state()["options"], &request.side) {
+            args.price = Value::Undefined;
+        }
+        self.create_order_typed(args.symbol, args.r#type, args.side, args.amount, args.price, args.params).await
+    }
+
+    async fn fetch_balance_typed(&mut self, params: Value) -> Result<Balances> {
+        typed(self.fetch_balance(params)).await
+    }
+
+    async fn create_order_typed(&mut self, symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Result<Order> {
+        typed(self.create_order(symbol, r#type, side, amount, price, params)).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn edit_order_typed(&mut self, id: Value, symbol: Value, r#type: Value, side: Value, amount: Value, price: Value, params: Value) -> Result<Order> {
+        typed(self.edit_order(id, symbol, r#type, side, amount, price, params)).await
+    }
+
+    async fn cancel_order_typed(&mut self, id: Value, symbol: Value, params: Value) -> Result<Order> {
+        typed(self.cancel_order(id, symbol, params)).await
+    }
+
+    async fn fetch_order_typed(&mut self, id: Value, symbol: Value, params: Value) -> Result<Order> {
+        typed(self.fetch_order(id, symbol, params)).await
+    }
+
+    async fn fetch_orders_typed(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Order>> {
+        typed(self.fetch_orders(symbol, since, limit, params)).await
+    }
+
+    async fn fetch_open_orders_typed(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Order>> {
+        typed(self.fetch_open_orders(symbol, since, limit, params)).await
+    }
+
+    async fn fetch_closed_orders_typed(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Order>> {
+        typed(self.fetch_closed_orders(symbol, since, limit, params)).await
+    }
+
+    async fn fetch_my_trades_typed(&mut self, symbol: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Trade>> {
+        typed(self.fetch_my_trades(symbol, since, limit, params)).await
+    }
+
+    async fn fetch_deposits_typed(&mut self, code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Transaction>> {
+        typed(self.fetch_deposits(code, since, limit, params)).await
+    }
+
+    async fn fetch_withdrawals_typed(&mut self, code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Transaction>> {
+        typed(self.fetch_withdrawals(code, since, limit, params)).await
+    }
+
+    async fn fetch_transactions_typed(&mut self, code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<Transaction>> {
+        typed(self.fetch_transactions(code, since, limit, params)).await
+    }
+
+    async fn withdraw_typed(&mut self, code: Value, amount: Value, address: Value, tag: Value, params: Value) -> Result<Transaction> {
+        typed(self.withdraw(code, amount, address, tag, params)).await
+    }
+
+    async fn fetch_ledger_typed(&mut self, code: Value, since: Value, limit: Value, params: Value) -> Result<Vec<LedgerEntry>> {
+        typed(self.fetch_ledger(code, since, limit, params)).await
+    }
+
+    async fn fetch_trading_fee_typed(&mut self, symbol: Value, params: Value) -> Result<TradingFee> {
+        typed(self.fetch_trading_fee(symbol, params)).await
+    }
+
+    async fn fetch_trading_fees_typed(&mut self, params: Value) -> Result<BTreeMap<String, TradingFee>> {
+        typed(self.fetch_trading_fees(params)).await
+    }
+
+    async fn fetch_positions_typed(&mut self, symbols: Value, params: Value) -> Result<Vec<Position>> {
+        typed(self.fetch_positions(symbols, params)).await
+    }
+}
+
+// the span of a unified call, the requests it sends are logged under it
+#[doc(hidden)]
+pub fn call_span<F: Future>(exchange: Value, method: &'static str, call: F) -> Instrumented<F> {
+    call.instrument(tracing::info_span!("call", exchange = %exchange.unwrap_str(), method))
+}
+
+// whether the exchange turns the price of a market order into a cost of amount * price in the quote currency,
+// binance with `quoteOrderQty`, phemex with `createOrderByQuoteRequiresPrice` and the market buys of the exchanges
+// with `createMarketBuyOrderRequiresPrice`, the others never read it
+fn market_order_takes_price(options: &serde_json::Value, side: &OrderSide) -> bool {
+    let enabled = |key: &str| options[key].as_bool() == Some(true);
+    enabled("quoteOrderQty") || enabled("createOrderByQuoteRequiresPrice") || (*side == OrderSide::Buy && enabled("createMarketBuyOrderRequiresPrice"))
+}
+
+async fn typed<T: DeserializeOwned>(call: impl Future<Output = Value>) -> Result<T> {
+    from_value(&catch(call).await?)
+}
+
+// every method but loadMarkets, which has no entry in `has`, checks the capabilities before delegating, the
+// others are listed with their name in `has` and their arguments and expand through the generic arm, spelled
+// out the way `async_trait` desugars them as the attribute on the impl runs before these expand
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unified_method {
+    ($trait:ident, load_markets) => {
+        fn load_markets<'life0, 'async_trait>(&'life0 mut self, reload: $crate::exchange::Value, params: $crate::exchange::Value)
+            -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = $crate::exchange::Value> + Send + 'async_trait>>
+        where 'life0: 'async_trait, Self: 'async_trait {
+            use $crate::unified::{call_span, UnifiedExchange};
+            Box::pin(call_span(UnifiedExchange::id(self), "loadMarkets", async move { $trait::load_markets(self, reload, params).await }))
+        }
+    };
+    // exchanges without a candles endpoint build them from their trades
+    ($trait:ident, fetch_ohlcv) => {
+        fn fetch_ohlcv<'life0, 'async_trait>(&'life0 mut self, symbol: $crate::exchange::Value, timeframe: $crate::exchange::Value, since: $crate::exchange::Value, limit: $crate::exchange::Value, params: $crate::exchange::Value)
+            -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = $crate::exchange::Value> + Send + 'async_trait>>
+        where 'life0: 'async_trait, Self: 'async_trait {
+            use $crate::unified::{call_span, not_supported, UnifiedExchange};
+            Box::pin(call_span(UnifiedExchange::id(self), "fetchOHLCV", async move {
+                let capabilities = UnifiedExchange::capabilities(self);
+                let emulated = capabilities.fetch_ohlcv == $crate::capabilities::Support::Emulated;
+                if emulated && capabilities.supports("fetchTrades") {
+                    return $crate::exchange::Exchange::fetch_ohlcv_from_trades(self, symbol, timeframe, since, limit, params, |exchange, symbol, since, limit, params| UnifiedExchange::fetch_trades(exchange, symbol, since, limit, params)).await;
+                }
+                if emulated || !capabilities.supports("fetchOHLCV") {
+                    return not_supported(UnifiedExchange::id(self), "fetchOHLCV");
+                }
+                $trait::fetch_ohlcv(self, symbol, timeframe, since, limit, params).await
+            }))
+        }
+    };
+    ($trait:ident, fetch_markets) => { $crate::unified_method!($trait, fetch_markets, "fetchMarkets", params); };
+    ($trait:ident, fetch_currencies) => { $crate::unified_method!($trait, fetch_currencies, "fetchCurrencies", params); };
+    ($trait:ident, fetch_ticker) => { $crate::unified_method!($trait, fetch_ticker, "fetchTicker", symbol, params); };
+    ($trait:ident, fetch_tickers) => { $crate::unified_method!($trait, fetch_tickers, "fetchTickers", symbols, params); };
+    ($trait:ident, fetch_order_book) => { $crate::unified_method!($trait, fetch_order_book, "fetchOrderBook", symbol, limit, params); };
+    ($trait:ident, fetch_trades) => { $crate::unified_method!($trait, fetch_trades, "fetchTrades", symbol, since, limit, params); };
+    ($trait:ident, fetch_status) => { $crate::unified_method!($trait, fetch_status, "fetchStatus", params); };
+    ($trait:ident, fetch_time) => { $crate::unified_method!($trait, fetch_time, "fetchTime", params); };
+    ($trait:ident, fetch_balance) => { $crate::unified_method!($trait, fetch_balance, "fetchBalance", params); };
+    ($trait:ident, create_order) => { $crate::unified_method!($trait, create_order, "createOrder", symbol, r#type, side, amount, price, params); };
+    ($trait:ident, edit_order) => { $crate::unified_method!($trait, edit_order, "editOrder", id, symbol, r#type, side, amount, price, params); };
+    ($trait:ident, cancel_order) => { $crate::unified_method!($trait, cancel_order, "cancelOrder", id, symbol, params); };
+    ($trait:ident, cancel_all_orders) => { $crate::unified_method!($trait, cancel_all_orders, "cancelAllOrders", symbol, params); };
+    ($trait:ident, fetch_order) => { $crate::unified_method!($trait, fetch_order, "fetchOrder", id, symbol, params); };
+    ($trait:ident, fetch_orders) => { $crate::unified_method!($trait, fetch_orders, "fetchOrders", symbol, since, limit, params); };
+    ($trait:ident, fetch_open_orders) => { $crate::unified_method!($trait, fetch_open_orders, "fetchOpenOrders", symbol, since, limit, params); };
+    ($trait:ident, fetch_closed_orders) => { $crate::unified_method!($trait, fetch_closed_orders, "fetchClosedOrders", symbol, since, limit, params); };
+    ($trait:ident, fetch_my_trades) => { $crate::unified_method!($trait, fetch_my_trades, "fetchMyTrades", symbol, since, limit, params); };
+    ($trait:ident, fetch_deposit_address) => { $crate::unified_method!($trait, fetch_deposit_address, "fetchDepositAddress", code, params); };
+    ($trait:ident, fetch_deposits) => { $crate::unified_method!($trait, fetch_deposits, "fetchDeposits", code, since, limit, params); };
+    ($trait:ident, fetch_withdrawals) => { $crate::unified_method!($trait, fetch_withdrawals, "fetchWithdrawals", code, since, limit, params); };
+    ($trait:ident, fetch_transactions) => { $crate::unified_method!($trait, fetch_transactions, "fetchTransactions", code, since, limit, params); };
+    ($trait:ident, withdraw) => { $crate::unified_method!($trait, withdraw, "withdraw", code, amount, address, tag, params); };
+    ($trait:ident, transfer) => { $crate::unified_method!($trait, transfer, "transfer", code, amount, from_account, to_account, params); };
+    ($trait:ident, fetch_ledger) => { $crate::unified_method!($trait, fetch_ledger, "fetchLedger", code, since, limit, params); };
+    ($trait:ident, fetch_trading_fee) => { $crate::unified_method!($trait, fetch_trading_fee, "fetchTradingFee", symbol, params); };
+    ($trait:ident, fetch_trading_fees) => { $crate::unified_method!($trait, fetch_trading_fees, "fetchTradingFees", params); };
+    ($trait:ident, fetch_positions) => { $crate::unified_method!($trait, fetch_positions, "fetchPositions", symbols, params); };
+    ($trait:ident, fetch_funding_rate) => { $crate::unified_method!($trait, fetch_funding_rate, "fetchFundingRate", symbol, params); };
+    ($trait:ident, fetch_funding_rates) => { $crate::unified_method!($trait, fetch_funding_rates, "fetchFundingRates", symbols, params); };
+    ($trait:ident, fetch_funding_history) => { $crate::unified_method!($trait, fetch_funding_history, "fetchFundingHistory", symbol, since, limit, params); };
+    ($trait:ident, set_leverage) => { $crate::unified_method!($trait, set_leverage, "setLeverage", leverage, symbol, params); };
+    ($trait:ident, fetch_leverage_tiers) => { $crate::unified_method!($trait, fetch_leverage_tiers, "fetchLeverageTiers", symbols, params); };
+    ($trait:ident, $method:ident, $name:literal, $($arg:ident),+) => {
+        fn $method<'life0, 'async_trait>(&'life0 mut self, $($arg: $crate::exchange::Value),+)
+            -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = $crate::exchange::Value> + Send + 'async_trait>>
+        where 'life0: 'async_trait, Self: 'async_trait {
+            use $crate::unified::{call_span, not_supported, UnifiedExchange};
+            Box::pin(call_span(UnifiedExchange::id(self), $name, async move {
+                if !UnifiedExchange::capabilities(self).supports($name) {
+                    return not_supported(UnifiedExchange::id(self), $name);
+                }
+                $trait::$method(self, $($arg),+).await
+            }))
+        }
+    };
+}
+
+/// Implements `UnifiedExchange` for `$impl`, each group lists the unified methods that are taken from that trait,
+/// the credentials are the field `credentials` names, the second one of the `XxxImpl` exchanges by default
+#[doc(hidden)]
+#[macro_export]
+macro_rules! unified_exchange {
+    ($impl:ty { $($groups:tt)* }) => {
+        $crate::unified_exchange!($impl, credentials: 1 { $($groups)* });
+    };
+    ($impl:ty, credentials: $credentials:tt { $($trait:ident => [$($method:ident),* $(,)?]),* $(,)? }) => {
+        #[$crate::unified::async_trait]
+        impl $crate::unified::UnifiedExchange for $impl {
+            fn id(&self) -> $crate::exchange::Value {
+                $crate::exchange::ValueTrait::get(self, "id".into())
+            }
+
+            fn name(&self) -> $crate::exchange::Value {
+                $crate::exchange::ValueTrait::get(self, "name".into())
+            }
+
+            fn has(&self) -> $crate::exchange::Value {
+                $crate::exchange::ValueTrait::get(self, "has".into())
+            }
+
+            fn state(&self) -> &serde_json::Value {
+                $crate::exchange::ValueTrait::unwrap_json(self)
+            }
+
+            fn state_mut(&mut self) -> &mut serde_json::Value {
+                $crate::exchange::ValueTrait::unwrap_json_mut(self)
+            }
+
+            fn clone_box(&self) -> Box<dyn $crate::unified::UnifiedExchange> {
+                Box::new(self.clone())
+            }
+
+            fn credentials_mut(&mut self) -> &mut $crate::credentials::Credentials {
+                &mut self.$credentials
+            }
+
+            $($($crate::unified_method!($trait, $method);)*)*
+        }
+    };
+}
+
+#[cfg(feature = "binance")]
+mod binance {
+    use crate::binance::{Binance, BinanceImpl};
+
+    unified_exchange!(BinanceImpl {
+        Binance => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_time, fetch_balance, create_order, edit_order,
+            cancel_order, cancel_all_orders, fetch_order, fetch_orders, fetch_open_orders,
+            fetch_closed_orders, fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals,
+            fetch_transactions, withdraw, transfer, fetch_trading_fee, fetch_trading_fees, fetch_positions,
+            fetch_funding_rate, fetch_funding_rates, fetch_funding_history, set_leverage,
+            fetch_leverage_tiers
+        ],
+    });
+}
+
+#[cfg(feature = "bitget")]
+mod bitget {
+    use crate::bitget::{Bitget, BitgetImpl};
+
+    unified_exchange!(BitgetImpl {
+        Bitget => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_time, fetch_balance, create_order, edit_order,
+            cancel_order, fetch_order, fetch_orders, fetch_open_orders, fetch_closed_orders, fetch_my_trades,
+            fetch_deposit_address, fetch_deposits, fetch_withdrawals, fetch_transactions, fetch_ledger,
+            fetch_trading_fee, fetch_trading_fees, fetch_positions, fetch_funding_rate, set_leverage
+        ],
+    });
+}
+
+#[cfg(feature = "coinex")]
+mod coinex {
+    use crate::coinex::{Coinex, CoinexImpl};
+
+    unified_exchange!(CoinexImpl {
+        Coinex => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_time, fetch_balance, create_order, edit_order,
+            cancel_order, cancel_all_orders, fetch_order, fetch_orders, fetch_open_orders,
+            fetch_closed_orders, fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals,
+            fetch_transactions, withdraw, transfer, fetch_trading_fee, fetch_trading_fees, fetch_positions,
+            fetch_funding_rate, fetch_funding_history, set_leverage, fetch_leverage_tiers
+        ],
+    });
+}
+
+#[cfg(feature = "cryptocom")]
+mod cryptocom {
+    use crate::cryptocom::{Cryptocom, CryptocomImpl};
+
+    unified_exchange!(CryptocomImpl {
+        Cryptocom => [
+            load_markets, fetch_markets, fetch_ticker, fetch_tickers, fetch_order_book, fetch_ohlcv,
+            fetch_trades, fetch_status, fetch_balance, create_order, edit_order, cancel_order,
+            cancel_all_orders, fetch_order, fetch_orders, fetch_open_orders, fetch_closed_orders,
+            fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals, fetch_transactions,
+            withdraw, transfer, fetch_trading_fee, fetch_trading_fees, fetch_funding_rate
+        ],
+    });
+}
+
+#[cfg(feature = "gate")]
+mod gate {
+    use crate::gate::{Gate, GateImpl};
+
+    unified_exchange!(GateImpl {
+        Gate => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_balance, create_order, edit_order, cancel_order,
+            cancel_all_orders, fetch_order, fetch_orders, fetch_open_orders, fetch_closed_orders,
+            fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals, fetch_transactions,
+            withdraw, transfer, fetch_trading_fee, fetch_trading_fees, fetch_positions, fetch_funding_rate,
+            fetch_funding_rates, fetch_funding_history, set_leverage, fetch_leverage_tiers
+        ],
+    });
+}
+
+#[cfg(feature = "gateio")]
+mod gateio {
+    use crate::gateio::{Gateio, GateioImpl};
+    use crate::gate::Gate;
+
+    unified_exchange!(GateioImpl {
+        Gateio => [
+            load_markets, fetch_ticker, fetch_tickers, fetch_ohlcv, fetch_status, fetch_balance,
+            create_order, edit_order, cancel_order, fetch_order, fetch_orders, fetch_open_orders,
+            fetch_closed_orders, fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals,
+            fetch_transactions, fetch_trading_fee, fetch_trading_fees, fetch_funding_rate
+        ],
+        Gate => [
+            fetch_markets, fetch_currencies, fetch_order_book, fetch_trades, cancel_all_orders, withdraw,
+            transfer, fetch_positions, fetch_funding_rates, fetch_funding_history, set_leverage,
+            fetch_leverage_tiers
+        ],
+    });
+}
+
+#[cfg(feature = "mexc3")]
+mod mexc3 {
+    use crate::mexc3::{Mexc3, Mexc3Impl};
+
+    unified_exchange!(Mexc3Impl {
+        Mexc3 => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_time, fetch_balance, create_order, edit_order,
+            cancel_order, cancel_all_orders, fetch_order, fetch_orders, fetch_open_orders,
+            fetch_closed_orders, fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals,
+            fetch_transactions, withdraw, transfer, fetch_trading_fee, fetch_trading_fees, fetch_positions,
+            fetch_funding_rate, fetch_funding_history, set_leverage, fetch_leverage_tiers
+        ],
+    });
+}
+
+#[cfg(feature = "okx")]
+mod okx {
+    use crate::okx::{Okx, OkxImpl};
+
+    unified_exchange!(OkxImpl {
+        Okx => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_time, fetch_balance, create_order, edit_order,
+            cancel_order, fetch_order, fetch_orders, fetch_open_orders, fetch_closed_orders, fetch_my_trades,
+            fetch_deposit_address, fetch_deposits, fetch_withdrawals, fetch_transactions, withdraw, transfer,
+            fetch_ledger, fetch_trading_fee, fetch_trading_fees, fetch_positions, fetch_funding_rate,
+            fetch_funding_history, set_leverage
+        ],
+    });
+}
+
+#[cfg(feature = "phemex")]
+mod phemex {
+    use crate::phemex::{Phemex, PhemexImpl};
+
+    unified_exchange!(PhemexImpl {
+        Phemex => [
+            load_markets, fetch_markets, fetch_currencies, fetch_ticker, fetch_tickers, fetch_order_book,
+            fetch_ohlcv, fetch_trades, fetch_status, fetch_balance, create_order, edit_order, cancel_order,
+            cancel_all_orders, fetch_order, fetch_orders, fetch_open_orders, fetch_closed_orders,
+            fetch_my_trades, fetch_deposit_address, fetch_deposits, fetch_withdrawals, fetch_transactions,
+            transfer, fetch_trading_fee, fetch_trading_fees, fetch_positions, fetch_funding_rate,
+            fetch_funding_history, set_leverage, fetch_leverage_tiers
+        ],
+    });
+}
+
+/// The ids of the exchanges compiled into this build, see the cargo features
+pub fn exchanges() -> Vec<&'static str> {
+    vec![
+        #[cfg(feature = "binance")]
+        "binance",
+        #[cfg(feature = "bitget")]
+        "bitget",
+        #[cfg(feature = "coinex")]
+        "coinex",
+        #[cfg(feature = "cryptocom")]
+        "cryptocom",
+        #[cfg(feature = "gate")]
+        "gate",
+        #[cfg(feature = "gateio")]
+        "gateio",
+        #[cfg(feature = "mexc3")]
+        "mexc3",
+        #[cfg(feature = "okx")]
+        "okx",
+        #[cfg(feature = "phemex")]
+        "phemex",
+    ]
+}
+
+/// Constructs the exchange with the given id, `config` is the same object the `XxxImpl::new` constructors take,
+/// `ExchangeNotFound` when the id is not one of `exchanges()`
+#[cfg_attr(not(any(
+    feature = "binance", feature = "bitget", feature = "coinex", feature = "cryptocom", feature = "gate",
+    feature = "gateio", feature = "mexc3", feature = "okx", feature = "phemex",
+)), allow(unused_variables))]
+pub fn new_exchange(id: &str, config: Value) -> Result<Box<dyn UnifiedExchange>> {
+    match id {
+        #[cfg(feature = "binance")]
+        "binance" => Ok(Box::new(crate::binance::BinanceImpl::new(config))),
+        #[cfg(feature = "bitget")]
+        "bitget" => Ok(Box::new(crate::bitget::BitgetImpl::new(config))),
+        #[cfg(feature = "coinex")]
+        "coinex" => Ok(Box::new(crate::coinex::CoinexImpl::new(config))),
+        #[cfg(feature = "cryptocom")]
+        "cryptocom" => Ok(Box::new(crate::cryptocom::CryptocomImpl::new(config))),
+        #[cfg(feature = "gate")]
+        "gate" => Ok(Box::new(crate::gate::GateImpl::new(config))),
+        #[cfg(feature = "gateio")]
+        "gateio" => Ok(Box::new(crate::gateio::GateioImpl::new(config))),
+        #[cfg(feature = "mexc3")]
+        "mexc3" => Ok(Box::new(crate::mexc3::Mexc3Impl::new(config))),
+        #[cfg(feature = "okx")]
+        "okx" => Ok(Box::new(crate::okx::OkxImpl::new(config))),
+        #[cfg(feature = "phemex")]
+        "phemex" => Ok(Box::new(crate::phemex::PhemexImpl::new(config))),
+        _ => Err(Error::new("ExchangeNotFound", format!("{} is not available, compiled exchanges are {}", id, exchanges().join(", ")))),
+    }
+}
+
+/// The ids of the compiled exchanges that support `method` natively or by emulation, e.g. `"fetchOrderBook"`
+pub fn exchanges_supporting(method: &str) -> Vec<&'static str> {
+    exchanges().into_iter().filter(|id| new_exchange(id, Value::Undefined).unwrap().capabilities().supports(method)).collect()
+}
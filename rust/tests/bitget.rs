@@ -0,0 +1,70 @@
+#![cfg(feature = "bitget")]
+
+mod common;
+
+use ccxt::bitget::Bitget;
+use ccxt::exchange::{normalize, Value, ValueTrait};
+
+mock_exchange!(MockBitget, ccxt::bitget::Bitget);
+
+fn bitget() -> MockBitget {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/spot/v1/public/currencies", include_str!("fixtures/bitget/currencies.json"));
+    recorder.respond("GET", "/api/spot/v1/public/products", include_str!("fixtures/bitget/spot_products.json"));
+    recorder.respond("GET", "/api/mix/v1/market/contracts?productType=umcbl", include_str!("fixtures/bitget/umcbl_contracts.json"));
+    recorder.respond("GET", "/api/mix/v1/market/contracts?productType=dmcbl", include_str!("fixtures/bitget/dmcbl_contracts.json"));
+    recorder.respond("GET", "/api/spot/v1/market/depth", include_str!("fixtures/bitget/depth.json"));
+    recorder.respond("GET", "/api/mix/v1/market/depth", include_str!("fixtures/bitget/error.json"));
+    MockBitget::new(Value::Undefined, recorder)
+}
+
+#[test]
+fn describes_itself() {
+    let exchange = bitget();
+    assert_eq!(exchange.get("id".into()), "bitget".into());
+    assert!(exchange.get("has".into()).get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn fetches_spot_and_swap_markets() {
+    let mut exchange = bitget();
+    let markets = normalize(&Bitget::fetch_markets(&mut exchange, Value::Undefined).await).unwrap();
+    let symbols: Vec<&str> = markets.as_array().unwrap().iter().map(|m| m["symbol"].as_str().unwrap()).collect();
+    assert_eq!(symbols, vec!["BTC/USDT", "BTC/USDT:USDT", "BTC/USD:BTC"]);
+
+    let spot = &markets[0];
+    assert_eq!(spot["id"], "BTCUSDT_SPBL");
+    assert_eq!(spot["spot"], true);
+    assert_eq!(spot["precision"]["price"].as_f64(), Some(0.01));
+    assert_eq!(spot["precision"]["amount"].as_f64(), Some(0.0001));
+
+    let linear = &markets[1];
+    assert_eq!(linear["swap"], true);
+    assert_eq!(linear["linear"], true);
+    assert_eq!(linear["settle"], "USDT");
+    assert_eq!(linear["precision"]["price"].as_f64(), Some(0.5));
+    assert_eq!(linear["precision"]["amount"].as_f64(), Some(0.000001));
+
+    assert_eq!(markets[2]["inverse"], true);
+}
+
+#[tokio::test]
+async fn fetches_order_book() {
+    let mut exchange = bitget();
+    let book = normalize(&Bitget::fetch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await).unwrap();
+    let request = exchange.1.last_request();
+    assert_eq!(request.url, "https://api.bitget.com/api/spot/v1/market/depth?symbol=BTCUSDT_SPBL&limit=2");
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["timestamp"], 1645854610294i64);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(39100.5));
+    assert_eq!(book["bids"][0][1].as_f64(), Some(1.773));
+    assert_eq!(book["asks"][0][0].as_f64(), Some(39102.0));
+    assert_eq!(book["asks"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+#[should_panic(expected = "ExchangeError")]
+async fn raises_on_error_responses() {
+    let mut exchange = bitget();
+    Bitget::fetch_order_book(&mut exchange, "BTC/USDT:USDT".into(), Value::Undefined, Value::Undefined).await;
+}
@@ -0,0 +1,61 @@
+#![cfg(all(feature = "binance", feature = "mexc3", feature = "okx"))]
+
+use ccxt::exchange::Value;
+use ccxt::{Capabilities, Support};
+use serde_json::json;
+
+#[test]
+fn reads_support_levels() {
+    assert_eq!(Support::from_value(&Value::Json(json!(true))), Support::Native);
+    assert_eq!(Support::from_value(&Value::Json(json!("emulated"))), Support::Emulated);
+    assert_eq!(Support::from_value(&Value::Json(json!(false))), Support::Unsupported);
+    assert_eq!(Support::from_value(&Value::Json(json!(null))), Support::Unsupported);
+    assert_eq!(Support::from_value(&Value::Undefined), Support::Unsupported);
+}
+
+#[test]
+fn layers_exchange_entries_over_the_base_defaults() {
+    let capabilities = Capabilities::from_has(&Value::Json(json!({
+        "fetchOHLCV": true,
+        "fetchTickers": "emulated",
+        "fetchOrderBook": false,
+        "fetchL3OrderBook": true,
+    })));
+    assert_eq!(capabilities.fetch_ohlcv, Support::Native);
+    assert_eq!(capabilities.fetch_tickers, Support::Emulated);
+    assert_eq!(capabilities.fetch_order_book, Support::Unsupported);
+    assert_eq!(capabilities.fetch_markets, Support::Native);
+    assert_eq!(capabilities.edit_order, Support::Emulated);
+    assert_eq!(capabilities.fetch_positions, Support::Unsupported);
+    assert_eq!(capabilities.get("fetchL3OrderBook"), Support::Native);
+    assert_eq!(capabilities.get("fetchSomethingElse"), Support::Unsupported);
+}
+
+#[test]
+fn describes_exchange_capabilities() {
+    let binance = ccxt::new_exchange("binance", Value::Undefined).unwrap().capabilities();
+    assert_eq!(binance.edit_order, Support::Emulated);
+    assert_eq!(binance.fetch_closed_orders, Support::Emulated);
+    assert_eq!(binance.fetch_order_book, Support::Native);
+    assert_eq!(binance.fetch_transactions, Support::Unsupported);
+    // mexc3 does not declare editOrder, so the base default applies
+    let mexc3 = ccxt::new_exchange("mexc3", Value::Undefined).unwrap().capabilities();
+    assert_eq!(mexc3.edit_order, Support::Emulated);
+}
+
+#[test]
+fn lists_exchanges_supporting_a_method() {
+    assert_eq!(ccxt::exchanges_supporting("fetchOrderBook"), ccxt::exchanges());
+    let venues = ccxt::exchanges_supporting("fetchLeverageTiers");
+    assert!(venues.contains(&"binance"));
+    assert!(venues.contains(&"mexc3"));
+    assert!(!venues.contains(&"okx"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "NotSupported::new(binance fetchTransactions() is not supported yet)")]
+async fn raises_not_supported_before_sending() {
+    // binance declares fetchTransactions as false, the call must fail without reaching the network
+    let mut exchange = ccxt::new_exchange("binance", Value::Undefined).unwrap();
+    exchange.fetch_transactions(Value::Undefined, Value::Undefined, Value::Undefined, Value::Undefined).await;
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "coinex")]
+
+mod common;
+
+use ccxt::coinex::Coinex;
+use ccxt::exchange::{normalize, Value, ValueTrait};
+use serde_json::json;
+
+mock_exchange!(MockCoinex, ccxt::coinex::Coinex);
+
+fn recorder() -> common::Recorder {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/v1/common/asset/config", include_str!("fixtures/coinex/asset_config.json"));
+    recorder.respond("GET", "/v1/market/info", include_str!("fixtures/coinex/market_info.json"));
+    recorder.respond("GET", "/perpetual/v1/market/list", include_str!("fixtures/coinex/market_list.json"));
+    recorder.respond("GET", "/v1/market/depth", include_str!("fixtures/coinex/depth.json"));
+    recorder
+}
+
+#[test]
+fn describes_itself() {
+    let exchange = MockCoinex::new(Value::Undefined, recorder());
+    assert_eq!(exchange.get("id".into()), "coinex".into());
+    assert!(exchange.get("has".into()).get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn fetches_spot_markets() {
+    let mut exchange = MockCoinex::new(Value::Undefined, recorder());
+    let markets = normalize(&Coinex::fetch_markets(&mut exchange, Value::Undefined).await).unwrap();
+    assert_eq!(markets.as_array().unwrap().len(), 1);
+    assert_eq!(markets[0]["symbol"], "BTC/USDT");
+    assert_eq!(markets[0]["spot"], true);
+    assert_eq!(markets[0]["taker"].as_f64(), Some(0.002));
+    assert_eq!(markets[0]["precision"]["amount"].as_f64(), Some(0.00000001));
+    assert_eq!(markets[0]["precision"]["price"].as_f64(), Some(0.01));
+    assert_eq!(markets[0]["limits"]["amount"]["min"].as_f64(), Some(0.0001));
+}
+
+#[tokio::test]
+async fn fetches_swap_markets() {
+    let mut exchange = MockCoinex::new(Value::Undefined, recorder());
+    let markets = normalize(&Coinex::fetch_markets(&mut exchange, Value::Json(json!({"type": "swap"}))).await).unwrap();
+    assert_eq!(exchange.1.last_request().url, "https://api.coinex.com/perpetual/v1/market/list");
+    assert_eq!(markets[0]["symbol"], "BTC/USDT:USDT");
+    assert_eq!(markets[0]["linear"], true);
+    assert_eq!(markets[0]["precision"]["amount"].as_f64(), Some(0.0001));
+    assert_eq!(markets[0]["limits"]["leverage"]["min"], "3");
+    assert_eq!(markets[0]["limits"]["leverage"]["max"], "100");
+}
+
+#[tokio::test]
+async fn fetches_order_book() {
+    let mut exchange = MockCoinex::new(Value::Undefined, recorder());
+    let book = normalize(&Coinex::fetch_order_book(&mut exchange, "BTC/USDT".into(), 5.into(), Value::Undefined).await).unwrap();
+    assert_eq!(exchange.1.last_request().url, "https://api.coinex.com/v1/market/depth?market=BTCUSDT&merge=0&limit=5");
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["timestamp"], 1650573220346i64);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(41050.61));
+    assert_eq!(book["asks"][0][1].as_f64(), Some(0.31727613));
+    assert_eq!(exchange.get("currencies".into()).get("USDT".into()).get("precision".into()), 0.000001.into());
+    assert_eq!(exchange.get("currencies".into()).get("USDT".into()).get("networks".into()).get("TRC20".into()).get("fee".into()), 1.0.into());
+}
+
+#[tokio::test]
+#[should_panic(expected = "RateLimitExceeded")]
+async fn raises_on_error_responses() {
+    let mut recorder = recorder();
+    recorder.respond("GET", "/v1/market/depth", include_str!("fixtures/coinex/error.json"));
+    let mut exchange = MockCoinex::new(Value::Undefined, recorder);
+    Coinex::fetch_order_book(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined).await;
+}
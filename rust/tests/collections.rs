@@ -0,0 +1,101 @@
+#![cfg(feature = "gateio")]
+
+mod common;
+
+use ccxt::exchange::{Exchange, Value, ValueTrait};
+use ccxt::gate::{Gate, GateImpl};
+use serde_json::json;
+
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate);
+
+fn exchange() -> GateImpl {
+    GateImpl::new(Value::Undefined)
+}
+
+fn trades() -> Value {
+    json!([
+        {"id": "1", "symbol": "BTC/USDT", "timestamp": 1000, "side": "buy"},
+        {"id": "2", "symbol": "ETH/USDT", "timestamp": 2000, "side": "sell"},
+        {"id": "3", "symbol": "BTC/USDT", "timestamp": 3000, "side": "sell"},
+        {"id": "4", "symbol": "BTC/USDT", "timestamp": 4000, "side": "buy"},
+        {"id": "5", "symbol": "BTC/USDT", "side": "buy"},
+    ]).into()
+}
+
+fn ids(array: Value) -> Vec<String> {
+    array.unwrap_json().as_array().unwrap().iter().map(|x| x["id"].as_str().unwrap().to_owned()).collect()
+}
+
+#[test]
+fn filters_by_since_and_limit() {
+    let exchange = exchange();
+    let none = Value::Undefined;
+    assert_eq!(ids(exchange.filter_by_since_limit(trades(), none.clone(), none.clone(), none.clone(), none.clone())), ["1", "2", "3", "4", "5"]);
+    // entries without a timestamp never match a since
+    assert_eq!(ids(exchange.filter_by_since_limit(trades(), 2000.into(), none.clone(), none.clone(), none.clone())), ["2", "3", "4"]);
+    assert_eq!(ids(exchange.filter_by_since_limit(trades(), 2000.into(), 2.into(), none.clone(), none.clone())), ["2", "3"]);
+    assert_eq!(ids(exchange.filter_by_since_limit(trades(), 2000.into(), 2.into(), none.clone(), true.into())), ["3", "4"]);
+    assert_eq!(ids(exchange.filter_by_since_limit(trades(), none.clone(), 10.into(), none.clone(), true.into())), ["1", "2", "3", "4", "5"]);
+    assert_eq!(ids(exchange.filter_by_since_limit(trades(), none.clone(), 0.into(), none.clone(), none.clone())), Vec::<String>::new());
+    let ohlcvs: Value = json!([[1000, 1.0], [2000, 2.0], [3000, 3.0]]).into();
+    assert_eq!(exchange.filter_by_since_limit(ohlcvs, 1500.into(), 1.into(), 0.into(), true.into()), json!([[3000, 3.0]]).into());
+    // objects are filtered by their values
+    let indexed: Value = json!({"a": {"id": "a", "timestamp": 1}, "b": {"id": "b", "timestamp": 2}}).into();
+    assert_eq!(ids(exchange.filter_by_since_limit(indexed, 2.into(), none.clone(), none.clone(), none)), ["b"]);
+}
+
+#[test]
+fn filters_by_value_since_and_limit() {
+    let exchange = exchange();
+    let none = Value::Undefined;
+    assert_eq!(ids(Gate::filter_by_symbol_since_limit(&exchange, trades(), "BTC/USDT".into(), none.clone(), none.clone(), none.clone())), ["1", "3", "4", "5"]);
+    assert_eq!(ids(Gate::filter_by_symbol_since_limit(&exchange, trades(), "BTC/USDT".into(), 2000.into(), 1.into(), none.clone())), ["3"]);
+    assert_eq!(ids(Gate::filter_by_symbol_since_limit(&exchange, trades(), "BTC/USDT".into(), none.clone(), 2.into(), true.into())), ["4", "5"]);
+    assert_eq!(ids(exchange.filter_by_value_since_limit(trades(), "side".into(), "sell".into(), none.clone(), none.clone(), none.clone(), none)), ["2", "3"]);
+}
+
+#[test]
+fn filters_by_key() {
+    let exchange = exchange();
+    assert_eq!(ids(exchange.filter_by(trades(), "side".into(), "buy".into(), Value::Undefined)), ["1", "4", "5"]);
+    assert_eq!(ids(exchange.filter_by(trades(), "timestamp".into(), json!(3000.0).into(), Value::Undefined)), ["3"]);
+    assert_eq!(ids(exchange.filter_by(trades(), "side".into(), "none".into(), Value::Undefined)), Vec::<String>::new());
+}
+
+#[test]
+fn aggregates_and_sums() {
+    let exchange = exchange();
+    let bidasks: Value = json!([[100.5, 1.0], ["100.5", "2"], [101, 0], [99, 0.5], [101, -1]]).into();
+    assert_eq!(exchange.aggregate(bidasks), json!([[100.5, 3.0], [99.0, 0.5]]).into());
+    assert_eq!(exchange.sum(1.into(), json!(0.5).into()), json!(1.5).into());
+    assert_eq!(exchange.sum(1.into(), Value::Undefined), 1.into());
+    assert!(exchange.sum(Value::Undefined, "1".into()).is_undefined());
+}
+
+#[test]
+fn reads_the_first_present_key() {
+    let exchange = exchange();
+    let order: Value = json!({"clientOrderId": "", "text": null, "orderId": 12345, "id": "abc"}).into();
+    let keys: Value = json!(["clientOrderId", "text", "orderId", "id"]).into();
+    assert_eq!(exchange.safe_string_n(order.clone(), keys.clone(), Value::Undefined), "12345".into());
+    assert_eq!(exchange.safe_value_n(order.clone(), keys, Value::Undefined), 12345.into());
+    assert_eq!(exchange.safe_string_n(order, json!(["missing"]).into(), "default".into()), "default".into());
+    assert_eq!(exchange.is_array(json!([]).into()), true.into());
+    assert_eq!(exchange.is_array(json!({}).into()), false.into());
+}
+
+#[tokio::test]
+async fn fetches_ohlcv_to_completion() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder.respond("GET", "/spot/candlesticks", include_str!("fixtures/gateio/candlesticks.json"));
+    let mut exchange = MockGateio::new(Value::Undefined, recorder);
+    let ohlcvs = Gate::fetch_ohlcv(&mut exchange, "BTC/USDT".into(), "1h".into(), Value::Undefined, 2.into(), Value::Undefined).await;
+    // candles are limited from the tail, the most recent ones are kept
+    assert_eq!(ohlcvs, json!([
+        [1662382800000i64, 19830.5, 19880.0, 19801.9, 19870.25, 215300.12],
+        [1662386400000i64, 19870.25, 19890.3, 19832.0, 19845.7, 180012.5],
+    ]).into());
+}
@@ -0,0 +1,179 @@
+#![allow(dead_code)]
+
+use ccxt::exchange::{Value, ValueTrait};
+use std::sync::{Arc, Mutex};
+
+/// A request captured by a mocked exchange instead of being sent over the wire
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub url: String,
+    pub method: String,
+    pub headers: serde_json::Value,
+    pub body: Option<String>,
+}
+
+/// An HTTP method, url fragment, status, headers and body
+pub type CannedResponse = (String, String, u16, serde_json::Value, String);
+
+/// Canned responses keyed by HTTP method and a fragment of the request url, fragments match the
+/// end of the url path unless they contain a `?`, in which case they match anywhere in the full url,
+/// one-off responses are served once, in order, before the others and fail the connection when their status
+/// is 0, clones share the recorded requests
+#[derive(Default, Clone)]
+pub struct Recorder {
+    pub responses: Vec<CannedResponse>,
+    pub once: Arc<Mutex<Vec<CannedResponse>>>,
+    pub requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl Recorder {
+    pub fn respond(&mut self, method: &str, url_fragment: &str, response: &str) {
+        self.respond_with_status(method, url_fragment, 200, response);
+    }
+
+    pub fn respond_with_status(&mut self, method: &str, url_fragment: &str, status: u16, response: &str) {
+        self.responses.push((method.to_owned(), url_fragment.to_owned(), status, serde_json::json!({}), response.to_owned()));
+    }
+
+    pub fn respond_once(&mut self, method: &str, url_fragment: &str, status: u16, response: &str) {
+        self.respond_once_with_headers(method, url_fragment, status, serde_json::json!({}), response);
+    }
+
+    pub fn respond_once_with_headers(&mut self, method: &str, url_fragment: &str, status: u16, headers: serde_json::Value, response: &str) {
+        self.once.lock().unwrap().push((method.to_owned(), url_fragment.to_owned(), status, headers, response.to_owned()));
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn last_request(&self) -> RecordedRequest {
+        self.requests().last().cloned().expect("no request was made")
+    }
+
+    pub fn handle(&self, url: Value, method: Value, headers: Value, body: Value) -> Value {
+        let url = url.unwrap_json().as_str().unwrap().to_owned();
+        let method = match method {
+            Value::Json(serde_json::Value::String(m)) => m,
+            _ => "GET".to_owned(),
+        };
+        self.requests.lock().unwrap().push(RecordedRequest {
+            url: url.clone(),
+            method: method.clone(),
+            headers: match headers {
+                Value::Json(h) => h,
+                _ => serde_json::Value::Null,
+            },
+            body: match body {
+                Value::Json(serde_json::Value::String(b)) => Some(b),
+                _ => None,
+            },
+        });
+        let path = url.split('?').next().unwrap();
+        let matches = |(m, fragment, _, _, _): &CannedResponse| *m == method && if fragment.contains('?') { url.contains(fragment.as_str()) } else { path.ends_with(fragment.as_str()) };
+        let mut once = self.once.lock().unwrap();
+        let response = match once.iter().position(matches) {
+            Some(i) => once.remove(i),
+            // the longest matching fragment wins so that "/orders" does not shadow "/spot/orders"
+            None => self.responses.iter()
+                .filter(|response| matches(response))
+                .max_by_key(|(_, fragment, _, _, _)| fragment.len())
+                .cloned()
+                .unwrap_or_else(|| panic!("no canned response for {} {}", method, url)),
+        };
+        drop(once);
+        if response.2 == 0 {
+            panic!("NetworkError::new({} {} connection reset by peer)", method, url);
+        }
+        Value::Json(serde_json::json!({
+            "status": response.2,
+            "statusText": "",
+            "headers": response.3,
+            "body": response.4,
+        }))
+    }
+}
+
+pub fn hmac_sha256_hex(secret: &str, payload: &str) -> String {
+    use hmac::Mac;
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Declares `$name`, a wrapper around the exchange trait `$exchange` whose `fetch_response` is served by a
+/// `Recorder`, so the generated request building, signing and parsing can run without a network,
+/// aliases also list the exchange traits they extend, a trailing `=> { Trait => [methods] }` also implements
+/// `UnifiedExchange` with `ccxt::unified_exchange!`, routing the listed methods to the exchange trait
+#[macro_export]
+macro_rules! mock_exchange {
+    ($name:ident, $exchange:path $(, $base:path)* => { $($groups:tt)* }) => {
+        mock_exchange!($name, $exchange $(, $base)*);
+        ccxt::unified_exchange!($name, credentials: 2 { $($groups)* });
+    };
+    ($name:ident, $exchange:path $(, $base:path)*) => {
+        #[derive(Clone)]
+        pub struct $name(ccxt::exchange::Value, pub common::Recorder, pub ccxt::credentials::Credentials);
+
+        impl $name {
+            pub fn new(params: ccxt::exchange::Value, recorder: common::Recorder) -> Self {
+                use ccxt::exchange::ValueTrait;
+                let mut rv = $name(match params {
+                    ccxt::exchange::Value::Json(_) => params,
+                    _ => ccxt::exchange::Value::new_object(),
+                }, recorder, Default::default());
+                rv.2 = ccxt::credentials::Credentials::take_from(&mut rv.0);
+                ccxt::exchange::ExchangeImpl::init(&mut rv.0);
+                let config_entries = <$name as $exchange>::describe(&rv);
+                for k in config_entries.keys() {
+                    rv.set(k.clone(), config_entries.get(k).clone());
+                }
+                rv
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl ccxt::exchange::Exchange for $name {
+            async fn fetch_response(&self, url: ccxt::exchange::Value, method: ccxt::exchange::Value, headers: ccxt::exchange::Value, body: ccxt::exchange::Value) -> ccxt::exchange::Value {
+                self.1.handle(url, method, headers, body)
+            }
+        }
+
+        impl $exchange for $name {}
+        $(impl $base for $name {})*
+
+        impl ccxt::exchange::ValueTrait for $name {
+            fn is_undefined(&self) -> bool { self.0.is_undefined() }
+            fn is_nullish(&self) -> bool { self.0.is_nullish() }
+            fn is_nonnullish(&self) -> bool { self.0.is_nonnullish() }
+            fn is_truthy(&self) -> bool { self.0.is_truthy() }
+            fn or_default(&self, default: ccxt::exchange::Value) -> ccxt::exchange::Value { self.0.or_default(default) }
+            fn is_number(&self) -> bool { self.0.is_number() }
+            fn is_string(&self) -> bool { self.0.is_string() }
+            fn is_object(&self) -> bool { self.0.is_object() }
+            fn is_falsy(&self) -> bool { self.0.is_falsy() }
+            fn to_upper_case(&self) -> ccxt::exchange::Value { self.0.to_upper_case() }
+            fn unwrap_str(&self) -> &str { self.0.unwrap_str() }
+            fn unwrap_usize(&self) -> usize { self.0.unwrap_usize() }
+            fn unwrap_bool(&self) -> bool { self.0.unwrap_bool() }
+            fn unwrap_precise(&self) -> &ccxt::exchange::Precise { self.0.unwrap_precise() }
+            fn unwrap_json(&self) -> &serde_json::Value { self.0.unwrap_json() }
+            fn unwrap_json_mut(&mut self) -> &mut serde_json::Value { self.0.unwrap_json_mut() }
+            fn unwrap_precise_mut(&mut self) -> &mut ccxt::exchange::Precise { self.0.unwrap_precise_mut() }
+            fn len(&self) -> usize { self.0.len() }
+            fn get(&self, key: ccxt::exchange::Value) -> ccxt::exchange::Value { self.2.get_value(&key).unwrap_or_else(|| self.0.get(key)) }
+            fn set(&mut self, key: ccxt::exchange::Value, value: ccxt::exchange::Value) { if !self.2.set_value(&key, &value) { self.0.set(key, value) } }
+            fn push(&mut self, value: ccxt::exchange::Value) { self.0.push(value) }
+            fn split(&self, separator: ccxt::exchange::Value) -> ccxt::exchange::Value { self.0.split(separator) }
+            fn contains_key(&self, key: ccxt::exchange::Value) -> bool { self.0.contains_key(key) }
+            fn keys(&self) -> Vec<ccxt::exchange::Value> { self.0.keys() }
+            fn values(&self) -> Vec<ccxt::exchange::Value> { self.0.values() }
+            fn to_array(&self, x: ccxt::exchange::Value) -> ccxt::exchange::Value { self.0.to_array(x) }
+            fn index_of(&self, x: ccxt::exchange::Value) -> ccxt::exchange::Value { self.0.index_of(x) }
+            fn join(&self, glue: ccxt::exchange::Value) -> ccxt::exchange::Value { self.0.join(glue) }
+            fn to_string(&self) -> ccxt::exchange::Value { self.0.to_string() }
+            fn typeof_(&self) -> ccxt::exchange::Value { self.0.typeof_() }
+            fn slice(&self, start: ccxt::exchange::Value) -> ccxt::exchange::Value { self.0.slice(start) }
+        }
+    };
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use ccxt::credentials::Credentials;
+#[cfg(feature = "phemex")]
+use ccxt::{errors::catch, exchange::{normalize, Value, ValueTrait}, phemex::{Phemex, PhemexImpl}, UnifiedExchange};
+#[cfg(feature = "phemex")]
+use serde_json::json;
+
+#[cfg(feature = "phemex")]
+mock_exchange!(MockPhemex, ccxt::phemex::Phemex);
+
+#[test]
+fn debug_output_hides_the_secrets() {
+    let credentials = Credentials::new().with("apiKey", "my-key").with("secret", "my-secret");
+    assert_eq!(format!("{:?}", credentials), r#"{"apiKey": ***, "secret": ***}"#);
+    assert_eq!(credentials.get("secret").unwrap().expose(), "my-secret");
+    assert_eq!(credentials.fields().collect::<Vec<_>>(), ["apiKey", "secret"]);
+}
+
+#[test]
+fn reads_environment_variables() {
+    std::env::set_var("CCXT_CREDTEST_APIKEY", "env-key");
+    std::env::set_var("CCXT_CREDTEST_PRIVATEKEY", "env-private-key");
+    let credentials = Credentials::from_env("credtest");
+    assert_eq!(credentials.get("apiKey").unwrap().expose(), "env-key");
+    assert_eq!(credentials.get("privateKey").unwrap().expose(), "env-private-key");
+    assert!(credentials.get("secret").is_none());
+}
+
+#[test]
+fn reads_keys_files() {
+    let path = std::env::temp_dir().join(format!("ccxt-keys-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"okx": {"apiKey": "file-key", "secret": "file-secret", "password": "file-password", "skip": true, "options": {"defaultType": "swap"}}, "binance": {"timeout": 10000}}"#).unwrap();
+    let credentials = Credentials::from_keys_file(&path, "okx").unwrap();
+    assert_eq!(credentials.fields().collect::<Vec<_>>(), ["apiKey", "password", "secret"]);
+    assert!(Credentials::from_keys_file(&path, "binance").unwrap().is_empty());
+    std::fs::remove_file(&path).unwrap();
+
+    let error = Credentials::from_keys_file(&path, "okx").unwrap_err();
+    assert!(error.is("AuthenticationError"));
+}
+
+#[cfg(feature = "okx")]
+#[test]
+fn checks_the_credentials_required_by_describe() {
+    use ccxt::errors::Error;
+    use ccxt::exchange::{Value, ValueTrait};
+    use ccxt::okx::{Okx, OkxImpl};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut exchange = OkxImpl::new(Value::Json(serde_json::json!({ "apiKey": "okx-api-key", "secret": "okx-secret" })));
+    assert_eq!(Okx::check_required_credentials(&mut exchange, false.into()), false.into());
+    let error = Error::from_panic(catch_unwind(AssertUnwindSafe(|| Okx::check_required_credentials(&mut exchange, Value::Undefined))).unwrap_err());
+    assert_eq!(error.message, r#"okx requires "password" credential"#);
+    exchange.set("password".into(), "okx-password".into());
+    assert_eq!(Okx::check_required_credentials(&mut exchange, false.into()), true.into());
+}
+
+#[cfg(feature = "phemex")]
+#[test]
+fn keeps_credentials_out_of_the_exchange_state() {
+    let exchange = PhemexImpl::new(Value::Json(json!({ "apiKey": "phemex-api-key", "secret": "phemex-secret" })));
+    assert!(!format!("{:?}", exchange).contains("phemex-secret"));
+    assert!(!normalize(&Value::Json(exchange.state().clone())).unwrap().to_string().contains("phemex-secret"));
+    assert_eq!(exchange.get("secret".into()), "phemex-secret".into());
+
+    let mut exchange = ccxt::new_exchange("phemex", Value::Undefined).unwrap();
+    exchange.set_credentials(Credentials::new().with("apiKey", "key").with("secret", "secret"));
+    assert_eq!(exchange.credentials_mut().get("apiKey").unwrap().expose(), "key");
+    assert!(exchange.state().get("apiKey").is_none());
+}
+
+#[cfg(feature = "phemex")]
+#[tokio::test]
+async fn signs_with_credentials_set_after_construction() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/cfg/v2/products", include_str!("fixtures/phemex/v2_products.json"));
+    recorder.respond("GET", "/exchange/public/products", include_str!("fixtures/phemex/v1_products.json"));
+    recorder.respond("POST", "/spot/orders", include_str!("fixtures/phemex/spot_order.json"));
+    let mut exchange = MockPhemex::new(Value::Undefined, recorder);
+
+    let error = catch(Phemex::create_order(&mut exchange, "BTC/USDT".into(), "limit".into(), "buy".into(), json!(0.1).into(), json!(20000).into(), Value::Undefined)).await.unwrap_err();
+    assert_eq!(error.kind, "AuthenticationError");
+    assert_eq!(error.message, r#"phemex requires "apiKey" credential"#);
+
+    exchange.2.extend(Credentials::new().with("apiKey", "late-key").with("secret", "late-secret"));
+    Phemex::create_order(&mut exchange, "BTC/USDT".into(), "limit".into(), "buy".into(), json!(0.1).into(), json!(20000).into(), Value::Undefined).await;
+    let request = exchange.1.last_request();
+    assert_eq!(request.headers["x-phemex-access-token"], "late-key");
+    let expiry = request.headers["x-phemex-request-expiry"].as_str().unwrap();
+    let auth = format!("/spot/orders{}{}", expiry, request.body.as_ref().unwrap());
+    assert_eq!(request.headers["x-phemex-request-signature"], common::hmac_sha256_hex("late-secret", &auth));
+}
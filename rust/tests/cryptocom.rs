@@ -0,0 +1,68 @@
+#![cfg(feature = "cryptocom")]
+
+mod common;
+
+use ccxt::cryptocom::Cryptocom;
+use ccxt::exchange::{normalize, Value, ValueTrait};
+
+mock_exchange!(MockCryptocom, ccxt::cryptocom::Cryptocom);
+
+fn recorder() -> common::Recorder {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/v2/public/get-instruments", include_str!("fixtures/cryptocom/spot_instruments.json"));
+    recorder.respond("GET", "/v1/public/get-instruments", include_str!("fixtures/cryptocom/derivatives_instruments.json"));
+    recorder.respond("GET", "/v2/public/get-book", include_str!("fixtures/cryptocom/book.json"));
+    recorder
+}
+
+#[test]
+fn describes_itself() {
+    let exchange = MockCryptocom::new(Value::Undefined, recorder());
+    assert_eq!(exchange.get("id".into()), "cryptocom".into());
+    assert!(exchange.get("has".into()).get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn fetches_spot_and_swap_markets() {
+    let mut exchange = MockCryptocom::new(Value::Undefined, recorder());
+    let markets = normalize(&Cryptocom::fetch_markets(&mut exchange, Value::Undefined).await).unwrap();
+    let symbols: Vec<&str> = markets.as_array().unwrap().iter().map(|m| m["symbol"].as_str().unwrap()).collect();
+    assert_eq!(symbols, vec!["BTC/USDT", "BTC/USD:USD"]);
+    assert_eq!(markets[0]["id"], "BTC_USDT");
+    assert_eq!(markets[0]["precision"]["amount"].as_f64(), Some(0.000001));
+    assert_eq!(markets[0]["precision"]["price"].as_f64(), Some(0.01));
+    assert_eq!(markets[0]["limits"]["leverage"]["max"].as_f64(), Some(10.0));
+    assert_eq!(markets[1]["id"], "BTCUSD-PERP");
+    assert_eq!(markets[1]["swap"], true);
+    assert_eq!(markets[1]["precision"]["price"].as_f64(), Some(0.1));
+    assert_eq!(markets[1]["limits"]["leverage"]["max"].as_f64(), Some(50.0));
+}
+
+#[tokio::test]
+async fn fetches_order_book() {
+    let mut exchange = MockCryptocom::new(Value::Undefined, recorder());
+    let book = normalize(&Cryptocom::fetch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await).unwrap();
+    assert_eq!(exchange.1.last_request().url, "https://api.crypto.com/v2/public/get-book?instrument_name=BTC_USDT&depth=2");
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["timestamp"], 1591704180270i64);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(9668.44));
+    assert_eq!(book["asks"][1][1].as_f64(), Some(1.722864));
+}
+
+#[tokio::test]
+#[should_panic(expected = "ExchangeError")]
+async fn raises_on_error_responses() {
+    let mut recorder = recorder();
+    recorder.respond("GET", "/v2/public/get-book", include_str!("fixtures/cryptocom/error.json"));
+    let mut exchange = MockCryptocom::new(Value::Undefined, recorder);
+    Cryptocom::fetch_order_book(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined).await;
+}
+
+#[tokio::test]
+async fn raises_readable_messages_for_unsupported_market_types() {
+    let mut exchange = MockCryptocom::new(Value::Undefined, recorder());
+    let params = Value::Json(serde_json::json!({"type": "option"}));
+    let error = ccxt::errors::catch(Cryptocom::fetch_balance(&mut exchange, params)).await.unwrap_err();
+    assert_eq!(error.kind, "NotSupported");
+    assert_eq!(error.message, "cryptocom option does not have a value in mapping");
+}
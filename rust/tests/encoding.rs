@@ -0,0 +1,75 @@
+#![cfg(feature = "gate")]
+
+use ccxt::exchange::{Exchange, Value, ValueTrait};
+use ccxt::gate::{Gate, GateImpl};
+use serde_json::json;
+
+fn exchange() -> GateImpl {
+    GateImpl::new(Value::Undefined)
+}
+
+#[test]
+fn urlencode_follows_rfc3986() {
+    let exchange = exchange();
+    let params: Value = json!({
+        "text": "a b&c=d/é~_.-",
+        "price": 20000.0,
+        "amount": 0.5,
+        "reduce": true,
+        "tag": null,
+    }).into();
+    assert_eq!(exchange.urlencode(params), "text=a%20b%26c%3Dd%2F%C3%A9~_.-&price=20000&amount=0.5&reduce=true&tag=".into());
+    assert_eq!(exchange.urlencode(json!({}).into()), "".into());
+    let mut params: Value = json!({"symbol": "BTC_USDT", "type": "limit", "side": "buy", "amount": 1}).into();
+    params.set("type".into(), Value::Undefined);
+    assert_eq!(exchange.urlencode(params), "symbol=BTC_USDT&side=buy&amount=1".into());
+    assert_eq!(exchange.urlencode(Value::Undefined), "".into());
+}
+
+#[test]
+fn encodes_arrays_and_nested_objects() {
+    let exchange = exchange();
+    let params: Value = json!({"ids": [1, 2], "filter": {"side": "buy sell"}, "symbol": "BTC_USDT"}).into();
+    assert_eq!(
+        exchange.urlencode(params.clone()),
+        "ids%5B0%5D=1&ids%5B1%5D=2&filter%5Bside%5D=buy%20sell&symbol=BTC_USDT".into(),
+    );
+    assert_eq!(exchange.rawencode(params.clone()), "ids[0]=1&ids[1]=2&filter[side]=buy sell&symbol=BTC_USDT".into());
+    assert_eq!(
+        exchange.urlencode_with_array_repeat(params),
+        "ids=1&ids=2&filter%5Bside%5D=buy%20sell&symbol=BTC_USDT".into(),
+    );
+    assert_eq!(exchange.urlencode_with_array_repeat(json!({"ids": []}).into()), "".into());
+}
+
+#[test]
+fn implodes_path_params() {
+    let exchange = exchange();
+    let path: Value = "futures/{settle}/orders/{order_id}".into();
+    assert_eq!(exchange.extract_params(path.clone()), json!(["settle", "order_id"]).into());
+    assert_eq!(
+        exchange.implode_params(path, json!({"settle": "usdt", "order_id": 12345, "contract": "BTC_USDT"}).into()),
+        "futures/usdt/orders/12345".into(),
+    );
+    assert_eq!(exchange.implode_params("{a}/{a}".into(), json!({"a": 1.0}).into()), "1/{a}".into());
+    assert_eq!(exchange.implode_params("{ids}".into(), json!({"ids": [1, 2]}).into()), "{ids}".into());
+}
+
+#[test]
+fn signs_path_params_out_of_the_query() {
+    let mut exchange = GateImpl::new(json!({"apiKey": "key", "secret": "secret"}).into());
+    let request = Gate::sign(
+        &mut exchange,
+        "{settle}/orders".into(),
+        json!(["private", "futures"]).into(),
+        "GET".into(),
+        json!({"settle": "usdt", "contract": "BTC_USDT", "status": "open"}).into(),
+        Value::Undefined,
+        Value::Undefined,
+    );
+    assert_eq!(
+        request.get("url".into()),
+        "https://api.gateio.ws/api/v4/futures/usdt/orders?contract=BTC_USDT&status=open".into(),
+    );
+    assert!(request.get("headers".into()).get("SIGN".into()).is_string());
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "gateio")]
+
+mod common;
+
+use ccxt::exchange::{normalize, Value, ValueTrait};
+use ccxt::gate::Gate;
+use serde_json::json;
+
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate);
+
+fn recorder() -> common::Recorder {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder.respond("GET", "/futures/usdt/contracts", include_str!("fixtures/gateio/usdt_contracts.json"));
+    recorder.respond("GET", "/futures/btc/contracts", "[]");
+    recorder.respond("GET", "/delivery/usdt/contracts", "[]");
+    recorder.respond("GET", "/delivery/btc/contracts", "[]");
+    recorder.respond("GET", "/spot/order_book", include_str!("fixtures/gateio/order_book.json"));
+    recorder
+}
+
+#[test]
+fn describes_itself_as_an_alias_of_gate() {
+    let exchange = MockGateio::new(Value::Undefined, recorder());
+    assert_eq!(exchange.get("id".into()), "gateio".into());
+    assert!(exchange.get("alias".into()).is_truthy());
+    assert!(exchange.get("has".into()).get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn fetches_spot_markets() {
+    let mut exchange = MockGateio::new(Value::Undefined, recorder());
+    let markets = normalize(&Gate::fetch_markets(&mut exchange, Value::Undefined).await).unwrap();
+    assert_eq!(markets.as_array().unwrap().len(), 1);
+    assert_eq!(markets[0]["symbol"], "BTC/USDT");
+    assert_eq!(markets[0]["margin"], true);
+    assert_eq!(markets[0]["active"], true);
+    assert_eq!(markets[0]["taker"].as_f64(), Some(0.002));
+    assert_eq!(markets[0]["precision"]["amount"].as_f64(), Some(0.0001));
+    assert_eq!(markets[0]["precision"]["price"].as_f64(), Some(0.01));
+    assert_eq!(markets[0]["limits"]["leverage"]["max"].as_f64(), Some(10.0));
+}
+
+#[tokio::test]
+async fn fetches_swap_markets() {
+    let mut exchange = MockGateio::new(Value::Undefined, recorder());
+    let markets = normalize(&Gate::fetch_markets(&mut exchange, Value::Json(json!({"type": "swap"}))).await).unwrap();
+    assert_eq!(markets.as_array().unwrap().len(), 1);
+    assert_eq!(markets[0]["symbol"], "BTC/USDT:USDT");
+    assert_eq!(markets[0]["linear"], true);
+    assert_eq!(markets[0]["contractSize"].as_f64(), Some(0.0001));
+    assert_eq!(markets[0]["limits"]["price"]["min"].as_f64(), Some(20000.0));
+    assert_eq!(markets[0]["limits"]["price"]["max"].as_f64(), Some(60000.0));
+    let urls: Vec<String> = exchange.1.requests().into_iter().map(|r| r.url).collect();
+    assert!(urls.contains(&"https://api.gateio.ws/api/v4/futures/usdt/contracts".to_owned()));
+}
+
+#[tokio::test]
+async fn fetches_order_book() {
+    let mut exchange = MockGateio::new(Value::Undefined, recorder());
+    let book = normalize(&Gate::fetch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await).unwrap();
+    assert_eq!(exchange.1.last_request().url, "https://api.gateio.ws/api/v4/spot/order_book?currency_pair=BTC_USDT&limit=2&with_id=true");
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["timestamp"], 1650560389264i64);
+    assert_eq!(book["nonce"], 3253917453i64);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(41069.27));
+    assert_eq!(book["asks"][1][1].as_f64(), Some(0.05));
+}
+
+#[tokio::test]
+#[should_panic(expected = "INVALID_CURRENCY_PAIR")]
+async fn raises_on_error_responses() {
+    let mut recorder = recorder();
+    recorder.respond_with_status("GET", "/spot/order_book", 400, include_str!("fixtures/gateio/error.json"));
+    let mut exchange = MockGateio::new(Value::Undefined, recorder);
+    Gate::fetch_order_book(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined).await;
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use ccxt::logging::{is_sensitive, redact_body, redact_headers, redact_url};
+use serde_json::json;
+#[cfg(feature = "phemex")]
+use ccxt::{exchange::Value, phemex::Phemex};
+#[cfg(feature = "phemex")]
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "phemex")]
+mock_exchange!(MockPhemex, ccxt::phemex::Phemex);
+
+#[test]
+fn recognizes_sensitive_keys() {
+    for key in ["apiKey", "X-MBX-APIKEY", "OK-ACCESS-SIGN", "OK-ACCESS-PASSPHRASE", "signature", "listenKey", "Authorization"] {
+        assert!(is_sensitive(key), "{}", key);
+    }
+    for key in ["symbol", "timestamp", "recvWindow", "Content-Type", "side"] {
+        assert!(!is_sensitive(key), "{}", key);
+    }
+}
+
+#[test]
+fn redacts_urls_headers_and_bodies() {
+    let secrets = vec!["my-api-key".to_owned(), "my-passphrase".to_owned()];
+    assert_eq!(
+        redact_url("https://api.binance.com/api/v3/order?symbol=BTCUSDT&timestamp=1&signature=abcdef", &secrets),
+        "https://api.binance.com/api/v3/order?symbol=BTCUSDT&timestamp=1&signature=***",
+    );
+    assert_eq!(redact_url("https://example.com/my-api-key/orders", &secrets), "https://example.com/***/orders");
+    assert_eq!(
+        redact_headers(&json!({"X-MBX-APIKEY": "my-api-key", "Content-Type": "application/json", "X-Memo": "my-passphrase"}), &secrets),
+        json!({"X-MBX-APIKEY": "***", "Content-Type": "application/json", "X-Memo": "***"}),
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&redact_body(r#"{"op": "login", "args": [{"apiKey": "k", "passphrase": "p", "sign": "s", "ts": 1}]}"#, &secrets)).unwrap(),
+        json!({"op": "login", "args": [{"apiKey": "***", "passphrase": "***", "sign": "***", "ts": 1}]}),
+    );
+    assert_eq!(redact_body("symbol=BTCUSDT&apiKey=k&signature=s", &secrets), "symbol=BTCUSDT&apiKey=***&signature=***");
+}
+
+#[cfg(feature = "phemex")]
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+#[cfg(feature = "phemex")]
+impl std::io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "phemex")]
+#[tokio::test]
+async fn logs_requests_without_credentials() {
+    let output = Output::default();
+    let writer = output.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/cfg/v2/products", include_str!("fixtures/phemex/v2_products.json"));
+    recorder.respond("GET", "/exchange/public/products", include_str!("fixtures/phemex/v1_products.json"));
+    recorder.respond("POST", "/spot/orders", include_str!("fixtures/phemex/spot_order.json"));
+    let mut exchange = MockPhemex::new(Value::Json(json!({
+        "apiKey": "phemex-api-key",
+        "secret": "phemex-secret",
+        "enableRateLimit": true,
+    })), recorder);
+    Phemex::create_order(&mut exchange, "BTC/USDT".into(), "limit".into(), "buy".into(), json!(0.1).into(), json!(20000).into(), Value::Undefined).await;
+
+    let signature = exchange.1.last_request().headers["x-phemex-request-signature"].as_str().unwrap().to_owned();
+    let logs = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("request{exchange=phemex api=private method=POST path=spot/orders"), "{}", logs);
+    assert!(logs.contains("status=200"), "{}", logs);
+    assert!(logs.contains("latency_ms="), "{}", logs);
+    assert!(logs.contains("cost="), "{}", logs);
+    assert!(logs.contains("x-phemex-access-token"), "{}", logs);
+    assert!(!logs.contains("phemex-api-key") && !logs.contains("phemex-secret") && !logs.contains(&signature), "{}", logs);
+}
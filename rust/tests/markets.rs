@@ -0,0 +1,114 @@
+#![cfg(feature = "gateio")]
+
+mod common;
+
+use ccxt::exchange::{Exchange, Value, ValueTrait};
+use ccxt::gate::Gate;
+use ccxt::{SharedExchange, UnifiedExchange};
+use serde_json::json;
+
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate => { Gate => [load_markets] });
+
+const ETH_USDT: &str = r#"[{"id": "ETH_USDT", "base": "ETH", "quote": "USDT", "fee": "0.2", "amount_precision": 4, "precision": 2, "trade_status": "tradable"}]"#;
+
+fn recorder() -> common::Recorder {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder
+}
+
+fn count(recorder: &common::Recorder, fragment: &str) -> usize {
+    recorder.requests().iter().filter(|r| r.url.split('?').next().unwrap().ends_with(fragment)).count()
+}
+
+fn symbols(exchange: &MockGateio) -> Vec<String> {
+    serde_json::from_value(exchange.get("symbols".into()).unwrap_json().clone()).unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_callers_share_one_load() {
+    let recorder = recorder();
+    let exchange = SharedExchange::new(Box::new(MockGateio::new(Value::Undefined, recorder.clone())));
+    let tasks: Vec<_> = (0..10).map(|_| {
+        let exchange = exchange.clone();
+        tokio::spawn(async move { exchange.load_markets(Value::Undefined, Value::Undefined).await })
+    }).collect();
+    for task in tasks {
+        assert!(task.await.unwrap().get("BTC/USDT".into()).is_object());
+    }
+    assert_eq!(count(&recorder, "/spot/currency_pairs"), 1);
+    assert!(exchange.get_path(&["marketsLoadedAt".into()]).is_number());
+}
+
+#[tokio::test]
+async fn reload_markets_swaps_in_new_listings() {
+    let mut exchange = MockGateio::new(Value::Undefined, recorder());
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert_eq!(count(&exchange.1, "/spot/currency_pairs"), 1);
+    assert_eq!(symbols(&exchange), ["BTC/USDT"]);
+
+    exchange.1.respond("GET", "/spot/currency_pairs", ETH_USDT);
+    let markets = exchange.reload_markets(Value::Undefined).await;
+    assert!(markets.get("ETH/USDT".into()).is_object());
+    assert!(markets.get("BTC/USDT".into()).is_undefined());
+    assert_eq!(symbols(&exchange), ["ETH/USDT"]);
+    assert!(exchange.get("markets_by_id".into()).get("ETH_USDT".into()).is_object());
+}
+
+#[tokio::test]
+async fn reloads_markets_older_than_the_interval() {
+    let mut exchange = MockGateio::new(Value::Json(json!({ "marketsReloadInterval": 0 })), recorder());
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    exchange.1.respond("GET", "/spot/currency_pairs", ETH_USDT);
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert_eq!(count(&exchange.1, "/spot/currency_pairs"), 2);
+    assert_eq!(symbols(&exchange), ["ETH/USDT"]);
+
+    exchange.set("marketsReloadInterval".into(), 3600000.into());
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert_eq!(count(&exchange.1, "/spot/currency_pairs"), 2);
+}
+
+fn cache_dir(name: &str) -> String {
+    let dir = std::env::temp_dir().join(format!("ccxt-markets-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.to_str().unwrap().to_owned()
+}
+
+#[tokio::test]
+async fn reads_the_markets_saved_by_a_previous_run() {
+    let dir = cache_dir("warm");
+    let mut exchange = MockGateio::new(Value::Json(json!({ "marketsCacheDir": dir })), recorder());
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert!(std::path::Path::new(&dir).join("gateio.json").exists());
+
+    let mut exchange = MockGateio::new(Value::Json(json!({ "marketsCacheDir": dir })), common::Recorder::default());
+    let markets = Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert!(exchange.1.requests().is_empty());
+    assert!(markets.get("BTC/USDT".into()).is_object());
+    assert_eq!(symbols(&exchange), ["BTC/USDT"]);
+    assert!(exchange.get("currencies".into()).get("BTC".into()).is_object());
+    assert!(exchange.get("marketsLoadedAt".into()).is_number());
+}
+
+#[tokio::test]
+async fn ignores_expired_and_sandbox_cache_files() {
+    let dir = cache_dir("cold");
+    let mut exchange = MockGateio::new(Value::Json(json!({ "marketsCacheDir": dir })), recorder());
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+
+    let mut exchange = MockGateio::new(Value::Json(json!({ "marketsCacheDir": dir, "marketsCacheMaxAge": 0 })), recorder());
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert_eq!(count(&exchange.1, "/spot/currency_pairs"), 1);
+
+    let mut exchange = MockGateio::new(Value::Json(json!({ "marketsCacheDir": dir })), recorder());
+    let api = exchange.get("urls".into()).get("api".into());
+    Exchange::set_path(&mut exchange, &["urls".into(), "test".into()], api);
+    Exchange::set_sandbox_mode(&mut exchange, true);
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    assert_eq!(count(&exchange.1, "/spot/currency_pairs"), 1);
+    assert!(std::path::Path::new(&dir).join("gateio-sandbox.json").exists());
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "mexc3")]
+
+mod common;
+
+use ccxt::exchange::{normalize, Value, ValueTrait};
+use ccxt::mexc3::Mexc3;
+
+mock_exchange!(MockMexc3, ccxt::mexc3::Mexc3);
+
+fn recorder() -> common::Recorder {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/open/api/v2/market/coin/list", include_str!("fixtures/mexc3/coin_list.json"));
+    recorder.respond("GET", "/api/v3/exchangeInfo", include_str!("fixtures/mexc3/exchange_info.json"));
+    recorder.respond("GET", "/api/v1/contract/detail", include_str!("fixtures/mexc3/contract_detail.json"));
+    recorder.respond("GET", "/api/v3/depth", include_str!("fixtures/mexc3/spot_depth.json"));
+    recorder.respond("GET", "/api/v1/contract/depth/BTC_USDT", include_str!("fixtures/mexc3/contract_depth.json"));
+    recorder
+}
+
+#[test]
+fn describes_itself() {
+    let exchange = MockMexc3::new(Value::Undefined, recorder());
+    assert_eq!(exchange.get("id".into()), "mexc3".into());
+    assert!(exchange.get("has".into()).get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn fetches_spot_and_swap_markets() {
+    let mut exchange = MockMexc3::new(Value::Undefined, recorder());
+    let markets = normalize(&Mexc3::fetch_markets(&mut exchange, Value::Undefined).await).unwrap();
+    let symbols: Vec<&str> = markets.as_array().unwrap().iter().map(|m| m["symbol"].as_str().unwrap()).collect();
+    assert_eq!(symbols, vec!["BTC/USDT", "BTC/USDT:USDT"]);
+    assert_eq!(markets[0]["id"], "BTCUSDT");
+    assert_eq!(markets[0]["precision"]["amount"].as_f64(), Some(0.000001));
+    assert_eq!(markets[0]["precision"]["price"].as_f64(), Some(0.01));
+    assert_eq!(markets[1]["id"], "BTC_USDT");
+    assert_eq!(markets[1]["contractSize"].as_f64(), Some(0.0001));
+    assert_eq!(markets[1]["active"], true);
+}
+
+#[tokio::test]
+async fn fetches_spot_order_book() {
+    let mut exchange = MockMexc3::new(Value::Undefined, recorder());
+    let book = normalize(&Mexc3::fetch_order_book(&mut exchange, "BTC/USDT".into(), 5.into(), Value::Undefined).await).unwrap();
+    assert_eq!(exchange.1.last_request().url, "https://api.mexc.com/api/v3/depth?symbol=BTCUSDT&limit=5");
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["nonce"], 744267132);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(40838.5));
+    assert_eq!(book["asks"][1][1].as_f64(), Some(0.498));
+}
+
+#[tokio::test]
+async fn fetches_swap_order_book() {
+    let mut exchange = MockMexc3::new(Value::Undefined, recorder());
+    let book = normalize(&Mexc3::fetch_order_book(&mut exchange, "BTC/USDT:USDT".into(), Value::Undefined, Value::Undefined).await).unwrap();
+    assert_eq!(book["symbol"], "BTC/USDT:USDT");
+    assert_eq!(book["timestamp"], 1634117846232i64);
+    assert_eq!(book["nonce"], 2827730444i64);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(3445.55));
+    assert_eq!(book["bids"][0][1].as_f64(), Some(44081.0));
+}
+
+#[tokio::test]
+#[should_panic(expected = "ExchangeError")]
+async fn raises_on_error_responses() {
+    let mut recorder = recorder();
+    recorder.respond("GET", "/api/v1/contract/depth/BTC_USDT", include_str!("fixtures/mexc3/error.json"));
+    let mut exchange = MockMexc3::new(Value::Undefined, recorder);
+    Mexc3::fetch_order_book(&mut exchange, "BTC/USDT:USDT".into(), Value::Undefined, Value::Undefined).await;
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use ccxt::exchange::{build_ohlcvc, timeframe_seconds, Value};
+use serde_json::json;
+#[cfg(feature = "gateio")]
+use ccxt::{exchange::Exchange, gate::Gate, UnifiedExchange};
+
+#[cfg(feature = "gateio")]
+// the unified methods the exchanges get, fetchOHLCV emulated from fetchTrades where `has` says so
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate => { Gate => [load_markets, fetch_ohlcv, fetch_trades] });
+
+#[test]
+fn parses_every_timeframe_unit() {
+    for (timeframe, seconds) in [
+        ("1s", 1), ("1m", 60), ("15m", 900), ("4h", 14400), ("1d", 86400), ("1w", 604800), ("1M", 2592000), ("1y", 31536000),
+    ] {
+        assert_eq!(timeframe_seconds(timeframe), Some(seconds), "{}", timeframe);
+    }
+    for timeframe in ["", "m", "1", "1x", "1H", "-m"] {
+        assert_eq!(timeframe_seconds(timeframe), None, "{}", timeframe);
+    }
+}
+
+#[test]
+#[should_panic(expected = "NotSupported")]
+fn rejects_unknown_timeframes() {
+    build_ohlcvc(json!([]).into(), "1x".into(), Value::Undefined, Value::Undefined);
+}
+
+fn trades() -> Value {
+    json!([
+        {"timestamp": 60000, "price": 10.0, "amount": 1.0},
+        {"timestamp": 60500, "price": 12.0, "amount": 2.0},
+        {"timestamp": 119999, "price": 9.0, "amount": 0.5},
+        {"timestamp": 180000, "price": "11", "amount": "1.5"},
+        {"timestamp": 181000, "price": 13.0},
+    ]).into()
+}
+
+#[test]
+fn builds_candles_with_trade_counts() {
+    assert_eq!(build_ohlcvc(trades(), "1m".into(), Value::Undefined, Value::Undefined), json!([
+        [60000, 10.0, 12.0, 9.0, 9.0, 3.5, 3],
+        [180000, 11.0, 11.0, 11.0, 11.0, 1.5, 1],
+    ]).into());
+    assert_eq!(build_ohlcvc(trades(), "5m".into(), Value::Undefined, Value::Undefined), json!([
+        [0, 10.0, 12.0, 9.0, 11.0, 5.0, 4],
+    ]).into());
+    // trades before since are left out, also from the candle since falls in, and limit caps the trades
+    assert_eq!(build_ohlcvc(trades(), "1m".into(), 60001.into(), Value::Undefined), json!([
+        [60000, 12.0, 12.0, 9.0, 9.0, 2.5, 2],
+        [180000, 11.0, 11.0, 11.0, 11.0, 1.5, 1],
+    ]).into());
+    assert_eq!(build_ohlcvc(trades(), "1m".into(), 120000.into(), Value::Undefined), json!([
+        [180000, 11.0, 11.0, 11.0, 11.0, 1.5, 1],
+    ]).into());
+    assert_eq!(build_ohlcvc(trades(), "1m".into(), Value::Undefined, 2.into()), json!([
+        [60000, 10.0, 12.0, 10.0, 12.0, 3.0, 2],
+    ]).into());
+    assert_eq!(build_ohlcvc(json!([]).into(), "1m".into(), Value::Undefined, Value::Undefined), json!([]).into());
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn emulates_ohlcv_from_trades() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder.respond("GET", "/spot/trades", include_str!("fixtures/gateio/trades.json"));
+    let mut exchange = MockGateio::new(Value::Undefined, recorder.clone());
+    Exchange::set_path(&mut exchange, &["has".into(), "fetchOHLCV".into()], "emulated".into());
+
+    let ohlcv = UnifiedExchange::fetch_ohlcv(&mut exchange, "BTC/USDT".into(), "1m".into(), Value::Undefined, Value::Undefined, Value::Undefined).await;
+    assert_eq!(ohlcv, json!([
+        [1662381000000i64, 19800.0, 19850.5, 19790.0, 19790.0, 1.75],
+        [1662381060000i64, 19810.0, 19810.0, 19810.0, 19810.0, 0.1],
+    ]).into());
+    assert!(recorder.requests().iter().any(|r| r.url.contains("/spot/trades")));
+    // the base trait cannot reach the exchange's fetch_trades and does not emulate
+    let ohlcv = ccxt::errors::catch(Exchange::fetch_ohlcv(&mut exchange, "BTC/USDT".into(), "1m".into(), Value::Undefined, Value::Undefined, Value::Undefined)).await;
+    assert!(ohlcv.unwrap_err().is("NotSupported"));
+
+    // without trades there is nothing to build the candles from
+    Exchange::set_path(&mut exchange, &["has".into(), "fetchTrades".into()], false.into());
+    let ohlcv = ccxt::errors::catch(UnifiedExchange::fetch_ohlcv(&mut exchange, "BTC/USDT".into(), "1m".into(), Value::Undefined, Value::Undefined, Value::Undefined)).await;
+    assert!(ohlcv.unwrap_err().is("NotSupported"));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "okx")]
+
+mod common;
+
+use ccxt::exchange::{normalize, Value, ValueTrait};
+use ccxt::okx::Okx;
+
+mock_exchange!(MockOkx, ccxt::okx::Okx);
+
+fn recorder() -> common::Recorder {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "?instType=SPOT", include_str!("fixtures/okx/spot_instruments.json"));
+    recorder.respond("GET", "?instType=SWAP", include_str!("fixtures/okx/swap_instruments.json"));
+    recorder.respond("GET", "?instType=FUTURES", include_str!("fixtures/okx/empty_instruments.json"));
+    recorder.respond("GET", "?instType=OPTION", include_str!("fixtures/okx/empty_instruments.json"));
+    recorder.respond("GET", "/api/v5/market/books", include_str!("fixtures/okx/books.json"));
+    recorder
+}
+
+#[test]
+fn describes_itself() {
+    let exchange = MockOkx::new(Value::Undefined, recorder());
+    assert_eq!(exchange.get("id".into()), "okx".into());
+    assert!(exchange.get("has".into()).get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn fetches_spot_and_swap_markets() {
+    let mut exchange = MockOkx::new(Value::Undefined, recorder());
+    let markets = normalize(&Okx::fetch_markets(&mut exchange, Value::Undefined).await).unwrap();
+    let symbols: Vec<&str> = markets.as_array().unwrap().iter().map(|m| m["symbol"].as_str().unwrap()).collect();
+    assert_eq!(symbols, vec!["BTC/USDT", "BTC/USDT:USDT"]);
+    assert_eq!(exchange.1.requests()[3].url, "https://www.okx.com/api/v5/public/instruments?instType=OPTION&uly=BTC-USD");
+    assert_eq!(markets[0]["margin"], true);
+    assert_eq!(markets[0]["precision"]["price"].as_f64(), Some(0.1));
+    assert_eq!(markets[0]["precision"]["amount"].as_f64(), Some(0.00000001));
+    assert_eq!(markets[1]["id"], "BTC-USDT-SWAP");
+    assert_eq!(markets[1]["linear"], true);
+    assert_eq!(markets[1]["contractSize"].as_f64(), Some(0.01));
+    assert_eq!(markets[1]["limits"]["leverage"]["max"].as_f64(), Some(125.0));
+}
+
+#[tokio::test]
+async fn fetches_order_book() {
+    let mut exchange = MockOkx::new(Value::Undefined, recorder());
+    let book = normalize(&Okx::fetch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await).unwrap();
+    assert_eq!(exchange.1.last_request().url, "https://www.okx.com/api/v5/market/books?instId=BTC-USDT&sz=2");
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["timestamp"], 1629966436396i64);
+    assert_eq!(book["bids"][0][0].as_f64(), Some(41006.3));
+    assert_eq!(book["asks"][1][1].as_f64(), Some(0.0001));
+}
+
+#[tokio::test]
+#[should_panic(expected = "ExchangeError")]
+async fn raises_on_error_responses() {
+    let mut recorder = recorder();
+    recorder.respond("GET", "/api/v5/market/books", include_str!("fixtures/okx/error.json"));
+    let mut exchange = MockOkx::new(Value::Undefined, recorder);
+    Okx::fetch_order_book(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined).await;
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "pro")]
+
+use ccxt::exchange::{normalize, Value};
+use ccxt::pro::order_book::{Delta, Level, LocalOrderBook};
+use serde_json::json;
+
+fn levels(levels: serde_json::Value) -> Vec<Level> {
+    Level::parse_all(&levels, 0, 1)
+}
+
+fn delta(first: i64, last: i64, bids: serde_json::Value, asks: serde_json::Value) -> Delta {
+    Delta { first: Some(first), last, bids: levels(bids), asks: levels(asks), ..Default::default() }
+}
+
+fn book(depth: Option<usize>) -> LocalOrderBook {
+    let mut book = LocalOrderBook::new("BTC/USDT", depth);
+    book.reset(&Value::Json(json!({
+        "symbol": "BTC/USDT",
+        "bids": [[100.0, 1.0], [98.0, 1.0]],
+        "asks": [[101.0, 1.0], [103.0, 1.0]],
+        "timestamp": 1672515782136u64,
+        "nonce": 10,
+    })));
+    book
+}
+
+#[test]
+fn sides_stay_sorted_as_levels_come_and_go() {
+    let mut book = book(None);
+    book.apply(&delta(11, 12, json!([["99", "2"], ["101.5", "1"], ["98", "0"]]), json!([["102", "3"], ["101", "0"], ["104", "1"]]))).unwrap();
+    let prices = |levels: &[Level]| levels.iter().map(|level| level.price).collect::<Vec<_>>();
+    assert_eq!(prices(book.bids.levels()), vec![101.5, 100.0, 99.0]);
+    assert_eq!(prices(book.asks.levels()), vec![102.0, 103.0, 104.0]);
+    book.apply(&delta(13, 13, json!([["100", "5"]]), json!([]))).unwrap();
+    assert_eq!(book.bids.levels()[1].amount, 5.0);
+
+    let orderbook = normalize(&book.order_book(&2.into())).unwrap();
+    assert_eq!(orderbook["symbol"], "BTC/USDT");
+    assert_eq!(orderbook["nonce"], 13);
+    assert_eq!(orderbook["datetime"], "2022-12-31T19:43:02.136Z");
+    assert_eq!(orderbook["bids"], json!([[101.5, 1.0], [100.0, 5.0]]));
+    assert_eq!(orderbook["asks"], json!([[102.0, 3.0], [103.0, 1.0]]));
+}
+
+#[test]
+fn updates_have_to_follow_the_book() {
+    let mut book = book(None);
+    // older than the snapshot
+    assert_eq!(book.apply(&delta(5, 10, json!([["97", "1"]]), json!([]))), Ok(false));
+    // the first update may start before the snapshot but has to reach past it
+    assert_eq!(book.apply(&delta(8, 12, json!([]), json!([]))), Ok(true));
+    assert_eq!(book.apply(&delta(13, 15, json!([]), json!([]))), Ok(true));
+    let error = book.apply(&delta(17, 18, json!([]), json!([]))).unwrap_err();
+    assert!(error.is("InvalidNonce"));
+
+    let mut book = self::book(None);
+    assert!(book.apply(&delta(12, 13, json!([]), json!([]))).unwrap_err().is("InvalidNonce"));
+}
+
+#[test]
+fn updates_can_name_the_one_before_them() {
+    let mut book = book(None);
+    let futures = |first: i64, last: i64, previous: i64| Delta { first: Some(first), last, previous: Some(previous), ..Default::default() };
+    assert_eq!(book.apply(&futures(9, 20, 8)), Ok(true));
+    assert_eq!(book.apply(&futures(25, 30, 20)), Ok(true));
+    assert!(book.apply(&futures(35, 40, 31)).unwrap_err().is("InvalidNonce"));
+}
+
+#[test]
+fn depth_is_bounded() {
+    let mut book = book(Some(2));
+    book.apply(&delta(11, 11, json!([["99", "1"], ["99.5", "1"]]), json!([["100.5", "1"]]))).unwrap();
+    assert_eq!(book.bids.levels().len(), 2);
+    assert_eq!(book.bids.levels()[1].price, 99.5);
+    assert_eq!(book.asks.levels().iter().map(|level| level.price).collect::<Vec<_>>(), vec![100.5, 101.0]);
+}
@@ -0,0 +1,161 @@
+#![cfg(all(feature = "binance", feature = "okx", feature = "phemex"))]
+
+mod common;
+
+use ccxt::binance::Binance;
+use ccxt::exchange::{Exchange, Value};
+use ccxt::okx::Okx;
+use ccxt::phemex::Phemex;
+use ccxt::types::{Decimal, OrderSide, OrderStatus, OrderType, TimeInForce};
+use ccxt::{OrderRequest, UnifiedExchange};
+use serde_json::json;
+use std::str::FromStr;
+
+mock_exchange!(MockPhemex, ccxt::phemex::Phemex => { Phemex => [create_order] });
+mock_exchange!(MockBinance, ccxt::binance::Binance => { Binance => [create_order] });
+mock_exchange!(MockOkx, ccxt::okx::Okx => { Okx => [create_order] });
+
+fn phemex() -> MockPhemex {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/cfg/v2/products", include_str!("fixtures/phemex/v2_products.json"));
+    recorder.respond("GET", "/exchange/public/products", include_str!("fixtures/phemex/v1_products.json"));
+    recorder.respond("POST", "/spot/orders", include_str!("fixtures/phemex/spot_order.json"));
+    MockPhemex::new(Value::Json(json!({
+        "apiKey": "phemex-api-key",
+        "secret": "phemex-secret",
+    })), recorder)
+}
+
+fn binance() -> MockBinance {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/v3/exchangeInfo", include_str!("fixtures/binance/exchange_info.json"));
+    recorder.respond("POST", "/api/v3/order", r#"{"symbol": "BTCUSDT", "orderId": 28, "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+        "transactTime": 1507725176595, "price": "0.00000000", "origQty": "0.50000000", "executedQty": "0.50000000",
+        "cummulativeQuoteQty": "10000.00000000", "status": "FILLED", "timeInForce": "GTC", "type": "MARKET", "side": "BUY"}"#);
+    let mut exchange = MockBinance::new(Value::Json(json!({
+        "apiKey": "binance-api-key",
+        "secret": "binance-secret",
+    })), recorder);
+    exchange.set_path(&["options".into(), "fetchCurrencies".into()], false.into());
+    exchange
+}
+
+fn okx() -> MockOkx {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "?instType=SPOT", include_str!("fixtures/okx/spot_instruments.json"));
+    recorder.respond("GET", "?instType=SWAP", include_str!("fixtures/okx/swap_instruments.json"));
+    recorder.respond("GET", "?instType=FUTURES", include_str!("fixtures/okx/empty_instruments.json"));
+    recorder.respond("GET", "?instType=OPTION", include_str!("fixtures/okx/empty_instruments.json"));
+    recorder.respond("GET", "/api/v5/asset/currencies", r#"{"code": "0", "msg": "", "data": []}"#);
+    recorder.respond("POST", "/api/v5/trade/batch-orders", r#"{"code": "0", "msg": "", "data": [{"clOrdId": "", "ordId": "312269865356374016", "tag": "", "sCode": "0", "sMsg": ""}]}"#);
+    MockOkx::new(Value::Json(json!({
+        "apiKey": "okx-api-key",
+        "secret": "okx-secret",
+        "password": "okx-password",
+    })), recorder)
+}
+
+fn decimal(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+#[tokio::test]
+async fn places_a_typed_order() {
+    let mut exchange = phemex();
+    let request = OrderRequest::limit("BTC/USDT", OrderSide::Buy, decimal("0.123456"), decimal("20000.12")).client_order_id("my-order-1");
+    let order = exchange.place_order(&request).await.unwrap();
+
+    let body: serde_json::Value = serde_json::from_str(exchange.1.last_request().body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["clOrdID"], "my-order-1");
+    assert_eq!(body["baseQtyEv"], 12345600);
+    assert_eq!(body["priceEp"], 2000012000000i64);
+    assert_eq!(order.status, Some(OrderStatus::Open));
+    assert_eq!(order.r#type, Some(OrderType::Limit));
+    assert_eq!(order.price, Some(decimal("20000.12")));
+}
+
+#[tokio::test]
+async fn binance_market_orders_spend_amount_times_price() {
+    let mut exchange = binance();
+    let request = OrderRequest::market("BTC/USDT", OrderSide::Buy, decimal("0.5")).price(decimal("20000"));
+    let order = exchange.place_order(&request).await.unwrap();
+    let body = exchange.1.last_request().body.unwrap();
+    assert!(body.contains("type=MARKET"));
+    assert!(body.contains("quoteOrderQty=10000"));
+    assert!(!body.contains("quantity="));
+    assert_eq!(order.status, Some(OrderStatus::Closed));
+    // without quoteOrderQty the exchange never reads the price
+    exchange.set_path(&["options".into(), "quoteOrderQty".into()], false.into());
+    let request = OrderRequest::market("BTC/USDT", OrderSide::Sell, decimal("0.5")).price(decimal("20000"));
+    exchange.place_order(&request).await.unwrap();
+    let body = exchange.1.last_request().body.unwrap();
+    assert!(body.contains("quantity=0.5"));
+    assert!(!body.contains("price="));
+}
+
+#[tokio::test]
+async fn okx_market_buys_spend_amount_times_price_when_asked_to() {
+    let mut exchange = okx();
+    // by default the amount of a quote_ccy market buy is the cost and the price is dropped
+    let request = OrderRequest::market("BTC/USDT", OrderSide::Buy, decimal("0.5")).price(decimal("20000.5")).param("tgtCcy", json!("quote_ccy"));
+    let order = exchange.place_order(&request).await.unwrap();
+    let body: serde_json::Value = serde_json::from_str(exchange.1.last_request().body.as_ref().unwrap()).unwrap();
+    assert_eq!(body[0]["ordType"], "market");
+    assert_eq!(body[0]["tgtCcy"], "quote_ccy");
+    assert_eq!(body[0]["sz"], "0.5");
+    assert!(body[0].get("px").is_none());
+    assert_eq!(order.id.as_deref(), Some("312269865356374016"));
+    exchange.set_path(&["options".into(), "createMarketBuyOrderRequiresPrice".into()], true.into());
+    exchange.place_order(&request).await.unwrap();
+    let body: serde_json::Value = serde_json::from_str(exchange.1.last_request().body.as_ref().unwrap()).unwrap();
+    assert_eq!(body[0]["sz"], "10000.25");
+}
+
+#[tokio::test]
+async fn rejects_invalid_orders_before_sending() {
+    let mut exchange = phemex();
+    let request = OrderRequest::market("BTC/USDT", OrderSide::Sell, decimal("1")).post_only();
+    let error = exchange.place_order(&request).await.unwrap_err();
+    assert_eq!(error.kind, "InvalidOrder");
+    assert!(exchange.1.requests().is_empty());
+}
+
+#[test]
+fn validates_combinations() {
+    let amount = decimal("1");
+    let price = decimal("100");
+    assert_eq!(OrderRequest::new("BTC/USDT", OrderType::Limit, OrderSide::Buy, amount).validate().unwrap_err().kind, "ArgumentsRequired");
+    assert_eq!(OrderRequest::limit("BTC/USDT", OrderSide::Buy, Decimal::ZERO, price).validate().unwrap_err().kind, "InvalidOrder");
+    let ioc = OrderRequest::limit("BTC/USDT", OrderSide::Buy, amount, price).post_only().time_in_force(TimeInForce::ImmediateOrCancel);
+    assert_eq!(ioc.validate().unwrap_err().message, "postOnly orders cannot have timeInForce equal to IOC");
+    let market_po = OrderRequest::market("BTC/USDT", OrderSide::Buy, amount).time_in_force(TimeInForce::PostOnly);
+    assert_eq!(market_po.validate().unwrap_err().kind, "InvalidOrder");
+    let both = OrderRequest::market("BTC/USDT", OrderSide::Buy, amount).stop_price(price).trigger_price(price);
+    assert_eq!(both.validate().unwrap_err().kind, "InvalidOrder");
+    let raw_post_only = OrderRequest::limit("BTC/USDT", OrderSide::Buy, amount, price).param("postOnly", json!(true));
+    assert_eq!(raw_post_only.validate().unwrap_err().kind, "BadRequest");
+    let raw_ioc = OrderRequest::limit("BTC/USDT", OrderSide::Buy, amount, price).post_only().param("timeInForce", json!("IOC"));
+    assert_eq!(raw_ioc.validate().unwrap_err().message, "createOrder() takes timeInForce through time_in_force(), not param()");
+}
+
+#[test]
+fn compiles_down_to_unified_params() {
+    let request = OrderRequest::limit("BTC/USDT:USDT", OrderSide::Sell, decimal("2"), decimal("41000.5"))
+        .time_in_force(TimeInForce::GoodTillCancel)
+        .reduce_only()
+        .take_profit(decimal("39000"))
+        .stop_loss(decimal("42000.25"))
+        .param("positionSide", json!("short"));
+    let args = request.to_unified().unwrap();
+    assert_eq!(args.symbol, "BTC/USDT:USDT".into());
+    assert_eq!((args.r#type, args.side), ("limit".into(), "sell".into()));
+    assert_eq!(args.amount, 2.into());
+    assert_eq!(args.price, 41000.5.into());
+    assert_eq!(args.params, Value::Json(json!({
+        "timeInForce": "GTC",
+        "reduceOnly": true,
+        "takeProfitPrice": 39000,
+        "stopLossPrice": 42000.25,
+        "positionSide": "short",
+    })));
+}
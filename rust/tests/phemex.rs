@@ -0,0 +1,121 @@
+#![cfg(feature = "phemex")]
+
+mod common;
+
+use ccxt::exchange::{normalize, Exchange, Value};
+use ccxt::phemex::Phemex;
+use serde_json::json;
+
+mock_exchange!(MockPhemex, ccxt::phemex::Phemex);
+
+fn phemex() -> MockPhemex {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/cfg/v2/products", include_str!("fixtures/phemex/v2_products.json"));
+    recorder.respond("GET", "/exchange/public/products", include_str!("fixtures/phemex/v1_products.json"));
+    recorder.respond("POST", "/spot/orders", include_str!("fixtures/phemex/spot_order.json"));
+    recorder.respond("POST", "/orders", include_str!("fixtures/phemex/swap_order.json"));
+    MockPhemex::new(Value::Json(json!({
+        "apiKey": "phemex-api-key",
+        "secret": "phemex-secret",
+    })), recorder)
+}
+
+fn body(request: &common::RecordedRequest) -> serde_json::Value {
+    serde_json::from_str(request.body.as_ref().expect("request has no body")).unwrap()
+}
+
+#[tokio::test]
+async fn creates_spot_limit_order_with_scaled_values() {
+    let mut exchange = phemex();
+    let order = Phemex::create_order(&mut exchange, "BTC/USDT".into(), "limit".into(), "buy".into(), json!(0.123456).into(), json!(20000.12).into(), Value::Undefined).await;
+
+    let request = exchange.1.last_request();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.url, "https://api.phemex.com/spot/orders");
+    assert_eq!(request.headers["x-phemex-access-token"], "phemex-api-key");
+    let expiry = request.headers["x-phemex-request-expiry"].as_str().unwrap();
+    let auth = format!("/spot/orders{}{}", expiry, request.body.as_ref().unwrap());
+    assert_eq!(request.headers["x-phemex-request-signature"], common::hmac_sha256_hex("phemex-secret", &auth));
+    let body = body(&request);
+    assert_eq!(body["symbol"], "sBTCUSDT");
+    assert_eq!(body["side"], "Buy");
+    assert_eq!(body["ordType"], "Limit");
+    assert_eq!(body["qtyType"], "ByBase");
+    assert_eq!(body["baseQtyEv"], 12345600);
+    assert_eq!(body["priceEp"], 2000012000000i64);
+    assert!(body["clOrdID"].as_str().unwrap().starts_with("ccxt2022"));
+
+    let order = normalize(&order).unwrap();
+    assert_eq!(order["id"], "d1d09454-cabc-4a23-89a7-59d43363f16d");
+    assert_eq!(order["symbol"], "BTC/USDT");
+    assert_eq!(order["side"], "buy");
+    assert_eq!(order["type"], "limit");
+    assert_eq!(order["status"], "open");
+    assert_eq!(order["price"].as_f64(), Some(20000.12));
+    assert_eq!(order["amount"].as_f64(), Some(0.123456));
+}
+
+#[tokio::test]
+async fn creates_contract_limit_order_with_scaled_price() {
+    let mut exchange = phemex();
+    let order = Phemex::create_order(&mut exchange, "BTC/USD:BTC".into(), "limit".into(), "sell".into(), json!(25).into(), json!(20000.5).into(), Value::Undefined).await;
+
+    let request = exchange.1.last_request();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.url, "https://api.phemex.com/orders");
+    let body = body(&request);
+    assert_eq!(body["symbol"], "BTCUSD");
+    assert_eq!(body["side"], "Sell");
+    assert_eq!(body["ordType"], "Limit");
+    assert_eq!(body["orderQty"], 25);
+    assert_eq!(body["priceEp"], 200005000);
+    assert!(body.get("baseQtyEv").is_none());
+
+    let order = normalize(&order).unwrap();
+    assert_eq!(order["id"], "7a1ad384-44a3-4e54-a102-de4195a29e32");
+    assert_eq!(order["symbol"], "BTC/USD:BTC");
+    assert_eq!(order["side"], "sell");
+    assert_eq!(order["price"].as_f64(), Some(20000.5));
+    assert_eq!(order["amount"].as_f64(), Some(25.0));
+}
+
+#[tokio::test]
+async fn scaled_integers_do_not_lose_precision() {
+    let mut exchange = phemex();
+    Phemex::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    let spot = Exchange::market(&exchange, "BTC/USDT".into());
+
+    // 2^53 + 1 is the first integer an f64 cannot represent
+    let ep = Phemex::to_ep(&exchange, "90071992.54740993".into(), spot.clone());
+    assert_eq!(normalize(&ep).unwrap(), json!(9007199254740993i64));
+    let price = Phemex::from_ep(&exchange, ep, spot.clone());
+    assert_eq!(normalize(&price).unwrap(), json!("90071992.54740993"));
+
+    // digits beyond the scale are truncated rather than rounded
+    let ev = Phemex::to_ev(&exchange, "0.123456789".into(), spot.clone());
+    assert_eq!(normalize(&ev).unwrap(), json!(12345678));
+
+    let er = Phemex::from_er(&exchange, json!(100000).into(), spot);
+    assert_eq!(normalize(&er).unwrap(), json!("0.001"));
+}
+
+#[test]
+fn integer_products_uuids_and_lowercase_are_exact() {
+    let exchange = phemex();
+    let order = Value::Json(json!({ "priceEp": "9007199254740993", "qty": 2, "side": null }));
+    let product = Exchange::safe_integer_product(&exchange, order.clone(), "priceEp".into(), json!(1).into(), Value::Undefined);
+    assert_eq!(normalize(&product).unwrap(), json!(9007199254740993i64));
+    let product = Exchange::safe_integer_product(&exchange, order.clone(), "qty".into(), json!(0.5).into(), Value::Undefined);
+    assert_eq!(normalize(&product).unwrap(), json!(1));
+    let missing = Exchange::safe_integer_product(&exchange, order.clone(), "side".into(), json!(10).into(), json!(7).into());
+    assert_eq!(normalize(&missing).unwrap(), json!(7));
+
+    let length = |v: Value| normalize(&v).unwrap().as_str().unwrap().len();
+    assert_eq!(length(Exchange::uuid16(&exchange, Value::Undefined)), 16);
+    assert_eq!(length(Exchange::uuid16(&exchange, json!(7).into())), 7);
+    assert_eq!(length(Exchange::uuid22(&exchange, json!(9).into())), 9);
+
+    assert_eq!(normalize(&Value::from("BTC").to_lower_case()).unwrap(), json!("btc"));
+    assert_eq!(normalize(&Value::Json(json!(5)).to_lower_case()).unwrap(), json!(5));
+    assert!(matches!(Value::Undefined.to_lower_case(), Value::Undefined));
+}
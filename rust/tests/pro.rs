@@ -0,0 +1,754 @@
+#![cfg(feature = "pro")]
+
+mod common;
+
+use ccxt::exchange::{normalize, Exchange, Value, ValueTrait};
+use ccxt::pro::client::{self, Event, Incoming, Protocol, StreamingConfig, Topic};
+use futures::{SinkExt, StreamExt};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+#[cfg(feature = "binance")]
+use ccxt::pro::binance::{BinancePro, BinanceProtocol};
+#[cfg(feature = "gateio")]
+use ccxt::pro::gate::{GatePro, GateProtocol};
+
+#[cfg(feature = "binance")]
+mock_exchange!(MockBinance, ccxt::binance::Binance, ccxt::pro::binance::BinancePro);
+#[cfg(feature = "gateio")]
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate, ccxt::pro::gate::GatePro);
+
+type Socket = WebSocketStream<TcpStream>;
+
+/// A websocket server on a free local port handing out the connections it accepts
+async fn server() -> (String, mpsc::UnboundedReceiver<Socket>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}/stream", listener.local_addr().unwrap());
+    let (sockets, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            if let Ok(socket) = tokio_tungstenite::accept_async(stream).await {
+                let _ = sockets.send(socket);
+            }
+        }
+    });
+    (url, receiver)
+}
+
+async fn timeout<T>(future: impl std::future::Future<Output = T>) -> T {
+    tokio::time::timeout(Duration::from_secs(5), future).await.expect("timed out")
+}
+
+/// The next JSON message the client sent, skipping pings
+async fn receive(socket: &mut Socket) -> serde_json::Value {
+    loop {
+        match timeout(socket.next()).await.expect("connection closed").unwrap() {
+            Message::Text(text) => return serde_json::from_str(&text).unwrap(),
+            Message::Close(_) => panic!("connection closed"),
+            _ => {}
+        }
+    }
+}
+
+async fn send(socket: &mut Socket, message: serde_json::Value) {
+    socket.send(Message::Text(message.to_string())).await.unwrap();
+}
+
+#[cfg(feature = "binance")]
+fn binance(url: &str) -> MockBinance {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/v3/exchangeInfo", include_str!("fixtures/binance/exchange_info.json"));
+    let mut exchange = MockBinance::new(Value::Undefined, recorder);
+    exchange.set_path(&["urls".into(), "api".into(), "ws".into(), "spot".into()], url.into());
+    exchange.set_path(&["options".into(), "retry".into()], Value::Json(json!({"baseDelay": 1, "maxDelay": 10})));
+    exchange
+}
+
+#[cfg(feature = "gateio")]
+fn gateio(url: &str) -> MockGateio {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder.respond("GET", "/futures/usdt/contracts", include_str!("fixtures/gateio/usdt_contracts.json"));
+    recorder.respond("GET", "/futures/btc/contracts", "[]");
+    recorder.respond("GET", "/delivery/usdt/contracts", "[]");
+    recorder.respond("GET", "/delivery/btc/contracts", "[]");
+    let mut exchange = MockGateio::new(Value::Undefined, recorder);
+    exchange.set_path(&["urls".into(), "api".into(), "ws".into(), "spot".into()], url.into());
+    exchange
+}
+
+#[cfg(feature = "binance")]
+#[test]
+fn binance_messages_are_routed_by_stream() {
+    let topic = Topic { hash: "btcusdt@trade".into(), request: "btcusdt@trade".into() };
+    assert_eq!(BinanceProtocol.subscribe(&topic, 3), json!({"method": "SUBSCRIBE", "params": ["btcusdt@trade"], "id": 3}));
+    assert_eq!(
+        BinanceProtocol.route(json!({"stream": "btcusdt@trade", "data": {"p": "1"}})),
+        Incoming::Updates(vec![("btcusdt@trade".into(), json!({"p": "1"}))]),
+    );
+    assert_eq!(BinanceProtocol.route(json!({"result": null, "id": 3})), Incoming::Reply { id: 3, error: None });
+    match BinanceProtocol.route(json!({"error": {"code": 2, "msg": "Invalid request"}, "id": 4})) {
+        Incoming::Reply { id: 4, error: Some(error) } => assert!(error.is("ExchangeError")),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[cfg(feature = "gateio")]
+#[test]
+fn gate_messages_are_routed_by_channel_and_market() {
+    let protocol = GateProtocol { prefix: "spot".into(), credentials: None };
+    assert_eq!(protocol.ping().unwrap()["channel"], "spot.ping");
+    assert_eq!(protocol.route(json!({"channel": "spot.pong", "event": ""})), Incoming::Ignore);
+    assert_eq!(
+        protocol.route(json!({"channel": "futures.trades", "event": "update", "result": [{"contract": "BTC_USDT", "id": 1}, {"contract": "ETH_USDT", "id": 2}]})),
+        Incoming::Updates(vec![
+            ("futures.trades:BTC_USDT".into(), json!({"contract": "BTC_USDT", "id": 1})),
+            ("futures.trades:ETH_USDT".into(), json!({"contract": "ETH_USDT", "id": 2})),
+        ]),
+    );
+    match protocol.route(json!({"id": 2, "channel": "spot.trades", "event": "subscribe", "error": {"code": 2, "message": "unknown currency pair"}})) {
+        Incoming::Reply { id: 2, error: Some(error) } => assert!(error.message.contains("unknown currency pair")),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn binance_trades_are_parsed() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    let mut trades = BinancePro::watch_trades(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined, Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let subscribe = receive(&mut socket).await;
+    assert_eq!(subscribe["method"], "SUBSCRIBE");
+    assert_eq!(subscribe["params"], json!(["btcusdt@trade"]));
+    send(&mut socket, json!({"result": null, "id": subscribe["id"]})).await;
+    send(&mut socket, json!({"stream": "btcusdt@trade", "data": {
+        "e": "trade", "E": 1672515782136u64, "s": "BTCUSDT", "t": 12345, "p": "16500.10", "q": "0.5", "b": 88, "a": 50,
+        "T": 1672515782134u64, "m": true, "M": true,
+    }})).await;
+    let update = normalize(&timeout(trades.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(update.as_array().unwrap().len(), 1);
+    assert_eq!(update[0]["symbol"], "BTC/USDT");
+    assert_eq!(update[0]["id"], "12345");
+    assert_eq!(update[0]["price"].as_f64(), Some(16500.1));
+    assert_eq!(update[0]["amount"].as_f64(), Some(0.5));
+    assert_eq!(update[0]["side"], "sell");
+    assert_eq!(update[0]["timestamp"].as_i64(), Some(1672515782134));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn streams_of_a_topic_share_one_subscription() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    let mut first = BinancePro::watch_ticker(&mut exchange, "BTC/USDT".into(), Value::Undefined).await;
+    let mut second = BinancePro::watch_ticker(&mut exchange.clone(), "BTC/USDT".into(), Value::Undefined).await;
+    let mut trades = BinancePro::watch_trades(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined, Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    // topics subscribed while connecting are sent in no particular order
+    let mut streams = vec![receive(&mut socket).await["params"][0].clone(), receive(&mut socket).await["params"][0].clone()];
+    streams.sort_by_key(|stream| stream.to_string());
+    assert_eq!(streams, vec![json!("btcusdt@ticker"), json!("btcusdt@trade")]);
+
+    send(&mut socket, json!({"stream": "btcusdt@ticker", "data": {
+        "e": "24hrTicker", "E": 1672515782136u64, "s": "BTCUSDT", "c": "16500.10", "b": "16500.00", "a": "16500.20",
+        "v": "1200.5", "q": "19800000", "O": 1672429382136u64, "C": 1672515782136u64,
+    }})).await;
+    for stream in [&mut first, &mut second] {
+        let ticker = normalize(&timeout(stream.next()).await.unwrap().unwrap()).unwrap();
+        assert_eq!(ticker["symbol"], "BTC/USDT");
+        assert_eq!(ticker["last"].as_f64(), Some(16500.1));
+        assert_eq!(ticker["bid"].as_f64(), Some(16500.0));
+        assert_eq!(ticker["baseVolume"].as_f64(), Some(1200.5));
+    }
+
+    send(&mut socket, json!({"stream": "btcusdt@trade", "data": {
+        "e": "trade", "E": 1672515782136u64, "s": "BTCUSDT", "t": 12345, "p": "16500.10", "q": "0.5", "T": 1672515782134u64, "m": false,
+    }})).await;
+    let update = normalize(&timeout(trades.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(update[0]["side"], "buy");
+
+    drop(first);
+    drop(trades);
+    let unsubscribe = receive(&mut socket).await;
+    assert_eq!(unsubscribe["method"], "UNSUBSCRIBE");
+    assert_eq!(unsubscribe["params"], json!(["btcusdt@trade"]));
+    drop(second);
+    let unsubscribe = receive(&mut socket).await;
+    assert_eq!(unsubscribe["params"], json!(["btcusdt@ticker"]));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn dropped_connections_are_reopened_and_subscribed_again() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    let mut candles = BinancePro::watch_ohlcv(&mut exchange, "BTC/USDT".into(), "1m".into(), Value::Undefined, Value::Undefined, Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    assert_eq!(receive(&mut socket).await["params"], json!(["btcusdt@kline_1m"]));
+    socket.close(None).await.unwrap();
+    drop(socket);
+
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    assert_eq!(receive(&mut socket).await["params"], json!(["btcusdt@kline_1m"]));
+    send(&mut socket, json!({"stream": "btcusdt@kline_1m", "data": {"e": "kline", "E": 1672515782136u64, "s": "BTCUSDT", "k": {
+        "t": 1672515780000u64, "T": 1672515839999u64, "s": "BTCUSDT", "i": "1m", "o": "16500.0", "c": "16510.5",
+        "h": "16520.0", "l": "16490.0", "v": "12.5", "x": false,
+    }}})).await;
+    let update = normalize(&timeout(candles.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(update, json!([[1672515780000u64, 16500.0, 16520.0, 16490.0, 16510.5, 12.5]]));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn rejected_subscriptions_end_with_an_error() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    let mut trades = BinancePro::watch_trades(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined, Value::Json(json!({"name": "nope"}))).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let subscribe = receive(&mut socket).await;
+    assert_eq!(subscribe["params"], json!(["btcusdt@nope"]));
+    send(&mut socket, json!({"error": {"code": 2, "msg": "Invalid request: unknown stream"}, "id": subscribe["id"]})).await;
+    let error = timeout(trades.next()).await.unwrap().unwrap_err();
+    assert!(error.is("ExchangeError"));
+    assert!(timeout(trades.next()).await.is_none());
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn connections_are_opened_by_the_first_subscription() {
+    let (url, mut sockets) = server().await;
+    let client = client::client("binance", &url, StreamingConfig::default(), || Box::new(BinanceProtocol));
+    assert!(tokio::time::timeout(Duration::from_millis(200), sockets.recv()).await.is_err());
+    let _trades = client.subscribe(Topic { hash: "btcusdt@trade".into(), request: "btcusdt@trade".into() });
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    assert_eq!(receive(&mut socket).await["params"], json!(["btcusdt@trade"]));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn streams_read_too_slowly_drop_updates_and_report_it() {
+    let (url, mut sockets) = server().await;
+    let client = client::client("binance", &url, StreamingConfig::default(), || Box::new(BinanceProtocol));
+    let mut trades = client.subscribe(Topic { hash: "btcusdt@trade".into(), request: "btcusdt@trade".into() });
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    receive(&mut socket).await;
+    for id in 0..client::SUBSCRIPTION_BUFFER + 10 {
+        send(&mut socket, json!({"stream": "btcusdt@trade", "data": {"t": id}})).await;
+    }
+    // the updates past the buffer are dropped and counted
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    for id in 0..client::SUBSCRIPTION_BUFFER {
+        match timeout(trades.next()).await.unwrap() {
+            Event::Update(update) => assert_eq!(update["t"], id),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+    send(&mut socket, json!({"stream": "btcusdt@trade", "data": {"t": "next"}})).await;
+    assert!(matches!(timeout(trades.next()).await.unwrap(), Event::Lagged(10)));
+    match timeout(trades.next()).await.unwrap() {
+        Event::Update(update) => assert_eq!(update["t"], "next"),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn idle_api_connections_are_let_go() {
+    let (url, _sockets) = server().await;
+    let config = StreamingConfig { api_idle_timeout: Duration::from_millis(200), ..StreamingConfig::default() };
+    let client = Arc::downgrade(&client::api_client("binance-api", &url, config.clone(), || Box::new(BinanceProtocol)));
+    tokio::time::sleep(Duration::from_millis(120)).await;
+    // used again, which keeps it for another timeout
+    client::api_client("binance-api", &url, config, || Box::new(BinanceProtocol));
+    tokio::time::sleep(Duration::from_millis(120)).await;
+    assert!(client.upgrade().is_some());
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(client.upgrade().is_none());
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn binance_order_books_follow_the_diff_stream_and_resync_on_gaps() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.1.respond_once("GET", "/api/v3/depth", 200, r#"{"lastUpdateId": 100, "bids": [["100.0", "1"], ["99.0", "2"]], "asks": [["101.0", "1"]]}"#);
+    exchange.1.respond("GET", "/api/v3/depth", r#"{"lastUpdateId": 200, "bids": [["100.0", "5"]], "asks": [["102.0", "1"], ["103.0", "1"]]}"#);
+    let mut books = BinancePro::watch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    assert_eq!(receive(&mut socket).await["params"], json!(["btcusdt@depth@100ms"]));
+    let update = |first: i64, last: i64, bids: serde_json::Value, asks: serde_json::Value| {
+        json!({"stream": "btcusdt@depth@100ms", "data": {"e": "depthUpdate", "E": 1672515782136u64 + last as u64, "s": "BTCUSDT", "U": first, "u": last, "b": bids, "a": asks}})
+    };
+
+    // the first update fetches the snapshot and is applied to it
+    send(&mut socket, update(95, 102, json!([["100.0", "0"]]), json!([["101.5", "3"]]))).await;
+    let book = normalize(&timeout(books.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["nonce"].as_i64(), Some(102));
+    assert_eq!(book["timestamp"].as_i64(), Some(1672515782238));
+    assert_eq!(book["bids"], json!([[99.0, 2.0]]));
+    assert_eq!(book["asks"], json!([[101.0, 1.0], [101.5, 3.0]]));
+
+    send(&mut socket, update(103, 104, json!([["99.5", "1"]]), json!([]))).await;
+    let book = normalize(&timeout(books.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(book["nonce"].as_i64(), Some(104));
+    assert_eq!(book["bids"], json!([[99.5, 1.0], [99.0, 2.0]]));
+
+    // updates 105 to 109 are missing, the book is fetched again and the update is older than it
+    send(&mut socket, update(110, 111, json!([["98.0", "1"]]), json!([]))).await;
+    let book = normalize(&timeout(books.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(book["nonce"].as_i64(), Some(200));
+    assert_eq!(book["bids"], json!([[100.0, 5.0]]));
+    assert_eq!(book["asks"], json!([[102.0, 1.0], [103.0, 1.0]]));
+
+    send(&mut socket, update(201, 202, json!([]), json!([["102.0", "0"]]))).await;
+    let book = normalize(&timeout(books.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(book["nonce"].as_i64(), Some(202));
+    assert_eq!(book["asks"], json!([[103.0, 1.0]]));
+    let snapshots = exchange.1.requests().into_iter().filter(|request| request.url.contains("/api/v3/depth")).count();
+    assert_eq!(snapshots, 2);
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn order_book_snapshots_are_fetched_one_at_a_time_and_retried() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.set_path(&["options".into(), "retry".into()], Value::Json(json!({"baseDelay": 1000, "maxDelay": 1000})));
+    exchange.1.respond_once("GET", "/api/v3/depth", 500, r#"{"code": -1000, "msg": "An unknown error occured while processing the request."}"#);
+    exchange.1.respond_once("GET", "/api/v3/depth", 200, r#"{"lastUpdateId": 100, "bids": [["100.0", "1"]], "asks": [["101.0", "1"]]}"#);
+    exchange.1.respond("GET", "/api/v3/depth", r#"{"lastUpdateId": 200, "bids": [["100.0", "2"]], "asks": [["101.0", "2"]]}"#);
+    let mut books = BinancePro::watch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    receive(&mut socket).await;
+    let update = |first: i64, last: i64| {
+        json!({"stream": "btcusdt@depth@100ms", "data": {"e": "depthUpdate", "E": 1672515782136u64, "s": "BTCUSDT", "U": first, "u": last, "b": [], "a": []}})
+    };
+    let snapshots = || exchange.1.requests().into_iter().filter(|request| request.url.contains("/api/v3/depth")).count();
+
+    // the failed snapshot is yielded, the updates that follow are buffered while the next one waits for its backoff
+    send(&mut socket, update(110, 111)).await;
+    assert!(timeout(books.next()).await.unwrap().is_err());
+    for last in (113..=131).step_by(2) {
+        send(&mut socket, update(last - 1, last)).await;
+    }
+    assert!(tokio::time::timeout(Duration::from_millis(300), books.next()).await.is_err());
+    assert_eq!(snapshots(), 1);
+
+    // the second snapshot is older than the buffered updates, the third one follows them
+    let book = normalize(&timeout(books.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(book["nonce"].as_i64(), Some(200));
+    assert_eq!(book["bids"], json!([[100.0, 2.0]]));
+    assert_eq!(snapshots(), 3);
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn order_book_updates_overflowing_the_buffer_subscribe_again() {
+    use ccxt::pro::order_book::MAX_BUFFERED_DELTAS;
+
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.set_path(&["options".into(), "retry".into()], Value::Json(json!({"baseDelay": 60000, "maxDelay": 60000})));
+    exchange.1.respond_with_status("GET", "/api/v3/depth", 500, r#"{"code": -1000, "msg": "An unknown error occured while processing the request."}"#);
+    let mut books = BinancePro::watch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    receive(&mut socket).await;
+    let update = |last: i64| {
+        json!({"stream": "btcusdt@depth@100ms", "data": {"e": "depthUpdate", "E": 1672515782136u64, "s": "BTCUSDT", "U": last, "u": last, "b": [], "a": []}})
+    };
+
+    send(&mut socket, update(1)).await;
+    assert!(timeout(books.next()).await.unwrap().is_err());
+    // the next snapshot waits a minute, the updates received meanwhile overflow the buffer
+    for last in 2..=MAX_BUFFERED_DELTAS as i64 + 1 {
+        send(&mut socket, update(last)).await;
+    }
+    let (_, resubscribe) = tokio::join!(tokio::time::timeout(Duration::from_millis(500), books.next()), receive(&mut socket));
+    assert_eq!(resubscribe["method"], "SUBSCRIBE");
+    assert_eq!(resubscribe["params"], json!(["btcusdt@depth@100ms"]));
+    assert_eq!(exchange.1.requests().into_iter().filter(|request| request.url.contains("/api/v3/depth")).count(), 1);
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn gate_order_books_are_kept_and_pinged() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = gateio(&url);
+    exchange.1.respond("GET", "/spot/order_book", include_str!("fixtures/gateio/order_book.json"));
+    exchange.set("streaming".into(), Value::Json(json!({"keepAlive": 50})));
+    let mut books = GatePro::watch_order_book(&mut exchange, "BTC/USDT".into(), 2.into(), Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let subscribe = receive(&mut socket).await;
+    assert_eq!(subscribe["channel"], "spot.order_book_update");
+    assert_eq!(subscribe["event"], "subscribe");
+    assert_eq!(subscribe["payload"], json!(["BTC_USDT", "100ms"]));
+    send(&mut socket, json!({"time": 1650560389, "id": subscribe["id"], "channel": "spot.order_book_update", "event": "subscribe", "error": null, "result": {"status": "success"}})).await;
+    send(&mut socket, json!({"time": 1650560390, "channel": "spot.order_book_update", "event": "update", "result": {
+        "t": 1650560390123u64, "e": "depthUpdate", "E": 1650560390, "s": "BTC_USDT", "U": 3253917450u64, "u": 3253917455u64,
+        "b": [["41069.27", "0"]], "a": [["41069.30", "1"]],
+    }})).await;
+    let book = normalize(&timeout(books.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(book["symbol"], "BTC/USDT");
+    assert_eq!(book["nonce"].as_i64(), Some(3253917455));
+    assert_eq!(book["timestamp"].as_i64(), Some(1650560390123));
+    assert_eq!(book["bids"], json!([[41066.49, 0.0162]]));
+    assert_eq!(book["asks"], json!([[41069.28, 0.4186], [41069.3, 1.0]]));
+
+    let ping = receive(&mut socket).await;
+    assert_eq!(ping["channel"], "spot.ping");
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn binance_user_data_streams_share_one_listen_key() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.set("apiKey".into(), "user-data-key".into());
+    exchange.set("secret".into(), "user-data-secret".into());
+    exchange.set_path(&["options".into(), "fetchCurrencies".into()], false.into());
+    exchange.1.respond_once("POST", "/api/v3/userDataStream", 200, r#"{"listenKey": "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}"#);
+    exchange.1.respond("POST", "/api/v3/userDataStream", r#"{"listenKey": "renewed-listen-key"}"#);
+    exchange.1.respond("DELETE", "/api/v3/userDataStream", "{}");
+    let mut orders = BinancePro::watch_orders(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined, Value::Undefined).await;
+    let mut trades = BinancePro::watch_my_trades(&mut exchange, Value::Undefined, Value::Undefined, Value::Undefined, Value::Undefined).await;
+    let mut balances = BinancePro::watch_balance(&mut exchange, Value::Undefined).await;
+    let creates = exchange.1.requests().into_iter().filter(|request| request.url.contains("userDataStream")).collect::<Vec<_>>();
+    assert_eq!(creates.len(), 1);
+    assert_eq!(creates[0].headers["X-MBX-APIKEY"], "user-data-key");
+
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let subscribe = receive(&mut socket).await;
+    assert_eq!(subscribe["params"], json!(["pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"]));
+    send(&mut socket, json!({"result": null, "id": subscribe["id"]})).await;
+    let stream = "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1";
+    send(&mut socket, json!({"stream": stream, "data": {
+        "e": "executionReport", "E": 1672515782136u64, "s": "BTCUSDT", "c": "web_1", "S": "BUY", "o": "LIMIT",
+        "f": "GTC", "q": "1.00000000", "p": "16500.00000000", "P": "0.00000000", "C": "", "x": "TRADE",
+        "X": "PARTIALLY_FILLED", "i": 4293153, "l": "0.40000000", "z": "0.40000000", "L": "16500.00000000",
+        "n": "0.00040000", "N": "BTC", "T": 1672515782134u64, "t": 1234, "w": false, "m": true,
+        "O": 1672515700000u64, "Z": "6600.00000000", "Y": "6600.00000000",
+    }})).await;
+    send(&mut socket, json!({"stream": stream, "data": {
+        "e": "outboundAccountPosition", "E": 1672515782137u64, "u": 1672515782135u64,
+        "B": [{"a": "BTC", "f": "1.40000000", "l": "0.00000000"}, {"a": "USDT", "f": "3400.00000000", "l": "10000.00000000"}],
+    }})).await;
+
+    let order = normalize(&timeout(orders.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(order[0]["id"], "4293153");
+    assert_eq!(order[0]["symbol"], "BTC/USDT");
+    assert_eq!(order[0]["status"], "open");
+    assert_eq!(order[0]["filled"].as_f64(), Some(0.4));
+    assert_eq!(order[0]["remaining"].as_f64(), Some(0.6));
+    assert_eq!(order[0]["timestamp"].as_i64(), Some(1672515700000));
+    let trade = normalize(&timeout(trades.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(trade[0]["id"], "1234");
+    assert_eq!(trade[0]["order"], "4293153");
+    assert_eq!(trade[0]["amount"].as_f64(), Some(0.4));
+    assert_eq!(trade[0]["takerOrMaker"], "maker");
+    assert_eq!(trade[0]["fee"]["cost"].as_f64(), Some(0.0004));
+    assert_eq!(trade[0]["timestamp"].as_i64(), Some(1672515782134));
+    let balance = normalize(&timeout(balances.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(balance["BTC"]["free"].as_f64(), Some(1.4));
+    assert_eq!(balance["USDT"]["total"].as_f64(), Some(13400.0));
+    assert_eq!(balance["timestamp"].as_i64(), Some(1672515782137));
+
+    // an expired key is replaced and the streams subscribe to the new one
+    send(&mut socket, json!({"stream": stream, "data": {"e": "listenKeyExpired", "E": 1672515782200u64, "listenKey": stream}})).await;
+    let next_balance = tokio::spawn(async move { (timeout(balances.next()).await, balances) });
+    let subscribe = loop {
+        let message = receive(&mut socket).await;
+        if message["method"] == "SUBSCRIBE" {
+            break message;
+        }
+    };
+    assert_eq!(subscribe["params"], json!(["renewed-listen-key"]));
+    send(&mut socket, json!({"result": null, "id": subscribe["id"]})).await;
+    send(&mut socket, json!({"stream": "renewed-listen-key", "data": {
+        "e": "outboundAccountPosition", "E": 1672515782300u64, "u": 1672515782299u64,
+        "B": [{"a": "BTC", "f": "1.50000000", "l": "0.00000000"}],
+    }})).await;
+    let (balance, balances) = next_balance.await.unwrap();
+    let balance = normalize(&balance.unwrap().unwrap()).unwrap();
+    assert_eq!(balance["BTC"]["free"].as_f64(), Some(1.5));
+    let creates = exchange.1.requests().into_iter().filter(|request| request.method == "POST" && request.url.contains("userDataStream")).count();
+    assert_eq!(creates, 2);
+
+    // the key is closed once the last stream is dropped
+    drop((orders, trades, balances));
+    timeout(async {
+        while !exchange.1.requests().iter().any(|request| request.method == "DELETE" && request.body.as_deref() == Some("listenKey=renewed-listen-key")) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }).await;
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn binance_listen_keys_that_cannot_be_kept_alive_are_replaced() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.set("apiKey".into(), "keep-alive-key".into());
+    exchange.set("secret".into(), "keep-alive-secret".into());
+    exchange.set_path(&["options".into(), "fetchCurrencies".into()], false.into());
+    exchange.set_path(&["options".into(), "listenKeyRefreshRate".into()], 50.into());
+    exchange.1.respond_once("POST", "/api/v3/userDataStream", 200, r#"{"listenKey": "first-listen-key"}"#);
+    exchange.1.respond("POST", "/api/v3/userDataStream", r#"{"listenKey": "second-listen-key"}"#);
+    exchange.1.respond_once("PUT", "/api/v3/userDataStream", 400, r#"{"code": -1125, "msg": "This listenKey does not exist."}"#);
+    exchange.1.respond("PUT", "/api/v3/userDataStream", "{}");
+    exchange.1.respond("DELETE", "/api/v3/userDataStream", "{}");
+    let mut balances = BinancePro::watch_balance(&mut exchange, Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    assert_eq!(receive(&mut socket).await["params"], json!(["first-listen-key"]));
+
+    let next_balance = tokio::spawn(async move { timeout(balances.next()).await });
+    let subscribe = loop {
+        let message = receive(&mut socket).await;
+        if message["method"] == "SUBSCRIBE" {
+            break message;
+        }
+    };
+    assert_eq!(subscribe["params"], json!(["second-listen-key"]));
+    send(&mut socket, json!({"result": null, "id": subscribe["id"]})).await;
+    send(&mut socket, json!({"stream": "second-listen-key", "data": {
+        "e": "outboundAccountPosition", "E": 1672515782300u64, "u": 1672515782299u64,
+        "B": [{"a": "USDT", "f": "10.00000000", "l": "0.00000000"}],
+    }})).await;
+    let balance = normalize(&next_balance.await.unwrap().unwrap().unwrap()).unwrap();
+    assert_eq!(balance["USDT"]["free"].as_f64(), Some(10.0));
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn gate_private_channels_are_signed() {
+    use hmac::Mac;
+    let (url, mut sockets) = server().await;
+    let mut exchange = gateio(&url);
+    exchange.set("apiKey".into(), "gate-key".into());
+    exchange.set("secret".into(), "gate-secret".into());
+    let mut orders = GatePro::watch_orders(&mut exchange, "BTC/USDT".into(), Value::Undefined, Value::Undefined, Value::Undefined).await;
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    // private connections log in for api requests
+    assert_eq!(receive(&mut socket).await["channel"], "spot.login");
+    let subscribe = receive(&mut socket).await;
+    assert_eq!(subscribe["channel"], "spot.orders");
+    assert_eq!(subscribe["payload"], json!(["!all"]));
+    assert_eq!(subscribe["auth"]["method"], "api_key");
+    assert_eq!(subscribe["auth"]["KEY"], "gate-key");
+    let mut mac = hmac::Hmac::<sha2::Sha512>::new_from_slice(b"gate-secret").unwrap();
+    mac.update(format!("channel=spot.orders&event=subscribe&time={}", subscribe["time"]).as_bytes());
+    assert_eq!(subscribe["auth"]["SIGN"], hex::encode(mac.finalize().into_bytes()));
+    send(&mut socket, json!({"time": 1622638707, "id": subscribe["id"], "channel": "spot.orders", "event": "subscribe", "error": null, "result": {"status": "success"}})).await;
+    let order = |pair: &str, event: &str| json!({"time": 1622638807, "channel": "spot.orders", "event": "update", "result": [{
+        "id": "12332324", "text": "t-123456", "create_time": "1622638707", "update_time": "1622638807",
+        "currency_pair": pair, "type": "limit", "account": "spot", "side": "sell", "amount": "1", "price": "10001",
+        "time_in_force": "gtc", "left": "0", "filled_total": "10001", "fee": "0", "fee_currency": "USDT",
+        "event": event, "finish_as": "filled", "create_time_ms": "1622638707000", "update_time_ms": "1622638807000",
+    }]});
+    send(&mut socket, order("ETH_USDT", "finish")).await;
+    send(&mut socket, order("BTC_USDT", "finish")).await;
+    let update = normalize(&timeout(orders.next()).await.unwrap().unwrap()).unwrap();
+    assert_eq!(update[0]["symbol"], "BTC/USDT");
+    assert_eq!(update[0]["status"], "closed");
+    assert_eq!(update[0]["filled"].as_f64(), Some(1.0));
+    assert_eq!(update[0]["timestamp"].as_i64(), Some(1622638707000));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn binance_orders_are_placed_over_the_websocket_api() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.set("apiKey".into(), "ws-api-key".into());
+    exchange.set("secret".into(), "ws-api-secret".into());
+    exchange.set_path(&["options".into(), "fetchCurrencies".into()], false.into());
+    exchange.set_path(&["urls".into(), "api".into(), "ws".into(), "api".into()], url.as_str().into());
+    exchange.set("streaming".into(), Value::Json(json!({"requestTimeout": 300})));
+    ccxt::binance::Binance::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+
+    let mut placing = exchange.clone();
+    let order = tokio::spawn(async move {
+        BinancePro::create_order_ws(&mut placing, "BTC/USDT".into(), "limit".into(), "buy".into(), 0.1.into(), 16500.into(), Value::Undefined).await
+    });
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let request = receive(&mut socket).await;
+    assert_eq!(request["method"], "order.place");
+    let params = &request["params"];
+    assert_eq!(params["symbol"], "BTCUSDT");
+    assert_eq!(params["side"], "BUY");
+    assert_eq!(params["type"], "LIMIT");
+    assert_eq!(params["quantity"], "0.1");
+    assert_eq!(params["price"], "16500");
+    assert_eq!(params["apiKey"], "ws-api-key");
+    // signed like the rest api, with the params sorted by name
+    let mut signed = params.as_object().unwrap().iter().collect::<std::collections::BTreeMap<_, _>>();
+    signed.remove(&"signature".to_owned());
+    let payload = signed.iter().map(|(key, value)| format!("{}={}", key, value.as_str().map_or_else(|| value.to_string(), str::to_owned))).collect::<Vec<_>>().join("&");
+    assert_eq!(params["signature"], common::hmac_sha256_hex("ws-api-secret", &payload));
+    send(&mut socket, json!({"id": request["id"], "status": 200, "result": {
+        "symbol": "BTCUSDT", "orderId": 12510053279u64, "orderListId": -1, "clientOrderId": "a097fe6304b20a7e4fc436",
+        "transactTime": 1672515782134u64, "price": "16500.00000000", "origQty": "0.10000000", "executedQty": "0.00000000",
+        "cummulativeQuoteQty": "0.00000000", "status": "NEW", "timeInForce": "GTC", "type": "LIMIT", "side": "BUY",
+    }, "rateLimits": []})).await;
+    let order = normalize(&timeout(order).await.unwrap()).unwrap();
+    assert_eq!(order["id"], "12510053279");
+    assert_eq!(order["status"], "open");
+    assert_eq!(order["price"].as_f64(), Some(16500.0));
+    assert_eq!(order["timestamp"].as_i64(), Some(1672515782134));
+
+    let mut canceling = exchange.clone();
+    let cancel = tokio::spawn(async move {
+        ccxt::errors::catch(BinancePro::cancel_order_ws(&mut canceling, "12510053279".into(), "BTC/USDT".into(), Value::Undefined)).await
+    });
+    let request = receive(&mut socket).await;
+    assert_eq!(request["method"], "order.cancel");
+    assert_eq!(request["params"]["orderId"], "12510053279");
+    send(&mut socket, json!({"id": request["id"], "status": 400, "error": {"code": -2011, "msg": "Unknown order sent."}})).await;
+    let error = timeout(cancel).await.unwrap().unwrap_err();
+    assert!(error.is("ExchangeError"));
+    assert!(error.message.contains("Unknown order sent."));
+
+    // nothing comes back
+    let mut canceling = exchange.clone();
+    let cancel = tokio::spawn(async move {
+        ccxt::errors::catch(BinancePro::cancel_order_ws(&mut canceling, "1".into(), "BTC/USDT".into(), Value::Undefined)).await
+    });
+    assert_eq!(receive(&mut socket).await["method"], "order.cancel");
+    assert!(timeout(cancel).await.unwrap().unwrap_err().is("RequestTimeout"));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn binance_websocket_api_requests_use_the_server_clock() {
+    let now = || std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as i64;
+    let (url, mut sockets) = server().await;
+    let mut exchange = binance(&url);
+    exchange.set("apiKey".into(), "ws-clock-key".into());
+    exchange.set("secret".into(), "ws-clock-secret".into());
+    exchange.set_path(&["options".into(), "fetchCurrencies".into()], false.into());
+    exchange.set_path(&["urls".into(), "api".into(), "ws".into(), "api".into()], url.as_str().into());
+    ccxt::binance::Binance::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    exchange.set_path(&["options".into(), "adjustForTimeDifference".into()], true.into());
+    exchange.1.respond("GET", "/api/v3/time", &format!(r#"{{"serverTime": {}}}"#, now() + 60000));
+
+    let mut canceling = exchange.clone();
+    let cancel = tokio::spawn(async move {
+        BinancePro::cancel_order_ws(&mut canceling, "12510053279".into(), "BTC/USDT".into(), Value::Undefined).await
+    });
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let request = receive(&mut socket).await;
+    assert!((request["params"]["timestamp"].as_i64().unwrap() - now() - 60000).abs() < 1000);
+    // a timestamp error is sent again once after the time difference is loaded again
+    send(&mut socket, json!({"id": request["id"], "status": 400, "error": {"code": -1021, "msg": "Timestamp for this request is outside of the recvWindow."}})).await;
+    let request = receive(&mut socket).await;
+    assert_eq!(request["method"], "order.cancel");
+    send(&mut socket, json!({"id": request["id"], "status": 200, "result": {
+        "symbol": "BTCUSDT", "orderId": 12510053279u64, "clientOrderId": "a097fe6304b20a7e4fc436", "transactTime": 1672515782134u64,
+        "price": "16500.00000000", "origQty": "0.10000000", "executedQty": "0.00000000", "status": "CANCELED",
+        "timeInForce": "GTC", "type": "LIMIT", "side": "BUY",
+    }})).await;
+    let order = normalize(&timeout(cancel).await.unwrap()).unwrap();
+    assert_eq!(order["status"], "canceled");
+    let times = exchange.1.requests().into_iter().filter(|request| request.url.ends_with("/api/v3/time")).count();
+    assert_eq!(times, 2);
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn gate_orders_are_placed_over_the_websocket_api_once_logged_in() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = gateio(&url);
+    exchange.set("apiKey".into(), "gate-api-key".into());
+    exchange.set("secret".into(), "gate-api-secret".into());
+    ccxt::gate::Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+
+    let mut placing = exchange.clone();
+    let order = tokio::spawn(async move {
+        GatePro::create_order_ws(&mut placing, "BTC/USDT".into(), "limit".into(), "sell".into(), 1.into(), 10001.into(), Value::Undefined).await
+    });
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let login = receive(&mut socket).await;
+    assert_eq!(login["channel"], "spot.login");
+    assert_eq!(login["event"], "api");
+    assert_eq!(login["payload"]["api_key"], "gate-api-key");
+    let signature = {
+        use hmac::Mac;
+        let mut mac = hmac::Hmac::<sha2::Sha512>::new_from_slice(b"gate-api-secret").unwrap();
+        mac.update(format!("api\nspot.login\n\n{}", login["payload"]["timestamp"].as_str().unwrap()).as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    };
+    assert_eq!(login["payload"]["signature"], signature);
+    // the order waits for the login reply
+    assert!(tokio::time::timeout(Duration::from_millis(200), socket.next()).await.is_err());
+    send(&mut socket, json!({"request_id": login["payload"]["req_id"], "ack": false, "header": {"status": "200", "channel": "spot.login"}, "data": {"result": {"uid": "110284739"}}})).await;
+    let request = receive(&mut socket).await;
+    assert_eq!(request["channel"], "spot.order_place");
+    assert_eq!(request["event"], "api");
+    let param = &request["payload"]["req_param"];
+    assert_eq!(param["currency_pair"], "BTC_USDT");
+    assert_eq!(param["side"], "sell");
+    assert_eq!(param["amount"], "1");
+    assert_eq!(param["price"], "10001");
+    let id = request["payload"]["req_id"].clone();
+    let header = json!({"response_time": "1681986204784", "status": "200", "channel": "spot.order_place", "event": "api"});
+    send(&mut socket, json!({"request_id": id, "ack": true, "header": header, "data": {"result": {"req_id": id}}})).await;
+    send(&mut socket, json!({"request_id": id, "ack": false, "header": header, "data": {"result": {
+        "id": "12332324", "text": "t-123456", "create_time": "1622638707", "update_time": "1622638707",
+        "currency_pair": "BTC_USDT", "status": "open", "type": "limit", "account": "spot", "side": "sell", "amount": "1",
+        "price": "10001", "time_in_force": "gtc", "left": "1", "filled_total": "0", "fee": "0", "fee_currency": "USDT",
+        "create_time_ms": "1622638707000", "update_time_ms": "1622638707000",
+    }}})).await;
+    let order = normalize(&timeout(order).await.unwrap()).unwrap();
+    assert_eq!(order["id"], "12332324");
+    assert_eq!(order["symbol"], "BTC/USDT");
+    assert_eq!(order["status"], "open");
+    assert_eq!(order["remaining"].as_f64(), Some(1.0));
+
+    let mut canceling = exchange.clone();
+    let cancel = tokio::spawn(async move {
+        ccxt::errors::catch(GatePro::cancel_order_ws(&mut canceling, "12332324".into(), "BTC/USDT".into(), Value::Undefined)).await
+    });
+    let request = receive(&mut socket).await;
+    assert_eq!(request["channel"], "spot.order_cancel");
+    assert_eq!(request["payload"]["req_param"]["order_id"], "12332324");
+    assert_eq!(request["payload"]["req_param"]["currency_pair"], "BTC_USDT");
+    send(&mut socket, json!({"request_id": request["payload"]["req_id"], "ack": false, "header": {"status": "400", "channel": "spot.order_cancel"},
+        "data": {"errs": {"label": "ORDER_NOT_FOUND", "message": "Order not found"}}})).await;
+    let error = timeout(cancel).await.unwrap().unwrap_err();
+    assert!(error.is("ExchangeError"));
+    assert!(error.message.contains("ORDER_NOT_FOUND"));
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn gate_websocket_api_requests_fail_when_the_login_is_rejected() {
+    let (url, mut sockets) = server().await;
+    let mut exchange = gateio(&url);
+    exchange.set("apiKey".into(), "gate-rejected-key".into());
+    exchange.set("secret".into(), "gate-rejected-secret".into());
+    ccxt::gate::Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+
+    let mut canceling = exchange.clone();
+    let cancel = tokio::spawn(async move {
+        ccxt::errors::catch(GatePro::cancel_order_ws(&mut canceling, "12332324".into(), "BTC/USDT".into(), Value::Undefined)).await
+    });
+    let mut socket = timeout(sockets.recv()).await.unwrap();
+    let login = receive(&mut socket).await;
+    assert_eq!(login["channel"], "spot.login");
+    send(&mut socket, json!({"request_id": login["payload"]["req_id"], "header": {"status": "401", "channel": "spot.login"},
+        "data": {"errs": {"label": "INVALID_KEY", "message": "Invalid key provided"}}})).await;
+    let error = timeout(cancel).await.unwrap().unwrap_err();
+    assert!(error.is("AuthenticationError"));
+    assert!(error.message.contains("INVALID_KEY"));
+    // nothing was sent with the rejected login
+    assert!(tokio::time::timeout(Duration::from_millis(200), socket.next()).await.is_err());
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "binance", feature = "bitget", feature = "okx"))]
+
+use ccxt::exchange::{Value, ValueTrait};
+use ccxt::UnifiedExchange;
+
+#[test]
+fn lists_compiled_exchanges() {
+    let ids = ccxt::exchanges();
+    assert!(ids.contains(&"binance"));
+    assert!(ids.contains(&"okx"));
+    for id in ids {
+        assert_eq!(ccxt::new_exchange(id, Value::Undefined).unwrap().id(), id.into());
+    }
+}
+
+#[test]
+fn constructs_exchanges_by_id() {
+    let exchanges: Vec<Box<dyn UnifiedExchange>> = vec!["binance", "okx"].into_iter()
+        .map(|id| ccxt::new_exchange(id, Value::Json(serde_json::json!({"apiKey": "key"}))).unwrap())
+        .collect();
+    assert_eq!(exchanges[0].name(), "Binance".into());
+    assert_eq!(exchanges[1].name(), "OKX".into());
+    assert!(exchanges[1].has().get("fetchOrderBook".into()).is_truthy());
+}
+
+#[tokio::test]
+async fn delegates_to_the_exchange() {
+    // okx only fetches currencies with credentials, so this does not touch the network
+    let mut exchange = ccxt::new_exchange("okx", Value::Undefined).unwrap();
+    assert!(exchange.fetch_currencies(Value::Undefined).await.is_undefined());
+}
+
+#[tokio::test]
+#[should_panic(expected = "NotSupported::new(bitget cancelAllOrders() is not supported yet)")]
+async fn raises_not_supported_for_missing_methods() {
+    let mut exchange = ccxt::new_exchange("bitget", Value::Undefined).unwrap();
+    exchange.cancel_all_orders("BTC/USDT".into(), Value::Undefined).await;
+}
+
+#[test]
+fn rejects_unknown_ids() {
+    let error = ccxt::new_exchange("nonexistent", Value::Undefined).err().unwrap();
+    assert_eq!(error.kind, "ExchangeNotFound");
+    assert!(error.message.starts_with("nonexistent is not available"));
+}
@@ -0,0 +1,204 @@
+mod common;
+
+#[cfg(feature = "binance")]
+use ccxt::binance::Binance;
+#[cfg(feature = "binance")]
+use ccxt::errors::catch;
+use ccxt::errors::Error;
+use ccxt::exchange::Value;
+#[cfg(feature = "binance")]
+use ccxt::exchange::{Exchange, ValueTrait};
+use ccxt::retry::{idempotency_context, is_idempotent, retry_after, RetryPolicy};
+use serde_json::json;
+use std::time::Duration;
+
+#[cfg(feature = "binance")]
+mock_exchange!(MockBinance, ccxt::binance::Binance);
+
+fn response(status: u16, headers: serde_json::Value) -> Value {
+    Value::Json(json!({"status": status, "statusText": "", "headers": headers, "body": ""}))
+}
+
+#[test]
+fn policy_is_read_from_options() {
+    assert_eq!(RetryPolicy::from_options(&Value::Json(json!({}))), RetryPolicy::default());
+    let policy = RetryPolicy::from_options(&Value::Json(json!({"retry": {"maxAttempts": 5, "baseDelay": 100}})));
+    assert_eq!(policy.max_attempts, 5);
+    assert_eq!(policy.base_delay, Duration::from_millis(100));
+    assert_eq!(policy.max_delay, RetryPolicy::default().max_delay);
+}
+
+#[test]
+fn only_transient_errors_are_retried() {
+    let policy = RetryPolicy::default();
+    let none = json!({});
+    assert!(policy.delay(0, true, &Error::new("ExchangeNotAvailable", "503"), &response(503, none.clone())).is_some());
+    assert!(policy.delay(0, true, &Error::new("RequestTimeout", "504"), &response(504, none.clone())).is_some());
+    assert!(policy.delay(0, true, &Error::new("RateLimitExceeded", "429"), &response(429, none.clone())).is_some());
+    assert!(policy.delay(0, true, &Error::new("NetworkError", "connection reset"), &Value::Undefined).is_some());
+    // the default http exceptions also map 400 and 404 to ExchangeNotAvailable
+    assert!(policy.delay(0, true, &Error::new("ExchangeNotAvailable", "404"), &response(404, none.clone())).is_none());
+    assert!(policy.delay(0, true, &Error::new("InsufficientFunds", "balance"), &response(400, none.clone())).is_none());
+    assert!(policy.delay(0, true, &Error::new("InvalidNonce", "recvWindow"), &response(400, none.clone())).is_none());
+    // unsafe to repeat or out of attempts
+    assert!(policy.delay(0, false, &Error::new("ExchangeNotAvailable", "503"), &response(503, none.clone())).is_none());
+    assert!(policy.delay(2, true, &Error::new("ExchangeNotAvailable", "503"), &response(503, none)).is_none());
+}
+
+#[test]
+fn backoff_is_exponential_and_jittered() {
+    let policy = RetryPolicy { max_attempts: 10, base_delay: Duration::from_millis(100), max_delay: Duration::from_millis(1000) };
+    let error = Error::new("ExchangeNotAvailable", "503");
+    for (retries, max) in [(0, 100), (1, 200), (2, 400), (3, 800), (4, 1000), (8, 1000)] {
+        let delay = policy.delay(retries, true, &error, &response(503, json!({}))).unwrap();
+        assert!(delay >= Duration::from_millis(max / 2) && delay <= Duration::from_millis(max), "{:?} after {} retries", delay, retries);
+    }
+}
+
+#[test]
+fn retry_after_is_honoured() {
+    let policy = RetryPolicy::default();
+    let error = Error::new("RateLimitExceeded", "429");
+    assert_eq!(policy.delay(0, true, &error, &response(429, json!({"retry-after": "2"}))), Some(Duration::from_secs(2)));
+    // waiting less than the server asks for would only extend the ban
+    assert_eq!(policy.delay(0, true, &error, &response(429, json!({"Retry-After": "120"}))), None);
+    assert_eq!(policy.delay(0, true, &error, &response(429, json!({"Retry-After": "30"}))), Some(policy.max_delay));
+    assert_eq!(retry_after(&Value::Json(json!({"Retry-After": "0.5"}))), Some(Duration::from_millis(500)));
+    let date = (chrono::Utc::now() + chrono::Duration::seconds(10)).to_rfc2822();
+    let delay = retry_after(&Value::Json(json!({"Retry-After": date}))).unwrap();
+    assert!(delay > Duration::from_secs(8) && delay <= Duration::from_secs(10));
+    assert_eq!(retry_after(&Value::Json(json!({"Retry-After": "Wed, 21 Oct 2015 07:28:00 GMT"}))), Some(Duration::ZERO));
+    assert_eq!(retry_after(&Value::Json(json!({}))), None);
+}
+
+#[test]
+fn reads_and_orders_with_client_ids_are_idempotent() {
+    let none = Value::Undefined;
+    assert!(is_idempotent(&"GET".into(), &Value::Json(json!({})), &none));
+    assert!(is_idempotent(&Value::Undefined, &Value::Json(json!({})), &none));
+    assert!(!is_idempotent(&"POST".into(), &Value::Json(json!({"symbol": "BTCUSDT"})), &none));
+    assert!(!is_idempotent(&"DELETE".into(), &Value::Json(json!({"orderId": "1"})), &none));
+    assert!(!is_idempotent(&"POST".into(), &Value::Json(json!({"text": "t-123"})), &none));
+    assert!(is_idempotent(&"POST".into(), &Value::Json(json!({"symbol": "BTCUSDT", "newClientOrderId": "abc"})), &none));
+    assert!(is_idempotent(&"POST".into(), &Value::Json(json!({"clOrdId": "abc"})), &none));
+    // an id the unified method generated itself does not count
+    assert!(!is_idempotent(&"POST".into(), &Value::Json(json!({"clOrdId": "abc"})), &idempotency_context(&Value::Undefined)));
+    assert!(is_idempotent(&"POST".into(), &Value::Json(json!({"clOrdId": "abc"})), &idempotency_context(&"abc".into())));
+    assert!(is_idempotent(&"GET".into(), &Value::Json(json!({})), &idempotency_context(&Value::Undefined)));
+}
+
+#[cfg(feature = "binance")]
+fn exchange(recorder: common::Recorder) -> MockBinance {
+    let mut exchange = MockBinance::new(Value::Json(json!({"apiKey": "key", "secret": "secret"})), recorder);
+    exchange.set_path(&["options".into(), "retry".into()], Value::Json(json!({"baseDelay": 1, "maxDelay": 10})));
+    exchange
+}
+
+#[cfg(feature = "binance")]
+async fn request(exchange: &mut MockBinance, path: &str, api: &str, method: &str, params: serde_json::Value) -> Value {
+    Binance::request(exchange, path.into(), api.into(), method.into(), Value::Json(params), Value::Undefined, Value::Undefined, Value::Undefined, Value::Undefined).await
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn public_requests_are_retried() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond_once("GET", "/api/v3/time", 0, "");
+    recorder.respond_once("GET", "/api/v3/time", 503, "Service Unavailable");
+    recorder.respond("GET", "/api/v3/time", r#"{"serverTime": 1000}"#);
+    let mut exchange = exchange(recorder.clone());
+    let response = request(&mut exchange, "time", "public", "GET", json!({})).await;
+    assert_eq!(response.get("serverTime".into()), 1000.into());
+    assert_eq!(recorder.requests().len(), 3);
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn retries_give_up_after_max_attempts() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond_with_status("GET", "/api/v3/time", 502, "Bad Gateway");
+    let mut exchange = exchange(recorder.clone());
+    let error = catch(request(&mut exchange, "time", "public", "GET", json!({}))).await.unwrap_err();
+    assert!(error.is("ExchangeNotAvailable"));
+    assert_eq!(recorder.requests().len(), 3);
+    // a single attempt turns retries off
+    exchange.set_path(&["options".into(), "retry".into(), "maxAttempts".into()], 1.into());
+    catch(request(&mut exchange, "time", "public", "GET", json!({}))).await.unwrap_err();
+    assert_eq!(recorder.requests().len(), 4);
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn orders_are_retried_only_with_a_client_order_id() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond_once("POST", "/api/v3/order", 503, "Service Unavailable");
+    recorder.respond("POST", "/api/v3/order", r#"{"orderId": 1, "clientOrderId": "my-order"}"#);
+    let mut exchange = exchange(recorder.clone());
+    let order = json!({"symbol": "BTCUSDT", "side": "BUY", "type": "MARKET", "quantity": "0.01"});
+    catch(request(&mut exchange, "order", "private", "POST", order.clone())).await.unwrap_err();
+    assert_eq!(recorder.requests().len(), 1);
+    recorder.respond_once("POST", "/api/v3/order", 504, "Gateway Timeout");
+    let mut order = order;
+    order["newClientOrderId"] = json!("my-order");
+    let response = request(&mut exchange, "order", "private", "POST", order).await;
+    assert_eq!(response.get("clientOrderId".into()), "my-order".into());
+    let requests = recorder.requests();
+    assert_eq!(requests.len(), 3);
+    // the retry is signed again with the same client order id
+    assert!(requests[1].body.as_deref().unwrap().contains("newClientOrderId=my-order"));
+    assert!(requests[2].body.as_deref().unwrap().contains("newClientOrderId=my-order"));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn create_order_is_retried_only_with_the_callers_client_order_id() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/v3/exchangeInfo", include_str!("fixtures/binance/exchange_info.json"));
+    recorder.respond_once("POST", "/api/v3/order", 503, "Service Unavailable");
+    recorder.respond("POST", "/api/v3/order", r#"{"symbol": "BTCUSDT", "orderId": 1, "clientOrderId": "my-order", "status": "NEW"}"#);
+    let mut exchange = exchange(recorder.clone());
+    exchange.set_path(&["options".into(), "fetchCurrencies".into()], false.into());
+    let orders = || recorder.requests().into_iter().filter(|request| request.url.contains("/api/v3/order")).collect::<Vec<_>>();
+
+    // the broker id added by create_order does not make the order safe to send twice
+    catch(Binance::create_order(&mut exchange, "BTC/USDT".into(), "market".into(), "buy".into(), json!(0.01).into(), Value::Undefined, Value::Undefined)).await.unwrap_err();
+    assert_eq!(orders().len(), 1);
+    assert!(orders()[0].body.as_deref().unwrap().contains("newClientOrderId=x-R4BD3S82"));
+
+    exchange.1.respond_once("POST", "/api/v3/order", 503, "Service Unavailable");
+    let params = Value::Json(json!({"clientOrderId": "my-order"}));
+    Binance::create_order(&mut exchange, "BTC/USDT".into(), "market".into(), "buy".into(), json!(0.01).into(), Value::Undefined, params).await;
+    let orders = orders();
+    assert_eq!(orders.len(), 3);
+    assert!(orders[1].body.as_deref().unwrap().contains("newClientOrderId=my-order"));
+    assert!(orders[2].body.as_deref().unwrap().contains("newClientOrderId=my-order"));
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn retry_after_headers_are_honoured() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond_once_with_headers("GET", "/api/v3/time", 429, json!({"Retry-After": "1"}), r#"{"code": -1003, "msg": "Too many requests."}"#);
+    recorder.respond("GET", "/api/v3/time", r#"{"serverTime": 1000}"#);
+    let mut exchange = exchange(recorder.clone());
+    exchange.set_path(&["options".into(), "retry".into(), "maxDelay".into()], 5000.into());
+    let started = std::time::Instant::now();
+    request(&mut exchange, "time", "public", "GET", json!({})).await;
+    assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+    assert_eq!(recorder.requests().len(), 2);
+}
+
+#[cfg(feature = "binance")]
+#[tokio::test]
+async fn retry_after_headers_longer_than_max_delay_are_raised() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond_once_with_headers("GET", "/api/v3/time", 418, json!({"Retry-After": "120"}), r#"{"code": -1003, "msg": "Way too many requests; IP banned until 1672515782134."}"#);
+    recorder.respond("GET", "/api/v3/time", r#"{"serverTime": 1000}"#);
+    let mut exchange = exchange(recorder.clone());
+    exchange.set_path(&["options".into(), "retry".into(), "maxDelay".into()], 5000.into());
+    let started = std::time::Instant::now();
+    let error = catch(request(&mut exchange, "time", "public", "GET", json!({}))).await.unwrap_err();
+    assert!(error.is("DDoSProtection"), "{:?}", error);
+    assert!(started.elapsed() < std::time::Duration::from_secs(1));
+    assert_eq!(recorder.requests().len(), 1);
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "gateio")]
+
+mod common;
+
+use ccxt::exchange::{Value, ValueTrait};
+use ccxt::gate::Gate;
+use ccxt::SharedExchange;
+
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate => { Gate => [load_markets, fetch_order_book] });
+
+fn exchange() -> (SharedExchange, common::Recorder) {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder.respond("GET", "/spot/order_book", include_str!("fixtures/gateio/order_book.json"));
+    let exchange = MockGateio::new(Value::Undefined, recorder.clone());
+    (SharedExchange::new(Box::new(exchange)), recorder)
+}
+
+fn count(recorder: &common::Recorder, fragment: &str) -> usize {
+    recorder.requests().iter().filter(|r| r.url.split('?').next().unwrap().ends_with(fragment)).count()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn shares_the_markets_between_tasks() {
+    let (exchange, recorder) = exchange();
+    exchange.load_markets(Value::Undefined, Value::Undefined).await;
+    assert!(exchange.get_path(&["markets".into(), "BTC/USDT".into()]).is_object());
+
+    let tasks: Vec<_> = (0..10).map(|_| {
+        let exchange = exchange.clone();
+        tokio::spawn(async move {
+            exchange.fetch_order_book("BTC/USDT".into(), Value::Undefined, Value::Undefined).await
+        })
+    }).collect();
+    for task in tasks {
+        let book = task.await.unwrap();
+        assert_eq!(book.get("symbol".into()), "BTC/USDT".into());
+    }
+    assert_eq!(count(&recorder, "/spot/currency_pairs"), 1);
+    assert_eq!(count(&recorder, "/spot/order_book"), 10);
+}
+
+#[tokio::test]
+async fn typed_methods_take_a_shared_reference() {
+    let (exchange, _) = exchange();
+    let book = exchange.fetch_order_book_typed("BTC/USDT".into(), Value::Undefined, Value::Undefined).await.unwrap();
+    assert_eq!(book.symbol.as_deref(), Some("BTC/USDT"));
+    let error = exchange.fetch_ticker_typed("BTC/USDT".into(), Value::Undefined).await.unwrap_err();
+    assert!(error.is("NotSupported"));
+}
+
+#[tokio::test]
+async fn merges_the_options_changed_by_each_call() {
+    let (exchange, _) = exchange();
+    exchange.set_path(&["options".into(), "first".into()], 1.into());
+    exchange.with(|exchange| Box::pin(async move {
+        exchange.state_mut()["options"]["second"] = 2.into();
+    })).await;
+    assert_eq!(exchange.get_path(&["options".into(), "first".into()]), 1.into());
+    assert_eq!(exchange.get_path(&["options".into(), "second".into()]), 2.into());
+    assert_eq!(exchange.id(), "gateio".into());
+}
+
+#[tokio::test]
+async fn keeps_the_markets_out_of_the_copies_until_a_call_loads_them() {
+    let (exchange, recorder) = exchange();
+    let markets = exchange.with(|exchange| Box::pin(async move {
+        let before = exchange.state()["markets"].is_object();
+        exchange.load_markets(Value::Undefined, Value::Undefined).await;
+        (before, exchange.state()["markets"]["BTC/USDT"].is_object())
+    })).await;
+    assert_eq!(markets, (false, true));
+    assert_eq!(count(&recorder, "/spot/currency_pairs"), 1);
+}
+
+#[tokio::test]
+async fn reuses_the_copies_of_the_calls_that_ended() {
+    let (exchange, recorder) = exchange();
+    exchange.load_markets(Value::Undefined, Value::Undefined).await;
+    exchange.set_path(&["options".into(), "later".into()], 3.into());
+    let (markets, later) = exchange.with(|exchange| Box::pin(async move {
+        (exchange.state()["markets"]["BTC/USDT"].is_object(), exchange.state()["options"]["later"].clone())
+    })).await;
+    assert!(markets);
+    assert_eq!(later, 3);
+    assert_eq!(count(&recorder, "/spot/currency_pairs"), 1);
+}
@@ -0,0 +1,52 @@
+#![cfg(all(feature = "binance", feature = "gate"))]
+
+mod common;
+
+use ccxt::binance::Binance;
+use ccxt::exchange::{normalize, Exchange, Value, ValueTrait};
+use ccxt::gate::GateImpl;
+use serde_json::json;
+
+mock_exchange!(MockBinance, ccxt::binance::Binance);
+
+#[test]
+fn set_path_persists_nested_options() {
+    let mut exchange = GateImpl::new(Value::Undefined);
+    exchange.set_path(&["options".into(), "limitsLoaded".into()], 1650000000000i64.into());
+    exchange.set_path(&["options".into(), "custom".into(), "depth".into()], 5.into());
+    assert_eq!(exchange.get("options".into()).get("limitsLoaded".into()), 1650000000000i64.into());
+    assert_eq!(exchange.get_path(&["options".into(), "custom".into(), "depth".into()]), 5.into());
+    // the generated options are untouched
+    assert!(exchange.get_path(&["options".into(), "networks".into()]).is_object());
+    exchange.set_path(&["options".into(), "custom".into()], Value::Undefined);
+    assert!(!exchange.get("options".into()).contains_key("custom".into()));
+}
+
+#[test]
+fn get_path_mut_creates_missing_entries() {
+    let mut exchange = GateImpl::new(Value::Undefined);
+    *exchange.get_path_mut(&["markets".into(), "BTC/USDT".into(), "active".into()]) = json!(false);
+    assert_eq!(exchange.get_path(&["markets".into(), "BTC/USDT".into(), "active".into()]), false.into());
+    assert!(exchange.get_path(&["markets".into(), "ETH/USDT".into()]).is_undefined());
+}
+
+#[test]
+fn safe_balance_fills_totals() {
+    let exchange = GateImpl::new(Value::Undefined);
+    let balance = normalize(&exchange.safe_balance(Value::Json(json!({
+        "BTC": {"free": "1", "used": "0.5"},
+    })))).unwrap();
+    assert_eq!(balance["BTC"]["total"].as_f64(), Some(1.5));
+    assert_eq!(balance["free"]["BTC"].as_f64(), Some(1.0));
+    assert_eq!(balance["total"]["BTC"].as_f64(), Some(1.5));
+}
+
+#[tokio::test]
+async fn time_difference_persists_between_calls() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/v3/time", r#"{"serverTime": 1000}"#);
+    let mut exchange = MockBinance::new(Value::Undefined, recorder);
+    let difference = Binance::load_time_difference(&mut exchange, Value::Undefined).await;
+    assert!(difference > 0.into());
+    assert_eq!(exchange.get("options".into()).get("timeDifference".into()), difference);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use ccxt::symbol::OptionType;
+use ccxt::types::Decimal;
+use ccxt::Symbol;
+use chrono::NaiveDate;
+#[cfg(feature = "gateio")]
+use ccxt::{errors::catch, exchange::{Exchange, Value, ValueTrait}, gate::Gate};
+
+#[test]
+fn parses_every_market_type() {
+    let spot: Symbol = "BTC/USDT".parse().unwrap();
+    assert_eq!(spot, Symbol::spot("BTC", "USDT"));
+    assert_eq!(spot.market_type(), "spot");
+
+    let swap: Symbol = "BTC/USDT:USDT".parse().unwrap();
+    assert_eq!(swap, Symbol::swap("BTC", "USDT", "USDT"));
+    assert_eq!(swap.market_type(), "swap");
+
+    let expiry = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap();
+    let future: Symbol = "BTC/USD:BTC-231229".parse().unwrap();
+    assert_eq!(future, Symbol::future("BTC", "USD", "BTC", expiry));
+    assert_eq!(future.market_type(), "future");
+
+    let option: Symbol = "ETH/USDT:USDT-231229-1500.5-P".parse().unwrap();
+    assert_eq!(option, Symbol::option("ETH", "USDT", "USDT", expiry, Decimal::new(15005, 1), OptionType::Put));
+    assert_eq!(option.market_type(), "option");
+}
+
+#[test]
+fn formats_back_to_the_unified_symbol() {
+    for symbol in ["BTC/USDT", "1000SHIB/USDT:USDT", "BTC/USD:BTC-231229", "BTC/USD:BTC-231229-30000-C", "ETH/USDT:USDT-240105-0.5-P"] {
+        assert_eq!(symbol.parse::<Symbol>().unwrap().to_string(), symbol);
+    }
+    let json = serde_json::to_value(Symbol::swap("BTC", "USDT", "USDT")).unwrap();
+    assert_eq!(json, "BTC/USDT:USDT");
+    assert_eq!(serde_json::from_value::<Symbol>(json).unwrap().settle.as_deref(), Some("USDT"));
+}
+
+#[test]
+fn rejects_malformed_symbols() {
+    for symbol in [
+        "BTCUSDT", "BTC/", "/USDT", "BTC/USDT:", "BTC/USD:BTC-2312", "BTC/USD:BTC-231332", "BTC/USD:BTC-231229-30000",
+        "BTC/USD:BTC-231229-30000-X", "BTC/USD:BTC-231229-abc-C", "BTC/USD:BTC-231229--1-C", "BTC/USD:BTC-231229-1-C-1", "BTC /USDT",
+    ] {
+        let error = symbol.parse::<Symbol>().unwrap_err();
+        assert!(error.is("BadSymbol"), "{}", symbol);
+        assert!(error.message.contains(symbol), "{}", error);
+    }
+    assert!(Symbol { strike: Some(Decimal::ONE), ..Symbol::spot("BTC", "USDT") }.validate().is_err());
+}
+
+#[cfg(feature = "gateio")]
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate);
+
+#[cfg(feature = "gateio")]
+async fn exchange() -> MockGateio {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    let mut exchange = MockGateio::new(Value::Undefined, recorder);
+    Gate::load_markets(&mut exchange, Value::Undefined, Value::Undefined).await;
+    exchange
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn unknown_symbols_raise_bad_symbol() {
+    let exchange = exchange().await;
+    assert_eq!(Exchange::market(&exchange, "BTC_USDT".into()).get("symbol".into()), "BTC/USDT".into());
+
+    let error = catch(async { Exchange::market(&exchange, "ETH/USDT".into()) }).await.unwrap_err();
+    assert_eq!(error.kind, "BadSymbol");
+    assert_eq!(error.message, "gateio does not have market symbol ETH/USDT");
+
+    let error = catch(async { Exchange::market(&exchange, "ETH-USDT".into()) }).await.unwrap_err();
+    assert!(error.is("BadRequest"));
+    assert!(error.message.starts_with("gateio does not have market symbol ETH-USDT, invalid symbol ETH-USDT"), "{}", error);
+
+    let error = catch(async { Gate::fetch_order_book(&mut exchange.clone(), "ETH/USDT".into(), Value::Undefined, Value::Undefined).await }).await.unwrap_err();
+    assert_eq!(error.kind, "BadSymbol");
+}
+
+#[cfg(feature = "gateio")]
+#[tokio::test]
+async fn safe_symbol_keeps_ids_that_do_not_split_into_a_symbol() {
+    let exchange = exchange().await;
+    assert_eq!(Exchange::safe_symbol(&exchange, "ETH_BTC".into(), Value::Undefined, "_".into()), "ETH/BTC".into());
+    assert_eq!(Exchange::safe_symbol(&exchange, "ETH_".into(), Value::Undefined, "_".into()), "ETH_".into());
+    assert_eq!(Exchange::safe_symbol(&exchange, "BTC_USDT".into(), Value::Undefined, "_".into()), "BTC/USDT".into());
+}
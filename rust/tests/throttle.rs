@@ -0,0 +1,11 @@
+use std::time::{Duration, Instant};
+
+use ccxt::throttle::throttler;
+
+#[tokio::test]
+async fn follows_the_latest_rate_limit_of_a_key() {
+    throttler("follows-the-latest-rate-limit", 60000.0).throttle(1.0).await;
+    let started = Instant::now();
+    throttler("follows-the-latest-rate-limit", 10.0).throttle(1.0).await;
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "gate")]
+
+use ccxt::exchange::{Exchange, Value, ValueTrait};
+use ccxt::gate::GateImpl;
+use serde_json::json;
+
+fn exchange() -> GateImpl {
+    GateImpl::new(Value::Undefined)
+}
+
+#[test]
+fn parse8601_matches_date_parse() {
+    let exchange = exchange();
+    for (date, timestamp) in [
+        ("2022-09-05T12:34:56.789Z", 1662381296789i64),
+        ("2022-09-05T12:34:56Z", 1662381296000),
+        ("2022-09-05T12:34:56", 1662381296000),
+        ("2022-09-05 12:34:56", 1662381296000),
+        ("2022-09-05 12:34:56.1", 1662381296100),
+        ("2022-09-05T12:34Z", 1662381240000),
+        ("2022-09-05T12:34:56.789999Z", 1662381296789),
+        ("2022-09-05T20:34:56.789+08:00", 1662381296789),
+        ("2022-09-05T07:04:56.789-0530", 1662381296789),
+        ("1969-12-31T23:59:59.999Z", -1),
+    ] {
+        assert_eq!(exchange.parse8601(date.into()), timestamp.into(), "{}", date);
+    }
+    for invalid in ["", "1662381296789", "2022-09-05", "12:34:56", "2022-13-05T12:34:56Z", "yesterday: noon"] {
+        assert!(exchange.parse8601(invalid.into()).is_undefined(), "{}", invalid);
+    }
+    assert!(exchange.parse8601(Value::Undefined).is_undefined());
+    assert!(exchange.parse8601(json!(1662381296789i64).into()).is_undefined());
+}
+
+#[test]
+fn iso8601_round_trips() {
+    let exchange = exchange();
+    assert_eq!(exchange.iso8601(json!(1662381296789i64).into()), "2022-09-05T12:34:56.789Z".into());
+    assert_eq!(exchange.iso8601(json!(1662381296000i64).into()), "2022-09-05T12:34:56.000Z".into());
+    assert_eq!(exchange.iso8601(json!(1662381296789.9).into()), "2022-09-05T12:34:56.789Z".into());
+    assert_eq!(exchange.iso8601("1662381296789".into()), "2022-09-05T12:34:56.789Z".into());
+    assert!(exchange.iso8601(json!(-1).into()).is_undefined());
+    assert!(exchange.iso8601(Value::Undefined).is_undefined());
+    for timestamp in [0i64, 1, 999, 951782400123, 1662381296789, 4102444799999] {
+        assert_eq!(exchange.parse8601(exchange.iso8601(timestamp.into())), timestamp.into());
+    }
+}
+
+#[test]
+fn formats_dates() {
+    let exchange = exchange();
+    let timestamp: Value = 1704067199999i64.into(); // 2023-12-31T23:59:59.999Z
+    assert_eq!(exchange.ymd(timestamp.clone(), Value::Undefined, Value::Undefined), "20231231".into());
+    assert_eq!(exchange.ymd(timestamp.clone(), "/".into(), false.into()), "23/12/31".into());
+    assert_eq!(exchange.yymmdd(timestamp.clone(), Value::Undefined), "231231".into());
+    assert_eq!(exchange.yymmdd(timestamp.clone(), "-".into()), "23-12-31".into());
+    assert_eq!(exchange.yyyymmdd(timestamp.clone(), Value::Undefined), "2023-12-31".into());
+    assert_eq!(exchange.yyyymmdd(timestamp.clone(), "".into()), "20231231".into());
+    assert_eq!(exchange.ymdhms(timestamp.clone(), Value::Undefined), "2023-12-31 23:59:59".into());
+    assert_eq!(exchange.ymdhms(timestamp.clone(), "T".into()), "2023-12-31T23:59:59".into());
+    assert_eq!(exchange.mdy(timestamp.clone(), Value::Undefined), "12-31-2023".into());
+    assert_eq!(exchange.yymmdd(1709251200000i64.into(), Value::Undefined), "240301".into());
+    assert!(exchange.ymd(Value::Undefined, Value::Undefined, Value::Undefined).is_undefined());
+}
+
+#[test]
+fn safe_timestamp_converts_seconds_exactly() {
+    let exchange = exchange();
+    let trade: Value = json!({
+        "create_time": "1662381296.789",
+        "update_time": 1662381296.123,
+        "close_time": 1662381296,
+        "expire_time": "1.662381296e9",
+        "bad": "soon",
+    }).into();
+    assert_eq!(exchange.safe_timestamp(trade.clone(), "create_time".into(), Value::Undefined), 1662381296789i64.into());
+    assert_eq!(exchange.safe_timestamp(trade.clone(), "update_time".into(), Value::Undefined), 1662381296123i64.into());
+    assert_eq!(exchange.safe_timestamp(trade.clone(), "close_time".into(), Value::Undefined), 1662381296000i64.into());
+    assert_eq!(exchange.safe_timestamp(trade.clone(), "expire_time".into(), Value::Undefined), 1662381296000i64.into());
+    assert!(exchange.safe_timestamp(trade.clone(), "bad".into(), Value::Undefined).is_undefined());
+    assert_eq!(exchange.safe_timestamp(trade.clone(), "missing".into(), 0.into()), 0.into());
+    assert_eq!(exchange.safe_timestamp_2(trade, "missing".into(), "close_time".into(), Value::Undefined), 1662381296000i64.into());
+}
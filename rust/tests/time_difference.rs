@@ -0,0 +1,117 @@
+#![cfg(feature = "binance")]
+
+mod common;
+
+use ccxt::binance::Binance;
+use ccxt::errors::catch;
+use ccxt::exchange::{Exchange, Value, ValueTrait};
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+mock_exchange!(MockBinance, ccxt::binance::Binance);
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64
+}
+
+fn signed(recorder: common::Recorder, adjust: bool) -> MockBinance {
+    let mut exchange = MockBinance::new(Value::Json(json!({"apiKey": "key", "secret": "secret"})), recorder);
+    exchange.set_path(&["options".into(), "adjustForTimeDifference".into()], adjust.into());
+    exchange
+}
+
+fn timestamp_of(url: &str) -> i64 {
+    let query = url.split_once('?').unwrap().1;
+    query.split('&').find_map(|pair| pair.strip_prefix("timestamp=")).unwrap().parse().unwrap()
+}
+
+async fn private_get_account(exchange: &mut MockBinance) -> Value {
+    Binance::request(exchange, "account".into(), "private".into(), "GET".into(), Value::new_object(), Value::Undefined, Value::Undefined, Value::Undefined, Value::Undefined).await
+}
+
+#[test]
+fn milliseconds_applies_the_time_difference() {
+    let mut exchange = signed(common::Recorder::default(), false);
+    exchange.set_time_difference((now() + 60000).into());
+    assert!((exchange.time_difference() + 60000).abs() < 1000);
+    let milliseconds = exchange.milliseconds().unwrap_json().as_i64().unwrap();
+    assert!((milliseconds - now() - 60000).abs() < 1000);
+    assert!((exchange.seconds().unwrap_json().as_i64().unwrap() - milliseconds / 1000).abs() <= 1);
+    // the raw clock is left alone
+    assert!((exchange.msec().unwrap_json().as_i64().unwrap() - now()).abs() < 1000);
+}
+
+#[test]
+fn time_difference_is_due_once_per_interval() {
+    let mut exchange = signed(common::Recorder::default(), false);
+    assert!(!exchange.time_difference_due(false));
+    assert!(!exchange.time_difference_due(true));
+    let mut exchange = signed(common::Recorder::default(), true);
+    assert!(exchange.time_difference_due(false));
+    assert!(!exchange.time_difference_due(false));
+    assert!(exchange.time_difference_due(true));
+    exchange.set_path(&["options".into(), "timeDifferenceInterval".into()], 0.into());
+    assert!(exchange.time_difference_due(false));
+}
+
+#[test]
+fn nonces_never_repeat() {
+    assert_eq!(ccxt::nonce::next("test:sequential", 1000), 1000);
+    assert_eq!(ccxt::nonce::next("test:sequential", 1000), 1001);
+    assert_eq!(ccxt::nonce::next("test:sequential", 999), 1002);
+    assert_eq!(ccxt::nonce::next("test:sequential", 2000), 2000);
+    // keys are independent
+    assert_eq!(ccxt::nonce::next("test:other", 1000), 1000);
+    let threads: Vec<_> = (0..8).map(|_| std::thread::spawn(|| (0..100).map(|_| ccxt::nonce::next("test:concurrent", 5)).collect::<Vec<_>>())).collect();
+    let mut nonces: Vec<u64> = threads.into_iter().flat_map(|thread| thread.join().unwrap()).collect();
+    nonces.sort_unstable();
+    nonces.dedup();
+    assert_eq!(nonces.len(), 800);
+}
+
+#[tokio::test]
+async fn signed_requests_use_the_server_clock() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/v3/time", &format!(r#"{{"serverTime": {}}}"#, now() + 60000));
+    recorder.respond("GET", "/api/v3/account", r#"{"balances": []}"#);
+    let mut exchange = signed(recorder.clone(), true);
+    private_get_account(&mut exchange).await;
+    private_get_account(&mut exchange).await;
+    let requests = recorder.requests();
+    // the time difference is loaded before the first request only
+    assert_eq!(requests.iter().filter(|request| request.url.ends_with("/api/v3/time")).count(), 1);
+    assert!((timestamp_of(&recorder.last_request().url) - now() - 60000).abs() < 1000);
+}
+
+#[tokio::test]
+async fn timestamp_errors_are_retried_once_after_adjusting() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/api/v3/time", &format!(r#"{{"serverTime": {}}}"#, now()));
+    recorder.respond_once("GET", "/api/v3/account", 400, r#"{"code": -1021, "msg": "Timestamp for this request is outside of the recvWindow."}"#);
+    recorder.respond("GET", "/api/v3/account", r#"{"balances": []}"#);
+    let mut exchange = signed(recorder.clone(), true);
+    let response = private_get_account(&mut exchange).await;
+    assert_eq!(response.get("balances".into()), Value::new_array());
+    let urls: Vec<_> = recorder.requests().into_iter().map(|request| request.url.split('?').next().unwrap().to_owned()).collect();
+    assert_eq!(urls, [
+        "https://api.binance.com/api/v3/time",
+        "https://api.binance.com/api/v3/account",
+        "https://api.binance.com/api/v3/time",
+        "https://api.binance.com/api/v3/account",
+    ]);
+    // a second timestamp error in a row is raised
+    recorder.respond_once("GET", "/api/v3/account", 400, r#"{"code": -1021, "msg": "Timestamp for this request is outside of the recvWindow."}"#);
+    recorder.respond_once("GET", "/api/v3/account", 400, r#"{"code": -1021, "msg": "Timestamp for this request is outside of the recvWindow."}"#);
+    let error = catch(private_get_account(&mut exchange)).await.unwrap_err();
+    assert!(error.is("InvalidNonce"));
+}
+
+#[tokio::test]
+async fn timestamp_errors_are_raised_without_adjusting() {
+    let mut recorder = common::Recorder::default();
+    recorder.respond_with_status("GET", "/api/v3/account", 400, r#"{"code": -1021, "msg": "Timestamp for this request is outside of the recvWindow."}"#);
+    let mut exchange = signed(recorder.clone(), false);
+    let error = catch(private_get_account(&mut exchange)).await.unwrap_err();
+    assert!(error.is("InvalidNonce"));
+    assert_eq!(recorder.requests().len(), 1);
+}
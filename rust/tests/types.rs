@@ -0,0 +1,158 @@
+#![cfg(feature = "gateio")]
+
+mod common;
+
+use ccxt::exchange::Value;
+use ccxt::gate::{Gate, GateImpl};
+use ccxt::types::{
+    from_value, Balances, Decimal, MarginMode, Order, OrderSide, OrderStatus, OrderType, Position, PositionSide, Ticker,
+    TimeInForce, Trade, OHLCV,
+};
+use ccxt::UnifiedExchange;
+use serde_json::json;
+use std::str::FromStr;
+
+mock_exchange!(MockGateio, ccxt::gateio::Gateio, ccxt::gate::Gate => { Gate => [load_markets, fetch_currencies, fetch_order_book] });
+
+fn exchange() -> MockGateio {
+    let mut recorder = common::Recorder::default();
+    recorder.respond("GET", "/spot/currencies", include_str!("fixtures/gateio/currencies.json"));
+    recorder.respond("GET", "/spot/currency_pairs", include_str!("fixtures/gateio/currency_pairs.json"));
+    recorder.respond("GET", "/margin/currency_pairs", include_str!("fixtures/gateio/margin_currency_pairs.json"));
+    recorder.respond("GET", "/futures/usdt/contracts", include_str!("fixtures/gateio/usdt_contracts.json"));
+    recorder.respond("GET", "/futures/btc/contracts", "[]");
+    recorder.respond("GET", "/delivery/usdt/contracts", "[]");
+    recorder.respond("GET", "/delivery/btc/contracts", "[]");
+    recorder.respond("GET", "/spot/order_book", include_str!("fixtures/gateio/order_book.json"));
+    MockGateio::new(Value::Undefined, recorder)
+}
+
+fn decimal(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+#[tokio::test]
+async fn loads_typed_markets() {
+    let mut exchange = exchange();
+    let markets = exchange.load_markets_typed(Value::Undefined, Value::Undefined).await.unwrap();
+    let market = &markets["BTC/USDT"];
+    assert_eq!(market.id, "BTC_USDT");
+    assert_eq!((market.base.as_str(), market.quote.as_str()), ("BTC", "USDT"));
+    assert!(market.spot && !market.contract);
+    assert_eq!(market.taker, Some(decimal("0.002")));
+    assert!(market.info.is_object());
+    assert_eq!(markets.len(), 1);
+}
+
+#[tokio::test]
+async fn fetches_a_typed_order_book() {
+    let mut exchange = exchange();
+    let book = exchange.fetch_order_book_typed("BTC/USDT".into(), Value::Undefined, Value::Undefined).await.unwrap();
+    assert_eq!(book.symbol.as_deref(), Some("BTC/USDT"));
+    assert_eq!(book.timestamp, Some(1650560389264));
+    assert_eq!(book.bids[0].price, decimal("41069.27"));
+    assert_eq!(book.asks[1].amount, decimal("0.05"));
+}
+
+#[tokio::test]
+async fn returns_errors_instead_of_panicking() {
+    let mut exchange = exchange();
+    let error = exchange.fetch_order_book_typed("DOGE/BTC".into(), Value::Undefined, Value::Undefined).await.unwrap_err();
+    assert_eq!(error.kind, "BadSymbol");
+    assert!(error.is("BadRequest") && error.is("ExchangeError") && !error.is("NetworkError"));
+    let error = exchange.fetch_trades_typed("BTC/USDT".into(), Value::Undefined, Value::Undefined, Value::Undefined).await.unwrap_err();
+    assert_eq!(error.kind, "NotSupported");
+}
+
+#[test]
+fn decodes_tickers_trades_and_candles() {
+    let ticker: Ticker = from_value(&Value::Json(json!({
+        "symbol": "BTC/USDT", "timestamp": 1650560389264i64, "bid": 41069.27, "ask": "41069.28", "baseVolume": null, "info": {"last": "41069.27"},
+    }))).unwrap();
+    assert_eq!(ticker.bid, Some(decimal("41069.27")));
+    assert_eq!(ticker.ask, Some(decimal("41069.28")));
+    assert_eq!(ticker.base_volume, None);
+    assert_eq!(ticker.info["last"], "41069.27");
+
+    let trade: Trade = from_value(&Value::Json(json!({
+        "id": 1234, "symbol": "BTC/USDT", "side": "buy", "takerOrMaker": "taker", "price": "0.1", "amount": "3",
+        "fee": {"currency": "USDT", "cost": 0.0003},
+    }))).unwrap();
+    assert_eq!(trade.id.as_deref(), Some("1234"));
+    assert_eq!(trade.taker_or_maker.as_deref(), Some("taker"));
+    assert_eq!(trade.fee.unwrap().cost, Some(decimal("0.0003")));
+
+    let candles: Vec<OHLCV> = from_value(&Value::Json(json!([[1650560340000i64, "41000", "41100.5", "40990", "41069.27", "12.5"]]))).unwrap();
+    assert_eq!(candles[0].timestamp, 1650560340000);
+    assert_eq!(candles[0].high, Some(decimal("41100.5")));
+    assert_eq!(candles[0].volume, Some(decimal("12.5")));
+}
+
+#[test]
+fn decodes_safe_order_output() {
+    let mut exchange = GateImpl::new(Value::Undefined);
+    let order = Gate::safe_order(&mut exchange, Value::Json(json!({
+        "id": 118088152, "clientOrderId": "t-abc", "symbol": "BTC/USDT", "type": "limit", "side": "sell",
+        "status": "open", "price": "41069.28", "amount": "0.01", "filled": "0.004", "postOnly": true, "info": {},
+    })), Value::Undefined);
+    let order = Order::try_from(order).unwrap();
+    assert_eq!(order.id.as_deref(), Some("118088152"));
+    assert_eq!(order.r#type, Some(OrderType::Limit));
+    assert_eq!(order.side, Some(OrderSide::Sell));
+    assert_eq!(order.status, Some(OrderStatus::Open));
+    assert_eq!(order.time_in_force, Some(TimeInForce::PostOnly));
+    assert_eq!(order.remaining, Some(decimal("0.006")));
+    assert!(order.trades.is_empty());
+}
+
+#[test]
+fn decodes_safe_balance_output() {
+    let exchange = GateImpl::new(Value::Undefined);
+    let balance = Gate::safe_balance(&exchange, Value::Json(json!({
+        "info": [{"currency": "USDT", "available": "100.5", "locked": "20"}],
+        "USDT": {"free": "100.5", "used": "20"},
+        "BTC": {"free": "0", "used": "0", "total": "0"},
+    })));
+    let balances = Balances::try_from(balance).unwrap();
+    assert_eq!(balances.currencies.len(), 2);
+    assert_eq!(balances.get("USDT").unwrap().total, Some(decimal("120.5")));
+    assert!(balances.info.is_array());
+}
+
+#[test]
+fn balances_round_trip_with_currencies_at_the_top_level() {
+    let balances: Balances = from_value(&Value::Json(json!({
+        "info": {}, "timestamp": 1650560389264i64, "datetime": "2022-04-21T16:59:49.264Z",
+        "USDT": {"free": "100.5", "used": "20", "total": "120.5"},
+        "free": {"USDT": "100.5"}, "used": {"USDT": "20"}, "total": {"USDT": "120.5"},
+    }))).unwrap();
+    let json = serde_json::to_value(&balances).unwrap();
+    assert_eq!(json["USDT"]["total"], json!("120.5"));
+    assert_eq!(json["free"]["USDT"], json!("100.5"));
+    assert!(json.get("currencies").is_none());
+    assert_eq!(serde_json::from_value::<Balances>(json).unwrap(), balances);
+}
+
+#[test]
+fn decodes_null_fee_and_trade_lists_as_empty() {
+    let order: Order = from_value(&Value::Json(json!({"id": "1", "fees": null, "trades": null, "info": {}}))).unwrap();
+    assert!(order.fees.is_empty());
+    assert!(order.trades.is_empty());
+    let trade: Trade = from_value(&Value::Json(json!({"id": "2", "fees": null, "info": {}}))).unwrap();
+    assert!(trade.fees.is_empty());
+}
+
+#[test]
+fn decodes_positions_and_unknown_enum_values() {
+    let position: Position = from_value(&Value::Json(json!({
+        "symbol": "BTC/USDT:USDT", "side": "short", "marginMode": "cross", "contracts": 3, "entryPrice": "41000.1", "info": {},
+    }))).unwrap();
+    assert_eq!(position.side, Some(PositionSide::Short));
+    assert_eq!(position.margin_mode, Some(MarginMode::Cross));
+    assert_eq!(position.contracts, Some(decimal("3")));
+
+    assert_eq!("gtc".parse::<TimeInForce>().unwrap(), TimeInForce::GoodTillCancel);
+    let stop: OrderType = "stop_limit".parse().unwrap();
+    assert_eq!(stop, OrderType::Other("stop_limit".to_owned()));
+    assert_eq!(stop.to_string(), "stop_limit");
+}